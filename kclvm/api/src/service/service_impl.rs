@@ -311,6 +311,7 @@ impl KclvmServiceImpl {
             yaml_result: result.yaml_result,
             log_message: result.log_message,
             err_message: result.err_message,
+            source_map: result.source_map,
         })
     }
 
@@ -382,6 +383,7 @@ impl KclvmServiceImpl {
             yaml_result: result.yaml_result,
             log_message: result.log_message,
             err_message: result.err_message,
+            source_map: result.source_map,
         })
     }
 
@@ -576,6 +576,8 @@ pub struct SchemaStmt {
     pub is_mixin: bool,
     pub is_protocol: bool,
     pub args: Option<NodeRef<Arguments>>,
+    /// Type parameters of a generic schema e.g., `T` in `schema Page[type T]`.
+    pub type_params: Vec<NodeRef<String>>,
     pub mixins: Vec<NodeRef<Identifier>>,
     pub body: Vec<NodeRef<Stmt>>,
     pub decorators: Vec<NodeRef<CallExpr>>,
@@ -584,6 +586,15 @@ pub struct SchemaStmt {
 }
 
 impl SchemaStmt {
+    /// Get the type parameter names of the generic schema.
+    #[inline]
+    pub fn get_type_param_names(&self) -> Vec<String> {
+        self.type_params
+            .iter()
+            .map(|param| param.node.clone())
+            .collect()
+    }
+
     /// Get schema full attribute list (line, column, name) including
     /// un-exported attributes.
     pub fn get_left_identifier_list(&self) -> Vec<(u64, u64, String)> {
@@ -1625,6 +1636,7 @@ pub enum Type {
     Union(UnionType),
     Literal(LiteralType),
     Function(FunctionType),
+    Generic(GenericType),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -1633,6 +1645,13 @@ pub struct FunctionType {
     pub ret_ty: Option<NodeRef<Type>>,
 }
 
+/// GenericType is an instantiation of a generic schema e.g., `Page[int]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GenericType {
+    pub name: Identifier,
+    pub type_args: Vec<NodeRef<Type>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum BasicType {
     Bool,
@@ -1761,6 +1780,17 @@ impl ToString for Type {
                         to_str(&ret.node, w);
                     }
                 }
+                Type::Generic(v) => {
+                    w.push_str(&v.name.get_name());
+                    w.push('[');
+                    for (i, arg) in v.type_args.iter().enumerate() {
+                        if i > 0 {
+                            w.push_str(", ");
+                        }
+                        to_str(&arg.node, w);
+                    }
+                    w.push(']');
+                }
             }
         }
 
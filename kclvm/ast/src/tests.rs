@@ -272,6 +272,7 @@ fn gen_schema_stmt(count: i32) -> Vec<NodeRef<ast::Stmt>> {
             is_mixin: false,
             is_protocol: false,
             args: None,
+            type_params: vec![],
             mixins: vec![],
             body: vec![],
             decorators: vec![],
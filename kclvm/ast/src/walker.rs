@@ -324,6 +324,13 @@ pub trait MutSelfMutWalker<'ctx> {
                     .iter_mut()
                     .for_each(|ty| self.walk_type(&mut ty.node));
             }
+            ast::Type::Generic(generic_ty) => {
+                self.walk_identifier(&mut generic_ty.name);
+                generic_ty
+                    .type_args
+                    .iter_mut()
+                    .for_each(|ty| self.walk_type(&mut ty.node));
+            }
             _ => {}
         }
     }
@@ -171,9 +171,22 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             self.write("schema ");
         }
         self.write(&schema_stmt.name.node);
-        if let Some(args) = &schema_stmt.args {
+        if !schema_stmt.type_params.is_empty() || schema_stmt.args.is_some() {
             self.write("[");
-            self.walk_arguments(&args.node);
+            interleave!(
+                || self.write(COMMA_WHITESPACE),
+                |param: &ast::NodeRef<String>| {
+                    self.write("type ");
+                    self.write(&param.node);
+                },
+                schema_stmt.type_params
+            );
+            if let Some(args) = &schema_stmt.args {
+                if !schema_stmt.type_params.is_empty() {
+                    self.write(COMMA_WHITESPACE);
+                }
+                self.walk_arguments(&args.node);
+            }
             self.write("]");
         }
         if let Some(parent_name) = &schema_stmt.parent_name {
@@ -774,7 +787,16 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         for value in &joined_string.values {
             match &value.node {
                 ast::Expr::StringLit(string_lit) => {
-                    self.write(&string_lit.value.replace('\"', "\\\""));
+                    // The `$` is written as `$$` which is unescaped in the joined string.
+                    let mut value = string_lit
+                        .value
+                        .replace('\\', "\\\\")
+                        .replace('\"', "\\\"")
+                        .replace('$', "$$");
+                    if !joined_string.is_long_string {
+                        value = value.replace('\n', "\\n");
+                    }
+                    self.write(&value);
                 }
                 _ => self.expr(value),
             }
@@ -786,7 +808,13 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
         self.write("${");
         self.expr(&formatted_value.value);
         if let Some(spec) = &formatted_value.format_spec {
-            self.write(&format!(": {}", spec));
+            // Python format specs e.g., `.2f` are written verbatim since the
+            // leading spaces are significant.
+            if spec.starts_with('#') {
+                self.write(&format!(": {}", spec));
+            } else {
+                self.write(&format!(":{}", spec));
+            }
         }
         self.write("}");
     }
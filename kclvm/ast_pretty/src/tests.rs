@@ -6,7 +6,7 @@ use pretty_assertions::assert_eq;
 
 const FILE_INPUT_SUFFIX: &str = ".input";
 const FILE_OUTPUT_SUFFIX: &str = ".output";
-const TEST_CASES: &[&str; 16] = &[
+const TEST_CASES: &[&str; 17] = &[
     "arguments",
     "empty",
     "if_stmt",
@@ -22,6 +22,7 @@ const TEST_CASES: &[&str; 16] = &[
     "rule",
     "str",
     "type_alias",
+    "generic_schema",
     "unification",
 ];
 
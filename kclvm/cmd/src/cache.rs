@@ -0,0 +1,56 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_runner::cache::{ArtifactCache, KCL_ARTIFACT_CACHE_DIR_ENV_VAR};
+use std::io::Write;
+
+use crate::util::string_from_matches;
+
+/// Run the KCL cache command.
+pub fn cache_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let dir = match string_from_matches(matches, "cache_dir")
+        .or_else(|| std::env::var(KCL_ARTIFACT_CACHE_DIR_ENV_VAR).ok())
+    {
+        Some(dir) => dir,
+        None => {
+            return Err(anyhow::anyhow!(
+                "No artifact cache directory, please specify it with --cache_dir or the {} environment variable",
+                KCL_ARTIFACT_CACHE_DIR_ENV_VAR
+            ))
+        }
+    };
+    let cache = ArtifactCache::new(dir)?;
+    match matches.subcommand() {
+        Some(("stats", _)) => {
+            let stats = cache.stats()?;
+            writeln!(writer, "entries: {}", cache.entries()?.len())?;
+            writeln!(writer, "size: {}", cache.size()?)?;
+            writeln!(writer, "hits: {}", stats.hits)?;
+            writeln!(writer, "misses: {}", stats.misses)?;
+            writeln!(writer, "evictions: {}", stats.evictions)?;
+        }
+        Some(("clean", _)) => cache.clean()?,
+        Some(("prune", sub_matches)) => {
+            let max_size = sub_matches
+                .get_one::<String>("max_size")
+                .map(|size| size.parse::<u64>())
+                .transpose()?
+                .unwrap_or_default();
+            let evictions = cache.evict(max_size)?;
+            writeln!(writer, "evicted {} artifacts", evictions)?;
+        }
+        Some(("export", sub_matches)) => {
+            let output = string_from_matches(sub_matches, "output")
+                .ok_or(anyhow::anyhow!("No export output directory"))?;
+            let count = cache.export(output)?;
+            writeln!(writer, "exported {} artifacts", count)?;
+        }
+        Some(("import", sub_matches)) => {
+            let input = string_from_matches(sub_matches, "input")
+                .ok_or(anyhow::anyhow!("No import input directory"))?;
+            let count = cache.import(input)?;
+            writeln!(writer, "imported {} artifacts", count)?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_runner::ExecProgramArgs;
+use kclvm_tools::doc::{gen_doc, DocFormat};
+use std::io::Write;
+use std::path::Path;
+
+use crate::settings::must_build_settings;
+use crate::util::string_from_matches;
+
+/// Run the KCL doc command, which generates the documentation pages of the
+/// schemas in the package and all its dependencies.
+pub fn doc_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let format: DocFormat = match string_from_matches(matches, "format") {
+        Some(format) => format.parse()?,
+        None => DocFormat::default(),
+    };
+    let output = string_from_matches(matches, "output").unwrap_or_else(|| "docs".to_string());
+    let settings = must_build_settings(matches);
+    let args: ExecProgramArgs = settings.try_into()?;
+    let pages = gen_doc(
+        &args.get_files(),
+        Some(args.get_load_program_options()),
+        format,
+        Path::new(&output),
+    )?;
+    writeln!(writer, "generated {} pages in {}", pages.len(), output)?;
+    Ok(())
+}
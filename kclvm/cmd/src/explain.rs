@@ -0,0 +1,79 @@
+use anyhow::{anyhow, Result};
+use clap::ArgMatches;
+use kclvm_error::StringError;
+use kclvm_parser::ParseSession;
+use kclvm_runner::{exec_program, ExecProgramArgs};
+use kclvm_runtime::SourceMapEntry;
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::settings::must_build_settings;
+use crate::util::string_from_matches;
+
+/// Run the KCL explain command, which prints the source locations of the
+/// config entries that produced the output value at the given path, e.g.,
+/// `kcl explain app.replicas main.k prod.k`.
+pub fn explain_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let path = string_from_matches(matches, "path").unwrap_or_default();
+    let settings = must_build_settings(matches);
+    let output = settings.output();
+    let sess = Arc::new(ParseSession::default());
+    let mut args: ExecProgramArgs = settings.try_into()?;
+    args.source_map = true;
+    let result = match exec_program(sess.clone(), &args) {
+        Ok(result) => result,
+        Err(msg) => {
+            if !sess.0.diag_handler.has_errors()? {
+                sess.0.add_err(StringError(msg.to_string()))?;
+            }
+            sess.0.emit_stashed_diagnostics_and_abort()?;
+            return Ok(());
+        }
+    };
+    if !result.err_message.is_empty() {
+        if !sess.0.diag_handler.has_errors()? {
+            sess.0.add_err(StringError(result.err_message))?;
+        }
+        sess.0.emit_stashed_diagnostics_and_abort()?;
+    }
+    let source_map: Vec<SourceMapEntry> = if result.source_map.is_empty() {
+        vec![]
+    } else {
+        serde_json::from_str(&result.source_map)?
+    };
+    let entries = explain(&source_map, &path);
+    if entries.is_empty() {
+        return Err(anyhow!("no output value is found at the path '{}'", path));
+    }
+    let mut explanation = String::new();
+    for entry in entries {
+        explanation.push_str(&format!("{}\n", entry.path));
+        for source in &entry.sources {
+            explanation.push_str(&format!(
+                "  {} {}:{}\n",
+                source.op, source.filename, source.line
+            ));
+        }
+    }
+    match output {
+        Some(o) => std::fs::write(o, explanation)?,
+        None => write!(writer, "{}", explanation)?,
+    }
+    Ok(())
+}
+
+/// Select the source map entries of the path and all its sub paths.
+pub fn explain<'a>(source_map: &'a [SourceMapEntry], path: &str) -> Vec<&'a SourceMapEntry> {
+    source_map
+        .iter()
+        .filter(|entry| {
+            path.is_empty()
+                || entry.path == path
+                || entry
+                    .path
+                    .strip_prefix(path)
+                    .map(|rest| rest.starts_with('.') || rest.starts_with('['))
+                    .unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_runner::ExecProgramArgs;
+use kclvm_tools::typegen::{gen_types_from_files, GenTypesOptions, Language};
+use std::io::Write;
+
+use crate::settings::must_build_settings;
+use crate::util::string_from_matches;
+
+/// Run the KCL gen command, which generates the Rust, Go, TypeScript or Python
+/// type definitions of the schemas.
+pub fn gen_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let mut opts = GenTypesOptions::default();
+    if let Some(lang) = string_from_matches(matches, "lang") {
+        opts.lang = lang.parse::<Language>()?;
+    }
+    if let Some(package) = string_from_matches(matches, "go_package") {
+        opts.go_package = package;
+    }
+    let schema = string_from_matches(matches, "schema");
+    let settings = must_build_settings(matches);
+    let args: ExecProgramArgs = settings.try_into()?;
+    let code = gen_types_from_files(
+        &args.get_files(),
+        Some(args.get_load_program_options()),
+        schema.as_deref(),
+        &opts,
+    )?;
+    match string_from_matches(matches, "output") {
+        Some(output) => std::fs::write(output, code)?,
+        None => write!(writer, "{}", code)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_tools::import::{import_file, ImportFormat, ImportOptions};
+use std::io::Write;
+
+use crate::util::{bool_from_matches, string_from_matches};
+
+/// Run the KCL import command, which converts the YAML, JSON or TOML data
+/// file into a KCL module.
+pub fn import_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let input = string_from_matches(matches, "input").unwrap_or_default();
+    let format = string_from_matches(matches, "format")
+        .map(|format| format.parse::<ImportFormat>())
+        .transpose()?;
+    let opts = ImportOptions {
+        schema: string_from_matches(matches, "schema"),
+        infer_schema: bool_from_matches(matches, "infer_schema").unwrap_or_default(),
+        var_name: string_from_matches(matches, "var_name"),
+    };
+    let code = import_file(&input, format, &opts)?;
+    match string_from_matches(matches, "output") {
+        Some(output) => std::fs::write(output, code)?,
+        None => write!(writer, "{}", code)?,
+    }
+    Ok(())
+}
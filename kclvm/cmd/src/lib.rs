@@ -3,12 +3,19 @@
 #[macro_use]
 extern crate clap;
 
+pub mod cache;
+pub mod doc;
+pub mod explain;
 pub mod fmt;
+pub mod gen;
+pub mod import;
 pub mod lint;
 pub mod run;
 pub mod settings;
+pub mod test;
 pub(crate) mod util;
 pub mod vet;
+pub(crate) mod watch;
 
 #[cfg(test)]
 mod tests;
@@ -18,9 +25,15 @@ use clap::{ArgAction, Command};
 use std::io;
 
 use anyhow::Result;
+use cache::cache_command;
+use doc::doc_command;
+use explain::explain_command;
 use fmt::fmt_command;
+use gen::gen_command;
+use import::import_command;
 use lint::lint_command;
 use run::run_command;
+use test::test_command;
 use vet::vet_command;
 
 /// Run the KCL main command.
@@ -32,6 +45,12 @@ pub fn main(args: &[&str]) -> Result<()> {
         Some(("lint", sub_matches)) => lint_command(sub_matches),
         Some(("fmt", sub_matches)) => fmt_command(sub_matches),
         Some(("vet", sub_matches)) => vet_command(sub_matches),
+        Some(("test", sub_matches)) => test_command(sub_matches, &mut io::stdout()),
+        Some(("cache", sub_matches)) => cache_command(sub_matches, &mut io::stdout()),
+        Some(("import", sub_matches)) => import_command(sub_matches, &mut io::stdout()),
+        Some(("doc", sub_matches)) => doc_command(sub_matches, &mut io::stdout()),
+        Some(("explain", sub_matches)) => explain_command(sub_matches, &mut io::stdout()),
+        Some(("gen", sub_matches)) => gen_command(sub_matches, &mut io::stdout()),
         Some(("server", _)) => kclvm_api::service::jsonrpc::start_stdio_server(),
         Some(("version", _)) => {
             println!("{}", kclvm_version::get_version_info());
@@ -62,7 +81,15 @@ pub fn app() -> Command {
             .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
             .arg(arg!(target: --target <target> "Specify the target type"))
             .arg(arg!(recursive: -R --recursive "Compile the files directory recursively"))
-            .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
+            .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..))
+            .arg(arg!(cache_dir: --cache_dir <cache_dir> "Specify the content-addressed artifact cache directory"))
+            .arg(arg!(clock: --clock <clock> "Specify the fixed current time in the RFC 3339 format for the datetime functions"))
+            .arg(arg!(big_int: --big_int "Promote the integers which overflow 64 bits to big integers"))
+            .arg(arg!(random_seed: --random_seed <random_seed> "Specify the seed of the random and uuid functions").value_parser(clap::value_parser!(i64)))
+            .arg(arg!(proto_descriptor: --proto_descriptor <proto_descriptor> "Output the protobuf encoded result with the message descriptors in the FileDescriptorSet file"))
+            .arg(arg!(proto_message: --proto_message <proto_message> "Specify the fully qualified protobuf message name of the result, e.g., pkg.Config"))
+            .arg(arg!(proto_text: --proto_text "Output the protobuf text format instead of the binary format"))
+            .arg(arg!(watch: -w --watch "Re-run when the files of the compile unit change")),
         )
         .subcommand(
             Command::new("lint")
@@ -98,7 +125,101 @@ pub fn app() -> Command {
                 .arg(arg!(<kcl_file> "KCL file"))
                 .arg(arg!(schema: -d --schema <schema> "Iterate through subdirectories recursively").num_args(1..))
                 .arg(arg!(attribute_name: -n --attribute_name <attribute_name> "The attribute name for the data loading"))
-                .arg(arg!(format: --format <format> "Validation data file format, support YAML and JSON, default is JSON")),
+                .arg(arg!(format: --format <format> "Validation data file format, support YAML and JSON, default is JSON"))
+                .arg(arg!(watch: -w --watch "Re-validate when the data file or KCL file changes")),
+        )
+        .subcommand(
+            Command::new("test")
+                .about("Run the KCL test suites in the packages")
+                .arg(arg!([input] ... "Specify the package paths to test, default is the current directory").num_args(0..))
+                .arg(arg!(run: --run <run> "Only run the test cases matching the regular expression"))
+                .arg(arg!(fail_fast: --fail_fast "Stop the test suite on the first failure"))
+                .arg(arg!(watch: -w --watch "Re-run the tests when the package files change")),
+        )
+        .subcommand(
+            Command::new("cache")
+                .about("Manage the content-addressed artifact cache")
+                .arg(arg!(cache_dir: --cache_dir <cache_dir> "Specify the artifact cache directory"))
+                .subcommand(Command::new("stats").about("Show the cache size and hit/miss statistics"))
+                .subcommand(Command::new("clean").about("Remove all the cached artifacts"))
+                .subcommand(
+                    Command::new("prune")
+                        .about("Evict least recently used artifacts until the cache size is not greater than the max size")
+                        .arg(arg!(<max_size> "The max size in bytes")),
+                )
+                .subcommand(
+                    Command::new("export")
+                        .about("Export the cached artifacts into a directory")
+                        .arg(arg!(<output> "The export directory")),
+                )
+                .subcommand(
+                    Command::new("import")
+                        .about("Import the cached artifacts from an exported directory")
+                        .arg(arg!(<input> "The exported directory")),
+                ),
+        )
+        .subcommand(
+            Command::new("import")
+                .about("Convert YAML, JSON or TOML data into a KCL module, or generate KCL schemas from protobuf definitions")
+                .arg(arg!(<input> "The data file or the .proto file, support multi-document YAML streams"))
+                .arg(arg!(output: -o --output <output> "Specify the KCL output file path"))
+                .arg(arg!(format: --format <format> "Specify the data format, support yaml, json, toml and proto, default is the file extension"))
+                .arg(arg!(schema: -s --schema <schema> "Specify the schema name to instantiate the data"))
+                .arg(arg!(infer_schema: --infer_schema "Infer the draft schema definition from the data, the schema name is required"))
+                .arg(arg!(var_name: --var <var_name> "Specify the variable name of the imported data")),
+        )
+        .subcommand(
+            Command::new("doc")
+                .about("Generate the documentation of the schemas in the package and its dependencies")
+                .arg(arg!([input] ... "Specify the input files").num_args(0..))
+                .arg(arg!(output: -o --output <output> "Specify the documentation output directory, default is docs"))
+                .arg(arg!(format: --format <format> "Specify the documentation format, support markdown and html, default is markdown"))
+                .arg(arg!(setting: -Y --setting <setting> ... "Specify the input setting file").num_args(1..))
+                .arg(arg!(verbose: -v --verbose "Print test information verbosely").action(ArgAction::Count))
+                .arg(arg!(disable_none: -n --disable_none "Disable dumping None values"))
+                .arg(arg!(strict_range_check: -r --strict_range_check "Do perform strict numeric range checks"))
+                .arg(arg!(debug: -d --debug "Run in debug mode (for developers only)"))
+                .arg(arg!(sort_keys: -k --sort_keys "Sort result keys"))
+                .arg(arg!(arguments: -D --argument <arguments> ... "Specify the top-level argument").num_args(1..))
+                .arg(arg!(path_selector: -S --path_selector <path_selector> ... "Specify the path selector").num_args(1..))
+                .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
+                .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
+        )
+        .subcommand(
+            Command::new("explain")
+                .about("Explain which config entries produced the output value at the path")
+                .arg(arg!(<path> "The output value path, e.g., app.replicas or app.containers[0]"))
+                .arg(arg!([input] ... "Specify the input files to run").num_args(0..))
+                .arg(arg!(output: -o --output <output> "Specify the explanation output file path"))
+                .arg(arg!(setting: -Y --setting <setting> ... "Specify the input setting file").num_args(1..))
+                .arg(arg!(verbose: -v --verbose "Print test information verbosely").action(ArgAction::Count))
+                .arg(arg!(disable_none: -n --disable_none "Disable dumping None values"))
+                .arg(arg!(strict_range_check: -r --strict_range_check "Do perform strict numeric range checks"))
+                .arg(arg!(debug: -d --debug "Run in debug mode (for developers only)"))
+                .arg(arg!(sort_keys: -k --sort_keys "Sort result keys"))
+                .arg(arg!(arguments: -D --argument <arguments> ... "Specify the top-level argument").num_args(1..))
+                .arg(arg!(path_selector: -S --path_selector <path_selector> ... "Specify the path selector").num_args(1..))
+                .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
+                .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
+        )
+        .subcommand(
+            Command::new("gen")
+                .about("Generate the Rust, Go, TypeScript or Python type definitions of the schemas")
+                .arg(arg!([input] ... "Specify the input files").num_args(0..))
+                .arg(arg!(output: -o --output <output> "Specify the generated code output file path"))
+                .arg(arg!(lang: --lang <lang> "Specify the target language, support rust, go, typescript, python and pydantic, default is rust"))
+                .arg(arg!(schema: -s --schema <schema> "Specify the schema name to generate, default is all the schemas"))
+                .arg(arg!(go_package: --go_package <go_package> "Specify the package name of the generated Go code, default is types"))
+                .arg(arg!(setting: -Y --setting <setting> ... "Specify the input setting file").num_args(1..))
+                .arg(arg!(verbose: -v --verbose "Print test information verbosely").action(ArgAction::Count))
+                .arg(arg!(disable_none: -n --disable_none "Disable dumping None values"))
+                .arg(arg!(strict_range_check: -r --strict_range_check "Do perform strict numeric range checks"))
+                .arg(arg!(debug: -d --debug "Run in debug mode (for developers only)"))
+                .arg(arg!(sort_keys: -k --sort_keys "Sort result keys"))
+                .arg(arg!(arguments: -D --argument <arguments> ... "Specify the top-level argument").num_args(1..))
+                .arg(arg!(path_selector: -S --path_selector <path_selector> ... "Specify the path selector").num_args(1..))
+                .arg(arg!(overrides: -O --overrides <overrides> ... "Specify the configuration override path and value").num_args(1..))
+                .arg(arg!(package_map: -E --external <package_map> ... "Mapping of package name and path where the package is located").num_args(1..)),
         )
     .subcommand(Command::new("server").about("Start a rpc server for APIs"))
     .subcommand(Command::new("version").about("Show the KCL version"))
@@ -1,20 +1,34 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::ArgMatches;
 use kclvm_error::StringError;
-use kclvm_parser::ParseSession;
-use kclvm_runner::exec_program;
+use kclvm_parser::{KCLModuleCache, ParseSession};
+use kclvm_runner::{
+    exec_program, exec_program_with_module_cache, expand_files, ExecProgramArgs, ExecProgramResult,
+};
+use kclvm_runtime::{Context, ProtoDescriptors, ProtobufFormat, ValueRef};
 use std::io::Write;
+use std::path::PathBuf;
 use std::sync::Arc;
 
-use crate::settings::must_build_settings;
+use crate::settings::{build_settings, must_build_settings};
+use crate::util::{bool_from_matches, i64_from_matches, string_from_matches};
+use crate::watch::{is_config_changed, mod_files, watch_iterations, Iteration, Output};
 
 /// Run the KCL run command.
 pub fn run_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    if bool_from_matches(matches, "watch").unwrap_or_default() {
+        return watch_run_command(matches, writer);
+    }
     // Config settings building
     let settings = must_build_settings(matches);
     let output = settings.output();
     let sess = Arc::new(ParseSession::default());
-    match exec_program(sess.clone(), &settings.try_into()?) {
+    let mut args: ExecProgramArgs = settings.try_into()?;
+    args.artifact_cache_dir = string_from_matches(matches, "cache_dir");
+    args.clock = string_from_matches(matches, "clock").or(args.clock);
+    args.big_int = args.big_int || bool_from_matches(matches, "big_int").unwrap_or_default();
+    args.random_seed = i64_from_matches(matches, "random_seed").unwrap_or(args.random_seed);
+    match exec_program(sess.clone(), &args) {
         Ok(result) => {
             // Output log message
             if !result.log_message.is_empty() {
@@ -23,18 +37,11 @@ pub fn run_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()>
             // Output execute error message
             if !result.err_message.is_empty() {
                 if !sess.0.diag_handler.has_errors()? {
-                    sess.0.add_err(StringError(result.err_message))?;
+                    sess.0.add_err(StringError(result.err_message.clone()))?;
                 }
                 sess.0.emit_stashed_diagnostics_and_abort()?;
             }
-            if !result.yaml_result.is_empty() {
-                match output {
-                    Some(o) => std::fs::write(o, result.yaml_result)?,
-                    // [`println!`] is not a good way to output content to stdout,
-                    // using [`writeln`] can be better to redirect the output.
-                    None => writeln!(writer, "{}", result.yaml_result)?,
-                }
-            }
+            planned_output(matches, &result)?.write(writer, output.as_deref())?;
         }
         // Other error message
         Err(msg) => {
@@ -46,3 +53,135 @@ pub fn run_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()>
     }
     Ok(())
 }
+
+/// Returns the planned output of the execution result, which is encoded into protobuf
+/// with `--proto_descriptor`, or else the YAML result.
+fn planned_output(matches: &ArgMatches, result: &ExecProgramResult) -> Result<Output> {
+    match string_from_matches(matches, "proto_descriptor") {
+        Some(descriptor_set) => Ok(Output::Bytes(protobuf_output(
+            matches,
+            &descriptor_set,
+            &result.json_result,
+        )?)),
+        None => Ok(Output::Text(result.yaml_result.clone())),
+    }
+}
+
+/// Encode the planned JSON result into the protobuf binary or text format with
+/// the message descriptors in the descriptor set file.
+fn protobuf_output(
+    matches: &ArgMatches,
+    descriptor_set: &str,
+    json_result: &str,
+) -> Result<Vec<u8>> {
+    let message = string_from_matches(matches, "proto_message")
+        .ok_or_else(|| anyhow!("the protobuf message name is required, use --proto_message"))?;
+    let descriptors = ProtoDescriptors::from_bytes(&std::fs::read(descriptor_set)?)
+        .map_err(|err| anyhow!(err))?;
+    let format = if bool_from_matches(matches, "proto_text").unwrap_or_default() {
+        ProtobufFormat::Text
+    } else {
+        ProtobufFormat::Binary
+    };
+    let mut ctx = Context::new();
+    let mut value = ValueRef::from_json(&mut ctx, json_result)?;
+    // The JSON result of a config is the list of the planned documents.
+    if value.is_list() && value.len() == 1 {
+        value = value.list_get(0).unwrap();
+    }
+    value
+        .plan_to_protobuf(&ctx, &descriptors, &message, format)
+        .map_err(|err| anyhow!(err))
+}
+
+/// Run the KCL run command in the watch mode. The parsed modules of unchanged
+/// files are reused between executions.
+fn watch_run_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    watch_run(matches, writer, None)
+}
+
+/// Run the KCL run command in the watch mode and return after `max_iterations`
+/// executions if it is set.
+pub(crate) fn watch_run<W: Write>(
+    matches: &ArgMatches,
+    writer: &mut W,
+    max_iterations: Option<usize>,
+) -> Result<()> {
+    let module_cache = KCLModuleCache::default();
+    // The output file is the same as the normal run, which may be set in the setting files.
+    let output = build_settings(matches)
+        .ok()
+        .and_then(|settings| settings.output());
+    let run = |changed: &[PathBuf]| {
+        if let Ok(mut cache) = module_cache.write() {
+            if is_config_changed(changed) {
+                cache.clear();
+            } else {
+                for file in changed {
+                    cache.shift_remove(&file.to_string_lossy().to_string());
+                }
+            }
+        }
+        let mut iteration = Iteration::default();
+        let mut args = match build_args(matches) {
+            Ok(args) => args,
+            Err(err) => {
+                iteration.diagnostics.push(err.to_string());
+                return iteration;
+            }
+        };
+        args.artifact_cache_dir = string_from_matches(matches, "cache_dir");
+        let sess = Arc::new(ParseSession::default());
+        let err_message =
+            match exec_program_with_module_cache(sess.clone(), &args, module_cache.clone()) {
+                Ok(result) => {
+                    iteration.log_message = result.log_message.clone();
+                    if result.err_message.is_empty() {
+                        match planned_output(matches, &result) {
+                            Ok(output) => iteration.output = output,
+                            Err(err) => iteration.diagnostics.push(err.to_string()),
+                        }
+                    }
+                    result.err_message
+                }
+                Err(err) => err.to_string(),
+            };
+        if !err_message.is_empty() {
+            match sess.0.emit_all_diags_into_string() {
+                Ok(diags) if !diags.is_empty() => iteration
+                    .diagnostics
+                    .extend(diags.into_iter().filter_map(|diag| diag.ok())),
+                _ => iteration.diagnostics.push(err_message),
+            }
+        }
+        // Watch all the files loaded by the program including the imported packages.
+        if let Ok(cache) = module_cache.read() {
+            iteration.files.extend(cache.keys().map(PathBuf::from));
+        }
+        iteration
+    };
+    watch_iterations(writer, output, run, || input_files(matches), max_iterations)
+}
+
+/// Returns the setting files, the input files expanded by [expand_files] and their
+/// `kcl.mod` files, which are expanded again on every poll of the watch mode.
+fn input_files(matches: &ArgMatches) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Some(settings) = matches.get_many::<String>("setting") {
+        files.extend(settings.map(PathBuf::from));
+    }
+    if let Ok(k_files) = build_args(matches).and_then(|args| expand_files(&args)) {
+        files.extend(mod_files(&k_files));
+        files.extend(k_files.into_iter().map(PathBuf::from));
+    }
+    files
+}
+
+/// Builds the [ExecProgramArgs] from the setting files and the command line arguments.
+fn build_args(matches: &ArgMatches) -> Result<ExecProgramArgs> {
+    let mut args: ExecProgramArgs = build_settings(matches)?.try_into()?;
+    args.clock = string_from_matches(matches, "clock").or(args.clock);
+    args.big_int = args.big_int || bool_from_matches(matches, "big_int").unwrap_or_default();
+    args.random_seed = i64_from_matches(matches, "random_seed").unwrap_or(args.random_seed);
+    Ok(args)
+}
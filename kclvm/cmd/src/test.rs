@@ -0,0 +1,84 @@
+use anyhow::Result;
+use clap::ArgMatches;
+use kclvm_tools::testing::{load_test_suites, TestOptions, TestRun};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::util::{bool_from_matches, string_from_matches, strings_from_matches};
+use crate::watch::{mod_files, watch, Iteration, Output};
+
+/// Run the KCL test command.
+pub fn test_command<W: Write>(matches: &ArgMatches, writer: &mut W) -> Result<()> {
+    let pkgs = strings_from_matches(matches, "input").unwrap_or_else(|| vec![".".to_string()]);
+    let opts = TestOptions {
+        run_regexp: string_from_matches(matches, "run").unwrap_or_default(),
+        fail_fast: bool_from_matches(matches, "fail_fast").unwrap_or_default(),
+        ..Default::default()
+    };
+    if bool_from_matches(matches, "watch").unwrap_or_default() {
+        let test = |_: &[PathBuf]| {
+            let mut iteration = Iteration::default();
+            match run_tests(&pkgs, &opts, false, &mut iteration.files) {
+                Ok((report, _)) => iteration.output = Output::Text(report),
+                Err(err) => iteration.diagnostics.push(err.to_string()),
+            }
+            iteration
+        };
+        // The package directories are watched, whose modification time changes when
+        // a file is created or deleted, so there is nothing to scan on every poll.
+        return watch(writer, None, test, Vec::new);
+    }
+    let (report, passed) = run_tests(&pkgs, &opts, true, &mut vec![])?;
+    write!(writer, "{}", report)?;
+    if passed {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("Test failed"))
+    }
+}
+
+/// Runs the test suites in the packages and returns the test report and whether
+/// all the test cases are passed. The package directories and the files of the
+/// test suites are appended into `files`. Durations are omitted in the report
+/// when `with_duration` is false, so that the reports of the watch mode can be
+/// compared between executions.
+fn run_tests(
+    pkgs: &[String],
+    opts: &TestOptions,
+    with_duration: bool,
+    files: &mut Vec<PathBuf>,
+) -> Result<(String, bool)> {
+    let mut report = String::new();
+    let mut passed = true;
+    for pkg in pkgs {
+        for suite in load_test_suites(pkg, opts)? {
+            files.push(PathBuf::from(&suite.pkg));
+            files.extend(mod_files(&[suite.pkg.clone()]));
+            files.extend(suite.normal_files.iter().map(PathBuf::from));
+            files.extend(suite.test_files.iter().map(PathBuf::from));
+            let result = suite.run(opts)?;
+            for (name, info) in &result.info {
+                let status = if info.error.is_some() { "FAIL" } else { "PASS" };
+                if with_duration {
+                    report.push_str(&format!(
+                        "--- {}: {} ({}ms)\n",
+                        status,
+                        name,
+                        info.duration.as_millis()
+                    ));
+                } else {
+                    report.push_str(&format!("--- {}: {}\n", status, name));
+                }
+                if !info.log_message.is_empty() {
+                    report.push_str(&info.log_message);
+                }
+                if let Some(err) = &info.error {
+                    passed = false;
+                    report.push_str(&format!("{}\n", err));
+                }
+            }
+        }
+    }
+    report.push_str(if passed { "PASS\n" } else { "FAIL\n" });
+    Ok((report, passed))
+}
@@ -0,0 +1,3 @@
+[package]
+name = "test_data"
+
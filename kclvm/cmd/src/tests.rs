@@ -11,12 +11,15 @@ use kclvm_runner::{exec_program, MapErrorResult};
 
 use crate::{
     app,
+    explain::{explain, explain_command},
     fmt::fmt_command,
     lint::lint_command,
-    run::run_command,
+    run::{run_command, watch_run},
     settings::{build_settings, must_build_settings},
+    test::test_command,
     util::hashmaps_from_matches,
     vet::vet_command,
+    watch::{FileWatcher, Output, OutputDiff},
 };
 
 #[cfg(unix)]
@@ -231,6 +234,7 @@ fn test_run_command() {
     test_error_message_fuzz_matched();
     test_error_message_fuzz_unmatched();
     test_keyword_argument_error_message();
+    test_explain_cmd();
 }
 
 fn test_run_command_with_import() {
@@ -631,3 +635,185 @@ fn test_keyword_argument_error_message() {
         }
     }
 }
+
+#[test]
+fn test_watch_file_watcher() {
+    let dir = env::temp_dir().join(format!("kcl_watch_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("main.k");
+    let new_file = dir.join("new.k");
+    fs::write(&file, "a = 1").unwrap();
+
+    let mut watcher = FileWatcher::default();
+    watcher.watch(vec![file.clone(), new_file.clone()]);
+    assert!(watcher.changed_files().is_empty());
+    // Make sure the modification time is changed on coarse-grained file systems.
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    fs::write(&file, "a = 2").unwrap();
+    fs::write(&new_file, "b = 1").unwrap();
+    assert_eq!(
+        watcher.changed_files(),
+        vec![file.clone(), new_file.clone()]
+    );
+    assert!(watcher.changed_files().is_empty());
+    remove_file(&new_file).unwrap();
+    assert_eq!(watcher.changed_files(), vec![new_file.clone()]);
+    // The files added after the start are reported as created.
+    watcher.add(vec![file.clone()]);
+    assert!(watcher.changed_files().is_empty());
+    fs::write(&new_file, "b = 2").unwrap();
+    watcher.add(vec![new_file.clone()]);
+    assert_eq!(watcher.changed_files(), vec![new_file]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_watch_run_rerun_on_change() {
+    let dir = env::temp_dir().join(format!("kcl_watch_run_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("main.k"), "a = 1").unwrap();
+    let output_file = dir.join("output.yaml");
+    let pattern = dir.join("*.k");
+    let matches = app().get_matches_from(&[
+        ROOT_CMD,
+        "run",
+        pattern.to_str().unwrap(),
+        "-o",
+        output_file.to_str().unwrap(),
+        "--watch",
+    ]);
+    let matches = matches.subcommand_matches("run").unwrap();
+    let changer = {
+        let dir = dir.clone();
+        let output_file = output_file.clone();
+        std::thread::spawn(move || {
+            // Wait for the output of the first execution.
+            while !output_file.exists() {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            assert_eq!(fs::read_to_string(&output_file).unwrap(), "a: 1\n");
+            // Make sure the modification time is changed on coarse-grained file systems.
+            std::thread::sleep(std::time::Duration::from_millis(1100));
+            fs::write(dir.join("main.k"), "a = 2").unwrap();
+            // The new file is matched by the input file pattern.
+            fs::write(dir.join("b.k"), "b = 1").unwrap();
+        })
+    };
+    let mut buf = Vec::new();
+    watch_run(matches, &mut buf, Some(2)).unwrap();
+    changer.join().unwrap();
+    assert_eq!(fs::read_to_string(&output_file).unwrap(), "a: 2\nb: 1\n");
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("Watching for file changes..."));
+    assert!(output.contains(&format!("File changed: {}", dir.join("b.k").display())));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_watch_run_with_proto_descriptor() {
+    let dir = env::temp_dir().join(format!("kcl_watch_proto_test_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("main.k");
+    fs::write(&file, "a = 1").unwrap();
+    let matches = app().get_matches_from(&[
+        ROOT_CMD,
+        "run",
+        file.to_str().unwrap(),
+        "--proto_descriptor",
+        dir.join("descriptor.pb").to_str().unwrap(),
+        "--watch",
+    ]);
+    let matches = matches.subcommand_matches("run").unwrap();
+    // The watch iterations output the protobuf result like the normal run instead
+    // of the YAML result.
+    let mut buf = Vec::new();
+    watch_run(matches, &mut buf, Some(1)).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("the protobuf message name is required, use --proto_message"));
+    assert!(!output.contains("a: 1"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_watch_output_diff() {
+    let mut diff = OutputDiff::default();
+    let output = || Output::Text("a: 1".to_string());
+    assert_eq!(diff.output(output()), Some(output()));
+    assert_eq!(diff.output(output()), None);
+    diff.reset_output();
+    assert_eq!(diff.output(output()), Some(output()));
+    assert_eq!(
+        diff.output(Output::Bytes(b"a: 1".to_vec())),
+        Some(Output::Bytes(b"a: 1".to_vec()))
+    );
+
+    let diags = vec!["error 1".to_string(), "error 2".to_string()];
+    assert_eq!(diff.diagnostics(&diags), diags);
+    assert!(diff.diagnostics(&diags).is_empty());
+    assert_eq!(
+        diff.diagnostics(&["error 2".to_string(), "error 3".to_string()]),
+        vec!["error 3".to_string()]
+    );
+    assert!(diff.diagnostics(&[]).is_empty());
+}
+
+fn test_explain_cmd() {
+    let dir = Path::new(".").join("src").join("test_data").join("explain");
+    let base = dir.join("base.k");
+    let prod = dir.join("prod.k");
+    let matches = app().get_matches_from(&[
+        ROOT_CMD,
+        "explain",
+        "app.replicas",
+        base.to_str().unwrap(),
+        prod.to_str().unwrap(),
+    ]);
+    let matches = matches.subcommand_matches("explain").unwrap();
+    let mut buf = Vec::new();
+    explain_command(matches, &mut buf).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "app.replicas");
+    assert!(lines[1].starts_with("  = ") && lines[1].ends_with("base.k:3"));
+    assert!(lines[2].starts_with("  = ") && lines[2].ends_with("prod.k:2"));
+
+    let matches =
+        app().get_matches_from(&[ROOT_CMD, "explain", "app.not_found", base.to_str().unwrap()]);
+    let matches = matches.subcommand_matches("explain").unwrap();
+    assert!(explain_command(matches, &mut Vec::new()).is_err());
+}
+
+#[test]
+fn test_explain_sub_paths() {
+    let source_map: Vec<kclvm_runtime::SourceMapEntry> =
+        ["app", "app.name", "app.names", "apps[0]"]
+            .iter()
+            .map(|path| kclvm_runtime::SourceMapEntry {
+                path: path.to_string(),
+                sources: vec![],
+            })
+            .collect();
+    let paths = |path: &str| -> Vec<String> {
+        explain(&source_map, path)
+            .iter()
+            .map(|entry| entry.path.clone())
+            .collect()
+    };
+    assert_eq!(paths("app"), vec!["app", "app.name", "app.names"]);
+    assert_eq!(paths("app.name"), vec!["app.name"]);
+    assert_eq!(paths("apps"), vec!["apps[0]"]);
+    assert!(paths("ap").is_empty());
+}
+
+#[test]
+fn test_test_cmd() {
+    let pkg = Path::new(".").join("src").join("test_data").join("test");
+    let matches = app().get_matches_from(&[ROOT_CMD, "test", pkg.to_str().unwrap()]);
+    let matches = matches.subcommand_matches("test").unwrap();
+    let mut buf = Vec::new();
+    assert!(test_command(matches, &mut buf).is_err());
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("--- PASS: test_func_0"));
+    assert!(output.contains("--- FAIL: test_func_1"));
+    assert!(output.ends_with("FAIL\n"));
+}
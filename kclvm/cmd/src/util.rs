@@ -40,6 +40,11 @@ pub(crate) fn string_from_matches(matches: &ArgMatches, key: &str) -> Option<Str
     matches.get_one::<String>(key).map(|v| v.to_string())
 }
 
+#[inline]
+pub(crate) fn i64_from_matches(matches: &ArgMatches, key: &str) -> Option<i64> {
+    matches.get_one::<i64>(key).copied()
+}
+
 #[inline]
 pub(crate) fn bool_from_matches(matches: &ArgMatches, key: &str) -> Option<bool> {
     if matches.get_flag(key) == true {
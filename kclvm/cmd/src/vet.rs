@@ -1,11 +1,42 @@
 use anyhow::Result;
 use clap::ArgMatches;
 use kclvm_tools::vet::validator::{validate, LoaderKind, ValidateOption};
+use std::path::PathBuf;
 
-use crate::util::string_from_matches;
+use crate::util::{bool_from_matches, string_from_matches};
+use crate::watch::{mod_files, watch, Iteration, Output};
 
 /// Run the KCL vet command.
 pub fn vet_command(matches: &ArgMatches) -> Result<()> {
+    if bool_from_matches(matches, "watch").unwrap_or_default() {
+        let validate = |_: &[PathBuf]| {
+            let mut iteration = Iteration::default();
+            match validate_command(matches) {
+                Ok(()) => iteration.output = Output::Text("Validate success!".to_string()),
+                Err(err) => iteration.diagnostics.push(err.to_string()),
+            }
+            iteration
+        };
+        return watch(&mut std::io::stdout(), None, validate, || {
+            input_files(matches)
+        });
+    }
+    validate_command(matches)
+}
+
+/// Returns the data file, the KCL file and its `kcl.mod` file to watch.
+fn input_files(matches: &ArgMatches) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for key in ["data_file", "kcl_file"] {
+        if let Some(file) = string_from_matches(matches, key) {
+            files.extend(mod_files(&[file.clone()]));
+            files.push(PathBuf::from(file));
+        }
+    }
+    files
+}
+
+fn validate_command(matches: &ArgMatches) -> Result<()> {
     let data_file = matches.get_one::<String>("data_file").map(|f| f.as_str());
     let kcl_file = matches.get_one::<String>("kcl_file").map(|f| f.as_str());
     match (data_file, kcl_file) {
@@ -0,0 +1,258 @@
+//! The watch mode of the `run`, `test` and `vet` commands. It monitors the files of
+//! the compile unit, the setting files and the `kcl.mod` file, re-executes the command
+//! when any of them changes, and prints only the changed output and new diagnostics.
+//! The files of the compile unit are expanded again on every poll, so that the files
+//! created after the start e.g., the new files matched by a glob pattern are watched too.
+use anyhow::Result;
+use kclvm_config::modfile::{get_pkg_root, KCL_FILE_EXTENSION, KCL_MOD_FILE};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// The interval of polling the modification time of the watched files.
+const WATCH_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The output of one execution of a command.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Output {
+    /// The text output e.g., the YAML result of `kcl run`, which is followed by a new
+    /// line when written into the writer.
+    Text(String),
+    /// The binary output e.g., the protobuf result of `kcl run --proto_descriptor`,
+    /// which is written as it is.
+    Bytes(Vec<u8>),
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Output::Text(String::new())
+    }
+}
+
+impl Output {
+    /// Writes the output into the output file if it is set, or else into the writer.
+    /// The empty text output is skipped.
+    pub(crate) fn write<W: Write>(&self, writer: &mut W, output_file: Option<&str>) -> Result<()> {
+        match (self, output_file) {
+            (Output::Text(text), _) if text.is_empty() => {}
+            (Output::Text(text), Some(file)) => fs::write(file, text)?,
+            // [`println!`] is not a good way to output content to stdout,
+            // using [`writeln`] can be better to redirect the output.
+            (Output::Text(text), None) => writeln!(writer, "{}", text)?,
+            (Output::Bytes(bytes), Some(file)) => fs::write(file, bytes)?,
+            (Output::Bytes(bytes), None) => writer.write_all(bytes)?,
+        }
+        Ok(())
+    }
+}
+
+/// The result of one execution in the watch mode.
+#[derive(Debug, Default)]
+pub(crate) struct Iteration {
+    /// The log messages of the execution, which are printed on every execution.
+    pub log_message: String,
+    /// The output of the command, e.g., the YAML result of `kcl run`.
+    pub output: Output,
+    /// The diagnostics emitted by the command.
+    pub diagnostics: Vec<String>,
+    /// The files to watch before the next execution.
+    pub files: Vec<PathBuf>,
+}
+
+/// FileWatcher polls the modification time of the watched files to find the changed ones.
+#[derive(Debug, Default)]
+pub(crate) struct FileWatcher {
+    files: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+impl FileWatcher {
+    /// Replaces the watched files and records their current modification time.
+    pub(crate) fn watch<I: IntoIterator<Item = PathBuf>>(&mut self, files: I) {
+        self.files = files
+            .into_iter()
+            .map(|file| {
+                let modified = modified(&file);
+                (file, modified)
+            })
+            .collect();
+    }
+
+    /// Adds the files which are not watched yet. They are regarded as created since
+    /// the last check, so they are returned by the next [FileWatcher::changed_files].
+    pub(crate) fn add<I: IntoIterator<Item = PathBuf>>(&mut self, files: I) {
+        for file in files {
+            self.files.entry(file).or_insert(None);
+        }
+    }
+
+    /// Returns the files modified, created or deleted since the last check.
+    pub(crate) fn changed_files(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (file, last_modified) in self.files.iter_mut() {
+            let modified = modified(file);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(file.clone());
+            }
+        }
+        changed.sort();
+        changed
+    }
+
+    /// Blocks until any watched file changes and returns the changed files. The files
+    /// returned by `scan` are added on every poll. Changes within one more interval are
+    /// merged, since editors may write a file several times.
+    pub(crate) fn wait<S>(&mut self, scan: &mut S) -> Vec<PathBuf>
+    where
+        S: FnMut() -> Vec<PathBuf>,
+    {
+        loop {
+            thread::sleep(WATCH_INTERVAL);
+            self.add(scan());
+            let mut changed = self.changed_files();
+            if !changed.is_empty() {
+                thread::sleep(WATCH_INTERVAL);
+                for file in self.changed_files() {
+                    if !changed.contains(&file) {
+                        changed.push(file);
+                    }
+                }
+                return changed;
+            }
+        }
+    }
+}
+
+/// OutputDiff remembers the output and diagnostics of the last execution
+/// to report only the changed ones.
+#[derive(Debug, Default)]
+pub(crate) struct OutputDiff {
+    output: Option<Output>,
+    diagnostics: HashSet<String>,
+}
+
+impl OutputDiff {
+    /// Returns the output if it is different from the last one.
+    pub(crate) fn output(&mut self, output: Output) -> Option<Output> {
+        if self.output.as_ref() == Some(&output) {
+            None
+        } else {
+            self.output = Some(output.clone());
+            Some(output)
+        }
+    }
+
+    /// Forgets the last output, e.g., when the execution fails, so that the same
+    /// output is printed again after the errors are fixed.
+    pub(crate) fn reset_output(&mut self) {
+        self.output = None;
+    }
+
+    /// Returns the diagnostics which are not reported by the last execution.
+    pub(crate) fn diagnostics(&mut self, diagnostics: &[String]) -> Vec<String> {
+        let new_diagnostics = diagnostics
+            .iter()
+            .filter(|diag| !self.diagnostics.contains(*diag))
+            .cloned()
+            .collect();
+        self.diagnostics = diagnostics.iter().cloned().collect();
+        new_diagnostics
+    }
+}
+
+/// Executes `f` and re-executes it whenever the watched files change until the process
+/// is terminated. The changed files are passed to `f` to invalidate its caches. `scan`
+/// returns the input files of the command, which is called on every poll. When
+/// `output_file` is set, the changed output is written into it instead of the writer.
+pub(crate) fn watch<W, F, S>(
+    writer: &mut W,
+    output_file: Option<String>,
+    f: F,
+    scan: S,
+) -> Result<()>
+where
+    W: Write,
+    F: FnMut(&[PathBuf]) -> Iteration,
+    S: FnMut() -> Vec<PathBuf>,
+{
+    watch_iterations(writer, output_file, f, scan, None)
+}
+
+/// Executes the watch loop like [watch] and returns after `max_iterations` executions
+/// if it is set.
+pub(crate) fn watch_iterations<W, F, S>(
+    writer: &mut W,
+    output_file: Option<String>,
+    mut f: F,
+    mut scan: S,
+    max_iterations: Option<usize>,
+) -> Result<()>
+where
+    W: Write,
+    F: FnMut(&[PathBuf]) -> Iteration,
+    S: FnMut() -> Vec<PathBuf>,
+{
+    let mut watcher = FileWatcher::default();
+    let mut diff = OutputDiff::default();
+    let mut changed = vec![];
+    let mut iterations = 0;
+    loop {
+        let iteration = f(&changed);
+        iterations += 1;
+        write!(writer, "{}", iteration.log_message)?;
+        for diag in diff.diagnostics(&iteration.diagnostics) {
+            writeln!(writer, "{}", diag)?;
+        }
+        if iteration.diagnostics.is_empty() {
+            if let Some(output) = diff.output(iteration.output) {
+                output.write(writer, output_file.as_deref())?;
+            }
+        } else {
+            diff.reset_output();
+        }
+        if max_iterations.map(|max| iterations >= max).unwrap_or(false) {
+            return Ok(());
+        }
+        writeln!(writer, "Watching for file changes...")?;
+        writer.flush()?;
+        watcher.watch(iteration.files.into_iter().chain(scan()));
+        changed = watcher.wait(&mut scan);
+        for file in &changed {
+            writeln!(writer, "File changed: {}", file.display())?;
+        }
+    }
+}
+
+/// Returns the `kcl.mod` files of the packages that the files belong to.
+pub(crate) fn mod_files(files: &[String]) -> Vec<PathBuf> {
+    let mut mod_files = vec![];
+    for file in files {
+        if let Some(root) = get_pkg_root(file) {
+            let mod_file = Path::new(&root).join(KCL_MOD_FILE);
+            if !mod_files.contains(&mod_file) {
+                mod_files.push(mod_file);
+            }
+        }
+    }
+    mod_files
+}
+
+/// Returns whether any of the changed files is a `kcl.mod` or setting file which
+/// may change the package mapping, so that all the caches should be dropped.
+pub(crate) fn is_config_changed(changed: &[PathBuf]) -> bool {
+    changed.iter().any(|file| {
+        file.extension()
+            .map(|ext| ext != KCL_FILE_EXTENSION)
+            .unwrap_or(true)
+    })
+}
+
+#[inline]
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -38,6 +38,7 @@ use crate::codegen::{
 use crate::codegen::{CodeGenContext, GLOBAL_LEVEL};
 use crate::value;
 
+use super::utils;
 use super::OBJECT_FILE_SUFFIX;
 
 /// SCALAR_KEY denotes the temp scalar key for the global variable json plan process.
@@ -102,6 +103,8 @@ pub struct LLVMCodeGenContext<'ctx> {
     pub import_names: IndexMap<String, IndexMap<String, String>>,
     // No link mode
     pub no_link: bool,
+    // Source map mode
+    pub source_map: bool,
     pub modules: RefCell<HashMap<String, RefCell<Module<'ctx>>>>,
 }
 
@@ -1222,6 +1225,7 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         program: &'ctx ast::Program,
         import_names: IndexMap<String, IndexMap<String, String>>,
         no_link: bool,
+        source_map: bool,
     ) -> LLVMCodeGenContext<'ctx> {
         LLVMCodeGenContext {
             context,
@@ -1246,6 +1250,7 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
             backtrack_meta: RefCell::new(None),
             import_names,
             no_link,
+            source_map,
             modules: RefCell::new(HashMap::new()),
         }
     }
@@ -2095,6 +2100,11 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         let scope = scopes.last().expect(kcl_error::INTERNAL_ERROR_MSG);
         let scalars = scope.scalars.borrow();
         let globals = scope.variables.borrow();
+        let global_stmts = if self.source_map {
+            self.global_stmts(&current_pkgpath)
+        } else {
+            HashMap::new()
+        };
         // Construct a plan object.
         let global_dict = self.dict_value();
         // Deal scalars
@@ -2109,6 +2119,9 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
             }
             let value = self.builder.build_load(*ptr, "");
             let value_dict = self.dict_value();
+            if let Some(stmt) = global_stmts.get(name) {
+                utils::update_ctx_location(self, stmt);
+            }
             self.dict_safe_insert(value_dict, name.as_str(), value, 0, -1);
             self.dict_safe_insert(global_dict, SCALAR_KEY, value_dict, 0, -1);
         }
@@ -2125,6 +2138,37 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
         )
     }
 
+    /// Get the last top-level statement that sets each global variable in the package,
+    /// which is used as the source location of the global variable in the output.
+    fn global_stmts(&self, pkgpath: &str) -> HashMap<String, &'ctx ast::Node<ast::Stmt>> {
+        let mut global_stmts = HashMap::new();
+        for module in self.program.pkgs.get(pkgpath).into_iter().flatten() {
+            for stmt in &module.body {
+                match &stmt.node {
+                    ast::Stmt::Assign(assign_stmt) => {
+                        for target in &assign_stmt.targets {
+                            global_stmts.insert(target.node.names[0].node.clone(), stmt.as_ref());
+                        }
+                    }
+                    ast::Stmt::AugAssign(aug_assign_stmt) => {
+                        global_stmts.insert(
+                            aug_assign_stmt.target.node.names[0].node.clone(),
+                            stmt.as_ref(),
+                        );
+                    }
+                    ast::Stmt::Unification(unification_stmt) => {
+                        global_stmts.insert(
+                            unification_stmt.target.node.names[0].node.clone(),
+                            stmt.as_ref(),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+        global_stmts
+    }
+
     /// Insert a dict entry including key, value, op and insert_index into the dict.
     #[inline]
     fn dict_safe_insert(
@@ -45,7 +45,14 @@ pub fn emit_code(
         load_runtime(&context)
     };
     // Create a KCL LLVM code generator using the KCL AST and the LLVM module
-    let ctx = LLVMCodeGenContext::new(&context, module, program, import_names, opt.no_link);
+    let ctx = LLVMCodeGenContext::new(
+        &context,
+        module,
+        program,
+        import_names,
+        opt.no_link,
+        opt.source_map,
+    );
     // Generate user KCL code LLVM IR
     crate::codegen::emit_code(ctx, opt)
 }
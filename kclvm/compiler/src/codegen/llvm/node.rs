@@ -2365,6 +2365,17 @@ impl<'ctx> TypedResultWalker<'ctx> for LLVMCodeGenContext<'ctx> {
             fn_name = match spec.to_lowercase().as_str() {
                 "#json" => ApiFunc::kclvm_value_to_json_value,
                 "#yaml" => ApiFunc::kclvm_value_to_yaml_value,
+                _ if kclvm_runtime::is_valid_format_spec(spec) => {
+                    // Python format specs e.g., `${value:>10}` and `${value:.2f}`.
+                    return Ok(self.build_call(
+                        &ApiFunc::kclvm_value_to_str_value_with_spec.name(),
+                        &[
+                            self.current_runtime_ctx_ptr(),
+                            formatted_expr_value,
+                            self.native_global_string_value(spec),
+                        ],
+                    ));
+                }
                 _ => panic!("{}", kcl_error::INVALID_STR_INTERPOLATION_SPEC_MSG),
             };
         }
@@ -3076,6 +3087,10 @@ impl<'ctx> LLVMCodeGenContext<'ctx> {
                     Some(name) => self.string_value(name),
                     None => self.walk_expr(key)?,
                 };
+                // Record the entry location for the provenance source map.
+                if self.source_map {
+                    utils::update_ctx_location(self, item);
+                }
                 self.dict_insert_with_key_value(
                     config_value,
                     key,
@@ -64,3 +64,21 @@ pub fn update_ctx_current_line(gen: &LLVMCodeGenContext) {
         ],
     );
 }
+
+/// Update runtime context filename, line and column at once, e.g., before
+/// a config entry insertion whose source location will be recorded.
+pub fn update_ctx_location<'ctx, T>(gen: &'ctx LLVMCodeGenContext, node: &'ctx ast::Node<T>) {
+    if node.filename.is_empty() {
+        return update_ctx_line_col(gen, node);
+    }
+    *gen.current_line.borrow_mut() = node.line;
+    gen.build_void_call(
+        &ApiFunc::kclvm_context_set_kcl_location.name(),
+        &[
+            gen.current_runtime_ctx_ptr(),
+            gen.native_global_string_value(&node.filename),
+            gen.native_int_value(node.line as i32),
+            gen.native_int_value(0),
+        ],
+    );
+}
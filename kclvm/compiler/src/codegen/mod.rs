@@ -38,6 +38,8 @@ pub struct EmitOptions<'a> {
     pub emit_path: Option<&'a str>,
     /// no_link indicates whether to link the generated code of different KCL packages to the same module.
     pub no_link: bool,
+    /// source_map indicates whether to record the source locations of the output values.
+    pub source_map: bool,
 }
 
 /// Emit code with the options using CodeGenContext.
@@ -3,7 +3,7 @@
 use anyhow::Result;
 use kclvm_utils::path::PathPrefix;
 use serde::Deserialize;
-use std::{env, fs, io::Read, path::PathBuf};
+use std::{collections::HashMap, env, fs, io::Read, path::PathBuf};
 use toml;
 
 use crate::path::ModRelativePath;
@@ -64,6 +64,9 @@ pub struct KCLModFile {
     pub root_pkg: Option<String>,
     pub build: Option<KCLModFileBuildSection>,
     pub expected: Option<KCLModFileExpectedSection>,
+    /// Native plugins registered as `kcl_plugin.<name>` modules, which maps the
+    /// plugin name to the dynamic library path relative to the kcl.mod file.
+    pub plugins: Option<HashMap<String, String>>,
 }
 
 #[allow(dead_code)]
@@ -222,5 +225,9 @@ mod modfile_test {
                 .unwrap(),
             "v0.2.0"
         );
+        assert_eq!(
+            kcl_mod.plugins.as_ref().unwrap().get("hello").unwrap(),
+            "./plugins/libhello.so"
+        );
     }
 }
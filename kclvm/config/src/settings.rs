@@ -65,6 +65,12 @@ pub struct Config {
     /// kcl needs a mapping between the package name and the package path
     /// to determine the source code path corresponding to different version package.
     pub package_maps: Option<HashMap<String, String>>,
+    /// The fixed current time in the RFC 3339 format used by the datetime functions.
+    pub clock: Option<String>,
+    /// Whether to promote the int values which overflow i64 to big integers.
+    pub big_int: Option<bool>,
+    /// The seed of the random and uuid functions.
+    pub random_seed: Option<i64>,
 }
 
 impl SettingsFile {
@@ -83,6 +89,9 @@ impl SettingsFile {
                 sort_keys: Some(false),
                 include_schema_type_path: Some(false),
                 package_maps: Some(HashMap::default()),
+                clock: None,
+                big_int: Some(false),
+                random_seed: None,
             }),
             kcl_options: Some(vec![]),
         }
@@ -387,6 +396,9 @@ pub fn merge_settings(settings: &[SettingsFile]) -> SettingsFile {
                     kcl_cli_configs
                 );
                 set_if!(result_kcl_cli_configs, package_maps, kcl_cli_configs);
+                set_if!(result_kcl_cli_configs, clock, kcl_cli_configs);
+                set_if!(result_kcl_cli_configs, big_int, kcl_cli_configs);
+                set_if!(result_kcl_cli_configs, random_seed, kcl_cli_configs);
             }
         }
         if let Some(kcl_options) = &setting.kcl_options {
@@ -4,3 +4,5 @@ cached_pkg_prefix="pkg.path"
 [expected]
 kclvm_version="v0.3.0"
 kcl_plugin_version="v0.2.0"
+[plugins]
+hello="./plugins/libhello.so"
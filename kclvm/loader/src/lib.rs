@@ -125,7 +125,11 @@ pub fn load_packages(opts: &LoadPackageOptions) -> Result<Packages> {
             None,
         );
         let node_ty_map = prog_scope.node_ty_map;
-        let gs = Namer::find_symbols(&program, GlobalState::default());
+        let gs = Namer::find_symbols_with_plugin_stubs(
+            &program,
+            GlobalState::default(),
+            &prog_scope.plugin_stubs,
+        );
         let gs = AdvancedResolver::resolve_program(&program, gs, node_ty_map.clone());
         (program, prog_scope.handler.diagnostics.clone(), gs)
     } else {
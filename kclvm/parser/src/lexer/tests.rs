@@ -5,19 +5,17 @@ use compiler_base_error::diagnostic_handler::DiagnosticHandler;
 use compiler_base_session::Session;
 use compiler_base_span::{span::new_byte_pos, FilePathMapping, SourceMap};
 use expect_test::{expect, Expect};
-use kclvm_error::Handler;
 use kclvm_span::create_session_globals_then;
-use std::cell::RefCell;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 impl ParseSession {
     #[inline]
     pub(crate) fn with_source_map(sm: Arc<SourceMap>) -> Self {
-        Self(
-            Arc::new(Session::new(sm, Arc::new(DiagnosticHandler::default()))),
-            RefCell::new(Handler::default()),
-        )
+        Self::with_session(Arc::new(Session::new(
+            sm,
+            Arc::new(DiagnosticHandler::default()),
+        )))
     }
 }
 
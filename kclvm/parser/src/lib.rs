@@ -16,12 +16,13 @@ pub use crate::session::{ParseSession, ParseSessionRef};
 use compiler_base_macros::bug;
 use compiler_base_session::Session;
 use compiler_base_span::span::new_byte_pos;
+use compiler_base_span::SourceFile;
 use file_graph::FileGraph;
 use indexmap::{IndexMap, IndexSet};
 use kclvm_ast::ast;
 use kclvm_config::modfile::{get_vendor_home, KCL_FILE_EXTENSION, KCL_FILE_SUFFIX, KCL_MOD_FILE};
 use kclvm_error::diagnostic::{Diagnostic, Range};
-use kclvm_error::{ErrorKind, Message, Position, Style};
+use kclvm_error::{ErrorKind, Message, ParseError, Position, Style};
 use kclvm_sema::plugin::PLUGIN_MODULE_PREFIX;
 use kclvm_utils::pkgpath::parse_external_pkg_name;
 use kclvm_utils::pkgpath::rm_external_pkg_name;
@@ -33,7 +34,10 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
-use kclvm_span::create_session_globals_then;
+use kclvm_span::{
+    create_session_globals_then, current_session_globals, set_session_globals_then, BytePos, Span,
+};
+use rayon::prelude::*;
 
 #[derive(Default, Debug)]
 /// [`PkgInfo`] is some basic information about a kcl package.
@@ -159,6 +163,16 @@ pub fn parse_file_with_session(
     filename: &str,
     code: Option<String>,
 ) -> Result<ast::Module> {
+    parse_source_file(&sess, filename, code).map(|(m, _)| m)
+}
+
+/// Parse a KCL file to the AST module with the parse session, and return the module
+/// with the source file stored in the source map of the parse session.
+fn parse_source_file(
+    sess: &ParseSession,
+    filename: &str,
+    code: Option<String>,
+) -> Result<(ast::Module, Arc<SourceFile>)> {
     // Code source.
     let src = if let Some(s) = code {
         s
@@ -197,7 +211,7 @@ pub fn parse_file_with_session(
     m.pkg = kclvm_ast::MAIN_PKG.to_string();
     m.name = kclvm_ast::MAIN_PKG.to_string();
 
-    Ok(m)
+    Ok((m, sf))
 }
 
 /// Parse a KCL file to the AST module with the parse session and the global session
@@ -210,6 +224,57 @@ pub fn parse_file_with_global_session(
     create_session_globals_then(move || parse_file_with_session(sess, filename, code))
 }
 
+/// A file parsed by [parse_file_with_error_buffer] on a worker thread.
+struct ParsedFile {
+    /// Module AST
+    module: ast::Module,
+    /// The normalized file source.
+    src: String,
+    /// The start position of the file in the private source map.
+    start_pos: BytePos,
+    /// Buffered parse errors whose spans are relative to the private source map.
+    errors: Vec<ParseError>,
+}
+
+/// Parse a KCL file with a private parse session whose parse errors are buffered,
+/// it can be called on worker threads and the result is merged into the shared parse
+/// session by the loader.
+fn parse_file_with_error_buffer(filename: &str, code: Option<String>) -> Result<ParsedFile> {
+    let sess = ParseSession::with_error_buffer();
+    let (module, sf) = parse_source_file(&sess, filename, code)?;
+    Ok(ParsedFile {
+        module,
+        src: sf
+            .src
+            .as_ref()
+            .map(|src| src.to_string())
+            .unwrap_or_default(),
+        start_pos: sf.start_pos,
+        errors: sess.take_buffered_errors(),
+    })
+}
+
+/// Move the span of a parse error from the source file starting at `from` to the
+/// same source file starting at `to` in another source map.
+fn rebase_parse_error(err: ParseError, from: BytePos, to: BytePos) -> ParseError {
+    let rebase = |span: Span| Span::new(span.lo() - from + to, span.hi() - from + to);
+    match err {
+        ParseError::UnexpectedToken {
+            expected,
+            got,
+            span,
+        } => ParseError::UnexpectedToken {
+            expected,
+            got,
+            span: rebase(span),
+        },
+        ParseError::Message { message, span } => ParseError::Message {
+            message,
+            span: rebase(span),
+        },
+    }
+}
+
 /// Parse a source string to a expression. When input empty string, it will return [None].
 ///
 /// # Examples
@@ -350,19 +415,11 @@ impl Loader {
             let k_files = entry.get_k_files();
             let maybe_k_codes = entry.get_k_codes();
             // Load main package.
-            for (i, filename) in k_files.iter().enumerate() {
-                let mut m = if let Some(module_cache) = self.module_cache.as_ref() {
-                    let m = parse_file_with_session(
-                        self.sess.clone(),
-                        filename,
-                        maybe_k_codes[i].clone(),
-                    )?;
+            for mut m in self.parse_files(k_files, maybe_k_codes)? {
+                if let Some(module_cache) = self.module_cache.as_ref() {
                     let mut module_cache_ref = module_cache.write().unwrap();
-                    module_cache_ref.insert(filename.clone(), m.clone());
-                    m
-                } else {
-                    parse_file_with_session(self.sess.clone(), filename, maybe_k_codes[i].clone())?
-                };
+                    module_cache_ref.insert(m.filename.clone(), m.clone());
+                }
                 fix_rel_import_path(entry.path(), &mut m);
                 pkg_files.push(m);
             }
@@ -417,6 +474,43 @@ impl Loader {
         })
     }
 
+    /// Parse files concurrently, each file is parsed on a worker thread with its own parse
+    /// session. The results are merged into the shared parse session in the order of
+    /// `filenames`, so source positions and diagnostics are the same as a serial parse.
+    fn parse_files(
+        &self,
+        filenames: &[String],
+        k_codes: &[Option<String>],
+    ) -> Result<Vec<ast::Module>> {
+        let session_globals = current_session_globals();
+        let parsed_files: Vec<Result<ParsedFile>> = filenames
+            .par_iter()
+            .enumerate()
+            .map(|(i, filename)| {
+                set_session_globals_then(&session_globals, || {
+                    parse_file_with_error_buffer(filename, k_codes.get(i).cloned().flatten())
+                })
+            })
+            .collect();
+        let mut modules = Vec::with_capacity(parsed_files.len());
+        for parsed_file in parsed_files {
+            let parsed_file = parsed_file?;
+            let sf = self.sess.0.sm.new_source_file(
+                PathBuf::from(&parsed_file.module.filename).into(),
+                parsed_file.src,
+            );
+            for err in parsed_file.errors {
+                self.sess.add_parse_err(rebase_parse_error(
+                    err,
+                    parsed_file.start_pos,
+                    sf.start_pos,
+                ));
+            }
+            modules.push(parsed_file.module);
+        }
+        Ok(modules)
+    }
+
     /// [`find_packages`] will find the kcl package.
     /// If the package is found, the basic information of the package [`PkgInfo`] will be returned.
     ///
@@ -602,23 +696,43 @@ impl Loader {
             return Ok(Some(pkg_info));
         }
 
-        // Load packages
+        // Load packages, the files not found in the module cache are parsed concurrently.
         let mut pkg_files = Vec::new();
-        let k_files = pkg_info.k_files.clone();
-        for filename in k_files {
-            let mut m = if let Some(module_cache) = self.module_cache.as_ref() {
-                let module_cache_ref = module_cache.read().unwrap();
-                if let Some(module) = module_cache_ref.get(&filename) {
-                    module.clone()
-                } else {
-                    let m = parse_file_with_session(self.sess.clone(), &filename, None)?;
-                    drop(module_cache_ref);
-                    let mut module_cache_ref = module_cache.write().unwrap();
+        let mut cached_modules: HashMap<String, ast::Module> = HashMap::new();
+        if let Some(module_cache) = self.module_cache.as_ref() {
+            let module_cache_ref = module_cache.read().unwrap();
+            for filename in &pkg_info.k_files {
+                if let Some(module) = module_cache_ref.get(filename) {
+                    cached_modules.insert(filename.clone(), module.clone());
+                }
+            }
+        }
+        let uncached_files: Vec<String> = pkg_info
+            .k_files
+            .iter()
+            .filter(|filename| !cached_modules.contains_key(*filename))
+            .cloned()
+            .collect();
+        let mut parsed_modules = self
+            .parse_files(&uncached_files, &[])?
+            .into_iter()
+            .map(|m| (m.filename.clone(), m))
+            .collect::<HashMap<String, ast::Module>>();
+        if let Some(module_cache) = self.module_cache.as_ref() {
+            let mut module_cache_ref = module_cache.write().unwrap();
+            for filename in &uncached_files {
+                if let Some(m) = parsed_modules.get(filename) {
                     module_cache_ref.insert(filename.clone(), m.clone());
-                    m
                 }
-            } else {
-                parse_file_with_session(self.sess.clone(), &filename, None)?
+            }
+        }
+        for filename in &pkg_info.k_files {
+            let mut m = match cached_modules.remove(filename) {
+                Some(m) => m,
+                None => match parsed_modules.remove(filename) {
+                    Some(m) => m,
+                    None => continue,
+                },
             };
 
             m.pkg = pkg_info.pkg_path.clone();
@@ -653,6 +653,7 @@ impl<'a> Parser<'a> {
     ///   [LEFT_BRACKETS [schema_arguments] RIGHT_BRACKETS]
     ///   [LEFT_PARENTHESES identifier (COMMA identifier)* RIGHT_PARENTHESES]
     ///   [for_host] COLON NEWLINE [schema_body]
+    /// schema_arguments: (type_parameter | parameter) (COMMA (type_parameter | parameter))*
     fn parse_schema_stmt(&mut self, decorators: Option<Vec<NodeRef<CallExpr>>>) -> NodeRef<Stmt> {
         let token = self.token;
 
@@ -695,16 +696,20 @@ impl<'a> Parser<'a> {
             is_protocol = true;
         }
 
-        // schema Name[args...]
+        // schema Name[type T, args...]
+        let mut type_params = vec![];
         let args = if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
-            self.parse_parameters(
+            self.parse_parameters_with_type_params(
                 &[TokenKind::OpenDelim(DelimToken::Bracket)],
                 &[TokenKind::CloseDelim(DelimToken::Bracket)],
                 true,
+                Some(&mut type_params),
             )
         } else {
             None
         };
+        // A generic schema with only type parameters has no arguments.
+        let args = args.filter(|args| !args.node.args.is_empty() || type_params.is_empty());
 
         // schema Name [args...](Base)
         let parent_name = if let TokenKind::OpenDelim(DelimToken::Paren) = self.token.kind {
@@ -748,6 +753,7 @@ impl<'a> Parser<'a> {
                     is_mixin,
                     is_protocol,
                     args,
+                    type_params,
                     mixins: body.mixins,
                     body: body.body,
                     decorators,
@@ -767,6 +773,7 @@ impl<'a> Parser<'a> {
                     is_mixin,
                     is_protocol,
                     args,
+                    type_params,
                     mixins: vec![],
                     body: vec![],
                     decorators,
@@ -821,6 +828,22 @@ impl<'a> Parser<'a> {
         open_tokens: &[TokenKind],
         close_tokens: &[TokenKind],
         bump_close: bool,
+    ) -> Option<NodeRef<Arguments>> {
+        self.parse_parameters_with_type_params(open_tokens, close_tokens, bump_close, None)
+    }
+
+    /// Syntax:
+    /// parameters: (type_parameter | parameter) (COMMA (type_parameter | parameter))*
+    /// type_parameter: TYPE NAME
+    ///
+    /// Parses the parameters like [Parser::parse_parameters], and the type parameters
+    /// e.g., `type T` are collected into `type_params` if it is set.
+    pub(crate) fn parse_parameters_with_type_params(
+        &mut self,
+        open_tokens: &[TokenKind],
+        close_tokens: &[TokenKind],
+        bump_close: bool,
+        mut type_params: Option<&mut Vec<NodeRef<String>>>,
     ) -> Option<NodeRef<Arguments>> {
         let mut has_open_token = false;
 
@@ -868,6 +891,23 @@ impl<'a> Parser<'a> {
                 break;
             }
 
+            if let Some(type_params) = type_params.as_mut() {
+                if self.token.is_keyword(kw::Type) {
+                    self.bump_keyword(kw::Type);
+                    let name_pos = self.token;
+                    let name = self.parse_identifier().node.get_name();
+                    type_params.push(node_ref!(
+                        name,
+                        self.token_span_pos(name_pos, self.prev_token)
+                    ));
+                    if let TokenKind::Comma = self.token.kind {
+                        self.bump();
+                    }
+                    self.drop(marker);
+                    continue;
+                }
+            }
+
             let name_pos = self.token;
             let name = self.parse_identifier().node;
             let name_end = self.prev_token;
@@ -1105,6 +1145,7 @@ impl<'a> Parser<'a> {
             is_mixin: false,
             is_protocol: false,
             args: None,
+            type_params: Vec::new(),
             decorators: Vec::new(),
         }
     }
@@ -1556,6 +1597,34 @@ impl<'a> Parser<'a> {
             values: Vec::new(),
         };
 
+        /// Returns the byte index of the format spec interval token `:` e.g., `${value:>10}`,
+        /// which is outside of the brackets and string literals.
+        fn format_spec_colon_index(src: &str) -> Option<usize> {
+            let mut depth = 0;
+            let mut quote: Option<char> = None;
+            let mut escaped = false;
+            for (i, c) in src.char_indices() {
+                if let Some(q) = quote {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if c == q {
+                        quote = None;
+                    }
+                    continue;
+                }
+                match c {
+                    '"' | '\'' => quote = Some(c),
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth -= 1,
+                    ':' if depth == 0 => return Some(i),
+                    _ => {}
+                }
+            }
+            None
+        }
+
         fn parse_expr(this: &mut Parser, src: &str, start_pos: BytePos) -> NodeRef<Expr> {
             use crate::lexer::parse_token_streams;
             // The string interpolation end pos.
@@ -1568,6 +1637,24 @@ impl<'a> Parser<'a> {
                     Span::new(start_pos, end_pos),
                 );
             }
+            // Python format specs e.g., `>10` and `.2f` are not tokenized, and the
+            // specs starting with `#` e.g., `#json` are parsed as the spec tokens.
+            let mut raw_format_spec = None;
+            let src_len = src.len();
+            let src = match format_spec_colon_index(src) {
+                Some(index) if !src[index + 1..].trim_start().starts_with('#') => {
+                    let spec = &src[index + 1..];
+                    if !kclvm_runtime::is_valid_format_spec(spec) {
+                        this.sess.struct_span_error(
+                            &format!("invalid string interpolation format spec '{spec}'"),
+                            Span::new(start_pos, end_pos),
+                        );
+                    }
+                    raw_format_spec = Some(spec.to_string());
+                    &src[..index]
+                }
+                _ => src,
+            };
 
             // Expression start pos, and skip the start '${'.
             let start_pos = start_pos + new_byte_pos(2);
@@ -1590,7 +1677,7 @@ impl<'a> Parser<'a> {
             let mut formatted_value = FormattedValue {
                 is_long_string: false,
                 value: expr,
-                format_spec: None,
+                format_spec: raw_format_spec,
             };
 
             if let TokenKind::Colon = parser.token.kind {
@@ -1610,7 +1697,7 @@ impl<'a> Parser<'a> {
 
             // The token pair (lo, hi).
             let lo = start_pos;
-            let hi = start_pos + new_byte_pos(src.len() as u32);
+            let hi = start_pos + new_byte_pos(src_len as u32);
             // Bump the expression endline.
             parser.skip_newlines();
             // If there are still remaining tokens, it indicates that an
@@ -13,7 +13,7 @@ impl<'a> Parser<'a> {
     ///
     /// type: type_element (OR type_element)*
     /// type_element: schema_type | basic_type | compound_type | literal_type
-    /// schema_type: identifier
+    /// schema_type: identifier (LEFT_BRACKETS type (COMMA type)* RIGHT_BRACKETS)?
     /// basic_type: STRING_TYPE | INT_TYPE | FLOAT_TYPE | BOOL_TYPE | ANY_TYPE
     /// compound_type: list_type | dict_type
     /// list_type: LEFT_BRACKETS (type)? RIGHT_BRACKETS
@@ -107,7 +107,22 @@ impl<'a> Parser<'a> {
         if let TokenKind::Ident(_) = self.token.kind {
             let ident = self.parse_identifier_expr();
             let ident = expr_as!(ident, Expr::Identifier).unwrap();
-            let t = Type::Named(ident);
+            // generic schema type e.g., `Page[int]`
+            let t = if let TokenKind::OpenDelim(DelimToken::Bracket) = self.token.kind {
+                self.bump_token(TokenKind::OpenDelim(DelimToken::Bracket));
+                let mut type_args = vec![self.parse_type_annotation()];
+                while let TokenKind::Comma = self.token.kind {
+                    self.bump();
+                    type_args.push(self.parse_type_annotation());
+                }
+                self.bump_token(TokenKind::CloseDelim(DelimToken::Bracket));
+                Type::Generic(ast::GenericType {
+                    name: ident,
+                    type_args,
+                })
+            } else {
+                Type::Named(ident)
+            };
             return Box::new(Node::node(
                 t,
                 self.sess.struct_token_loc(token, self.prev_token),
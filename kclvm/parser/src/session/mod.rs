@@ -11,14 +11,39 @@ pub type ParseSessionRef = Arc<ParseSession>;
 
 /// ParseSession represents the data associated with a parse session such as the
 /// source map and the error handler.
+///
+/// When the error buffer is enabled, parse errors are only collected into the buffer
+/// and are not reported to the handlers, see [ParseSession::with_error_buffer].
 #[derive(Default, Clone)]
-pub struct ParseSession(pub Arc<Session>, pub RefCell<Handler>);
+pub struct ParseSession(
+    pub Arc<Session>,
+    pub RefCell<Handler>,
+    RefCell<Option<Vec<ParseError>>>,
+);
 
 impl ParseSession {
     /// New a parse session with the global session.
     #[inline]
     pub fn with_session(sess: Arc<Session>) -> Self {
-        Self(sess, RefCell::new(Handler::default()))
+        Self(sess, RefCell::new(Handler::default()), RefCell::new(None))
+    }
+
+    /// New a parse session which buffers parse errors instead of reporting them.
+    /// It is used to parse files on worker threads, and the buffered errors are
+    /// reported into the shared session later by [ParseSession::add_parse_err].
+    #[inline]
+    pub(crate) fn with_error_buffer() -> Self {
+        Self(
+            Arc::new(Session::default()),
+            RefCell::new(Handler::default()),
+            RefCell::new(Some(vec![])),
+        )
+    }
+
+    /// Take all buffered parse errors.
+    #[inline]
+    pub(crate) fn take_buffered_errors(&self) -> Vec<ParseError> {
+        self.2.borrow_mut().take().unwrap_or_default()
     }
 
     /// Lookup char pos from span.
@@ -62,7 +87,11 @@ impl ParseSession {
 
     /// Add a error into the session.
     #[inline]
-    fn add_parse_err(&self, err: ParseError) {
+    pub(crate) fn add_parse_err(&self, err: ParseError) {
+        if let Some(buffer) = self.2.borrow_mut().as_mut() {
+            buffer.push(err);
+            return;
+        }
         let add_error = || -> Result<()> {
             self.0.add_err(err.clone().into_diag(&self.0)?)?;
             self.1.borrow_mut().add_diagnostic(err.into_diag(&self.0)?);
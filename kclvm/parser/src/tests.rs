@@ -737,3 +737,52 @@ pub fn test_pkg_not_found_suggestion() {
         }
     }
 }
+
+#[test]
+fn test_parallel_parse_diagnostics_in_order() {
+    let testpath = PathBuf::from("./src/testdata/parallel_parse/main.k")
+        .canonicalize()
+        .unwrap()
+        .display()
+        .to_string();
+    let load = || {
+        let sess = Arc::new(ParseSession::default());
+        let result = load_program(sess.clone(), &[&testpath], None, None).unwrap();
+        (result, sess)
+    };
+    let (result, sess) = load();
+    let modules = result.program.pkgs.get("pkg").unwrap();
+    let filenames: Vec<String> = modules
+        .iter()
+        .map(|m| {
+            Path::new(&m.filename)
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(filenames, vec!["a1.k", "a2.k", "a3.k", "a4.k"]);
+    // Diagnostics are reported in the file order, as a serial parse does.
+    let error_files: Vec<String> = result
+        .errors
+        .iter()
+        .map(|diag| diag.messages[0].range.0.filename.clone())
+        .collect();
+    let mut expected_files = vec![testpath.clone()];
+    expected_files.extend(modules.iter().map(|m| m.filename.clone()));
+    let mut deduped_error_files = error_files.clone();
+    deduped_error_files.dedup();
+    assert_eq!(deduped_error_files, expected_files);
+    // Error positions are mapped into the shared source map.
+    for diag in &result.errors {
+        let pos = &diag.messages[0].range.0;
+        let sf = sess.0.sm.source_file_by_filename(&pos.filename).unwrap();
+        assert!(sf.count_lines() >= pos.line as usize);
+    }
+    let (other_result, _) = load();
+    assert_eq!(
+        result.errors.iter().collect::<Vec<_>>(),
+        other_result.errors.iter().collect::<Vec<_>>()
+    );
+}
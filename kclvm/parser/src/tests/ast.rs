@@ -33,6 +33,15 @@ schema TestBool:
         name not None, "we fail here"
         "####
 );
+parse_file_ast_json_snapshot!(
+    generic_schema_stmt,
+    "hello.k",
+    r####"
+schema Pair[type K, type V, name: str = "pair"]:
+    key: K
+    value: V
+        "####
+);
 parse_file_ast_json_snapshot!(assign_stmt, "hello.k", r####"a=123"####);
 parse_file_ast_json_snapshot!(
     if_stmt_0,
@@ -1,3 +1,4 @@
+use crate::cache::{package_keys, ArtifactCache};
 use anyhow::Result;
 use compiler_base_macros::bug;
 use indexmap::IndexMap;
@@ -7,6 +8,7 @@ use kclvm_compiler::codegen::{
     EmitOptions,
 };
 use kclvm_config::cache::{load_pkg_cache, save_pkg_cache, CacheOption, KCL_CACHE_PATH_ENV_VAR};
+use kclvm_parser::ParseSessionRef;
 use kclvm_sema::resolver::scope::ProgramScope;
 use std::{
     collections::HashMap,
@@ -54,12 +56,15 @@ pub(crate) trait LibAssembler {
     ///
     /// "object_file_path" is the full filename of the generated intermediate code file with suffix.
     /// e.g. code_file_path : "/test_dir/test_code_file.o"
+    ///
+    /// "source_map" indicates whether to record the source locations of the output values.
     fn assemble(
         &self,
         compile_prog: &Program,
         import_names: IndexMap<String, IndexMap<String, String>>,
         code_file: &str,
         code_file_path: &str,
+        source_map: bool,
     ) -> Result<String>;
 
     /// Clean cache lock files.
@@ -90,6 +95,7 @@ impl LibAssembler for KclvmLibAssembler {
         import_names: IndexMap<String, IndexMap<String, String>>,
         code_file: &str,
         object_file_path: &str,
+        source_map: bool,
     ) -> Result<String> {
         match &self {
             KclvmLibAssembler::LLVM => LlvmLibAssembler::default().assemble(
@@ -97,6 +103,7 @@ impl LibAssembler for KclvmLibAssembler {
                 import_names,
                 code_file,
                 object_file_path,
+                source_map,
             ),
         }
     }
@@ -145,6 +152,7 @@ impl LibAssembler for LlvmLibAssembler {
         import_names: IndexMap<String, IndexMap<String, String>>,
         code_file: &str,
         object_file_path: &str,
+        source_map: bool,
     ) -> Result<String> {
         // Clean the existed "*.o" object file.
         clean_path(object_file_path)?;
@@ -157,6 +165,7 @@ impl LibAssembler for LlvmLibAssembler {
                 from_path: None,
                 emit_path: Some(code_file),
                 no_link: true,
+                source_map,
             },
         )
         .map_err(|e| {
@@ -196,6 +205,10 @@ pub(crate) struct KclvmAssembler {
     single_file_assembler: KclvmLibAssembler,
     target: String,
     external_pkgs: HashMap<String, String>,
+    artifact_cache: Option<ArtifactCache>,
+    artifact_flags: String,
+    sess: ParseSessionRef,
+    source_map: bool,
 }
 
 impl KclvmAssembler {
@@ -216,9 +229,38 @@ impl KclvmAssembler {
             single_file_assembler,
             target: env!("KCLVM_DEFAULT_TARGET").to_string(),
             external_pkgs,
+            artifact_cache: None,
+            artifact_flags: String::new(),
+            sess: ParseSessionRef::default(),
+            source_map: false,
         }
     }
 
+    /// Use the content-addressed artifact cache to read and write the object files
+    /// of all packages including the main package. `flags` are the [crate::ExecProgramArgs]
+    /// flags which are a part of the cache key, and `sess` holds the sources of the
+    /// program which are hashed into the cache key.
+    #[inline]
+    pub(crate) fn with_artifact_cache(
+        mut self,
+        cache: Option<ArtifactCache>,
+        flags: String,
+        sess: ParseSessionRef,
+    ) -> Self {
+        self.artifact_cache = cache;
+        self.artifact_flags = flags;
+        self.sess = sess;
+        self
+    }
+
+    /// Record the source locations of the output values in the generated code,
+    /// which are used to build the source map.
+    #[inline]
+    pub(crate) fn with_source_map(mut self, source_map: bool) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
     /// Clean up the path of the dynamic link libraries generated.
     /// It will remove the file in "file_path" and all the files in file_path end with ir code file suffix.
     #[inline]
@@ -277,6 +319,15 @@ impl KclvmAssembler {
             &self.single_file_assembler.get_code_file_suffix(),
         )?;
         let cache_dir = self.load_cache_dir(&self.program.root)?;
+        let artifact_keys = self.artifact_cache.as_ref().map(|_| {
+            package_keys(
+                &self.sess,
+                &self.program,
+                &self.scope.import_names,
+                &self.target,
+                &self.artifact_flags,
+            )
+        });
         let mut compile_progs: IndexMap<
             String,
             (
@@ -328,62 +379,89 @@ impl KclvmAssembler {
                 file_lock.lock()?;
 
                 let root = &compile_prog.root;
-                // The main package does not perform cache reading and writing,
-                // and other packages perform read and write caching. Because
-                // KCL supports multi-file compilation, it is impossible to
-                // specify a standard entry for these multi-files and cannot
-                // be shared, so the cache of the main package is not read and
-                // written.
-                let file_path = if is_main_pkg {
-                    // generate dynamic link library for single file kcl program
-                    assembler.assemble(&compile_prog, import_names, &code_file, &code_file_path)?
-                } else {
-                    // Read the lib path cache
-                    let file_relative_path: Option<String> = load_pkg_cache(
-                        root,
-                        &target,
-                        &pkgpath,
-                        CacheOption::default(),
-                        &self.external_pkgs,
-                    );
-                    let file_abs_path = match file_relative_path {
-                        Some(file_relative_path) => {
-                            let path = if file_relative_path.starts_with('.') {
-                                file_relative_path.replacen('.', root, 1)
-                            } else {
-                                file_relative_path
-                            };
-                            if Path::new(&path).exists() {
-                                Some(path)
-                            } else {
-                                None
-                            }
-                        }
-                        None => None,
-                    };
-                    match file_abs_path {
-                        Some(path) => path,
-                        None => {
-                            // Generate the object file for single file kcl program.
+                let artifact_key = artifact_keys.as_ref().and_then(|keys| keys.get(&pkgpath));
+                // The content-addressed artifact cache is keyed by the package sources
+                // instead of the file path, so the main package can be cached as well.
+                let file_path =
+                    if let (Some(cache), Some(key)) = (&self.artifact_cache, artifact_key) {
+                        if cache.get(key, Path::new(&code_file_path))? {
+                            code_file_path.clone()
+                        } else {
                             let file_path = assembler.assemble(
                                 &compile_prog,
                                 import_names,
                                 &code_file,
                                 &code_file_path,
+                                self.source_map,
                             )?;
-                            let lib_relative_path = file_path.replacen(root, ".", 1);
-                            let _ = save_pkg_cache(
-                                root,
-                                &target,
-                                &pkgpath,
-                                lib_relative_path,
-                                CacheOption::default(),
-                                &self.external_pkgs,
-                            );
+                            cache.put(key, Path::new(&file_path))?;
                             file_path
                         }
                     }
-                };
+                    // The main package does not perform cache reading and writing,
+                    // and other packages perform read and write caching. Because
+                    // KCL supports multi-file compilation, it is impossible to
+                    // specify a standard entry for these multi-files and cannot
+                    // be shared, so the cache of the main package is not read and
+                    // written. The package cache does not record whether the source
+                    // locations are generated, so it is skipped for the source map as well.
+                    else if is_main_pkg || self.source_map {
+                        // generate dynamic link library for single file kcl program
+                        assembler.assemble(
+                            &compile_prog,
+                            import_names,
+                            &code_file,
+                            &code_file_path,
+                            self.source_map,
+                        )?
+                    } else {
+                        // Read the lib path cache
+                        let file_relative_path: Option<String> = load_pkg_cache(
+                            root,
+                            &target,
+                            &pkgpath,
+                            CacheOption::default(),
+                            &self.external_pkgs,
+                        );
+                        let file_abs_path = match file_relative_path {
+                            Some(file_relative_path) => {
+                                let path = if file_relative_path.starts_with('.') {
+                                    file_relative_path.replacen('.', root, 1)
+                                } else {
+                                    file_relative_path
+                                };
+                                if Path::new(&path).exists() {
+                                    Some(path)
+                                } else {
+                                    None
+                                }
+                            }
+                            None => None,
+                        };
+                        match file_abs_path {
+                            Some(path) => path,
+                            None => {
+                                // Generate the object file for single file kcl program.
+                                let file_path = assembler.assemble(
+                                    &compile_prog,
+                                    import_names,
+                                    &code_file,
+                                    &code_file_path,
+                                    self.source_map,
+                                )?;
+                                let lib_relative_path = file_path.replacen(root, ".", 1);
+                                let _ = save_pkg_cache(
+                                    root,
+                                    &target,
+                                    &pkgpath,
+                                    lib_relative_path,
+                                    CacheOption::default(),
+                                    &self.external_pkgs,
+                                );
+                                file_path
+                            }
+                        }
+                    };
                 file_lock.unlock()?;
                 lib_paths.push(file_path);
             };
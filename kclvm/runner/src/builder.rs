@@ -0,0 +1,185 @@
+//! The typed API to run KCL programs from Rust hosts.
+//!
+//! [ProgramBuilder] collects the files, code, arguments and overrides of a program
+//! and executes it into the plain data of its JSON result, which can be deserialized
+//! into the host types with serde, and host values can be passed into the program as
+//! top-level arguments, e.g.,
+//!
+//! ```no_run
+//! use kclvm_runner::ProgramBuilder;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize)]
+//! struct Env {
+//!     replicas: i64,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     replicas: i64,
+//! }
+//!
+//! let config: Config = ProgramBuilder::new()
+//!     .code("main.k", "env = option(\"env\")\nreplicas = env.replicas")
+//!     .argument_value("env", &Env { replicas: 3 })
+//!     .unwrap()
+//!     .exec_into()
+//!     .unwrap();
+//! assert_eq!(config.replicas, 3);
+//! ```
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use kclvm_ast::ast;
+use kclvm_parser::ParseSession;
+use kclvm_query::r#override::parse_override_spec;
+use kclvm_runtime::{from_value, to_value, Context, ValueRef};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::plugin::PluginFunction;
+use crate::{exec_program, ExecProgramArgs};
+
+/// A builder of the KCL program execution.
+#[derive(Debug, Default, Clone)]
+pub struct ProgramBuilder {
+    args: ExecProgramArgs,
+}
+
+impl ProgramBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the builder from the execution arguments, e.g., the arguments
+    /// loaded from a settings file.
+    pub fn from_args(args: ExecProgramArgs) -> Self {
+        Self { args }
+    }
+
+    /// Returns the execution arguments built so far.
+    pub fn args(&self) -> &ExecProgramArgs {
+        &self.args
+    }
+
+    /// Set the work directory used to resolve the relative file paths.
+    pub fn work_dir(mut self, work_dir: impl Into<String>) -> Self {
+        self.args.work_dir = Some(work_dir.into());
+        self
+    }
+
+    /// Add the KCL file or the file path pattern into the program.
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.args.k_filename_list.push(file.into());
+        self
+    }
+
+    /// Add the KCL source code into the program as the file `filename`.
+    pub fn code(mut self, filename: impl Into<String>, code: impl Into<String>) -> Self {
+        // The code list is matched with the leading files of the file list, so
+        // the code files are placed before the files loaded from the file system.
+        let index = self.args.k_code_list.len();
+        self.args.k_filename_list.insert(index, filename.into());
+        self.args.k_code_list.push(code.into());
+        self
+    }
+
+    /// Add the top-level argument like `-D name=value`, the value is a JSON
+    /// string or a plain string.
+    pub fn argument(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.args.args.push(ast::CmdArgSpec {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Add the serializable host value as the top-level argument, which can be
+    /// read with `option(name)` in the program.
+    pub fn argument_value<T: Serialize + ?Sized>(
+        self,
+        name: impl Into<String>,
+        value: &T,
+    ) -> Result<Self> {
+        let value = to_value(value)?;
+        Ok(self.argument(name, value.to_json_string()))
+    }
+
+    /// Add the override spec like `-O pkgpath:path.to.field=value`.
+    pub fn override_spec(mut self, spec: &str) -> Result<Self> {
+        self.args.overrides.push(parse_override_spec(spec)?);
+        Ok(self)
+    }
+
+    /// Add the external package like `-E name=path`.
+    pub fn external_pkg(mut self, name: impl Into<String>, path: impl Into<String>) -> Self {
+        self.args.external_pkgs.push(ast::CmdExternalPkgSpec {
+            pkg_name: name.into(),
+            pkg_path: path.into(),
+        });
+        self
+    }
+
+    /// Select the output value with the path like `-S path`.
+    pub fn path_selector(mut self, path: impl Into<String>) -> Self {
+        self.args.path_selector.push(path.into());
+        self
+    }
+
+    pub fn sort_keys(mut self, sort_keys: bool) -> Self {
+        self.args.sort_keys = sort_keys;
+        self
+    }
+
+    pub fn disable_none(mut self, disable_none: bool) -> Self {
+        self.args.disable_none = disable_none;
+        self
+    }
+
+    pub fn strict_range_check(mut self, strict_range_check: bool) -> Self {
+        self.args.strict_range_check = strict_range_check;
+        self
+    }
+
+    /// Register the Rust function of the native plugin `plugin` for the program,
+    /// which can be called with `kcl_plugin.<plugin>.<name>` in the KCL code.
+    pub fn plugin_function<F>(mut self, plugin: &str, func: PluginFunction, handler: F) -> Self
+    where
+        F: Fn(&[ValueRef]) -> Result<ValueRef> + Send + Sync + 'static,
+    {
+        self.args.plugins.register_function(plugin, func, handler);
+        self
+    }
+
+    /// Execute the program and returns the value decoded from its planned JSON
+    /// result. A single document is returned as it is and multiple documents are
+    /// returned as a list. Runtime errors are returned as errors.
+    ///
+    /// The value is not typed: the program runs in its own runtime context and only
+    /// the JSON result is returned, so schema instances are plain dicts, and number
+    /// multipliers and decimals are strings.
+    pub fn exec_json(&self) -> Result<ValueRef> {
+        let mut args = self.args.clone();
+        args.disable_yaml_result = true;
+        let result = exec_program(Arc::new(ParseSession::default()), &args)?;
+        if !result.err_message.is_empty() {
+            bail!(result.err_message);
+        }
+        if result.json_result.is_empty() {
+            return Ok(ValueRef::dict(None));
+        }
+        let mut ctx = Context::new();
+        let value = ValueRef::from_json(&mut ctx, &result.json_result)
+            .map_err(|err| anyhow!("invalid program result: {err}"))?;
+        // The JSON result of a config is the list of the planned documents.
+        if value.is_list() && value.len() == 1 {
+            return Ok(value.list_get(0).unwrap());
+        }
+        Ok(value)
+    }
+
+    /// Execute the program and deserialize the JSON result into the host type.
+    pub fn exec_into<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(from_value(&self.exec_json()?)?)
+    }
+}
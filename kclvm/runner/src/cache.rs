@@ -0,0 +1,366 @@
+//! The content-addressed artifact cache stores the compiled object file of each
+//! package under a key computed from the package sources and paths, the compiler
+//! version, the target and the [ExecProgramArgs] flags. The object files embed the
+//! source file paths e.g., in the error messages, so the cache directory can be
+//! shared between machines which check out the sources at the same path, e.g., CI runners.
+//!
+//! The cache directory layout is:
+//!
+//! ```text
+//! <cache_dir>
+//! +-- objects
+//! |   +-- 3f
+//! |   |   +-- 3f5d...e1.o
+//! +-- stats.json
+//! +-- stats.json.lock
+//! ```
+use anyhow::Result;
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+use indexmap::IndexMap;
+use kclvm_ast::ast;
+use kclvm_ast_pretty::print_ast_module;
+use kclvm_parser::ParseSession;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::SystemTime,
+};
+
+use crate::ExecProgramArgs;
+
+/// The environment variable of the artifact cache directory.
+pub const KCL_ARTIFACT_CACHE_DIR_ENV_VAR: &str = "KCL_ARTIFACT_CACHE_DIR";
+/// The environment variable of the max size in bytes of the artifact cache directory.
+pub const KCL_ARTIFACT_CACHE_MAX_SIZE_ENV_VAR: &str = "KCL_ARTIFACT_CACHE_MAX_SIZE";
+
+const OBJECTS_DIR: &str = "objects";
+const STATS_FILE: &str = "stats.json";
+const LOCK_SUFFIX: &str = ".lock";
+const ARTIFACT_SUFFIX: &str = ".o";
+
+/// The counter of the temp files written by [ArtifactCache::put] in this process.
+static TMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The hit and miss statistics of the artifact cache.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of cache hits.
+    pub hits: u64,
+    /// The number of cache misses.
+    pub misses: u64,
+    /// The number of evicted artifacts.
+    pub evictions: u64,
+}
+
+/// ArtifactCache is a content-addressed store of package object files.
+#[derive(Debug, Clone)]
+pub struct ArtifactCache {
+    dir: PathBuf,
+    max_size: Option<u64>,
+}
+
+impl ArtifactCache {
+    /// Opens the artifact cache in the directory and creates the directory if it doesn't exist.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(dir.join(OBJECTS_DIR))?;
+        Ok(Self {
+            dir,
+            max_size: None,
+        })
+    }
+
+    /// Sets the max size in bytes of the cache, least recently used
+    /// artifacts are evicted when the size is exceeded.
+    pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Opens the artifact cache configured by the [ExecProgramArgs] or the environment
+    /// variables. Returns `None` when no cache directory is configured.
+    pub fn from_args(args: &ExecProgramArgs) -> Result<Option<Self>> {
+        let dir = match &args.artifact_cache_dir {
+            Some(dir) if !dir.is_empty() => dir.clone(),
+            _ => match std::env::var(KCL_ARTIFACT_CACHE_DIR_ENV_VAR) {
+                Ok(dir) if !dir.is_empty() => dir,
+                _ => return Ok(None),
+            },
+        };
+        let max_size = args.artifact_cache_max_size.or_else(|| {
+            std::env::var(KCL_ARTIFACT_CACHE_MAX_SIZE_ENV_VAR)
+                .ok()
+                .and_then(|size| size.parse().ok())
+        });
+        Ok(Some(Self::new(dir)?.with_max_size(max_size)))
+    }
+
+    /// Returns the cache directory.
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the path of the artifact of the key.
+    #[inline]
+    fn artifact_path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(OBJECTS_DIR)
+            .join(&key[..2.min(key.len())])
+            .join(format!("{}{}", key, ARTIFACT_SUFFIX))
+    }
+
+    /// Copies the artifact of the key to `dst` and returns true if it exists in the cache.
+    pub fn get(&self, key: &str, dst: &Path) -> Result<bool> {
+        let path = self.artifact_path(key);
+        let hit = path.is_file() && fs::copy(&path, dst).is_ok();
+        if hit {
+            // Refresh the modification time for the least recently used eviction.
+            let _ = File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|f| f.set_modified(SystemTime::now()));
+        }
+        self.update_stats(|stats| {
+            if hit {
+                stats.hits += 1
+            } else {
+                stats.misses += 1
+            }
+        })?;
+        Ok(hit)
+    }
+
+    /// Stores the artifact file `src` under the key and evicts artifacts if the max size is exceeded.
+    pub fn put(&self, key: &str, src: &Path) -> Result<()> {
+        let path = self.artifact_path(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write to a temp file and rename it to make the put atomic for concurrent writers.
+        // The temp file name is unique among the processes and the threads of a process.
+        let tmp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::copy(src, &tmp_path)?;
+        fs::rename(&tmp_path, &path)?;
+        if let Some(max_size) = self.max_size {
+            self.evict(max_size)?;
+        }
+        Ok(())
+    }
+
+    /// Returns all the artifact paths in the cache.
+    pub fn entries(&self) -> Result<Vec<PathBuf>> {
+        let mut entries = vec![];
+        for entry in walkdir::WalkDir::new(self.dir.join(OBJECTS_DIR))
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if path.is_file() && path.to_string_lossy().ends_with(ARTIFACT_SUFFIX) {
+                entries.push(path.to_path_buf());
+            }
+        }
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// Returns the total size in bytes of all the artifacts.
+    pub fn size(&self) -> Result<u64> {
+        let mut size = 0;
+        for entry in self.entries()? {
+            size += fs::metadata(entry)?.len();
+        }
+        Ok(size)
+    }
+
+    /// Removes the least recently used artifacts until the cache size is not greater
+    /// than `max_size` and returns the number of evicted artifacts.
+    pub fn evict(&self, max_size: u64) -> Result<u64> {
+        let mut entries = vec![];
+        let mut size = 0;
+        for entry in self.entries()? {
+            let metadata = fs::metadata(&entry)?;
+            size += metadata.len();
+            entries.push((
+                metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                metadata.len(),
+                entry,
+            ));
+        }
+        entries.sort();
+        let mut evictions = 0;
+        for (_, len, entry) in entries {
+            if size <= max_size {
+                break;
+            }
+            fs::remove_file(entry)?;
+            size -= len;
+            evictions += 1;
+        }
+        if evictions > 0 {
+            self.update_stats(|stats| stats.evictions += evictions)?;
+        }
+        Ok(evictions)
+    }
+
+    /// Removes all the artifacts and statistics in the cache.
+    pub fn clean(&self) -> Result<()> {
+        let objects_dir = self.dir.join(OBJECTS_DIR);
+        if objects_dir.exists() {
+            fs::remove_dir_all(&objects_dir)?;
+        }
+        fs::create_dir_all(&objects_dir)?;
+        self.update_stats(|stats| *stats = CacheStats::default())
+    }
+
+    /// Returns the hit and miss statistics of the cache.
+    pub fn stats(&self) -> Result<CacheStats> {
+        let path = self.dir.join(STATS_FILE);
+        if path.is_file() {
+            Ok(serde_json::from_str(&fs::read_to_string(path)?).unwrap_or_default())
+        } else {
+            Ok(CacheStats::default())
+        }
+    }
+
+    fn update_stats<F: FnOnce(&mut CacheStats)>(&self, f: F) -> Result<()> {
+        let path = self.dir.join(STATS_FILE);
+        let mut lock_file = fslock::LockFile::open(&format!("{}{}", path.display(), LOCK_SUFFIX))?;
+        lock_file.lock()?;
+        let mut stats = self.stats()?;
+        f(&mut stats);
+        fs::write(&path, serde_json::to_string(&stats)?)?;
+        lock_file.unlock()?;
+        Ok(())
+    }
+
+    /// Exports all the artifacts into the directory `dst` which can be imported by
+    /// [ArtifactCache::import] on another machine. Returns the number of exported artifacts.
+    pub fn export<P: AsRef<Path>>(&self, dst: P) -> Result<usize> {
+        let dst = ArtifactCache::new(dst)?;
+        let mut count = 0;
+        for entry in self.entries()? {
+            let key = artifact_key_from_path(&entry);
+            if !dst.artifact_path(&key).exists() {
+                dst.put(&key, &entry)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Imports the artifacts exported into the directory `src` and returns the number
+    /// of imported artifacts. Existing artifacts are not overwritten.
+    pub fn import<P: AsRef<Path>>(&self, src: P) -> Result<usize> {
+        let src = src.as_ref();
+        if !src.join(OBJECTS_DIR).is_dir() {
+            return Err(anyhow::anyhow!(
+                "{} is not a KCL artifact cache directory",
+                src.display()
+            ));
+        }
+        ArtifactCache::new(src)?.export(&self.dir)
+    }
+}
+
+/// Returns the source of the module from the source map of the parse session, or
+/// from the file when the module is not parsed in the session, e.g., it is loaded
+/// from a module cache.
+fn module_source(sess: &ParseSession, module: &ast::Module) -> String {
+    match sess
+        .0
+        .sm
+        .source_file_by_filename(&module.filename)
+        .and_then(|sf| sf.src.as_ref().map(|src| src.to_string()))
+    {
+        Some(src) => src,
+        None => {
+            std::fs::read_to_string(&module.filename).unwrap_or_else(|_| print_ast_module(module))
+        }
+    }
+}
+
+#[inline]
+fn artifact_key_from_path(path: &Path) -> String {
+    path.file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .trim_end_matches(ARTIFACT_SUFFIX)
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Computes the cache key of every package in the program.
+///
+/// The key of a package covers the compiler version, the target, the flags, the
+/// sources of the package and the sources of all packages it imports transitively.
+/// The full source file paths and the raw sources are hashed because the paths, lines
+/// and columns are embedded in the object files.
+pub fn package_keys(
+    sess: &ParseSession,
+    program: &ast::Program,
+    import_names: &IndexMap<String, IndexMap<String, String>>,
+    target: &str,
+    flags: &str,
+) -> HashMap<String, String> {
+    // The digest of the package sources and the imported packages of each package.
+    let mut digests: HashMap<String, (String, Vec<String>)> = HashMap::new();
+    for (pkgpath, modules) in &program.pkgs {
+        let mut sources: Vec<(String, String)> = modules
+            .iter()
+            .map(|module| (module.filename.clone(), module_source(sess, module)))
+            .collect();
+        sources.sort();
+        let mut hasher = Sha256::new();
+        for (name, source) in &sources {
+            hasher.input_str(name);
+            hasher.input_str(source);
+        }
+        let mut imports: Vec<String> = modules
+            .iter()
+            .filter_map(|module| import_names.get(&module.filename))
+            .flat_map(|names| names.values().cloned())
+            .filter(|import_path| program.pkgs.contains_key(import_path))
+            .collect();
+        imports.sort();
+        imports.dedup();
+        digests.insert(pkgpath.clone(), (hasher.result_str(), imports));
+    }
+    let mut keys = HashMap::new();
+    for pkgpath in program.pkgs.keys() {
+        // Collect all the packages imported transitively.
+        let mut visited = HashSet::new();
+        let mut stack = vec![pkgpath.clone()];
+        while let Some(pkg) = stack.pop() {
+            if visited.insert(pkg.clone()) {
+                if let Some((_, imports)) = digests.get(&pkg) {
+                    stack.extend(imports.iter().cloned());
+                }
+            }
+        }
+        let mut deps: Vec<&String> = visited.iter().collect();
+        deps.sort();
+        let mut hasher = Sha256::new();
+        hasher.input_str(&kclvm_version::get_version_string());
+        hasher.input_str(target);
+        hasher.input_str(flags);
+        hasher.input_str(pkgpath);
+        for dep in deps {
+            if let Some((digest, _)) = digests.get(dep) {
+                hasher.input_str(dep);
+                hasher.input_str(digest);
+            }
+        }
+        keys.insert(pkgpath.clone(), hasher.result_str());
+    }
+    keys
+}
@@ -2,6 +2,8 @@ use std::{collections::HashMap, ffi::OsStr, path::Path};
 
 use anyhow::{anyhow, bail, Result};
 use assembler::KclvmLibAssembler;
+pub use builder::ProgramBuilder;
+use cache::ArtifactCache;
 use kclvm_ast::{
     ast::{Module, Program},
     MAIN_PKG,
@@ -19,7 +21,10 @@ use runner::{KclLibRunner, KclLibRunnerOptions, ProgramRunner};
 use tempfile::tempdir;
 
 pub mod assembler;
+pub mod builder;
+pub mod cache;
 pub mod linker;
+pub mod plugin;
 pub mod runner;
 
 #[cfg(test)]
@@ -71,11 +76,25 @@ pub mod tests;
 /// let result = exec_program(sess, &args).unwrap();
 /// ```
 pub fn exec_program(sess: ParseSessionRef, args: &ExecProgramArgs) -> Result<ExecProgramResult> {
+    exec_program_with_module_cache(sess, args, KCLModuleCache::default())
+}
+
+/// Execute the KCL program like [exec_program] and reuse the parsed AST modules in
+/// the `module_cache` between executions, e.g., the iterations of the watch mode.
+/// Callers should remove the changed files from the cache before the execution.
+pub fn exec_program_with_module_cache(
+    sess: ParseSessionRef,
+    args: &ExecProgramArgs,
+    module_cache: KCLModuleCache,
+) -> Result<ExecProgramResult> {
     // parse args from json string
-    let opts = args.get_load_program_options();
     let kcl_paths = expand_files(args)?;
+    let mut args = args.clone();
+    args.plugins
+        .load_mod_plugins(&kcl_paths, &args.work_dir.clone().unwrap_or_default())?;
+    let args = &args;
+    let opts = args.get_load_program_options();
     let kcl_paths_str = kcl_paths.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-    let module_cache = KCLModuleCache::default();
     let mut program = load_program(
         sess.clone(),
         kcl_paths_str.as_slice(),
@@ -198,7 +217,7 @@ pub fn execute(
     // Resolve ast
     let scope = resolve_program(&mut program);
     // Emit parse and resolve errors if exists.
-    emit_compile_diag_to_string(sess, &scope, false)?;
+    emit_compile_diag_to_string(sess.clone(), &scope, false)?;
     // Create a temp entry file and the temp dir will be delete automatically
     let temp_dir = tempdir()?;
     let temp_dir_path = temp_dir.path().to_str().ok_or(anyhow!(
@@ -215,6 +234,12 @@ pub fn execute(
         KclvmLibAssembler::LLVM,
         args.get_package_maps_from_external_pkg(),
     )
+    .with_artifact_cache(
+        ArtifactCache::from_args(args)?,
+        args.get_artifact_flags(),
+        sess,
+    )
+    .with_source_map(args.source_map)
     .gen_libs()?;
 
     // Link libs into one library
@@ -264,15 +289,19 @@ pub fn build_program<P: AsRef<Path>>(
     output: Option<P>,
 ) -> Result<Artifact> {
     // Parse program.
-    let opts = args.get_load_program_options();
     let kcl_paths = expand_files(args)?;
+    let mut args = args.clone();
+    args.plugins
+        .load_mod_plugins(&kcl_paths, &args.work_dir.clone().unwrap_or_default())?;
+    let args = &args;
+    let opts = args.get_load_program_options();
     let kcl_paths_str = kcl_paths.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
     let mut program =
         load_program(sess.clone(), kcl_paths_str.as_slice(), Some(opts), None)?.program;
     // Resolve program.
     let scope = resolve_program(&mut program);
     // Emit parse and resolve errors if exists.
-    emit_compile_diag_to_string(sess, &scope, false)?;
+    emit_compile_diag_to_string(sess.clone(), &scope, false)?;
     // Create a temp entry file and the temp dir will be delete automatically.
     let temp_dir = tempdir()?;
     let temp_dir_path = temp_dir.path().to_str().ok_or(anyhow!(
@@ -288,6 +317,12 @@ pub fn build_program<P: AsRef<Path>>(
         KclvmLibAssembler::LLVM,
         args.get_package_maps_from_external_pkg(),
     )
+    .with_artifact_cache(
+        ArtifactCache::from_args(args)?,
+        args.get_artifact_flags(),
+        sess,
+    )
+    .with_source_map(args.source_map)
     .gen_libs()?;
 
     // Link libs into one library.
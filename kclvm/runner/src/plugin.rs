@@ -0,0 +1,460 @@
+//! Native plugins registered as `kcl_plugin.<name>` modules.
+//!
+//! A native plugin is either a set of in-process Rust closures registered with
+//! [PluginRegistry::register_function], or a dynamic library declared in the
+//! `[plugins]` table of the kcl.mod file, e.g.,
+//!
+//! ```toml
+//! [plugins]
+//! hello = "./plugins/libhello.so"
+//! ```
+//!
+//! The dynamic library exports two C functions:
+//!
+//! - `const char* kcl_plugin_spec()` returns the JSON [PluginSpec] of the plugin.
+//! - `const char* kcl_plugin_invoke(const char* method, const char* args, const char* kwargs)`
+//!   calls the function `method` e.g., `kcl_plugin.hello.say_hello` with the JSON
+//!   list of the arguments bound to the declared parameters and returns the JSON
+//!   result, or `{"__kcl_PanicInfo__": "message"}` on errors.
+//!
+//! The arguments and results are checked with the declared types, thus the plugin
+//! calls work without the Python plugin agent. Methods which are not registered
+//! natively fall back to the plugin agent set in [ExecProgramArgs::plugin_agent].
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, bail, Result};
+use kclvm_config::modfile::{get_pkg_root_from_paths, load_mod_file};
+use kclvm_runtime::{check_type, Context, ValueRef, KCL_TYPE_ANY};
+use kclvm_sema::plugin::PLUGIN_MODULE_PREFIX;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+const PANIC_INFO_KEY: &str = "__kcl_PanicInfo__";
+const PLUGIN_SPEC_SYMBOL: &[u8] = b"kcl_plugin_spec";
+const PLUGIN_INVOKE_SYMBOL: &[u8] = b"kcl_plugin_invoke";
+
+/// The Rust function of the plugin, which receives the arguments bound to the
+/// declared parameters, and the missing optional arguments are `None`.
+pub type PluginHandler = Arc<dyn Fn(&[ValueRef]) -> Result<ValueRef> + Send + Sync>;
+
+type PluginInvokeFn =
+    unsafe extern "C" fn(*const c_char, *const c_char, *const c_char) -> *const c_char;
+type PluginSpecFn = unsafe extern "C" fn() -> *const c_char;
+
+/// The declaration of a plugin and its functions.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginSpec {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub functions: Vec<PluginFunction>,
+}
+
+/// The function signature of a plugin function.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginFunction {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<PluginParam>,
+    /// The KCL type string of the return value, e.g., `str`, `[int]` and `{str:any}`.
+    #[serde(default = "any_type")]
+    pub return_type: String,
+    #[serde(default)]
+    pub doc: String,
+}
+
+/// The parameter of a plugin function.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginParam {
+    pub name: String,
+    /// The KCL type string of the parameter.
+    #[serde(rename = "type", default = "any_type")]
+    pub ty: String,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+fn any_type() -> String {
+    KCL_TYPE_ANY.to_string()
+}
+
+#[derive(Clone)]
+struct RegisteredFunction {
+    func: PluginFunction,
+    handler: PluginHandler,
+}
+
+/// The native plugins of a program. Every program has its own registry, see
+/// [crate::ExecProgramArgs::plugins], so the plugins registered for a program
+/// are not callable in other programs.
+#[derive(Clone, Default)]
+pub struct PluginRegistry {
+    /// The registered functions keyed by the full method name e.g., `kcl_plugin.hello.say_hello`.
+    functions: HashMap<String, RegisteredFunction>,
+    /// The canonical library paths of the plugins loaded from dynamic libraries
+    /// keyed by the plugin name.
+    libraries: HashMap<String, String>,
+}
+
+impl std::fmt::Debug for PluginRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut methods: Vec<&String> = self.functions.keys().collect();
+        methods.sort();
+        f.debug_struct("PluginRegistry")
+            .field("functions", &methods)
+            .field("libraries", &self.libraries)
+            .finish()
+    }
+}
+
+/// The plugin library loaded in the process, which is shared by the registries
+/// loading the same library.
+struct PluginLibrary {
+    spec: PluginSpec,
+    invoke_fn: PluginInvokeFn,
+}
+
+/// The loaded plugin libraries keyed by the canonical library path.
+static PLUGIN_LIBRARIES: Lazy<Mutex<HashMap<String, Arc<PluginLibrary>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The plugins and the fallback plugin agent of the program running in the thread.
+struct PluginContext {
+    registry: PluginRegistry,
+    fallback: u64,
+}
+
+thread_local! {
+    /// The last result returned by the native plugin agent, which is copied by
+    /// the runtime before the next call.
+    static PLUGIN_RESULT: RefCell<CString> = RefCell::new(CString::default());
+    /// The plugin context of the program running in the current thread. The KCL
+    /// runtime calls the plugin agent in the thread running the program.
+    static PLUGIN_CONTEXT: RefCell<Option<PluginContext>> = RefCell::new(None);
+}
+
+impl PluginRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the Rust function `func` of the plugin `plugin`, which can be called
+    /// with `kcl_plugin.<plugin>.<name>` in the KCL code.
+    pub fn register_function<F>(&mut self, plugin: &str, func: PluginFunction, handler: F)
+    where
+        F: Fn(&[ValueRef]) -> Result<ValueRef> + Send + Sync + 'static,
+    {
+        self.register(plugin, func, Arc::new(handler));
+    }
+
+    fn register(&mut self, plugin: &str, func: PluginFunction, handler: PluginHandler) {
+        let method = format!("{}{}.{}", PLUGIN_MODULE_PREFIX, plugin, func.name);
+        self.functions
+            .insert(method, RegisteredFunction { func, handler });
+    }
+
+    /// Whether there are no native plugin functions registered.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    /// Returns the signatures of the registered functions grouped by the plugin name.
+    pub fn specs(&self) -> Vec<PluginSpec> {
+        let mut specs: HashMap<String, PluginSpec> = HashMap::new();
+        for (method, registered) in self.functions.iter() {
+            let name = method
+                .trim_start_matches(PLUGIN_MODULE_PREFIX)
+                .rsplit_once('.')
+                .map(|(plugin, _)| plugin)
+                .unwrap_or_default()
+                .to_string();
+            specs
+                .entry(name.clone())
+                .or_insert_with(|| PluginSpec {
+                    name,
+                    functions: vec![],
+                })
+                .functions
+                .push(registered.func.clone());
+        }
+        let mut specs: Vec<PluginSpec> = specs.into_values().collect();
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        for spec in &mut specs {
+            spec.functions.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        specs
+    }
+
+    /// Load the plugin dynamic library at `path` and register its functions as the
+    /// plugin `name`. A library is loaded once in the process and shared by the
+    /// registries, and a plugin name can't be bound to different libraries.
+    pub fn load_library(&mut self, name: &str, path: &Path) -> Result<PluginSpec> {
+        let path = path.canonicalize().map_err(|err| {
+            anyhow!(
+                "failed to load plugin {name} from {}: {err}",
+                path.display()
+            )
+        })?;
+        let key = path.display().to_string();
+        match self.libraries.get(name) {
+            Some(loaded) if loaded != &key => {
+                bail!("the plugin {name} is loaded from both {loaded} and {key}")
+            }
+            _ => {}
+        }
+        let library = load_library(name, &key)?;
+        let mut spec = library.spec.clone();
+        spec.name = name.to_string();
+        for func in &spec.functions {
+            let method = CString::new(format!("{}{}.{}", PLUGIN_MODULE_PREFIX, name, func.name))?;
+            let invoke_fn = library.invoke_fn;
+            self.register_function(name, func.clone(), move |args| {
+                let args = CString::new(ValueRef::list_value(Some(args)).to_json_string())?;
+                let result = unsafe {
+                    c_str_to_string(invoke_fn(
+                        method.as_ptr(),
+                        args.as_ptr(),
+                        b"{}\0".as_ptr() as *const c_char,
+                    ))
+                };
+                let mut ctx = Context::new();
+                let value = ValueRef::from_json(&mut ctx, &result)
+                    .map_err(|err| anyhow!("invalid plugin result: {err}"))?;
+                if let Some(msg) = value.dict_get_value(PANIC_INFO_KEY) {
+                    bail!("{}", msg.as_str());
+                }
+                Ok(value)
+            });
+        }
+        self.libraries.insert(name.to_string(), key);
+        Ok(spec)
+    }
+
+    /// Load the plugins declared in the kcl.mod file of the input files.
+    pub fn load_mod_plugins(
+        &mut self,
+        k_files: &[String],
+        work_dir: &str,
+    ) -> Result<Vec<PluginSpec>> {
+        let root = match get_pkg_root_from_paths(k_files, work_dir.to_string()) {
+            Ok(root) if !root.is_empty() => root,
+            _ => return Ok(vec![]),
+        };
+        let mut plugins: Vec<(String, String)> = load_mod_file(&root)
+            .plugins
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        plugins.sort();
+        plugins
+            .iter()
+            .map(|(name, path)| self.load_library(name, &Path::new(&root).join(path)))
+            .collect()
+    }
+}
+
+/// Load the plugin library at the canonical path `key` or returns the loaded one.
+fn load_library(name: &str, key: &str) -> Result<Arc<PluginLibrary>> {
+    let mut libraries = PLUGIN_LIBRARIES.lock().unwrap();
+    if let Some(library) = libraries.get(key) {
+        return Ok(library.clone());
+    }
+    // The library is never unloaded because the registered functions are
+    // callable until the process exits.
+    let lib: &'static libloading::Library = Box::leak(Box::new(unsafe {
+        libloading::Library::new(key)
+            .map_err(|err| anyhow!("failed to load plugin {name} from {key}: {err}"))?
+    }));
+    let (spec_fn, invoke_fn) = unsafe {
+        let spec_fn: libloading::Symbol<PluginSpecFn> = lib.get(PLUGIN_SPEC_SYMBOL)?;
+        let invoke_fn: libloading::Symbol<PluginInvokeFn> = lib.get(PLUGIN_INVOKE_SYMBOL)?;
+        (*spec_fn, *invoke_fn)
+    };
+    let spec_json = unsafe { c_str_to_string(spec_fn()) };
+    let spec: PluginSpec = serde_json::from_str(&spec_json)
+        .map_err(|err| anyhow!("invalid spec of the plugin {name}: {err}"))?;
+    let library = Arc::new(PluginLibrary { spec, invoke_fn });
+    libraries.insert(key.to_string(), library.clone());
+    Ok(library)
+}
+
+/// Restores the plugin context of the thread when the program exits.
+struct PluginContextGuard(Option<PluginContext>);
+
+impl Drop for PluginContextGuard {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        PLUGIN_CONTEXT.with(|context| *context.borrow_mut() = prev);
+    }
+}
+
+/// Run the program `run` with the plugin agent address passed into the KCL runtime.
+/// The native plugin agent is used when there are native plugin functions in the
+/// `registry`, and `fallback` is called for the other methods. Both are only visible
+/// to the program running in the current thread.
+pub(crate) fn with_plugin_agent<T>(
+    registry: &PluginRegistry,
+    fallback: u64,
+    run: impl FnOnce(u64) -> T,
+) -> T {
+    if registry.is_empty() {
+        return run(fallback);
+    }
+    let prev = PLUGIN_CONTEXT.with(|context| {
+        context.replace(Some(PluginContext {
+            registry: registry.clone(),
+            fallback,
+        }))
+    });
+    let _guard = PluginContextGuard(prev);
+    run(native_plugin_agent as usize as u64)
+}
+
+extern "C" fn native_plugin_agent(
+    method: *const c_char,
+    args: *const c_char,
+    kwargs: *const c_char,
+) -> *const c_char {
+    let method_name = unsafe { c_str_to_string(method) };
+    // Release the context before calling the function, which may run other programs.
+    let (registered, fallback) = PLUGIN_CONTEXT.with(|context| match &*context.borrow() {
+        Some(context) => (
+            context.registry.functions.get(&method_name).cloned(),
+            context.fallback,
+        ),
+        None => (None, 0),
+    });
+    let result = match registered {
+        Some(registered) => {
+            let (args, kwargs) = unsafe { (c_str_to_string(args), c_str_to_string(kwargs)) };
+            call_plugin_function(
+                &method_name,
+                &registered.func,
+                &registered.handler,
+                &args,
+                &kwargs,
+            )
+        }
+        None => {
+            if fallback != 0 {
+                let fallback: extern "C" fn(
+                    *const c_char,
+                    *const c_char,
+                    *const c_char,
+                ) -> *const c_char = unsafe { std::mem::transmute(fallback as usize) };
+                return fallback(method, args, kwargs);
+            }
+            Err(anyhow!(
+                "the plugin function `{}` is not found",
+                method_name
+            ))
+        }
+    };
+    let result = match result {
+        Ok(value) => value.to_json_string(),
+        Err(err) => {
+            let mut panic_info = ValueRef::dict(None);
+            panic_info.dict_update_key_value(PANIC_INFO_KEY, ValueRef::str(&err.to_string()));
+            panic_info.to_json_string()
+        }
+    };
+    PLUGIN_RESULT.with(|buffer| {
+        *buffer.borrow_mut() = CString::new(result).unwrap_or_default();
+        buffer.borrow().as_ptr()
+    })
+}
+
+/// Bind the JSON arguments to the declared parameters, check the argument types
+/// and the return type, and call the plugin function.
+fn call_plugin_function(
+    method: &str,
+    func: &PluginFunction,
+    handler: &PluginHandler,
+    args: &str,
+    kwargs: &str,
+) -> Result<ValueRef> {
+    let mut ctx = Context::new();
+    let args = parse_json_or(&mut ctx, args, ValueRef::list(None))?;
+    let kwargs = parse_json_or(&mut ctx, kwargs, ValueRef::dict(None))?;
+    let params = &func.params;
+    if args.len() > params.len() {
+        bail!(
+            "{}() takes {} positional argument{} but {} were given",
+            method,
+            params.len(),
+            if params.len() == 1 { "" } else { "s" },
+            args.len()
+        );
+    }
+    let mut bound = Vec::with_capacity(params.len());
+    for (i, param) in params.iter().enumerate() {
+        let value = if i < args.len() {
+            if kwargs.dict_get_value(&param.name).is_some() {
+                bail!(
+                    "{}() got multiple values for argument '{}'",
+                    method,
+                    param.name
+                );
+            }
+            args.list_get(i as isize).unwrap()
+        } else if let Some(value) = kwargs.dict_get_value(&param.name) {
+            value
+        } else if param.optional {
+            ValueRef::none()
+        } else {
+            bail!(
+                "{}() missing 1 required positional argument: '{}'",
+                method,
+                param.name
+            );
+        };
+        if !value.is_none() && !check_type(&value, &param.ty) {
+            bail!(
+                "argument '{}' of {}() expect {}, got {}",
+                param.name,
+                method,
+                param.ty,
+                value.type_str()
+            );
+        }
+        bound.push(value);
+    }
+    for key in kwargs.as_dict_ref().values.keys() {
+        if !params.iter().any(|param| &param.name == key) {
+            bail!("{}() got an unexpected keyword argument '{}'", method, key);
+        }
+    }
+    let result = handler(&bound)?;
+    if !check_type(&result, &func.return_type) {
+        bail!(
+            "the return value of {}() expect {}, got {}",
+            method,
+            func.return_type,
+            result.type_str()
+        );
+    }
+    Ok(result)
+}
+
+fn parse_json_or(ctx: &mut Context, s: &str, default: ValueRef) -> Result<ValueRef> {
+    if s.is_empty() {
+        return Ok(default);
+    }
+    let value =
+        ValueRef::from_json(ctx, s).map_err(|err| anyhow!("invalid plugin arguments: {err}"))?;
+    Ok(if value.is_none() { default } else { value })
+}
+
+unsafe fn c_str_to_string(s: *const c_char) -> String {
+    if s.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(s).to_string_lossy().to_string()
+}
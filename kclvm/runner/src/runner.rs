@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 use std::ffi::OsStr;
 use std::os::raw::c_char;
 
+use crate::plugin::{with_plugin_agent, PluginRegistry};
+
 const RESULT_SIZE: usize = 2048 * 2048;
 
 #[allow(non_camel_case_types)]
@@ -53,11 +55,34 @@ pub struct ExecProgramArgs {
     pub sort_keys: bool,
     /// Whether including schema type in JSON/YAML result
     pub include_schema_type_path: bool,
+    /// Whether to emit the source map which maps every output path to the
+    /// KCL source locations that set it.
+    #[serde(default)]
+    pub source_map: bool,
+    /// The fixed current time in the RFC 3339 format used by the datetime functions,
+    /// which makes the outputs depending on the current time reproducible.
+    #[serde(default)]
+    pub clock: Option<String>,
+    /// Whether to promote the int values which overflow i64 to big integers.
+    #[serde(default)]
+    pub big_int: bool,
+    /// The seed of the random and uuid functions, which makes the random outputs
+    /// reproducible and defaults to 0.
+    #[serde(default)]
+    pub random_seed: i64,
     // Whether to compile only.
     pub compile_only: bool,
+    /// The content-addressed artifact cache directory, see [crate::cache::ArtifactCache].
+    pub artifact_cache_dir: Option<String>,
+    /// The max size in bytes of the artifact cache directory.
+    pub artifact_cache_max_size: Option<u64>,
     // plugin_agent is the address of plugin.
     #[serde(skip)]
     pub plugin_agent: u64,
+    /// The native plugins of the program, and the plugins declared in the kcl.mod
+    /// file are loaded into it when the program is executed.
+    #[serde(skip)]
+    pub plugins: PluginRegistry,
 }
 
 impl ExecProgramArgs {
@@ -89,6 +114,10 @@ pub struct ExecProgramResult {
     pub yaml_result: String,
     pub log_message: String,
     pub err_message: String,
+    /// The source map in the JSON format when [ExecProgramArgs::source_map] is enabled,
+    /// see [kclvm_runtime::SourceMapEntry].
+    #[serde(default)]
+    pub source_map: String,
 }
 
 pub trait MapErrorResult {
@@ -139,6 +168,26 @@ impl ExecProgramArgs {
         serde_json::ser::to_string(self).unwrap()
     }
 
+    /// Get the flags which affect the compiled artifacts, used as a part of the artifact cache key.
+    pub fn get_artifact_flags(&self) -> String {
+        let mut external_pkgs: Vec<String> = self
+            .external_pkgs
+            .iter()
+            .map(|pkg| pkg.pkg_name.clone())
+            .collect();
+        external_pkgs.sort();
+        format!(
+            "strict_range_check={};disable_none={};sort_keys={};include_schema_type_path={};source_map={};debug={};external_pkgs={}",
+            self.strict_range_check,
+            self.disable_none,
+            self.sort_keys,
+            self.include_schema_type_path,
+            self.source_map,
+            self.debug,
+            external_pkgs.join(",")
+        )
+    }
+
     /// Get the input file list.
     pub fn get_files(&self) -> Vec<&str> {
         self.k_filename_list.iter().map(|s| s.as_str()).collect()
@@ -151,7 +200,7 @@ impl ExecProgramArgs {
             vendor_dirs: vec![get_vendor_home()],
             package_maps: self.get_package_maps_from_external_pkg(),
             k_code_list: self.k_code_list.clone(),
-            load_plugins: self.plugin_agent > 0,
+            load_plugins: self.plugin_agent > 0 || !self.plugins.is_empty(),
             ..Default::default()
         }
     }
@@ -177,6 +226,9 @@ impl TryFrom<SettingsFile> for ExecProgramArgs {
                 args.overrides.push(parse_override_spec(override_str)?);
             }
             args.path_selector = cli_configs.path_selector.unwrap_or_default();
+            args.clock = cli_configs.clock;
+            args.big_int = cli_configs.big_int.unwrap_or_default();
+            args.random_seed = cli_configs.random_seed.unwrap_or_default();
             args.set_external_pkg_from_package_maps(
                 cli_configs.package_maps.unwrap_or(HashMap::default()),
             )
@@ -214,10 +266,10 @@ pub trait ProgramRunner {
 
 impl ProgramRunner for Artifact {
     fn run(&self, args: &ExecProgramArgs) -> Result<ExecProgramResult> {
-        unsafe {
-            KclLibRunner::lib_kclvm_plugin_init(&self.0, args.plugin_agent)?;
+        with_plugin_agent(&args.plugins, args.plugin_agent, |plugin_agent| unsafe {
+            KclLibRunner::lib_kclvm_plugin_init(&self.0, plugin_agent)?;
             KclLibRunner::lib_kcl_run(&self.0, args)
-        }
+        })
     }
 }
 
@@ -254,11 +306,16 @@ impl KclLibRunner {
 
     /// Run kcl library with exec arguments.
     pub fn run(&self, lib_path: &str, args: &ExecProgramArgs) -> Result<ExecProgramResult> {
-        unsafe {
-            let lib = libloading::Library::new(std::path::PathBuf::from(lib_path).canonicalize()?)?;
-            Self::lib_kclvm_plugin_init(&lib, self.opts.plugin_agent_ptr)?;
-            Self::lib_kcl_run(&lib, args)
-        }
+        with_plugin_agent(
+            &args.plugins,
+            self.opts.plugin_agent_ptr,
+            |plugin_agent| unsafe {
+                let lib =
+                    libloading::Library::new(std::path::PathBuf::from(lib_path).canonicalize()?)?;
+                Self::lib_kclvm_plugin_init(&lib, plugin_agent)?;
+                Self::lib_kcl_run(&lib, args)
+            },
+        )
     }
 }
 
@@ -312,12 +369,18 @@ impl KclLibRunner {
                 disable_schema_check: i32,
                 list_option_mode: i32,
                 debug_mode: i32,
+                provenance: i32,
+                clock: *const kclvm_char_t,
+                big_int: i32,
+                random_seed: i64,
                 result_buffer_len: *mut kclvm_size_t,
                 result_buffer: *mut kclvm_char_t,
                 warn_buffer_len: *mut kclvm_size_t,
                 warn_buffer: *mut kclvm_char_t,
                 log_buffer_len: *mut kclvm_size_t,
                 log_buffer: *mut kclvm_char_t,
+                source_map_buffer_len: *mut kclvm_size_t,
+                source_map_buffer: *mut kclvm_char_t,
             ) -> kclvm_size_t,
         > = lib.get(b"_kcl_run")?;
 
@@ -361,6 +424,10 @@ impl KclLibRunner {
         let disable_schema_check = 0; // todo
         let list_option_mode = 0; // todo
         let debug_mode = args.debug;
+        let provenance = args.source_map as i32;
+        let clock = std::ffi::CString::new(args.clock.clone().unwrap_or_default())?;
+        let big_int = args.big_int as i32;
+        let random_seed = args.random_seed;
 
         // Exec json result
         let mut json_result = vec![0u8; RESULT_SIZE];
@@ -377,6 +444,11 @@ impl KclLibRunner {
         let mut log_buffer_len = log_data.len() as i32 - 1;
         let log_buffer = log_data.as_mut_ptr() as *mut c_char;
 
+        // Exec source map data
+        let mut source_map_data = vec![0u8; if args.source_map { RESULT_SIZE } else { 0 }];
+        let mut source_map_buffer_len = source_map_data.len() as i32 - 1;
+        let source_map_buffer = source_map_data.as_mut_ptr() as *mut c_char;
+
         let n = kcl_run(
             kclvm_main_ptr,
             option_len,
@@ -387,17 +459,34 @@ impl KclLibRunner {
             disable_schema_check,
             list_option_mode,
             debug_mode,
+            provenance,
+            clock.as_ptr(),
+            big_int,
+            random_seed,
             &mut result_buffer_len,
             json_result_buffer,
             &mut warn_buffer_len,
             warn_buffer,
             &mut log_buffer_len,
             log_buffer,
+            &mut source_map_buffer_len,
+            source_map_buffer,
         );
         let mut result = ExecProgramResult {
             log_message: String::from_utf8(log_data[0..log_buffer_len as usize].to_vec())?,
             ..Default::default()
         };
+        if args.source_map && source_map_buffer_len < 0 {
+            return Err(anyhow!(
+                "the source map size {} exceeds the buffer size {}",
+                0 - source_map_buffer_len,
+                source_map_data.len() - 1
+            ));
+        }
+        if args.source_map && source_map_buffer_len > 0 {
+            result.source_map =
+                String::from_utf8(source_map_data[0..source_map_buffer_len as usize].to_vec())?;
+        }
         if n > 0 {
             let s = std::str::from_utf8(&json_result[0..n as usize])?;
             match wrap_msg_in_result(s) {
@@ -2,6 +2,7 @@ use crate::assembler::clean_path;
 use crate::assembler::KclvmAssembler;
 use crate::assembler::KclvmLibAssembler;
 use crate::assembler::LibAssembler;
+use crate::cache::{package_keys, ArtifactCache};
 use crate::exec_program;
 use crate::temp_file;
 use crate::{execute, runner::ExecProgramArgs};
@@ -279,6 +280,7 @@ fn assemble_lib_for_test(
             scope.import_names,
             entry_file,
             temp_entry_file_path,
+            false,
         )
         .unwrap()
 }
@@ -436,6 +438,131 @@ fn test_clean_path_for_genlibs() {
     assert_eq!(path2.exists(), false);
 }
 
+#[test]
+fn test_artifact_cache() {
+    let cache_dir = tempdir().unwrap();
+    let cache = ArtifactCache::new(cache_dir.path()).unwrap();
+    let work_dir = tempdir().unwrap();
+    let src = work_dir.path().join("a.o");
+    let dst = work_dir.path().join("b.o");
+    fs::write(&src, "artifact").unwrap();
+
+    assert!(!cache.get("abcdef", &dst).unwrap());
+    cache.put("abcdef", &src).unwrap();
+    assert!(cache.get("abcdef", &dst).unwrap());
+    assert_eq!(fs::read_to_string(&dst).unwrap(), "artifact");
+    let stats = cache.stats().unwrap();
+    assert_eq!((stats.hits, stats.misses), (1, 1));
+
+    // Export the artifacts and import them into another cache.
+    let export_dir = tempdir().unwrap();
+    assert_eq!(cache.export(export_dir.path()).unwrap(), 1);
+    let other_dir = tempdir().unwrap();
+    let other = ArtifactCache::new(other_dir.path()).unwrap();
+    assert_eq!(other.import(export_dir.path()).unwrap(), 1);
+    assert!(other.get("abcdef", &dst).unwrap());
+
+    cache.put("123456", &src).unwrap();
+    assert_eq!(cache.entries().unwrap().len(), 2);
+    assert_eq!(cache.evict(8).unwrap(), 1);
+    assert_eq!(cache.entries().unwrap().len(), 1);
+    cache.clean().unwrap();
+    assert!(cache.entries().unwrap().is_empty());
+    assert_eq!(cache.stats().unwrap(), Default::default());
+}
+
+#[test]
+fn test_gen_libs_with_artifact_cache() {
+    let cache_dir = tempdir().unwrap();
+    let temp_dir = tempdir().unwrap();
+    let temp_entry_file = temp_file(temp_dir.path().to_str().unwrap()).unwrap();
+    let entry_file = format!("{}{}", temp_entry_file, "4gen_libs_cache");
+    let kcl_path = gen_full_path(
+        Path::new(&test_case_path())
+            .join("multi_file_compilation")
+            .join("import_abs_path")
+            .join("app-main")
+            .join(KCL_FILE_NAME)
+            .display()
+            .to_string(),
+    )
+    .unwrap();
+    let gen_libs = || {
+        gen_assembler(&entry_file, &kcl_path)
+            .with_artifact_cache(
+                Some(ArtifactCache::new(cache_dir.path()).unwrap()),
+                String::new(),
+                Arc::new(ParseSession::default()),
+            )
+            .gen_libs()
+            .unwrap()
+    };
+    let cache = ArtifactCache::new(cache_dir.path()).unwrap();
+    // The first build misses the cache and stores the object files of all packages.
+    let lib_paths = gen_libs();
+    let pkg_count = lib_paths.len() as u64;
+    assert_eq!(cache.stats().unwrap().misses, pkg_count);
+    assert_eq!(cache.entries().unwrap().len() as u64, pkg_count);
+    // The second build copies the object files from the cache.
+    for lib_path in &lib_paths {
+        clean_path(lib_path).unwrap();
+    }
+    let lib_paths = gen_libs();
+    assert_eq!(cache.stats().unwrap().hits, pkg_count);
+    for lib_path in &lib_paths {
+        assert!(Path::new(lib_path).exists());
+        clean_path(lib_path).unwrap();
+    }
+}
+
+#[test]
+fn test_artifact_cache_package_keys() {
+    let prog = parse_program(
+        &Path::new(".")
+            .join("src")
+            .join("test_datas")
+            .join("multi_file_compilation")
+            .join("import_abs_path")
+            .join("app-main")
+            .join("main.k")
+            .display()
+            .to_string(),
+    );
+    let mut prog_clone = prog.clone();
+    let scope = resolve_program(&mut prog_clone);
+    let sess = ParseSession::default();
+    let keys = package_keys(&sess, &prog, &scope.import_names, "", "");
+    assert_eq!(keys.len(), prog.pkgs.len());
+    assert_eq!(
+        keys,
+        package_keys(&sess, &prog, &scope.import_names, "", "")
+    );
+    let other_keys = package_keys(&sess, &prog, &scope.import_names, "wasm32", "");
+    for (pkgpath, key) in &keys {
+        assert_ne!(key, &other_keys[pkgpath]);
+    }
+}
+
+#[test]
+fn test_artifact_cache_package_keys_with_comments() {
+    let temp_dir = tempdir().unwrap();
+    let main_file = temp_dir.path().join("main.k");
+    let main_file = main_file.to_str().unwrap();
+    let main_key = |code: &str| {
+        fs::write(main_file, code).unwrap();
+        let sess = Arc::new(ParseSession::default());
+        let mut prog = load_program(sess.clone(), &[main_file], None, None)
+            .unwrap()
+            .program;
+        let scope = resolve_program(&mut prog);
+        package_keys(&sess, &prog, &scope.import_names, "", "")[kclvm_ast::MAIN_PKG].clone()
+    };
+    let key = main_key("a = 1\n");
+    assert_eq!(key, main_key("a = 1\n"));
+    // The AST is the same, but the line of `a` is embedded in the object file.
+    assert_ne!(key, main_key("# The comment line\na = 1\n"));
+}
+
 #[test]
 fn test_to_json_program_arg() {
     for case in exec_prog_args_test_case() {
@@ -544,6 +671,15 @@ fn test_exec() {
 
     test_compile_with_file_pattern();
     println!("test_compile_with_file_pattern - PASS");
+
+    test_exec_with_source_map();
+    println!("test_exec_with_source_map - PASS");
+
+    test_program_builder();
+    println!("test_program_builder - PASS");
+
+    test_native_plugin();
+    println!("test_native_plugin - PASS");
 }
 
 fn test_indent_error() {
@@ -674,3 +810,256 @@ fn test_compile_with_file_pattern() {
         "[{\"k3\": \"Hello World!\", \"k1\": \"Hello World!\", \"k2\": \"Hello World!\"}]"
     );
 }
+
+fn test_exec_with_source_map() {
+    let test_path = PathBuf::from("./src/test_datas/source_map");
+    let mut args = ExecProgramArgs::default();
+    args.k_filename_list
+        .push(test_path.join("base.k").display().to_string());
+    args.k_filename_list
+        .push(test_path.join("prod.k").display().to_string());
+    args.source_map = true;
+    let result = exec_program(Arc::new(ParseSession::default()), &args).unwrap();
+    assert_eq!(result.yaml_result, "app:\n  name: app\n  replicas: 3");
+    let source_map: Vec<kclvm_runtime::SourceMapEntry> =
+        serde_json::from_str(&result.source_map).unwrap();
+    let replicas = source_map
+        .iter()
+        .find(|entry| entry.path == "app.replicas")
+        .unwrap();
+    let sources: Vec<(String, i32, String)> = replicas
+        .sources
+        .iter()
+        .map(|s| {
+            (
+                Path::new(&s.filename)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string(),
+                s.line,
+                s.op.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sources,
+        vec![
+            ("base.k".to_string(), 3, "=".to_string()),
+            ("prod.k".to_string(), 2, "=".to_string()),
+        ]
+    );
+}
+
+fn test_program_builder() {
+    #[derive(serde::Serialize)]
+    struct Env {
+        name: String,
+        replicas: i64,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct App {
+        name: String,
+        replicas: i64,
+        labels: std::collections::HashMap<String, String>,
+    }
+
+    let env = Env {
+        name: "app".to_string(),
+        replicas: 2,
+    };
+    let app: App = crate::ProgramBuilder::new()
+        .code(
+            "main.k",
+            r#"env = option("env")
+name = env.name
+replicas = env.replicas
+labels = {app = env.name}
+"#,
+        )
+        .argument_value("env", &env)
+        .unwrap()
+        .override_spec("replicas=3")
+        .unwrap()
+        .exec_into()
+        .unwrap();
+    assert_eq!(
+        app,
+        App {
+            name: "app".to_string(),
+            replicas: 3,
+            labels: [("app".to_string(), "app".to_string())].into(),
+        }
+    );
+    let result = crate::ProgramBuilder::new()
+        .code("main.k", "a = 1\nassert a == 2, \"a must be 2\"\n")
+        .exec_json();
+    assert!(result.unwrap_err().to_string().contains("a must be 2"));
+    // The value is decoded from the JSON result.
+    let value = crate::ProgramBuilder::new()
+        .code(
+            "main.k",
+            r#"schema Person:
+    name: str
+
+person = Person {name = "alice"}
+memory = 1Ki
+"#,
+        )
+        .exec_json()
+        .unwrap();
+    let person = value.dict_get_value("person").unwrap();
+    assert!(person.is_dict() && !person.is_schema());
+    assert_eq!(person.dict_get_value("name").unwrap().as_str(), "alice");
+    assert_eq!(value.dict_get_value("memory").unwrap().as_str(), "1Ki");
+}
+
+fn test_native_plugin() {
+    use crate::plugin::{PluginFunction, PluginParam};
+    use kclvm_runtime::ValueRef;
+
+    let builder = crate::ProgramBuilder::new().plugin_function(
+        "strings",
+        PluginFunction {
+            name: "repeat".to_string(),
+            params: vec![
+                PluginParam {
+                    name: "s".to_string(),
+                    ty: "str".to_string(),
+                    optional: false,
+                },
+                PluginParam {
+                    name: "n".to_string(),
+                    ty: "int".to_string(),
+                    optional: true,
+                },
+            ],
+            return_type: "str".to_string(),
+            doc: String::new(),
+        },
+        |args| {
+            let n = if args[1].is_none() {
+                2
+            } else {
+                args[1].as_int()
+            };
+            Ok(ValueRef::str(&args[0].as_str().repeat(n as usize)))
+        },
+    );
+    let code = r#"import kcl_plugin.strings
+
+a = strings.repeat("ab")
+b = strings.repeat("ab", n=3)
+"#;
+    let value = builder.clone().code("main.k", code).exec_json().unwrap();
+    assert_eq!(value.dict_get_value("a").unwrap().as_str(), "abab");
+    assert_eq!(value.dict_get_value("b").unwrap().as_str(), "ababab");
+    let err = builder
+        .clone()
+        .code(
+            "main.k",
+            "import kcl_plugin.strings\n\na = strings.repeat(1)\n",
+        )
+        .exec_json()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("argument 's' of kcl_plugin.strings.repeat() expect str, got int"));
+    let err = builder
+        .clone()
+        .code(
+            "main.k",
+            "import kcl_plugin.strings\n\na = strings.repeat()\n",
+        )
+        .exec_json()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("missing 1 required positional argument: 's'"));
+    // The plugin functions are only registered for the programs of the builder.
+    assert!(crate::ProgramBuilder::new()
+        .code("main.k", code)
+        .exec_json()
+        .is_err());
+}
+
+#[test]
+fn test_plugin_agent_per_program() {
+    use crate::plugin::{with_plugin_agent, PluginFunction, PluginRegistry};
+    use kclvm_runtime::ValueRef;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    type PluginAgent = extern "C" fn(*const c_char, *const c_char, *const c_char) -> *const c_char;
+
+    fn call(plugin_agent: u64, method: &str) -> String {
+        let plugin_agent: PluginAgent = unsafe { std::mem::transmute(plugin_agent as usize) };
+        let method = CString::new(method).unwrap();
+        let result = plugin_agent(
+            method.as_ptr(),
+            b"[]\0".as_ptr() as *const c_char,
+            b"{}\0".as_ptr() as *const c_char,
+        );
+        unsafe { CStr::from_ptr(result).to_string_lossy().to_string() }
+    }
+
+    extern "C" fn fallback_agent(
+        _method: *const c_char,
+        _args: *const c_char,
+        _kwargs: *const c_char,
+    ) -> *const c_char {
+        b"\"fallback\"\0".as_ptr() as *const c_char
+    }
+
+    fn registry(value: &'static str) -> PluginRegistry {
+        let mut registry = PluginRegistry::new();
+        registry.register_function(
+            "app",
+            PluginFunction {
+                name: "name".to_string(),
+                return_type: "str".to_string(),
+                ..Default::default()
+            },
+            move |_| Ok(ValueRef::str(value)),
+        );
+        registry
+    }
+
+    // Programs running concurrently call the plugins of the same name registered
+    // for themselves and their own fallback agents.
+    let handles: Vec<_> = ["a", "b"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            std::thread::spawn(move || {
+                let fallback = if i == 0 {
+                    fallback_agent as usize as u64
+                } else {
+                    0
+                };
+                with_plugin_agent(&registry(value), fallback, |plugin_agent| {
+                    (0..100)
+                        .map(|_| {
+                            (
+                                call(plugin_agent, "kcl_plugin.app.name"),
+                                call(plugin_agent, "kcl_plugin.app.other"),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert!(results[0]
+        .iter()
+        .all(|(name, other)| name == "\"a\"" && other == "\"fallback\""));
+    assert!(results[1]
+        .iter()
+        .all(|(name, other)| name == "\"b\"" && other.contains("is not found")));
+    // The agent is not available after the program exits.
+    let plugin_agent = with_plugin_agent(&registry("a"), 0, |plugin_agent| plugin_agent);
+    assert!(call(plugin_agent, "kcl_plugin.app.name").contains("is not found"));
+    assert!(PluginRegistry::new().is_empty());
+}
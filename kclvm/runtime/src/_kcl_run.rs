@@ -60,12 +60,18 @@ pub unsafe extern "C" fn _kcl_run(
     disable_schema_check: i32,
     list_option_mode: i32,
     debug_mode: i32,
+    provenance: i32,
+    clock: *const kclvm_char_t,
+    big_int: i32,
+    random_seed: i64,
     result_buffer_len: *mut kclvm_size_t,
     result_buffer: *mut kclvm_char_t,
     warn_buffer_len: *mut kclvm_size_t,
     warn_buffer: *mut kclvm_char_t,
     log_buffer_len: *mut kclvm_size_t,
     log_buffer: *mut kclvm_char_t,
+    source_map_buffer_len: *mut kclvm_size_t,
+    source_map_buffer: *mut kclvm_char_t,
 ) -> kclvm_size_t {
     let ctx = kclvm_context_new();
 
@@ -104,6 +110,10 @@ pub unsafe extern "C" fn _kcl_run(
             disable_schema_check,
             list_option_mode,
             debug_mode,
+            provenance,
+            clock,
+            big_int,
+            random_seed,
             result_buffer_len,
             result_buffer,
         )
@@ -123,6 +133,20 @@ pub unsafe extern "C" fn _kcl_run(
         std::ptr::copy(c_str_ptr, log_buffer, c_str_len as usize);
         *log_buffer_len = c_str_len
     }
+    // Copy source map pointer
+    if let Some(source_map) = &ctx_ref.buffer.source_map {
+        let c_str_ptr = source_map.as_ptr() as *const c_char;
+        let c_str_len = source_map.len() as i32;
+        if c_str_len <= *source_map_buffer_len {
+            std::ptr::copy(c_str_ptr, source_map_buffer, c_str_len as usize);
+            *source_map_buffer_len = c_str_len
+        } else {
+            // Return the negative source map length when it exceeds the buffer size.
+            *source_map_buffer_len = 0 - c_str_len
+        }
+    } else {
+        *source_map_buffer_len = 0;
+    }
     // Copy panic info message pointer
     let json_panic_info = ctx_ref.get_panic_info_json_string();
     let c_str_ptr = json_panic_info.as_ptr() as *const c_char;
@@ -168,6 +192,10 @@ unsafe fn _kcl_run_in_closure(
     disable_schema_check: i32,
     list_option_mode: i32,
     debug_mode: i32,
+    provenance: i32,
+    clock: *const kclvm_char_t,
+    big_int: i32,
+    random_seed: i64,
     result_buffer_len: *mut kclvm_size_t,
     result_buffer: *mut kclvm_char_t,
 ) -> kclvm_size_t {
@@ -179,6 +207,15 @@ unsafe fn _kcl_run_in_closure(
     kclvm_context_set_disable_schema_check(ctx, disable_schema_check as kclvm_bool_t);
     kclvm_context_set_list_option_mode(ctx, list_option_mode as kclvm_bool_t);
     kclvm_context_set_debug_mode(ctx, debug_mode as kclvm_bool_t);
+    mut_ptr_as_ref(ctx).cfg.provenance = provenance != 0;
+    mut_ptr_as_ref(ctx).cfg.big_int = big_int != 0;
+    mut_ptr_as_ref(ctx).cfg.random_seed = random_seed;
+    if !clock.is_null() {
+        let clock = c2str(clock);
+        if !clock.is_empty() {
+            mut_ptr_as_ref(ctx).cfg.clock = Some(clock.to_string());
+        }
+    }
 
     unsafe {
         let option_keys = std::slice::from_raw_parts(option_keys, option_len as usize);
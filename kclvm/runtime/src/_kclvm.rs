@@ -34,6 +34,7 @@ pub enum ApiFunc {
     kclvm_builtin_any_true,
     kclvm_builtin_bin,
     kclvm_builtin_bool,
+    kclvm_builtin_decimal,
     kclvm_builtin_dict,
     kclvm_builtin_float,
     kclvm_builtin_hex,
@@ -56,8 +57,12 @@ pub enum ApiFunc {
     kclvm_builtin_sorted,
     kclvm_builtin_str,
     kclvm_builtin_str_capitalize,
+    kclvm_builtin_str_casefold,
+    kclvm_builtin_str_center,
     kclvm_builtin_str_count,
+    kclvm_builtin_str_encode,
     kclvm_builtin_str_endswith,
+    kclvm_builtin_str_expandtabs,
     kclvm_builtin_str_find,
     kclvm_builtin_str_format,
     kclvm_builtin_str_index,
@@ -69,24 +74,42 @@ pub enum ApiFunc {
     kclvm_builtin_str_istitle,
     kclvm_builtin_str_isupper,
     kclvm_builtin_str_join,
+    kclvm_builtin_str_ljust,
     kclvm_builtin_str_lower,
     kclvm_builtin_str_lstrip,
+    kclvm_builtin_str_partition,
     kclvm_builtin_str_removeprefix,
     kclvm_builtin_str_removesuffix,
     kclvm_builtin_str_replace,
     kclvm_builtin_str_rfind,
     kclvm_builtin_str_rindex,
+    kclvm_builtin_str_rjust,
+    kclvm_builtin_str_rpartition,
     kclvm_builtin_str_rsplit,
     kclvm_builtin_str_rstrip,
     kclvm_builtin_str_split,
     kclvm_builtin_str_splitlines,
     kclvm_builtin_str_startswith,
     kclvm_builtin_str_strip,
+    kclvm_builtin_str_swapcase,
     kclvm_builtin_str_title,
+    kclvm_builtin_str_translate,
     kclvm_builtin_str_upper,
+    kclvm_builtin_str_zfill,
     kclvm_builtin_sum,
     kclvm_builtin_typeof,
     kclvm_builtin_zip,
+    kclvm_collection_chunk,
+    kclvm_collection_deep_get,
+    kclvm_collection_deep_set,
+    kclvm_collection_filter,
+    kclvm_collection_flatten,
+    kclvm_collection_group_by,
+    kclvm_collection_map,
+    kclvm_collection_merge,
+    kclvm_collection_reduce,
+    kclvm_collection_union_all,
+    kclvm_collection_unique_by,
     kclvm_config_attr_map,
     kclvm_context_delete,
     kclvm_context_invoke,
@@ -105,16 +128,40 @@ pub enum ApiFunc {
     kclvm_context_set_list_option_mode,
     kclvm_context_set_strict_range_check,
     kclvm_convert_collection_value,
+    kclvm_crypto_argon2,
+    kclvm_crypto_argon2_verify,
+    kclvm_crypto_bcrypt,
+    kclvm_crypto_bcrypt_verify,
+    kclvm_crypto_blake3,
+    kclvm_crypto_fileblake3,
+    kclvm_crypto_filesha256,
+    kclvm_crypto_filesha512,
+    kclvm_crypto_hmac,
     kclvm_crypto_md5,
     kclvm_crypto_sha1,
     kclvm_crypto_sha224,
     kclvm_crypto_sha256,
     kclvm_crypto_sha384,
+    kclvm_crypto_sha3_224,
+    kclvm_crypto_sha3_256,
+    kclvm_crypto_sha3_384,
+    kclvm_crypto_sha3_512,
     kclvm_crypto_sha512,
+    kclvm_datetime_add,
+    kclvm_datetime_compare,
     kclvm_datetime_date,
+    kclvm_datetime_diff,
+    kclvm_datetime_duration,
+    kclvm_datetime_format,
+    kclvm_datetime_from_timestamp,
     kclvm_datetime_now,
+    kclvm_datetime_parse,
+    kclvm_datetime_sub,
     kclvm_datetime_ticks,
+    kclvm_datetime_timestamp,
+    kclvm_datetime_to_timezone,
     kclvm_datetime_today,
+    kclvm_datetime_validate,
     kclvm_default_collection_insert_int_pointer,
     kclvm_default_collection_insert_value,
     kclvm_dict_clear,
@@ -142,8 +189,12 @@ pub enum ApiFunc {
     kclvm_iterator_is_end,
     kclvm_iterator_next_value,
     kclvm_json_decode,
+    kclvm_json_diff,
     kclvm_json_dump_to_file,
     kclvm_json_encode,
+    kclvm_json_merge_patch,
+    kclvm_json_patch,
+    kclvm_json_pointer,
     kclvm_json_validate,
     kclvm_list_append,
     kclvm_list_append_bool,
@@ -180,8 +231,16 @@ pub enum ApiFunc {
     kclvm_math_modf,
     kclvm_math_pow,
     kclvm_math_sqrt,
+    kclvm_net_CIDR_contains,
+    kclvm_net_CIDR_host,
+    kclvm_net_CIDR_hosts,
+    kclvm_net_CIDR_overlaps,
+    kclvm_net_CIDR_subnet,
+    kclvm_net_CIDR_subnets,
     kclvm_net_IP_string,
+    kclvm_net_IP_to_int,
     kclvm_net_fqdn,
+    kclvm_net_int_to_IP,
     kclvm_net_is_IP,
     kclvm_net_is_IPv4,
     kclvm_net_is_global_unicast_IP,
@@ -192,6 +251,7 @@ pub enum ApiFunc {
     kclvm_net_is_multicast_IP,
     kclvm_net_is_unspecified_IP,
     kclvm_net_join_host_port,
+    kclvm_net_parse_CIDR,
     kclvm_net_parse_IP,
     kclvm_net_split_host_port,
     kclvm_net_to_IP16,
@@ -199,6 +259,12 @@ pub enum ApiFunc {
     kclvm_plugin_init,
     kclvm_plugin_invoke,
     kclvm_plugin_invoke_json,
+    kclvm_random_choice,
+    kclvm_random_randint,
+    kclvm_random_random,
+    kclvm_random_sample,
+    kclvm_random_seed,
+    kclvm_random_shuffle,
     kclvm_regex_compile,
     kclvm_regex_findall,
     kclvm_regex_match,
@@ -214,6 +280,20 @@ pub enum ApiFunc {
     kclvm_schema_optional_check,
     kclvm_schema_value_check,
     kclvm_schema_value_new,
+    kclvm_semver_bump,
+    kclvm_semver_compare,
+    kclvm_semver_is_valid,
+    kclvm_semver_max_satisfying,
+    kclvm_semver_parse,
+    kclvm_semver_satisfies,
+    kclvm_semver_sort,
+    kclvm_units_add_quantity,
+    kclvm_units_compare_quantity,
+    kclvm_units_format_quantity,
+    kclvm_units_parse_quantity,
+    kclvm_units_scale_quantity,
+    kclvm_units_sub_quantity,
+    kclvm_units_sum_quantities,
     kclvm_units_to_G,
     kclvm_units_to_Gi,
     kclvm_units_to_K,
@@ -227,6 +307,9 @@ pub enum ApiFunc {
     kclvm_units_to_m,
     kclvm_units_to_n,
     kclvm_units_to_u,
+    kclvm_uuid_is_valid,
+    kclvm_uuid_uuid4,
+    kclvm_uuid_uuid5,
     kclvm_value_Bool,
     kclvm_value_Decorator,
     kclvm_value_Dict,
@@ -305,13 +388,13 @@ pub enum ApiFunc {
     kclvm_value_to_json_value,
     kclvm_value_to_json_value_with_null,
     kclvm_value_to_str_value,
+    kclvm_value_to_str_value_with_spec,
     kclvm_value_to_yaml_value,
     kclvm_value_unary_l_not,
     kclvm_value_unary_minus,
     kclvm_value_unary_not,
     kclvm_value_unary_plus,
     kclvm_value_union,
-    kclvm_value_union_all,
     kclvm_yaml_decode,
     kclvm_yaml_decode_all,
     kclvm_yaml_dump_all_to_file,
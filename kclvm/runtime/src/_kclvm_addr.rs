@@ -13,6 +13,7 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_builtin_any_true" => crate::kclvm_builtin_any_true as *const () as u64,
         "kclvm_builtin_bin" => crate::kclvm_builtin_bin as *const () as u64,
         "kclvm_builtin_bool" => crate::kclvm_builtin_bool as *const () as u64,
+        "kclvm_builtin_decimal" => crate::kclvm_builtin_decimal as *const () as u64,
         "kclvm_builtin_dict" => crate::kclvm_builtin_dict as *const () as u64,
         "kclvm_builtin_float" => crate::kclvm_builtin_float as *const () as u64,
         "kclvm_builtin_hex" => crate::kclvm_builtin_hex as *const () as u64,
@@ -35,8 +36,12 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_builtin_sorted" => crate::kclvm_builtin_sorted as *const () as u64,
         "kclvm_builtin_str" => crate::kclvm_builtin_str as *const () as u64,
         "kclvm_builtin_str_capitalize" => crate::kclvm_builtin_str_capitalize as *const () as u64,
+        "kclvm_builtin_str_casefold" => crate::kclvm_builtin_str_casefold as *const () as u64,
+        "kclvm_builtin_str_center" => crate::kclvm_builtin_str_center as *const () as u64,
         "kclvm_builtin_str_count" => crate::kclvm_builtin_str_count as *const () as u64,
+        "kclvm_builtin_str_encode" => crate::kclvm_builtin_str_encode as *const () as u64,
         "kclvm_builtin_str_endswith" => crate::kclvm_builtin_str_endswith as *const () as u64,
+        "kclvm_builtin_str_expandtabs" => crate::kclvm_builtin_str_expandtabs as *const () as u64,
         "kclvm_builtin_str_find" => crate::kclvm_builtin_str_find as *const () as u64,
         "kclvm_builtin_str_format" => crate::kclvm_builtin_str_format as *const () as u64,
         "kclvm_builtin_str_index" => crate::kclvm_builtin_str_index as *const () as u64,
@@ -48,8 +53,10 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_builtin_str_istitle" => crate::kclvm_builtin_str_istitle as *const () as u64,
         "kclvm_builtin_str_isupper" => crate::kclvm_builtin_str_isupper as *const () as u64,
         "kclvm_builtin_str_join" => crate::kclvm_builtin_str_join as *const () as u64,
+        "kclvm_builtin_str_ljust" => crate::kclvm_builtin_str_ljust as *const () as u64,
         "kclvm_builtin_str_lower" => crate::kclvm_builtin_str_lower as *const () as u64,
         "kclvm_builtin_str_lstrip" => crate::kclvm_builtin_str_lstrip as *const () as u64,
+        "kclvm_builtin_str_partition" => crate::kclvm_builtin_str_partition as *const () as u64,
         "kclvm_builtin_str_removeprefix" => {
             crate::kclvm_builtin_str_removeprefix as *const () as u64
         }
@@ -59,17 +66,33 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_builtin_str_replace" => crate::kclvm_builtin_str_replace as *const () as u64,
         "kclvm_builtin_str_rfind" => crate::kclvm_builtin_str_rfind as *const () as u64,
         "kclvm_builtin_str_rindex" => crate::kclvm_builtin_str_rindex as *const () as u64,
+        "kclvm_builtin_str_rjust" => crate::kclvm_builtin_str_rjust as *const () as u64,
+        "kclvm_builtin_str_rpartition" => crate::kclvm_builtin_str_rpartition as *const () as u64,
         "kclvm_builtin_str_rsplit" => crate::kclvm_builtin_str_rsplit as *const () as u64,
         "kclvm_builtin_str_rstrip" => crate::kclvm_builtin_str_rstrip as *const () as u64,
         "kclvm_builtin_str_split" => crate::kclvm_builtin_str_split as *const () as u64,
         "kclvm_builtin_str_splitlines" => crate::kclvm_builtin_str_splitlines as *const () as u64,
         "kclvm_builtin_str_startswith" => crate::kclvm_builtin_str_startswith as *const () as u64,
         "kclvm_builtin_str_strip" => crate::kclvm_builtin_str_strip as *const () as u64,
+        "kclvm_builtin_str_swapcase" => crate::kclvm_builtin_str_swapcase as *const () as u64,
         "kclvm_builtin_str_title" => crate::kclvm_builtin_str_title as *const () as u64,
+        "kclvm_builtin_str_translate" => crate::kclvm_builtin_str_translate as *const () as u64,
         "kclvm_builtin_str_upper" => crate::kclvm_builtin_str_upper as *const () as u64,
+        "kclvm_builtin_str_zfill" => crate::kclvm_builtin_str_zfill as *const () as u64,
         "kclvm_builtin_sum" => crate::kclvm_builtin_sum as *const () as u64,
         "kclvm_builtin_typeof" => crate::kclvm_builtin_typeof as *const () as u64,
         "kclvm_builtin_zip" => crate::kclvm_builtin_zip as *const () as u64,
+        "kclvm_collection_chunk" => crate::kclvm_collection_chunk as *const () as u64,
+        "kclvm_collection_deep_get" => crate::kclvm_collection_deep_get as *const () as u64,
+        "kclvm_collection_deep_set" => crate::kclvm_collection_deep_set as *const () as u64,
+        "kclvm_collection_filter" => crate::kclvm_collection_filter as *const () as u64,
+        "kclvm_collection_flatten" => crate::kclvm_collection_flatten as *const () as u64,
+        "kclvm_collection_group_by" => crate::kclvm_collection_group_by as *const () as u64,
+        "kclvm_collection_map" => crate::kclvm_collection_map as *const () as u64,
+        "kclvm_collection_merge" => crate::kclvm_collection_merge as *const () as u64,
+        "kclvm_collection_reduce" => crate::kclvm_collection_reduce as *const () as u64,
+        "kclvm_collection_union_all" => crate::kclvm_collection_union_all as *const () as u64,
+        "kclvm_collection_unique_by" => crate::kclvm_collection_unique_by as *const () as u64,
         "kclvm_config_attr_map" => crate::kclvm_config_attr_map as *const () as u64,
         "kclvm_context_delete" => crate::kclvm_context_delete as *const () as u64,
         "kclvm_context_invoke" => crate::kclvm_context_invoke as *const () as u64,
@@ -108,16 +131,40 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_convert_collection_value" => {
             crate::kclvm_convert_collection_value as *const () as u64
         }
+        "kclvm_crypto_argon2" => crate::kclvm_crypto_argon2 as *const () as u64,
+        "kclvm_crypto_argon2_verify" => crate::kclvm_crypto_argon2_verify as *const () as u64,
+        "kclvm_crypto_bcrypt" => crate::kclvm_crypto_bcrypt as *const () as u64,
+        "kclvm_crypto_bcrypt_verify" => crate::kclvm_crypto_bcrypt_verify as *const () as u64,
+        "kclvm_crypto_blake3" => crate::kclvm_crypto_blake3 as *const () as u64,
+        "kclvm_crypto_fileblake3" => crate::kclvm_crypto_fileblake3 as *const () as u64,
+        "kclvm_crypto_filesha256" => crate::kclvm_crypto_filesha256 as *const () as u64,
+        "kclvm_crypto_filesha512" => crate::kclvm_crypto_filesha512 as *const () as u64,
+        "kclvm_crypto_hmac" => crate::kclvm_crypto_hmac as *const () as u64,
         "kclvm_crypto_md5" => crate::kclvm_crypto_md5 as *const () as u64,
         "kclvm_crypto_sha1" => crate::kclvm_crypto_sha1 as *const () as u64,
         "kclvm_crypto_sha224" => crate::kclvm_crypto_sha224 as *const () as u64,
         "kclvm_crypto_sha256" => crate::kclvm_crypto_sha256 as *const () as u64,
         "kclvm_crypto_sha384" => crate::kclvm_crypto_sha384 as *const () as u64,
+        "kclvm_crypto_sha3_224" => crate::kclvm_crypto_sha3_224 as *const () as u64,
+        "kclvm_crypto_sha3_256" => crate::kclvm_crypto_sha3_256 as *const () as u64,
+        "kclvm_crypto_sha3_384" => crate::kclvm_crypto_sha3_384 as *const () as u64,
+        "kclvm_crypto_sha3_512" => crate::kclvm_crypto_sha3_512 as *const () as u64,
         "kclvm_crypto_sha512" => crate::kclvm_crypto_sha512 as *const () as u64,
+        "kclvm_datetime_add" => crate::kclvm_datetime_add as *const () as u64,
+        "kclvm_datetime_compare" => crate::kclvm_datetime_compare as *const () as u64,
         "kclvm_datetime_date" => crate::kclvm_datetime_date as *const () as u64,
+        "kclvm_datetime_diff" => crate::kclvm_datetime_diff as *const () as u64,
+        "kclvm_datetime_duration" => crate::kclvm_datetime_duration as *const () as u64,
+        "kclvm_datetime_format" => crate::kclvm_datetime_format as *const () as u64,
+        "kclvm_datetime_from_timestamp" => crate::kclvm_datetime_from_timestamp as *const () as u64,
         "kclvm_datetime_now" => crate::kclvm_datetime_now as *const () as u64,
+        "kclvm_datetime_parse" => crate::kclvm_datetime_parse as *const () as u64,
+        "kclvm_datetime_sub" => crate::kclvm_datetime_sub as *const () as u64,
         "kclvm_datetime_ticks" => crate::kclvm_datetime_ticks as *const () as u64,
+        "kclvm_datetime_timestamp" => crate::kclvm_datetime_timestamp as *const () as u64,
+        "kclvm_datetime_to_timezone" => crate::kclvm_datetime_to_timezone as *const () as u64,
         "kclvm_datetime_today" => crate::kclvm_datetime_today as *const () as u64,
+        "kclvm_datetime_validate" => crate::kclvm_datetime_validate as *const () as u64,
         "kclvm_default_collection_insert_int_pointer" => {
             crate::kclvm_default_collection_insert_int_pointer as *const () as u64
         }
@@ -149,8 +196,12 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_iterator_is_end" => crate::kclvm_iterator_is_end as *const () as u64,
         "kclvm_iterator_next_value" => crate::kclvm_iterator_next_value as *const () as u64,
         "kclvm_json_decode" => crate::kclvm_json_decode as *const () as u64,
+        "kclvm_json_diff" => crate::kclvm_json_diff as *const () as u64,
         "kclvm_json_dump_to_file" => crate::kclvm_json_dump_to_file as *const () as u64,
         "kclvm_json_encode" => crate::kclvm_json_encode as *const () as u64,
+        "kclvm_json_merge_patch" => crate::kclvm_json_merge_patch as *const () as u64,
+        "kclvm_json_patch" => crate::kclvm_json_patch as *const () as u64,
+        "kclvm_json_pointer" => crate::kclvm_json_pointer as *const () as u64,
         "kclvm_json_validate" => crate::kclvm_json_validate as *const () as u64,
         "kclvm_list_append" => crate::kclvm_list_append as *const () as u64,
         "kclvm_list_append_bool" => crate::kclvm_list_append_bool as *const () as u64,
@@ -187,8 +238,16 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_math_modf" => crate::kclvm_math_modf as *const () as u64,
         "kclvm_math_pow" => crate::kclvm_math_pow as *const () as u64,
         "kclvm_math_sqrt" => crate::kclvm_math_sqrt as *const () as u64,
+        "kclvm_net_CIDR_contains" => crate::kclvm_net_CIDR_contains as *const () as u64,
+        "kclvm_net_CIDR_host" => crate::kclvm_net_CIDR_host as *const () as u64,
+        "kclvm_net_CIDR_hosts" => crate::kclvm_net_CIDR_hosts as *const () as u64,
+        "kclvm_net_CIDR_overlaps" => crate::kclvm_net_CIDR_overlaps as *const () as u64,
+        "kclvm_net_CIDR_subnet" => crate::kclvm_net_CIDR_subnet as *const () as u64,
+        "kclvm_net_CIDR_subnets" => crate::kclvm_net_CIDR_subnets as *const () as u64,
         "kclvm_net_IP_string" => crate::kclvm_net_IP_string as *const () as u64,
+        "kclvm_net_IP_to_int" => crate::kclvm_net_IP_to_int as *const () as u64,
         "kclvm_net_fqdn" => crate::kclvm_net_fqdn as *const () as u64,
+        "kclvm_net_int_to_IP" => crate::kclvm_net_int_to_IP as *const () as u64,
         "kclvm_net_is_IP" => crate::kclvm_net_is_IP as *const () as u64,
         "kclvm_net_is_IPv4" => crate::kclvm_net_is_IPv4 as *const () as u64,
         "kclvm_net_is_global_unicast_IP" => {
@@ -207,6 +266,7 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_net_is_multicast_IP" => crate::kclvm_net_is_multicast_IP as *const () as u64,
         "kclvm_net_is_unspecified_IP" => crate::kclvm_net_is_unspecified_IP as *const () as u64,
         "kclvm_net_join_host_port" => crate::kclvm_net_join_host_port as *const () as u64,
+        "kclvm_net_parse_CIDR" => crate::kclvm_net_parse_CIDR as *const () as u64,
         "kclvm_net_parse_IP" => crate::kclvm_net_parse_IP as *const () as u64,
         "kclvm_net_split_host_port" => crate::kclvm_net_split_host_port as *const () as u64,
         "kclvm_net_to_IP16" => crate::kclvm_net_to_IP16 as *const () as u64,
@@ -214,6 +274,12 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_plugin_init" => crate::kclvm_plugin_init as *const () as u64,
         "kclvm_plugin_invoke" => crate::kclvm_plugin_invoke as *const () as u64,
         "kclvm_plugin_invoke_json" => crate::kclvm_plugin_invoke_json as *const () as u64,
+        "kclvm_random_choice" => crate::kclvm_random_choice as *const () as u64,
+        "kclvm_random_randint" => crate::kclvm_random_randint as *const () as u64,
+        "kclvm_random_random" => crate::kclvm_random_random as *const () as u64,
+        "kclvm_random_sample" => crate::kclvm_random_sample as *const () as u64,
+        "kclvm_random_seed" => crate::kclvm_random_seed as *const () as u64,
+        "kclvm_random_shuffle" => crate::kclvm_random_shuffle as *const () as u64,
         "kclvm_regex_compile" => crate::kclvm_regex_compile as *const () as u64,
         "kclvm_regex_findall" => crate::kclvm_regex_findall as *const () as u64,
         "kclvm_regex_match" => crate::kclvm_regex_match as *const () as u64,
@@ -231,6 +297,20 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_schema_optional_check" => crate::kclvm_schema_optional_check as *const () as u64,
         "kclvm_schema_value_check" => crate::kclvm_schema_value_check as *const () as u64,
         "kclvm_schema_value_new" => crate::kclvm_schema_value_new as *const () as u64,
+        "kclvm_semver_bump" => crate::kclvm_semver_bump as *const () as u64,
+        "kclvm_semver_compare" => crate::kclvm_semver_compare as *const () as u64,
+        "kclvm_semver_is_valid" => crate::kclvm_semver_is_valid as *const () as u64,
+        "kclvm_semver_max_satisfying" => crate::kclvm_semver_max_satisfying as *const () as u64,
+        "kclvm_semver_parse" => crate::kclvm_semver_parse as *const () as u64,
+        "kclvm_semver_satisfies" => crate::kclvm_semver_satisfies as *const () as u64,
+        "kclvm_semver_sort" => crate::kclvm_semver_sort as *const () as u64,
+        "kclvm_units_add_quantity" => crate::kclvm_units_add_quantity as *const () as u64,
+        "kclvm_units_compare_quantity" => crate::kclvm_units_compare_quantity as *const () as u64,
+        "kclvm_units_format_quantity" => crate::kclvm_units_format_quantity as *const () as u64,
+        "kclvm_units_parse_quantity" => crate::kclvm_units_parse_quantity as *const () as u64,
+        "kclvm_units_scale_quantity" => crate::kclvm_units_scale_quantity as *const () as u64,
+        "kclvm_units_sub_quantity" => crate::kclvm_units_sub_quantity as *const () as u64,
+        "kclvm_units_sum_quantities" => crate::kclvm_units_sum_quantities as *const () as u64,
         "kclvm_units_to_G" => crate::kclvm_units_to_G as *const () as u64,
         "kclvm_units_to_Gi" => crate::kclvm_units_to_Gi as *const () as u64,
         "kclvm_units_to_K" => crate::kclvm_units_to_K as *const () as u64,
@@ -244,6 +324,9 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_units_to_m" => crate::kclvm_units_to_m as *const () as u64,
         "kclvm_units_to_n" => crate::kclvm_units_to_n as *const () as u64,
         "kclvm_units_to_u" => crate::kclvm_units_to_u as *const () as u64,
+        "kclvm_uuid_is_valid" => crate::kclvm_uuid_is_valid as *const () as u64,
+        "kclvm_uuid_uuid4" => crate::kclvm_uuid_uuid4 as *const () as u64,
+        "kclvm_uuid_uuid5" => crate::kclvm_uuid_uuid5 as *const () as u64,
         "kclvm_value_Bool" => crate::kclvm_value_Bool as *const () as u64,
         "kclvm_value_Decorator" => crate::kclvm_value_Decorator as *const () as u64,
         "kclvm_value_Dict" => crate::kclvm_value_Dict as *const () as u64,
@@ -334,13 +417,15 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
             crate::kclvm_value_to_json_value_with_null as *const () as u64
         }
         "kclvm_value_to_str_value" => crate::kclvm_value_to_str_value as *const () as u64,
+        "kclvm_value_to_str_value_with_spec" => {
+            crate::kclvm_value_to_str_value_with_spec as *const () as u64
+        }
         "kclvm_value_to_yaml_value" => crate::kclvm_value_to_yaml_value as *const () as u64,
         "kclvm_value_unary_l_not" => crate::kclvm_value_unary_l_not as *const () as u64,
         "kclvm_value_unary_minus" => crate::kclvm_value_unary_minus as *const () as u64,
         "kclvm_value_unary_not" => crate::kclvm_value_unary_not as *const () as u64,
         "kclvm_value_unary_plus" => crate::kclvm_value_unary_plus as *const () as u64,
         "kclvm_value_union" => crate::kclvm_value_union as *const () as u64,
-        "kclvm_value_union_all" => crate::kclvm_value_union_all as *const () as u64,
         "kclvm_yaml_decode" => crate::kclvm_yaml_decode as *const () as u64,
         "kclvm_yaml_decode_all" => crate::kclvm_yaml_decode_all as *const () as u64,
         "kclvm_yaml_dump_all_to_file" => crate::kclvm_yaml_dump_all_to_file as *const () as u64,
@@ -2,8 +2,10 @@
 
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = crate::ValueRef;
-use crate::{new_mut_ptr, IndexMap};
+use crate::{new_mut_ptr, Decimal, IndexMap};
 use indexmap::IndexSet;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::panic::{RefUnwindSafe, UnwindSafe};
@@ -108,6 +110,9 @@ impl PartialEq for ValueRef {
 
 impl Ord for ValueRef {
     fn cmp(&self, other: &ValueRef) -> Ordering {
+        if let Some(ord) = self.cmp_big_number(other) {
+            return ord;
+        }
         let ord = match *self.rc.borrow() {
             Value::int_value(a) => match *other.rc.borrow() {
                 Value::int_value(b) => a.partial_cmp(&b),
@@ -148,6 +153,9 @@ impl Hash for ValueRef {
             Value::undefined => panic!("unsupport hash for undefined"),
             Value::none => panic!("unsupport hash for none"),
             Value::int_value(v) => (*v as f64).to_bits().hash(state),
+            // Big numbers hash like the equal int and float values.
+            Value::bigint_value(v) => v.to_f64().unwrap_or(f64::NAN).to_bits().hash(state),
+            Value::decimal_value(v) => v.to_f64().to_bits().hash(state),
             Value::unit_value(_real, raw, unit) => {
                 raw.hash(state);
                 unit.hash(state);
@@ -212,7 +220,10 @@ pub enum Value {
     none,
     bool_value(bool),
     int_value(i64),
+    /// The int value which overflows i64, only produced when the config `big_int` is enabled.
+    bigint_value(BigInt),
     float_value(f64),
+    decimal_value(Decimal),
     str_value(String),
     list_value(Box<ListValue>),
     dict_value(Box<DictValue>),
@@ -238,6 +249,19 @@ pub struct DictValue {
     pub ops: IndexMap<String, ConfigEntryOperationKind>,
     pub insert_indexs: IndexMap<String, i32>,
     pub attr_map: IndexMap<String, String>,
+    /// The source locations which contribute to the entry values in order,
+    /// only recorded when the context config `provenance` is enabled.
+    pub sources: IndexMap<String, Vec<SourceLocation>>,
+}
+
+/// SourceLocation denotes a KCL source location that sets a dict entry
+/// and the config operation it uses.
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub filename: String,
+    pub line: i32,
+    /// The config entry operator, e.g., `:`, `=` and `+=`.
+    pub op: String,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -318,6 +342,17 @@ pub struct ContextConfig {
     pub disable_none: bool,
     /// Whether to emit empty list in the plan process.
     pub disable_empty_list: bool,
+    /// Whether to record the source locations of dict entries.
+    pub provenance: bool,
+    /// The fixed current time in the RFC 3339 format used by the datetime
+    /// functions instead of the system clock.
+    pub clock: Option<String>,
+    /// Whether to promote the int values which overflow i64 to big integers
+    /// instead of raising the overflow errors.
+    pub big_int: bool,
+    /// The seed of the context random generator used by the random and uuid
+    /// functions.
+    pub random_seed: i64,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -325,6 +360,8 @@ pub struct ContextBuffer {
     pub kclvm_context_invoke_result: String,
     /// Custom manifest output string.
     pub custom_manifests_output: Option<String>,
+    /// The source map of the planned result in the JSON format.
+    pub source_map: Option<String>,
 }
 
 impl Default for ContextBuffer {
@@ -332,6 +369,7 @@ impl Default for ContextBuffer {
         Self {
             kclvm_context_invoke_result: "\0".to_string(),
             custom_manifests_output: None,
+            source_map: None,
         }
     }
 }
@@ -379,6 +417,9 @@ pub struct Context {
     pub objects: IndexSet<usize>,
     /// Log message used to store print results.
     pub log_message: String,
+    /// The state of the context random generator, which is seeded with
+    /// `cfg.random_seed` on the first use.
+    pub random_state: Option<u64>,
 }
 
 impl UnwindSafe for Context {}
@@ -454,6 +495,7 @@ pub enum Kind {
     StrLit = 16,
     Unit = 17,
     Func = 18,
+    Decimal = 19,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
@@ -465,6 +507,15 @@ pub enum ConfigEntryOperationKind {
 }
 
 impl ConfigEntryOperationKind {
+    /// Returns the config entry operator symbol.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ConfigEntryOperationKind::Union => ":",
+            ConfigEntryOperationKind::Override => "=",
+            ConfigEntryOperationKind::Insert => "+=",
+        }
+    }
+
     pub fn from_i32(v: i32) -> Self {
         match v {
             x if x == ConfigEntryOperationKind::Union as i32 => ConfigEntryOperationKind::Union,
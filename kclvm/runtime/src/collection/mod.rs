@@ -1,10 +1,21 @@
 //! Copyright The KCL Authors. All rights reserved.
+//!
+//! Functions that take callbacks e.g., `map`, `filter` and `reduce` accept KCL
+//! lambdas, and the nested paths of `deep_get` and `deep_set` are dotted strings
+//! e.g., `"spec.containers.0.image"` or lists of keys and indices.
 
 use crate::*;
 
+pub const MERGE_STRATEGY_UNION: &str = "union";
+pub const MERGE_STRATEGY_OVERRIDE: &str = "override";
+pub const MERGE_STRATEGY_APPEND: &str = "append";
+pub const MERGE_STRATEGY_KEEP: &str = "keep";
+
+// union_all(configs: [{:}]) -> {:}
+
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_value_union_all(
+pub extern "C" fn kclvm_collection_union_all(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
@@ -29,3 +40,505 @@ pub extern "C" fn kclvm_value_union_all(
     }
     panic!("union_all() takes at least 1 argument (0 given)")
 }
+
+// map(items: [any], func: (any) -> any) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_map(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("map() missing 1 required positional argument: 'items'");
+    let func = get_call_arg(args, kwargs, 1, Some("func"))
+        .expect("map() missing 1 required positional argument: 'func'");
+    let values: Vec<ValueRef> = iter_items(&items, "map")
+        .into_iter()
+        .map(|item| func.call(ctx, &[item]))
+        .collect();
+    list_of(&values).into_raw(ctx)
+}
+
+// filter(items: [any], func: (any) -> bool) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_filter(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("filter() missing 1 required positional argument: 'items'");
+    let func = get_call_arg(args, kwargs, 1, Some("func"))
+        .expect("filter() missing 1 required positional argument: 'func'");
+    let values: Vec<ValueRef> = iter_items(&items, "filter")
+        .into_iter()
+        .filter(|item| func.call(ctx, &[item.clone()]).is_truthy())
+        .collect();
+    list_of(&values).into_raw(ctx)
+}
+
+// reduce(items: [any], func: (any, any) -> any, initial?: any) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_reduce(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("reduce() missing 1 required positional argument: 'items'");
+    let func = get_call_arg(args, kwargs, 1, Some("func"))
+        .expect("reduce() missing 1 required positional argument: 'func'");
+    let mut items = iter_items(&items, "reduce").into_iter();
+    let initial = match get_call_arg(args, kwargs, 2, Some("initial")) {
+        Some(initial) => initial,
+        None => items
+            .next()
+            .expect("reduce() of empty items with no initial value"),
+    };
+    items
+        .fold(initial, |acc, item| func.call(ctx, &[acc, item]))
+        .into_raw(ctx)
+}
+
+// group_by(items: [any], key: (any) -> any) -> {str:[any]}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_group_by(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("group_by() missing 1 required positional argument: 'items'");
+    let key = get_call_arg(args, kwargs, 1, Some("key"))
+        .expect("group_by() missing 1 required positional argument: 'key'");
+    let mut groups = ValueRef::dict(None);
+    for item in iter_items(&items, "group_by") {
+        let group_key = key.call(ctx, &[item.clone()]).to_string();
+        match groups.dict_get_value(&group_key) {
+            Some(mut group) => group.list_append(&item),
+            None => groups.dict_update_key_value(&group_key, ValueRef::list(Some(&[&item]))),
+        }
+    }
+    groups.into_raw(ctx)
+}
+
+// unique_by(items: [any], key: (any) -> any) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_unique_by(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("unique_by() missing 1 required positional argument: 'items'");
+    let key = get_call_arg(args, kwargs, 1, Some("key"))
+        .expect("unique_by() missing 1 required positional argument: 'key'");
+    let mut seen: Vec<ValueRef> = vec![];
+    let mut values: Vec<ValueRef> = vec![];
+    for item in iter_items(&items, "unique_by") {
+        let item_key = key.call(ctx, &[item.clone()]);
+        if !seen.iter().any(|k| k.cmp_equal(&item_key)) {
+            seen.push(item_key);
+            values.push(item);
+        }
+    }
+    list_of(&values).into_raw(ctx)
+}
+
+// flatten(items: [any], depth: int = 1) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_flatten(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("flatten() missing 1 required positional argument: 'items'");
+    let depth = get_call_arg_int(args, kwargs, 1, Some("depth")).unwrap_or(1);
+    let mut values = vec![];
+    flatten_into(&iter_items(&items, "flatten"), depth, &mut values);
+    list_of(&values).into_raw(ctx)
+}
+
+// chunk(items: [any], size: int) -> [[any]]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_chunk(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let items = get_call_arg(args, kwargs, 0, Some("items"))
+        .expect("chunk() missing 1 required positional argument: 'items'");
+    let size = get_call_arg_int(args, kwargs, 1, Some("size"))
+        .expect("chunk() missing 1 required positional argument: 'size'");
+    if size <= 0 {
+        panic!("chunk() size must be a positive integer, got {size}");
+    }
+    let chunks: Vec<ValueRef> = iter_items(&items, "chunk")
+        .chunks(size as usize)
+        .map(list_of)
+        .collect();
+    list_of(&chunks).into_raw(ctx)
+}
+
+// deep_get(obj: {str:}, path: str | [str | int], default: any = None) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_deep_get(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let obj = get_call_arg(args, kwargs, 0, Some("obj"))
+        .expect("deep_get() missing 1 required positional argument: 'obj'");
+    let path = get_call_arg(args, kwargs, 1, Some("path"))
+        .expect("deep_get() missing 1 required positional argument: 'path'");
+    let default = get_call_arg(args, kwargs, 2, Some("default")).unwrap_or_else(ValueRef::none);
+    let mut value = obj;
+    for segment in path_segments(&path) {
+        match get_segment(&value, &segment) {
+            Some(v) => value = v,
+            None => return default.into_raw(ctx),
+        }
+    }
+    value.into_raw(ctx)
+}
+
+// deep_set(obj: {str:}, path: str | [str | int], value: any) -> {str:}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_deep_set(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let obj = get_call_arg(args, kwargs, 0, Some("obj"))
+        .expect("deep_set() missing 1 required positional argument: 'obj'");
+    let path = get_call_arg(args, kwargs, 1, Some("path"))
+        .expect("deep_set() missing 1 required positional argument: 'path'");
+    let value = args
+        .arg_i(2)
+        .or_else(|| kwargs.kwarg("value"))
+        .expect("deep_set() missing 1 required positional argument: 'value'");
+    let segments = path_segments(&path);
+    if segments.is_empty() {
+        panic!("deep_set() path must not be empty");
+    }
+    let result = obj.deep_copy();
+    set_segments(&result, &segments, &value);
+    result.into_raw(ctx)
+}
+
+// merge(configs: [{:}], strategy: str = "override") -> {:}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_collection_merge(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let configs = get_call_arg(args, kwargs, 0, Some("configs"))
+        .expect("merge() missing 1 required positional argument: 'configs'");
+    let strategy = get_call_arg_str(args, kwargs, 1, Some("strategy"))
+        .unwrap_or_else(|| MERGE_STRATEGY_OVERRIDE.to_string());
+    let configs = iter_items(&configs, "merge");
+    let mut result = match configs.first() {
+        Some(first) => first.deep_copy(),
+        None => return ValueRef::dict(None).into_raw(ctx),
+    };
+    for config in &configs[1..] {
+        result = match strategy.as_str() {
+            MERGE_STRATEGY_UNION => {
+                result.bin_aug_union_with(ctx, config);
+                result
+            }
+            MERGE_STRATEGY_OVERRIDE | MERGE_STRATEGY_APPEND | MERGE_STRATEGY_KEEP => {
+                merge_value(&result, config, &strategy)
+            }
+            _ => panic!(
+                "merge() strategy must be one of 'union', 'override', 'append' and 'keep', got '{strategy}'"
+            ),
+        };
+    }
+    result.into_raw(ctx)
+}
+
+/// Returns the items of the iterable value, the keys for the config value
+/// and the characters for the string value.
+fn iter_items(value: &ValueRef, fn_name: &str) -> Vec<ValueRef> {
+    match &*value.rc.borrow() {
+        Value::undefined | Value::none => vec![],
+        Value::list_value(list) => list.values.clone(),
+        Value::dict_value(dict) => dict.values.keys().map(|k| ValueRef::str(k)).collect(),
+        Value::schema_value(schema) => schema
+            .config
+            .values
+            .keys()
+            .map(|k| ValueRef::str(k))
+            .collect(),
+        Value::str_value(s) => s.chars().map(|c| ValueRef::str(&c.to_string())).collect(),
+        _ => panic!(
+            "{fn_name}() argument 'items' must be iterable, not '{}'",
+            value.type_str()
+        ),
+    }
+}
+
+#[inline]
+fn list_of(values: &[ValueRef]) -> ValueRef {
+    ValueRef::list(Some(&values.iter().collect::<Vec<&ValueRef>>()))
+}
+
+/// Flatten the nested lists up to `depth` levels, a negative `depth` flattens all levels.
+fn flatten_into(items: &[ValueRef], depth: i64, values: &mut Vec<ValueRef>) {
+    for item in items {
+        if depth != 0 && item.is_list() {
+            let nested = item.as_list_ref().values.clone();
+            flatten_into(&nested, depth - 1, values);
+        } else {
+            values.push(item.clone());
+        }
+    }
+}
+
+/// A dotted string path is split by `.`, and a list path holds keys and indices.
+fn path_segments(path: &ValueRef) -> Vec<ValueRef> {
+    if path.is_str() {
+        let path = path.as_str();
+        if path.is_empty() {
+            vec![]
+        } else {
+            path.split('.').map(ValueRef::str).collect()
+        }
+    } else if path.is_list() {
+        path.as_list_ref().values.clone()
+    } else {
+        panic!(
+            "path must be a str or a list of keys, not '{}'",
+            path.type_str()
+        )
+    }
+}
+
+/// Returns the list index denoted by the path segment e.g., `0` or `"0"`.
+fn segment_index(segment: &ValueRef) -> Option<usize> {
+    if segment.is_int() {
+        usize::try_from(segment.as_int()).ok()
+    } else if segment.is_str() {
+        segment.as_str().parse::<usize>().ok()
+    } else {
+        None
+    }
+}
+
+fn get_segment(value: &ValueRef, segment: &ValueRef) -> Option<ValueRef> {
+    if value.is_config() {
+        value.dict_get_value(&segment.to_string())
+    } else if value.is_list() {
+        let index = segment_index(segment)?;
+        value.as_list_ref().values.get(index).cloned()
+    } else {
+        None
+    }
+}
+
+fn set_segments(value: &ValueRef, segments: &[ValueRef], new_value: &ValueRef) {
+    let segment = &segments[0];
+    let rest = &segments[1..];
+    if value.is_config() {
+        let key = segment.to_string();
+        let mut value = value.clone();
+        if rest.is_empty() {
+            value.dict_update_key_value(&key, new_value.clone());
+            return;
+        }
+        let child = match value.dict_get_value(&key) {
+            Some(child) if child.is_config() || child.is_list() => child,
+            _ => {
+                let child = ValueRef::dict(None);
+                value.dict_update_key_value(&key, child.clone());
+                child
+            }
+        };
+        set_segments(&child, rest, new_value);
+    } else if value.is_list() {
+        let len = value.len();
+        let index = match segment_index(segment) {
+            Some(index) if index < len => index,
+            _ => panic!("deep_set() list index '{segment}' out of range"),
+        };
+        if rest.is_empty() {
+            value.clone().list_set(index, new_value);
+        } else {
+            let child = value.as_list_ref().values[index].clone();
+            set_segments(&child, rest, new_value);
+        }
+    } else {
+        panic!(
+            "deep_set() can't set the key '{segment}' on '{}'",
+            value.type_str()
+        );
+    }
+}
+
+/// Deep merge two config values. Nested configs are merged recursively, and the
+/// other values are overridden (`override`), concatenated for lists (`append`)
+/// or kept from the base (`keep`).
+fn merge_value(base: &ValueRef, other: &ValueRef, strategy: &str) -> ValueRef {
+    if base.is_config() && other.is_config() {
+        let mut result = base.deep_copy();
+        for (key, value) in other.as_dict_ref().values.iter() {
+            let merged = match base.dict_get_value(key) {
+                Some(base_value) => merge_value(&base_value, value, strategy),
+                None => value.deep_copy(),
+            };
+            result.dict_update_key_value(key, merged);
+        }
+        result
+    } else if strategy == MERGE_STRATEGY_APPEND && base.is_list() && other.is_list() {
+        let mut values = base.as_list_ref().values.clone();
+        values.extend(other.as_list_ref().values.iter().cloned());
+        list_of(&values).deep_copy()
+    } else if strategy == MERGE_STRATEGY_KEEP && !base.is_none_or_undefined() {
+        base.deep_copy()
+    } else {
+        other.deep_copy()
+    }
+}
+
+#[cfg(test)]
+mod test_collection {
+    use super::*;
+
+    fn config(s: &str) -> ValueRef {
+        ValueRef::from_json(&mut Context::new(), s).unwrap()
+    }
+
+    #[test]
+    fn test_flatten_into() {
+        let items = config("[1, [2, [3, [4]]], 5]").as_list_ref().values.clone();
+        let cases = [
+            (0, "[1, [2, [3, [4]]], 5]"),
+            (1, "[1, 2, [3, [4]], 5]"),
+            (2, "[1, 2, 3, [4], 5]"),
+            (-1, "[1, 2, 3, 4, 5]"),
+        ];
+        for (depth, expected) in cases {
+            let mut values = vec![];
+            flatten_into(&items, depth, &mut values);
+            assert_eq!(list_of(&values), config(expected), "{depth}");
+        }
+    }
+
+    #[test]
+    fn test_deep_get_and_set() {
+        let obj = config(r#"{"a": {"b": [{"c": 1}]}}"#);
+        for path in [ValueRef::str("a.b.0.c"), config(r#"["a", "b", 0, "c"]"#)] {
+            let mut value = obj.clone();
+            for segment in path_segments(&path) {
+                value = get_segment(&value, &segment).unwrap();
+            }
+            assert_eq!(value, ValueRef::int(1));
+        }
+        assert!(get_segment(&obj, &ValueRef::str("x")).is_none());
+
+        let result = obj.deep_copy();
+        set_segments(
+            &result,
+            &path_segments(&ValueRef::str("a.b.0.c")),
+            &ValueRef::int(2),
+        );
+        set_segments(
+            &result,
+            &path_segments(&ValueRef::str("x.y")),
+            &ValueRef::int(3),
+        );
+        assert_eq!(result, config(r#"{"a": {"b": [{"c": 2}]}, "x": {"y": 3}}"#));
+        // The original object is unchanged.
+        assert_eq!(obj, config(r#"{"a": {"b": [{"c": 1}]}}"#));
+    }
+
+    #[test]
+    fn test_merge_value() {
+        let base = config(r#"{"a": {"b": 1, "l": [1]}, "c": 1}"#);
+        let other = config(r#"{"a": {"b": 2, "l": [2]}, "d": 2}"#);
+        let cases = [
+            (
+                MERGE_STRATEGY_OVERRIDE,
+                r#"{"a": {"b": 2, "l": [2]}, "c": 1, "d": 2}"#,
+            ),
+            (
+                MERGE_STRATEGY_APPEND,
+                r#"{"a": {"b": 2, "l": [1, 2]}, "c": 1, "d": 2}"#,
+            ),
+            (
+                MERGE_STRATEGY_KEEP,
+                r#"{"a": {"b": 1, "l": [1]}, "c": 1, "d": 2}"#,
+            ),
+        ];
+        for (strategy, expected) in cases {
+            assert_eq!(
+                merge_value(&base, &other, strategy),
+                config(expected),
+                "{strategy}"
+            );
+        }
+    }
+}
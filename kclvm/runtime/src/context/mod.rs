@@ -162,6 +162,18 @@ impl crate::Context {
         }
     }
 
+    /// Returns the current KCL source location with the config entry operation.
+    pub fn current_source_location(
+        &self,
+        op: &crate::ConfigEntryOperationKind,
+    ) -> crate::SourceLocation {
+        crate::SourceLocation {
+            filename: self.panic_info.kcl_file.clone(),
+            line: self.panic_info.kcl_line,
+            op: op.symbol().to_string(),
+        }
+    }
+
     pub fn set_err_type(&mut self, err_type: &crate::RuntimeErrorType) {
         self.panic_info.__kcl_PanicInfo__ = true;
         self.panic_info.err_type_code = *err_type as i32;
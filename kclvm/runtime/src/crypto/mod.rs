@@ -1,164 +1,659 @@
 //! Copyright The KCL Authors. All rights reserved.
+//!
+//! The digests are encoded by the `format` argument, which is one of `hex` (the
+//! default), `base64` and `base64url`. The salts of the password hashes are
+//! read from the operating system, unless a `seed` is given to reproduce the
+//! same hash.
 
 extern crate md5;
 extern crate sha1;
 extern crate sha2;
 
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
 use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
+use crate::random::{seed_of, SplitMix64};
 use crate::*;
 
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = ValueRef;
 
-// md5(value: str, encoding: str = "utf-8") -> str
+/// The default cost of the bcrypt password hashes.
+const DEFAULT_BCRYPT_COST: u32 = 10;
+
+/// The digest function of a hash algorithm.
+type HashFn = fn(&[u8]) -> Vec<u8>;
+
+/// The hash algorithms with the digest function and the block size in bytes.
+const HASH_ALGORITHMS: &[(&str, HashFn, usize)] = &[
+    ("md5", md5_digest, 64),
+    ("sha1", sha1_digest, 64),
+    ("sha224", digest::<Sha224>, 64),
+    ("sha256", digest::<Sha256>, 64),
+    ("sha384", digest::<Sha384>, 128),
+    ("sha512", digest::<Sha512>, 128),
+    ("sha3_224", digest::<Sha3_224>, 144),
+    ("sha3_256", digest::<Sha3_256>, 136),
+    ("sha3_384", digest::<Sha3_384>, 104),
+    ("sha3_512", digest::<Sha3_512>, 72),
+    ("blake3", blake3_digest, 64),
+];
+
+#[inline]
+fn digest<D: Digest>(data: &[u8]) -> Vec<u8> {
+    D::digest(data).to_vec()
+}
 
-#[no_mangle]
-#[runtime_fn]
-pub extern "C" fn kclvm_crypto_md5(
+#[inline]
+fn md5_digest(data: &[u8]) -> Vec<u8> {
+    md5::compute(data).0.to_vec()
+}
+
+#[inline]
+fn sha1_digest(data: &[u8]) -> Vec<u8> {
+    sha1::Sha1::from(data).digest().bytes().to_vec()
+}
+
+#[inline]
+fn blake3_digest(data: &[u8]) -> Vec<u8> {
+    blake3::hash(data).as_bytes().to_vec()
+}
+
+fn hash_algorithm(algorithm: &str) -> Result<(HashFn, usize), String> {
+    HASH_ALGORITHMS
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(algorithm))
+        .map(|(_, hash, block_size)| (*hash, *block_size))
+        .ok_or_else(|| format!("unsupported hash algorithm '{algorithm}'"))
+}
+
+/// Returns the digest of the data using the hash `algorithm`.
+pub fn hash_bytes(algorithm: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    let (hash, _) = hash_algorithm(algorithm)?;
+    Ok(hash(data))
+}
+
+/// Returns the HMAC (RFC 2104) of the message using the hash `algorithm`.
+pub fn hmac_bytes(algorithm: &str, key: &[u8], message: &[u8]) -> Result<Vec<u8>, String> {
+    let (hash, block_size) = hash_algorithm(algorithm)?;
+    let mut key = if key.len() > block_size {
+        hash(key)
+    } else {
+        key.to_vec()
+    };
+    key.resize(block_size, 0);
+    let mut inner: Vec<u8> = key.iter().map(|b| b ^ 0x36).collect();
+    inner.extend_from_slice(message);
+    let mut outer: Vec<u8> = key.iter().map(|b| b ^ 0x5c).collect();
+    outer.extend(hash(&inner));
+    Ok(hash(&outer))
+}
+
+/// Encode the digest bytes in the `format` hex, base64 or base64url.
+pub fn encode_digest(digest: &[u8], format: &str) -> Result<String, String> {
+    match format {
+        "hex" => Ok(digest.iter().map(|b| format!("{b:02x}")).collect()),
+        "base64" => Ok(::base64::encode(digest)),
+        "base64url" => Ok(::base64::encode_config(digest, ::base64::URL_SAFE)),
+        _ => Err(format!(
+            "invalid digest format '{format}', expected 'hex', 'base64' or 'base64url'"
+        )),
+    }
+}
+
+/// Hash the `value` argument with the `algorithm` and encode the digest with the
+/// `format` argument.
+fn hash_value(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+    algorithm: &str,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
     let ctx = mut_ptr_as_ref(ctx);
 
-    if let Some(s) = args.arg_i_str(0, None) {
-        let hex = format!("{:x}", md5::compute(s));
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
+    if let Some(value) = get_call_arg_str(args, kwargs, 0, Some("value")) {
+        let format = get_call_arg_str(args, kwargs, 2, Some("format"));
+        let result = hash_bytes(algorithm, value.as_bytes())
+            .and_then(|digest| encode_digest(&digest, format.as_deref().unwrap_or("hex")));
+        return match result {
+            Ok(hex) => ValueRef::str(&hex).into_raw(ctx),
+            Err(err) => panic!("{}", err),
+        };
     }
-    panic!("md5() missing 1 required positional argument: 'value'");
+    panic!("{algorithm}() missing 1 required positional argument: 'value'");
 }
 
-// sha1(value: str, encoding: str = "utf-8") -> str
-
-#[no_mangle]
-#[runtime_fn]
-pub extern "C" fn kclvm_crypto_sha1(
+/// Hash the content of the `filepath` argument with the `algorithm` and encode
+/// the digest with the `format` argument.
+fn hash_file(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+    algorithm: &str,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
     let ctx = mut_ptr_as_ref(ctx);
 
-    if let Some(s) = args.arg_i_str(0, None) {
-        let hex = sha1::Sha1::from(s).digest().to_string();
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
+    if let Some(filepath) = get_call_arg_str(args, kwargs, 0, Some("filepath")) {
+        let data = std::fs::read(&filepath)
+            .unwrap_or_else(|err| panic!("failed to read file '{filepath}': {err}"));
+        let format = get_call_arg_str(args, kwargs, 1, Some("format"));
+        let result = hash_bytes(algorithm, &data)
+            .and_then(|digest| encode_digest(&digest, format.as_deref().unwrap_or("hex")));
+        return match result {
+            Ok(hex) => ValueRef::str(&hex).into_raw(ctx),
+            Err(err) => panic!("{}", err),
+        };
     }
-    panic!("sha1() missing 1 required positional argument: 'value'");
+    panic!("file{algorithm}() missing 1 required positional argument: 'filepath'");
 }
 
-// sha224(value: str, encoding: str = "utf-8") -> str
+// md5(value: str, encoding: str = "utf-8", format: str = "hex") -> str
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_crypto_sha224(
+pub extern "C" fn kclvm_crypto_md5(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
-    let args = ptr_as_ref(args);
-    let ctx = mut_ptr_as_ref(ctx);
+    hash_value(ctx, args, kwargs, "md5")
+}
 
-    if let Some(s) = args.arg_i_str(0, None) {
-        let mut hasher = Sha224::new();
-        hasher.update(&s);
-        let result = hasher.finalize();
+// sha1(value: str, encoding: str = "utf-8", format: str = "hex") -> str
 
-        let mut hex = String::with_capacity(2 * Sha256::output_size());
-        use std::fmt::Write;
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha1(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha1")
+}
 
-        for byte in result {
-            let _ = write!(&mut hex, "{byte:02x}");
-        }
+// sha224(value: str, encoding: str = "utf-8", format: str = "hex") -> str
 
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
-    }
-    panic!("sha224() missing 1 required positional argument: 'value'");
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha224(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha224")
 }
 
-// sha256(value: str, encoding: str = "utf-8") -> str
+// sha256(value: str, encoding: str = "utf-8", format: str = "hex") -> str
 
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_crypto_sha256(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha256")
+}
+
+// sha384(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha384(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha384")
+}
+
+// sha512(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha512(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha512")
+}
+
+// sha3_224(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha3_224(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha3_224")
+}
+
+// sha3_256(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha3_256(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha3_256")
+}
+
+// sha3_384(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha3_384(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha3_384")
+}
+
+// sha3_512(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_sha3_512(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "sha3_512")
+}
+
+// blake3(value: str, encoding: str = "utf-8", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_blake3(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_value(ctx, args, kwargs, "blake3")
+}
+
+// filesha256(filepath: str, format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_filesha256(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_file(ctx, args, kwargs, "sha256")
+}
+
+// filesha512(filepath: str, format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_filesha512(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_file(ctx, args, kwargs, "sha512")
+}
+
+// fileblake3(filepath: str, format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_fileblake3(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    hash_file(ctx, args, kwargs, "blake3")
+}
+
+// hmac(key: str, value: str, algorithm: str = "sha256", format: str = "hex") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_hmac(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
     let ctx = mut_ptr_as_ref(ctx);
-    if let Some(s) = args.arg_i_str(0, None) {
-        let mut hasher = Sha256::new();
-        hasher.update(&s);
-        let result = hasher.finalize();
 
-        let mut hex = String::with_capacity(2 * Sha256::output_size());
-        use std::fmt::Write;
+    let key = match get_call_arg_str(args, kwargs, 0, Some("key")) {
+        Some(key) => key,
+        None => panic!("hmac() missing 2 required positional arguments: 'key' and 'value'"),
+    };
+    let value = match get_call_arg_str(args, kwargs, 1, Some("value")) {
+        Some(value) => value,
+        None => panic!("hmac() missing 1 required positional argument: 'value'"),
+    };
+    let algorithm = get_call_arg_str(args, kwargs, 2, Some("algorithm"));
+    let format = get_call_arg_str(args, kwargs, 3, Some("format"));
+    let result = hmac_bytes(
+        algorithm.as_deref().unwrap_or("sha256"),
+        key.as_bytes(),
+        value.as_bytes(),
+    )
+    .and_then(|digest| encode_digest(&digest, format.as_deref().unwrap_or("hex")));
+    match result {
+        Ok(hex) => ValueRef::str(&hex).into_raw(ctx),
+        Err(err) => panic!("{}", err),
+    }
+}
 
-        for byte in result {
-            let _ = write!(&mut hex, "{byte:02x}");
+/// Returns the salt of the password hashes, generated from the `seed` value when
+/// it is given, or read from the operating system otherwise.
+fn password_salt(seed: Option<ValueRef>) -> [u8; 16] {
+    let mut salt = [0u8; 16];
+    match seed {
+        Some(seed) => SplitMix64::new(seed_of(&seed)).fill_bytes(&mut salt),
+        None => {
+            if let Err(err) = getrandom::getrandom(&mut salt) {
+                panic!("failed to generate the password salt: {}", err)
+            }
         }
-
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
     }
-    panic!("sha256() missing 1 required positional argument: 'value'");
+    salt
 }
 
-// sha384(value: str, encoding: str = "utf-8") -> str
+// bcrypt(value: str, cost: int = 10, seed: int|str = None) -> str
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_crypto_sha384(
+pub extern "C" fn kclvm_crypto_bcrypt(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
     let ctx = mut_ptr_as_ref(ctx);
 
-    if let Some(s) = args.arg_i_str(0, None) {
-        let mut hasher = Sha384::new();
-        hasher.update(&s);
-        let result = hasher.finalize();
+    if let Some(value) = get_call_arg_str(args, kwargs, 0, Some("value")) {
+        let cost = get_call_arg_int(args, kwargs, 1, Some("cost"))
+            .map(|cost| cost as u32)
+            .unwrap_or(DEFAULT_BCRYPT_COST);
+        let salt = password_salt(get_call_arg(args, kwargs, 2, Some("seed")));
+        return match bcrypt::hash_with_salt(value, cost, salt) {
+            // The `$2y$` prefix is compatible with the htpasswd files.
+            Ok(parts) => {
+                ValueRef::str(&parts.format_for_version(bcrypt::Version::TwoY)).into_raw(ctx)
+            }
+            Err(err) => panic!("bcrypt() failed: {}", err),
+        };
+    }
+    panic!("bcrypt() missing 1 required positional argument: 'value'");
+}
 
-        let mut hex = String::with_capacity(2 * Sha256::output_size());
-        use std::fmt::Write;
+// bcrypt_verify(value: str, hash: str) -> bool
 
-        for byte in result {
-            let _ = write!(&mut hex, "{byte:02x}");
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_bcrypt_verify(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let value = match get_call_arg_str(args, kwargs, 0, Some("value")) {
+        Some(value) => value,
+        None => {
+            panic!("bcrypt_verify() missing 2 required positional arguments: 'value' and 'hash'")
         }
+    };
+    let hash = match get_call_arg_str(args, kwargs, 1, Some("hash")) {
+        Some(hash) => hash,
+        None => panic!("bcrypt_verify() missing 1 required positional argument: 'hash'"),
+    };
+    let matched = bcrypt::verify(value, &hash).unwrap_or(false);
+    kclvm_value_Bool(ctx, matched as i8)
+}
+
+// argon2(value: str, seed: int|str = None) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_argon2(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
 
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
+    if let Some(value) = get_call_arg_str(args, kwargs, 0, Some("value")) {
+        let salt = password_salt(get_call_arg(args, kwargs, 1, Some("seed")));
+        let result = SaltString::encode_b64(&salt).and_then(|salt| {
+            Argon2::default()
+                .hash_password(value.as_bytes(), &salt)
+                .map(|hash| hash.to_string())
+        });
+        return match result {
+            Ok(hash) => ValueRef::str(&hash).into_raw(ctx),
+            Err(err) => panic!("argon2() failed: {}", err),
+        };
     }
-    panic!("sha384() missing 1 required positional argument: 'value'");
+    panic!("argon2() missing 1 required positional argument: 'value'");
 }
 
-// sha512(value: str, encoding: str = "utf-8") -> str
+// argon2_verify(value: str, hash: str) -> bool
 
 #[no_mangle]
 #[runtime_fn]
-pub extern "C" fn kclvm_crypto_sha512(
+pub extern "C" fn kclvm_crypto_argon2_verify(
     ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
-    let ctx = mut_ptr_as_ref(ctx);
-    if let Some(s) = args.arg_i_str(0, None) {
-        let mut hasher = Sha512::new();
-        hasher.update(&s);
-        let result = hasher.finalize();
+    let kwargs = ptr_as_ref(kwargs);
 
-        let mut hex = String::with_capacity(2 * Sha256::output_size());
-        use std::fmt::Write;
+    let value = match get_call_arg_str(args, kwargs, 0, Some("value")) {
+        Some(value) => value,
+        None => {
+            panic!("argon2_verify() missing 2 required positional arguments: 'value' and 'hash'")
+        }
+    };
+    let hash = match get_call_arg_str(args, kwargs, 1, Some("hash")) {
+        Some(hash) => hash,
+        None => panic!("argon2_verify() missing 1 required positional argument: 'hash'"),
+    };
+    let matched = PasswordHash::new(&hash)
+        .map(|hash| {
+            Argon2::default()
+                .verify_password(value.as_bytes(), &hash)
+                .is_ok()
+        })
+        .unwrap_or(false);
+    kclvm_value_Bool(ctx, matched as i8)
+}
 
-        for byte in result {
-            let _ = write!(&mut hex, "{byte:02x}");
+#[cfg(test)]
+mod test_crypto {
+    use super::*;
+
+    fn hex(algorithm: &str, data: &str) -> String {
+        encode_digest(&hash_bytes(algorithm, data.as_bytes()).unwrap(), "hex").unwrap()
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hex("md5", "abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex("sha1", "abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex("sha3_256", "abc"),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(
+            hex("blake3", "abc"),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        assert!(hash_bytes("sha0", b"abc").is_err());
+    }
+
+    #[test]
+    fn test_hmac() {
+        // The keys and messages of the RFC 4231 test cases 1, 2 and 6. The SHA-2 digests
+        // are from RFC 4231, the other digests are from the Python hmac module and the
+        // BLAKE3 reference implementation.
+        let messages: [(&[u8], &[u8]); 3] = [
+            (&[0x0b; 20], b"Hi There"),
+            (b"Jefe", b"what do ya want for nothing?"),
+            (
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First",
+            ),
+        ];
+        let cases = [
+            (
+                "md5",
+                [
+                    "5ccec34ea9656392457fa1ac27f08fbc",
+                    "750c783e6ab0b503eaa86e310a5db738",
+                    "bfecaf4efff90a3a668f3922fec3762d",
+                ],
+            ),
+            (
+                "sha1",
+                [
+                    "b617318655057264e28bc0b6fb378c8ef146be00",
+                    "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+                    "90d0dace1c1bdc957339307803160335bde6df2b",
+                ],
+            ),
+            (
+                "sha224",
+                [
+                    "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+                    "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44",
+                    "95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e",
+                ],
+            ),
+            (
+                "sha256",
+                [
+                    "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+                    "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+                    "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+                ],
+            ),
+            (
+                "sha384",
+                [
+                    "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6",
+                    "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649",
+                    "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952",
+                ],
+            ),
+            (
+                "sha512",
+                [
+                    "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+                    "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+                    "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+                ],
+            ),
+            (
+                "sha3_224",
+                [
+                    "3b16546bbc7be2706a031dcafd56373d9884367641d8c59af3c860f7",
+                    "7fdb8dd88bd2f60d1b798634ad386811c2cfc85bfaf5d52bbace5e66",
+                    "b4a1f04c00287a9b7f6075b313d279b833bc8f75124352d05fb9995f",
+                ],
+            ),
+            (
+                "sha3_256",
+                [
+                    "ba85192310dffa96e2a3a40e69774351140bb7185e1202cdcc917589f95e16bb",
+                    "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5",
+                    "ed73a374b96c005235f948032f09674a58c0ce555cfc1f223b02356560312c3b",
+                ],
+            ),
+            (
+                "sha3_384",
+                [
+                    "68d2dcf7fd4ddd0a2240c8a437305f61fb7334cfb5d0226e1bc27dc10a2e723a20d370b47743130e26ac7e3d532886bd",
+                    "f1101f8cbf9766fd6764d2ed61903f21ca9b18f57cf3e1a23ca13508a93243ce48c045dc007f26a21b3f5e0e9df4c20a",
+                    "0fc19513bf6bd878037016706a0e57bc528139836b9a42c3d419e498e0e1fb9616fd669138d33a1105e07c72b6953bcc",
+                ],
+            ),
+            (
+                "sha3_512",
+                [
+                    "eb3fbd4b2eaab8f5c504bd3a41465aacec15770a7cabac531e482f860b5ec7ba47ccb2c6f2afce8f88d22b6dc61380f23a668fd3888bb80537c0a0b86407689e",
+                    "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024",
+                    "00f751a9e50695b090ed6911a4b65524951cdc15a73a5d58bb55215ea2cd839ac79d2b44a39bafab27e83fde9e11f6340b11d991b1b91bf2eee7fc872426c3a4",
+                ],
+            ),
+            (
+                "blake3",
+                [
+                    "0bd71bad2f522a89551e0246a42cd24e960641c71195f33df08ead6af3bbeccb",
+                    "732da99ccc24e277b2fec6c42e0f29f1093689ff0821de4df22f7faec5168776",
+                    "206553225c4716b9b4f6fc279d4d67d5a033e3b6520f2c0aad2d6f91ff06762a",
+                ],
+            ),
+        ];
+        for (algorithm, digests) in cases {
+            for ((key, message), expected) in messages.iter().zip(digests) {
+                let digest = hmac_bytes(algorithm, key, message).unwrap();
+                assert_eq!(
+                    encode_digest(&digest, "hex").unwrap(),
+                    expected,
+                    "hmac {algorithm}"
+                );
+            }
         }
+    }
+
+    #[test]
+    fn test_encode_digest() {
+        let digest = [0xfb, 0xff, 0x01];
+        assert_eq!(encode_digest(&digest, "hex").unwrap(), "fbff01");
+        assert_eq!(encode_digest(&digest, "base64").unwrap(), "+/8B");
+        assert_eq!(encode_digest(&digest, "base64url").unwrap(), "-_8B");
+        assert!(encode_digest(&digest, "base32").is_err());
+    }
+
+    #[test]
+    fn test_password_hash() {
+        assert!(bcrypt::verify(
+            "U*U",
+            "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"
+        )
+        .unwrap());
+        let hash = bcrypt::hash_with_salt("secret", 4, [7; 16])
+            .unwrap()
+            .format_for_version(bcrypt::Version::TwoY);
+        assert!(hash.starts_with("$2y$04$"));
+        assert!(bcrypt::verify("secret", &hash).unwrap());
+    }
 
-        return ValueRef::str(hex.as_ref()).into_raw(ctx);
+    #[test]
+    fn test_password_salt() {
+        let seed = ValueRef::str("kcl");
+        assert_eq!(password_salt(Some(seed.clone())), password_salt(Some(seed)));
+        assert_ne!(password_salt(None), password_salt(None));
     }
-    panic!("sha512() missing 1 required positional argument: 'value'");
 }
@@ -1,8 +1,19 @@
 //! Copyright The KCL Authors. All rights reserved.
+//!
+//! Datetime values are represented as RFC 3339 strings e.g., `2024-01-02T03:04:05Z`,
+//! and durations are represented as Go-style strings e.g., `72h` and `1h30m`, ISO 8601
+//! strings e.g., `P1DT12H`, or numbers of seconds.
 
 extern crate chrono;
+extern crate chrono_tz;
+
+use std::fmt::Write;
 
 use chrono::prelude::Local;
+use chrono::{
+    DateTime, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, Offset, SecondsFormat,
+    TimeZone, Utc,
+};
 
 use crate::*;
 
@@ -15,22 +26,28 @@ pub extern "C" fn kclvm_datetime_today(
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
-    let s = Local::now().to_string();
     let ctx = mut_ptr_as_ref(ctx);
+    let s = current_time(ctx).to_string();
     return ValueRef::str(s.as_ref()).into_raw(ctx);
 }
 
-// now() -> str:
+// now(format: str = None) -> str:
 
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_datetime_now(
     ctx: *mut kclvm_context_t,
-    _args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
-    let s = Local::now().to_string();
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
     let ctx = mut_ptr_as_ref(ctx);
+    let now = current_time(ctx);
+    let s = match get_call_arg_str(args, kwargs, 0, Some("format")) {
+        Some(format) => format_datetime(&now, &format).unwrap_or_else(|err| panic!("{}", err)),
+        None => now.to_string(),
+    };
     return ValueRef::str(s.as_ref()).into_raw(ctx);
 }
 
@@ -44,7 +61,8 @@ pub extern "C" fn kclvm_datetime_ticks(
     _kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let ctx = mut_ptr_as_ref(ctx);
-    let x = Local::now().timestamp();
+    // The ticks are whole seconds, the fractional seconds are truncated.
+    let x = current_time(ctx).timestamp();
     ValueRef::float(x as f64).into_raw(ctx)
 }
 
@@ -57,7 +75,729 @@ pub extern "C" fn kclvm_datetime_date(
     _args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
-    let s = Local::now().to_string();
     let ctx = mut_ptr_as_ref(ctx);
+    let s = current_time(ctx).to_string();
     return ValueRef::str(s.as_ref()).into_raw(ctx);
 }
+
+// parse(date: str, format: str = None, tz: str = "UTC") -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_parse(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        let format = get_call_arg_str(args, kwargs, 1, Some("format"));
+        let tz = arg_timezone(args, kwargs, 2);
+        let dt =
+            parse_datetime(&date, format.as_deref(), &tz).unwrap_or_else(|err| panic!("{}", err));
+        return ValueRef::str(&datetime_str(&dt)).into_raw(ctx);
+    }
+    panic!("parse() missing 1 required positional argument: 'date'");
+}
+
+// format(date: str, format: str, tz: str = None) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_format(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        if let Some(format) = get_call_arg_str(args, kwargs, 1, Some("format")) {
+            let mut dt = must_parse_datetime(&date);
+            if let Some(tz) = get_call_arg_str(args, kwargs, 2, Some("tz")) {
+                dt = convert_timezone(&dt, &must_parse_timezone(&tz));
+            }
+            let s = format_datetime(&dt, &format).unwrap_or_else(|err| panic!("{}", err));
+            return ValueRef::str(&s).into_raw(ctx);
+        }
+    }
+    panic!("format() missing 2 required positional arguments: 'date' and 'format'");
+}
+
+// validate(date: str, format: str) -> bool:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_validate(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        if let Some(format) = get_call_arg_str(args, kwargs, 1, Some("format")) {
+            let ok = parse_datetime(&date, Some(&format), &TimeZoneSpec::Utc).is_ok();
+            return ValueRef::bool(ok).into_raw(ctx);
+        }
+    }
+    panic!("validate() missing 2 required positional arguments: 'date' and 'format'");
+}
+
+// timestamp(date: str) -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_timestamp(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        let dt = must_parse_datetime(&date);
+        return ValueRef::float(timestamp(&dt)).into_raw(ctx);
+    }
+    panic!("timestamp() missing 1 required positional argument: 'date'");
+}
+
+// from_timestamp(timestamp: float, tz: str = "UTC") -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_from_timestamp(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(ts) = get_call_arg(args, kwargs, 0, Some("timestamp")) {
+        let ts = if ts.is_int() || ts.is_float() {
+            ts.as_float()
+        } else {
+            panic!(
+                "from_timestamp() argument 'timestamp' must be int or float, not {}",
+                ts.type_str()
+            )
+        };
+        let secs = ts.floor();
+        let nanos = ((ts - secs) * 1e9).round() as u32;
+        let dt = Utc
+            .timestamp_opt(secs as i64, nanos.min(999_999_999))
+            .single()
+            .unwrap_or_else(|| panic!("timestamp {} is out of range", ts));
+        let dt = convert_timezone(
+            &dt.with_timezone(&Utc.fix()),
+            &arg_timezone(args, kwargs, 1),
+        );
+        return ValueRef::str(&datetime_str(&dt)).into_raw(ctx);
+    }
+    panic!("from_timestamp() missing 1 required positional argument: 'timestamp'");
+}
+
+// to_timezone(date: str, tz: str) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_to_timezone(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        if let Some(tz) = get_call_arg_str(args, kwargs, 1, Some("tz")) {
+            let dt = convert_timezone(&must_parse_datetime(&date), &must_parse_timezone(&tz));
+            return ValueRef::str(&datetime_str(&dt)).into_raw(ctx);
+        }
+    }
+    panic!("to_timezone() missing 2 required positional arguments: 'date' and 'tz'");
+}
+
+// duration(value: str) -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_duration(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(value) = get_call_arg_str(args, kwargs, 0, Some("value")) {
+        let duration = parse_duration(&value).unwrap_or_else(|err| panic!("{}", err));
+        if duration.months != 0 {
+            panic!(
+                "duration '{}' with years or months can not be converted to seconds",
+                value
+            );
+        }
+        return ValueRef::float(duration_seconds(&duration.time)).into_raw(ctx);
+    }
+    panic!("duration() missing 1 required positional argument: 'value'");
+}
+
+// add(date: str, duration: str|int|float) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_add(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        if let Some(duration) = get_call_arg(args, kwargs, 1, Some("duration")) {
+            let dt = add_duration(
+                &must_parse_datetime(&date),
+                &must_duration(&duration),
+                false,
+            );
+            return ValueRef::str(&datetime_str(&dt)).into_raw(ctx);
+        }
+    }
+    panic!("add() missing 2 required positional arguments: 'date' and 'duration'");
+}
+
+// sub(date: str, duration: str|int|float) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_sub(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(date) = get_call_arg_str(args, kwargs, 0, Some("date")) {
+        if let Some(duration) = get_call_arg(args, kwargs, 1, Some("duration")) {
+            let dt = add_duration(&must_parse_datetime(&date), &must_duration(&duration), true);
+            return ValueRef::str(&datetime_str(&dt)).into_raw(ctx);
+        }
+    }
+    panic!("sub() missing 2 required positional arguments: 'date' and 'duration'");
+}
+
+// diff(a: str, b: str) -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_diff(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(a) = get_call_arg_str(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg_str(args, kwargs, 1, Some("b")) {
+            let d = must_parse_datetime(&a).signed_duration_since(must_parse_datetime(&b));
+            return ValueRef::float(duration_seconds(&d)).into_raw(ctx);
+        }
+    }
+    panic!("diff() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// compare(a: str, b: str) -> int:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_compare(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+    if let Some(a) = get_call_arg_str(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg_str(args, kwargs, 1, Some("b")) {
+            let ordering = must_parse_datetime(&a).cmp(&must_parse_datetime(&b));
+            return ValueRef::int(ordering as i64).into_raw(ctx);
+        }
+    }
+    panic!("compare() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// ----------------------------------------------------------------------------
+// datetime helpers
+// ----------------------------------------------------------------------------
+
+/// The time zone of the datetime functions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeZoneSpec {
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+    Named(chrono_tz::Tz),
+}
+
+/// The duration with the calendar months and the exact time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarDuration {
+    pub months: i32,
+    pub time: Duration,
+}
+
+/// Returns the current time of the context clock, or the system clock when the
+/// clock is not set.
+pub fn current_time(ctx: &Context) -> DateTime<FixedOffset> {
+    match &ctx.cfg.clock {
+        Some(clock) => parse_datetime(clock, None, &TimeZoneSpec::Utc)
+            .unwrap_or_else(|err| panic!("invalid clock: {}", err)),
+        None => {
+            let now = Local::now();
+            now.with_timezone(&now.offset().fix())
+        }
+    }
+}
+
+/// Parse the datetime string with the strftime `format`, or the RFC 3339 and
+/// the common date formats when the format is `None`. Datetimes without the UTC
+/// offset are in the time zone `tz`.
+pub fn parse_datetime(
+    s: &str,
+    format: Option<&str>,
+    tz: &TimeZoneSpec,
+) -> Result<DateTime<FixedOffset>, String> {
+    let s = s.trim();
+    match format {
+        Some(format) => {
+            if let Ok(dt) = DateTime::parse_from_str(s, format) {
+                return Ok(dt);
+            }
+            if let Ok(naive) = NaiveDateTime::parse_from_str(s, format) {
+                return from_naive(&naive, tz);
+            }
+            if let Ok(date) = NaiveDate::parse_from_str(s, format) {
+                return from_naive(&date.and_hms_opt(0, 0, 0).unwrap(), tz);
+            }
+            Err(format!(
+                "invalid datetime '{}' for the format '{}'",
+                s, format
+            ))
+        }
+        None => {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                return Ok(dt);
+            }
+            // The format of the datetime string conversion e.g., `now()`.
+            if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f %:z") {
+                return Ok(dt);
+            }
+            for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+                if let Ok(naive) = NaiveDateTime::parse_from_str(s, format) {
+                    return from_naive(&naive, tz);
+                }
+            }
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+                return from_naive(&date.and_hms_opt(0, 0, 0).unwrap(), tz);
+            }
+            Err(format!("invalid RFC 3339 datetime '{}'", s))
+        }
+    }
+}
+
+/// Parse the time zone, e.g., `UTC`, `Local`, `Asia/Shanghai` and `+08:00`.
+pub fn parse_timezone(s: &str) -> Result<TimeZoneSpec, String> {
+    let s = s.trim();
+    match s {
+        "UTC" | "utc" | "Z" => return Ok(TimeZoneSpec::Utc),
+        "Local" | "local" => return Ok(TimeZoneSpec::Local),
+        _ => {}
+    }
+    if let Some(offset) = parse_offset(s) {
+        return Ok(TimeZoneSpec::Fixed(offset));
+    }
+    s.parse::<chrono_tz::Tz>()
+        .map(TimeZoneSpec::Named)
+        .map_err(|_| format!("unknown time zone '{}'", s))
+}
+
+/// Parse the duration string, e.g., `72h`, `1h30m`, `-1.5s`, `P1Y2M` and `PT30M`.
+pub fn parse_duration(s: &str) -> Result<CalendarDuration, String> {
+    let err = || format!("invalid duration '{}'", s);
+    let trimmed = s.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let mut duration = match rest.strip_prefix('P') {
+        Some(iso) => parse_iso_duration(iso).ok_or_else(err)?,
+        None => parse_go_duration(rest).ok_or_else(err)?,
+    };
+    if negative {
+        duration.months = -duration.months;
+        duration.time = -duration.time;
+    }
+    Ok(duration)
+}
+
+/// Add the duration to the datetime, or subtract it when `negate` is true.
+pub fn add_duration(
+    dt: &DateTime<FixedOffset>,
+    duration: &CalendarDuration,
+    negate: bool,
+) -> DateTime<FixedOffset> {
+    let (months, time) = if negate {
+        (-duration.months, -duration.time)
+    } else {
+        (duration.months, duration.time)
+    };
+    let dt = if months >= 0 {
+        dt.checked_add_months(Months::new(months as u32))
+    } else {
+        dt.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    dt.and_then(|dt| dt.checked_add_signed(time))
+        .unwrap_or_else(|| panic!("datetime is out of range"))
+}
+
+/// Returns the RFC 3339 string of the datetime.
+pub fn datetime_str(dt: &DateTime<FixedOffset>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+fn format_datetime(dt: &DateTime<FixedOffset>, format: &str) -> Result<String, String> {
+    let mut s = String::new();
+    write!(s, "{}", dt.format(format))
+        .map_err(|_| format!("invalid datetime format '{}'", format))?;
+    Ok(s)
+}
+
+fn timestamp(dt: &DateTime<FixedOffset>) -> f64 {
+    dt.timestamp() as f64 + dt.timestamp_subsec_nanos() as f64 / 1e9
+}
+
+fn duration_seconds(d: &Duration) -> f64 {
+    match d.num_nanoseconds() {
+        Some(nanos) => nanos as f64 / 1e9,
+        None => d.num_milliseconds() as f64 / 1e3,
+    }
+}
+
+fn convert_timezone(dt: &DateTime<FixedOffset>, tz: &TimeZoneSpec) -> DateTime<FixedOffset> {
+    match tz {
+        TimeZoneSpec::Utc => dt.with_timezone(&Utc.fix()),
+        TimeZoneSpec::Local => {
+            let local = dt.with_timezone(&Local);
+            local.with_timezone(&local.offset().fix())
+        }
+        TimeZoneSpec::Fixed(offset) => dt.with_timezone(offset),
+        TimeZoneSpec::Named(tz) => {
+            let named = dt.with_timezone(tz);
+            named.with_timezone(&named.offset().fix())
+        }
+    }
+}
+
+fn from_naive(naive: &NaiveDateTime, tz: &TimeZoneSpec) -> Result<DateTime<FixedOffset>, String> {
+    let dt = match tz {
+        TimeZoneSpec::Utc => Some(Utc.fix().from_utc_datetime(naive)),
+        TimeZoneSpec::Local => Local
+            .from_local_datetime(naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&dt.offset().fix())),
+        TimeZoneSpec::Fixed(offset) => offset.from_local_datetime(naive).earliest(),
+        TimeZoneSpec::Named(tz) => tz
+            .from_local_datetime(naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&dt.offset().fix())),
+    };
+    dt.ok_or_else(|| format!("the local time '{}' does not exist in the time zone", naive))
+}
+
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.chars().next()? {
+        '+' => (1, &s[1..]),
+        '-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits[2..].parse().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parse the Go-style duration like `1h30m`, with the additional day unit `d`.
+fn parse_go_duration(s: &str) -> Option<CalendarDuration> {
+    if s == "0" {
+        return Some(CalendarDuration {
+            months: 0,
+            time: Duration::zero(),
+        });
+    }
+    let mut nanos: f64 = 0.0;
+    let mut rest = s;
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let (value, after) = split_number(rest)?;
+        let unit_len = after
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(after.len());
+        let unit = match &after[..unit_len] {
+            "ns" => 1.0,
+            "us" | "µs" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            "m" => 60.0 * 1e9,
+            "h" => 3600.0 * 1e9,
+            "d" => 86400.0 * 1e9,
+            _ => return None,
+        };
+        nanos += value * unit;
+        rest = &after[unit_len..];
+    }
+    Some(CalendarDuration {
+        months: 0,
+        time: Duration::nanoseconds(nanos.round() as i64),
+    })
+}
+
+/// Parse the ISO 8601 duration without the leading `P` like `1Y2M3DT4H5M6.5S`.
+fn parse_iso_duration(s: &str) -> Option<CalendarDuration> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (s, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+    let mut months: i32 = 0;
+    let mut nanos: f64 = 0.0;
+    let mut rest = date;
+    while !rest.is_empty() {
+        let (value, after) = split_number(rest)?;
+        let unit = after.chars().next()?;
+        match unit {
+            'Y' | 'M' if value.fract() != 0.0 => return None,
+            'Y' => {
+                months = i32::try_from(value as i64)
+                    .ok()
+                    .and_then(|years| years.checked_mul(12))
+                    .and_then(|n| months.checked_add(n))?
+            }
+            'M' => {
+                months = i32::try_from(value as i64)
+                    .ok()
+                    .and_then(|n| months.checked_add(n))?
+            }
+            'W' => nanos += value * 7.0 * 86400.0 * 1e9,
+            'D' => nanos += value * 86400.0 * 1e9,
+            _ => return None,
+        }
+        rest = &after[1..];
+    }
+    let mut rest = time.unwrap_or_default();
+    while !rest.is_empty() {
+        let (value, after) = split_number(rest)?;
+        let unit = match after.chars().next()? {
+            'H' => 3600.0,
+            'M' => 60.0,
+            'S' => 1.0,
+            _ => return None,
+        };
+        nanos += value * unit * 1e9;
+        rest = &after[1..];
+    }
+    Some(CalendarDuration {
+        months,
+        time: Duration::nanoseconds(nanos.round() as i64),
+    })
+}
+
+/// Split the leading decimal number of the string.
+fn split_number(s: &str) -> Option<(f64, &str)> {
+    let len = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    if len == 0 {
+        return None;
+    }
+    Some((s[..len].parse().ok()?, &s[len..]))
+}
+
+fn must_parse_datetime(s: &str) -> DateTime<FixedOffset> {
+    parse_datetime(s, None, &TimeZoneSpec::Utc).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn must_parse_timezone(s: &str) -> TimeZoneSpec {
+    parse_timezone(s).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn must_duration(value: &ValueRef) -> CalendarDuration {
+    if value.is_str() {
+        parse_duration(&value.as_str()).unwrap_or_else(|err| panic!("{}", err))
+    } else if value.is_int() || value.is_float() {
+        CalendarDuration {
+            months: 0,
+            time: Duration::nanoseconds((value.as_float() * 1e9).round() as i64),
+        }
+    } else {
+        panic!(
+            "duration must be str, int or float, not {}",
+            value.type_str()
+        )
+    }
+}
+
+fn arg_timezone(args: &ValueRef, kwargs: &ValueRef, i: usize) -> TimeZoneSpec {
+    match get_call_arg_str(args, kwargs, i, Some("tz")) {
+        Some(tz) => must_parse_timezone(&tz),
+        None => TimeZoneSpec::Utc,
+    }
+}
+
+#[cfg(test)]
+mod test_datetime {
+    use super::*;
+
+    #[test]
+    fn test_parse_datetime() {
+        let utc = TimeZoneSpec::Utc;
+        let cases = [
+            ("2024-01-02T03:04:05Z", None, "2024-01-02T03:04:05Z"),
+            (
+                "2024-01-02T03:04:05.5+08:00",
+                None,
+                "2024-01-02T03:04:05.500+08:00",
+            ),
+            (
+                "2024-01-02 03:04:05 +08:00",
+                None,
+                "2024-01-02T03:04:05+08:00",
+            ),
+            ("2024-01-02 03:04:05", None, "2024-01-02T03:04:05Z"),
+            ("2024-01-02", None, "2024-01-02T00:00:00Z"),
+            (
+                "02/01/2024 10:30",
+                Some("%d/%m/%Y %H:%M"),
+                "2024-01-02T10:30:00Z",
+            ),
+            ("20240102", Some("%Y%m%d"), "2024-01-02T00:00:00Z"),
+        ];
+        for (s, format, expected) in cases {
+            assert_eq!(
+                datetime_str(&parse_datetime(s, format, &utc).unwrap()),
+                expected
+            );
+        }
+        let shanghai = parse_timezone("Asia/Shanghai").unwrap();
+        assert_eq!(
+            datetime_str(&parse_datetime("2024-01-02 03:04:05", None, &shanghai).unwrap()),
+            "2024-01-02T03:04:05+08:00"
+        );
+        assert!(parse_datetime("2024-13-02", None, &utc).is_err());
+        assert!(parse_datetime("2024-01-02", Some("%d/%m/%Y"), &utc).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let cases = [
+            ("72h", 0, 72 * 3600 * 1_000_000_000),
+            ("1h30m", 0, 90 * 60 * 1_000_000_000),
+            ("1.5s", 0, 1_500_000_000),
+            ("-500ms", 0, -500_000_000),
+            ("2d", 0, 2 * 86400 * 1_000_000_000),
+            ("0", 0, 0),
+            ("P1Y2M", 14, 0),
+            ("P1W", 0, 7 * 86400 * 1_000_000_000),
+            ("P1DT12H", 0, 36 * 3600 * 1_000_000_000),
+            ("PT30M", 0, 30 * 60 * 1_000_000_000),
+            ("-P1M", -1, 0),
+        ];
+        for (s, months, nanos) in cases {
+            let duration = parse_duration(s).unwrap();
+            assert_eq!(duration.months, months, "{s}");
+            assert_eq!(duration.time.num_nanoseconds().unwrap(), nanos, "{s}");
+        }
+        for s in [
+            "",
+            "h",
+            "1x",
+            "P",
+            "PT",
+            "P1.5M",
+            "1h 30m",
+            "P178956971Y",
+            "P2147483648M",
+            "P2147483647M1M",
+        ] {
+            assert!(parse_duration(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_add_duration() {
+        let dt = parse_datetime("2024-01-31T00:00:00Z", None, &TimeZoneSpec::Utc).unwrap();
+        let cases = [
+            ("P1M", false, "2024-02-29T00:00:00Z"),
+            ("72h", false, "2024-02-03T00:00:00Z"),
+            ("P1DT1H", true, "2024-01-29T23:00:00Z"),
+            ("P1Y", true, "2023-01-31T00:00:00Z"),
+        ];
+        for (duration, negate, expected) in cases {
+            let duration = parse_duration(duration).unwrap();
+            assert_eq!(
+                datetime_str(&add_duration(&dt, &duration, negate)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_timezone() {
+        let dt = parse_datetime("2024-07-01T12:00:00Z", None, &TimeZoneSpec::Utc).unwrap();
+        let cases = [
+            ("UTC", "2024-07-01T12:00:00Z"),
+            ("+08:00", "2024-07-01T20:00:00+08:00"),
+            ("-0530", "2024-07-01T06:30:00-05:30"),
+            ("America/New_York", "2024-07-01T08:00:00-04:00"),
+            ("Europe/Berlin", "2024-07-01T14:00:00+02:00"),
+        ];
+        for (tz, expected) in cases {
+            let tz = parse_timezone(tz).unwrap();
+            assert_eq!(datetime_str(&convert_timezone(&dt, &tz)), expected);
+        }
+        assert!(parse_timezone("Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn test_clock() {
+        let mut ctx = Context::new();
+        ctx.cfg.clock = Some("2024-01-02T03:04:05Z".to_string());
+        let now = current_time(&ctx);
+        assert_eq!(datetime_str(&now), "2024-01-02T03:04:05Z");
+        assert_eq!(timestamp(&now), 1704164645.0);
+        assert_eq!(format_datetime(&now, "%Y/%m/%d").unwrap(), "2024/01/02");
+        // The ticks are whole seconds.
+        ctx.cfg.clock = Some("2024-01-02T03:04:05.5Z".to_string());
+        assert_eq!(timestamp(&current_time(&ctx)), 1704164645.5);
+        let ticks = kclvm_datetime_ticks(&mut ctx, std::ptr::null(), std::ptr::null());
+        assert_eq!(ptr_as_ref(ticks).as_float(), 1704164645.0);
+    }
+}
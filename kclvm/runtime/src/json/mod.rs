@@ -2,6 +2,9 @@
 
 use crate::*;
 
+mod patch;
+pub use patch::*;
+
 // data, sort_keys=False, indent=None, ignore_private=False, ignore_none=False
 
 #[no_mangle]
@@ -83,6 +86,98 @@ pub extern "C" fn kclvm_json_dump_to_file(
     panic!("dump_to_file() missing 2 required positional arguments: 'data' and 'filename'")
 }
 
+// patch(doc: any, ops: [{str:}]) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_json_patch(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let doc = match get_call_arg(args, kwargs, 0, Some("doc")) {
+        Some(doc) => doc,
+        None => panic!("patch() missing 2 required positional arguments: 'doc' and 'ops'"),
+    };
+    let ops = match get_call_arg(args, kwargs, 1, Some("ops")) {
+        Some(ops) => ops,
+        None => panic!("patch() missing 1 required positional argument: 'ops'"),
+    };
+    match json_patch(&doc, &ops) {
+        Ok(result) => result.into_raw(ctx),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+// merge_patch(doc: any, patch: any) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_json_merge_patch(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let doc = get_call_arg(args, kwargs, 0, Some("doc")).unwrap_or_else(ValueRef::none);
+    match get_call_arg(args, kwargs, 1, Some("patch")) {
+        Some(patch) => json_merge_patch(&doc, &patch).into_raw(ctx),
+        None => panic!("merge_patch() missing 1 required positional argument: 'patch'"),
+    }
+}
+
+// diff(a: any, b: any) -> [{str:}]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_json_diff(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let a = get_call_arg(args, kwargs, 0, Some("a")).unwrap_or_else(ValueRef::none);
+    let b = get_call_arg(args, kwargs, 1, Some("b")).unwrap_or_else(ValueRef::none);
+    json_diff(&a, &b).into_raw(ctx)
+}
+
+// pointer(doc: any, pointer: str, default: any = None) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_json_pointer(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let doc = get_call_arg(args, kwargs, 0, Some("doc")).unwrap_or_else(ValueRef::none);
+    let pointer = match get_call_arg_str(args, kwargs, 1, Some("pointer")) {
+        Some(pointer) => pointer,
+        None => panic!("pointer() missing 1 required positional argument: 'pointer'"),
+    };
+    match json_pointer_get(&doc, &pointer) {
+        Ok(Some(value)) => value.into_raw(ctx),
+        Ok(None) => get_call_arg(args, kwargs, 2, Some("default"))
+            .unwrap_or_else(ValueRef::none)
+            .into_raw(ctx),
+        Err(err) => panic!("{}", err),
+    }
+}
+
 fn kwargs_to_opts(kwargs: &ValueRef) -> JsonEncodeOptions {
     let mut opts = JsonEncodeOptions::default();
     if let Some(sort_keys) = kwargs.kwarg_bool("sort_keys", None) {
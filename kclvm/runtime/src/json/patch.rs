@@ -0,0 +1,512 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! JSON Pointer ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)), JSON Patch
+//! ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) and JSON Merge Patch
+//! ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386)) on KCL values. The
+//! documents are never modified in place, and the replaced config entries keep
+//! their positions while the added entries are appended.
+
+use crate::*;
+
+/// Parse the JSON pointer e.g., `/a/0/b` into the unescaped reference tokens.
+pub fn parse_json_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(vec![]);
+    }
+    match pointer.strip_prefix('/') {
+        Some(rest) => Ok(rest
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect()),
+        None => Err(format!(
+            "invalid JSON pointer '{pointer}', it must be empty or start with '/'"
+        )),
+    }
+}
+
+/// Escape the reference token of the JSON pointer.
+pub fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Returns the list index of the reference token, which must be `0` or a
+/// decimal number without the leading zeros.
+fn token_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || !token.bytes().all(|b| b.is_ascii_digit())
+        || (token.len() > 1 && token.starts_with('0'))
+    {
+        None
+    } else {
+        token.parse().ok()
+    }
+}
+
+fn get_token(value: &ValueRef, token: &str) -> Option<ValueRef> {
+    if value.is_config() {
+        value.dict_get_value(token)
+    } else if value.is_list() {
+        let index = token_index(token)?;
+        value.as_list_ref().values.get(index).cloned()
+    } else {
+        None
+    }
+}
+
+/// Returns the value referenced by the JSON pointer, or None when it does not exist.
+pub fn json_pointer_get(doc: &ValueRef, pointer: &str) -> Result<Option<ValueRef>, String> {
+    let tokens = parse_json_pointer(pointer)?;
+    let mut value = doc.clone();
+    for token in &tokens {
+        match get_token(&value, token) {
+            Some(child) => value = child,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(value))
+}
+
+/// Returns the parent value and the last reference token of the non-root pointer.
+fn resolve_parent(doc: &ValueRef, pointer: &str) -> Result<(ValueRef, String), String> {
+    let mut tokens = parse_json_pointer(pointer)?;
+    let last = tokens.pop().unwrap_or_default();
+    let mut value = doc.clone();
+    for token in &tokens {
+        value =
+            get_token(&value, token).ok_or_else(|| format!("path '{pointer}' does not exist"))?;
+    }
+    Ok((value, last))
+}
+
+fn add_value(doc: &ValueRef, path: &str, value: ValueRef) -> Result<ValueRef, String> {
+    if path.is_empty() {
+        return Ok(value);
+    }
+    let (mut parent, token) = resolve_parent(doc, path)?;
+    if parent.is_config() {
+        parent.dict_update_key_value(&token, value);
+    } else if parent.is_list() {
+        let len = parent.len();
+        match token.as_str() {
+            "-" => parent.list_append(&value),
+            _ => match token_index(&token) {
+                Some(index) if index <= len => parent.list_insert_at(index, &value),
+                _ => return Err(format!("list index of path '{path}' out of range")),
+            },
+        }
+    } else {
+        return Err(format!("path '{path}' does not exist"));
+    }
+    Ok(doc.clone())
+}
+
+fn remove_value(doc: &ValueRef, path: &str) -> Result<ValueRef, String> {
+    if path.is_empty() {
+        return Err("cannot remove the root document".to_string());
+    }
+    let (mut parent, token) = resolve_parent(doc, path)?;
+    let removed = if parent.is_config() {
+        let mut dict = parent.as_dict_mut_ref();
+        dict.ops.shift_remove(&token);
+        dict.insert_indexs.shift_remove(&token);
+        dict.sources.shift_remove(&token);
+        dict.values.shift_remove(&token)
+    } else if parent.is_list() {
+        match token_index(&token) {
+            Some(index) if index < parent.len() => {
+                let value = parent.as_list_ref().values[index].clone();
+                parent.list_remove_at(index);
+                Some(value)
+            }
+            _ => None,
+        }
+    } else {
+        None
+    };
+    match removed {
+        Some(removed) => Ok(removed),
+        None => Err(format!("path '{path}' does not exist")),
+    }
+}
+
+fn replace_value(doc: &ValueRef, path: &str, value: ValueRef) -> Result<ValueRef, String> {
+    if path.is_empty() {
+        return Ok(value);
+    }
+    let (mut parent, token) = resolve_parent(doc, path)?;
+    if get_token(&parent, &token).is_none() {
+        return Err(format!("path '{path}' does not exist"));
+    }
+    if parent.is_config() {
+        // Updating the existing key keeps its position.
+        parent.dict_update_key_value(&token, value);
+    } else if let Some(index) = token_index(&token) {
+        parent.list_set(index, &value);
+    }
+    Ok(doc.clone())
+}
+
+/// Returns the string field of the patch operation.
+fn op_field(op: &ValueRef, name: &str, index: usize) -> Result<String, String> {
+    match op.dict_get_value(name) {
+        Some(value) if value.is_str() => Ok(value.as_str()),
+        _ => Err(format!(
+            "patch operation {index} missing the string member '{name}'"
+        )),
+    }
+}
+
+/// Apply the JSON patch operations to the copy of the document.
+pub fn json_patch(doc: &ValueRef, ops: &ValueRef) -> Result<ValueRef, String> {
+    if !ops.is_list() {
+        return Err(format!(
+            "patch must be a list of operations, not '{}'",
+            ops.type_str()
+        ));
+    }
+    let mut doc = doc.deep_copy();
+    for (index, op) in ops.as_list_ref().values.iter().enumerate() {
+        if !op.is_config() {
+            return Err(format!(
+                "patch operation {index} must be a dict, not '{}'",
+                op.type_str()
+            ));
+        }
+        let name = op_field(op, "op", index)?;
+        let path = op_field(op, "path", index)?;
+        let value = || {
+            op.dict_get_value("value")
+                .map(|value| value.deep_copy())
+                .ok_or_else(|| format!("patch operation {index} missing the member 'value'"))
+        };
+        doc = match name.as_str() {
+            "add" => add_value(&doc, &path, value()?)?,
+            "remove" => {
+                remove_value(&doc, &path)?;
+                doc
+            }
+            "replace" => replace_value(&doc, &path, value()?)?,
+            "move" => {
+                let from = op_field(op, "from", index)?;
+                if path.starts_with(&format!("{from}/")) {
+                    return Err(format!(
+                        "cannot move the path '{from}' into its child '{path}'"
+                    ));
+                }
+                if from == path {
+                    doc
+                } else {
+                    let value = remove_value(&doc, &from)?;
+                    add_value(&doc, &path, value)?
+                }
+            }
+            "copy" => {
+                let from = op_field(op, "from", index)?;
+                let value = json_pointer_get(&doc, &from)?
+                    .ok_or_else(|| format!("path '{from}' does not exist"))?;
+                add_value(&doc, &path, value.deep_copy())?
+            }
+            "test" => {
+                let expected = value()?;
+                match json_pointer_get(&doc, &path)? {
+                    Some(actual) if actual.cmp_equal(&expected) => doc,
+                    _ => {
+                        return Err(format!(
+                            "patch operation {index} test failed at path '{path}'"
+                        ))
+                    }
+                }
+            }
+            _ => return Err(format!("invalid patch operation '{name}'")),
+        };
+    }
+    Ok(doc)
+}
+
+/// Returns the document merged with the JSON merge patch, in which the None
+/// values remove the keys.
+pub fn json_merge_patch(target: &ValueRef, patch: &ValueRef) -> ValueRef {
+    if !patch.is_config() {
+        return patch.deep_copy();
+    }
+    let mut result = if target.is_config() {
+        target.deep_copy()
+    } else {
+        ValueRef::dict(None)
+    };
+    let entries: Vec<(String, ValueRef)> = patch
+        .as_dict_ref()
+        .values
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    for (key, value) in entries {
+        if value.is_none() {
+            let mut dict = result.as_dict_mut_ref();
+            dict.ops.shift_remove(&key);
+            dict.insert_indexs.shift_remove(&key);
+            dict.sources.shift_remove(&key);
+            dict.values.shift_remove(&key);
+        } else {
+            let current = result.dict_get_value(&key).unwrap_or_else(ValueRef::none);
+            result.dict_update_key_value(&key, json_merge_patch(&current, &value));
+        }
+    }
+    result
+}
+
+fn patch_op(op: &str, path: &str, value: Option<&ValueRef>) -> ValueRef {
+    let mut result = ValueRef::dict(None);
+    result.dict_update_key_value("op", ValueRef::str(op));
+    result.dict_update_key_value("path", ValueRef::str(path));
+    if let Some(value) = value {
+        result.dict_update_key_value("value", value.deep_copy());
+    }
+    result
+}
+
+fn diff_into(a: &ValueRef, b: &ValueRef, path: &str, ops: &mut Vec<ValueRef>) {
+    if a.cmp_equal(b) {
+        return;
+    }
+    if a.is_config() && b.is_config() {
+        let a_dict = a.as_dict_ref();
+        let b_dict = b.as_dict_ref();
+        for (key, a_value) in &a_dict.values {
+            let child = format!("{path}/{}", escape_json_pointer_token(key));
+            match b_dict.values.get(key) {
+                Some(b_value) => diff_into(a_value, b_value, &child, ops),
+                None => ops.push(patch_op("remove", &child, None)),
+            }
+        }
+        for (key, b_value) in &b_dict.values {
+            if !a_dict.values.contains_key(key) {
+                let child = format!("{path}/{}", escape_json_pointer_token(key));
+                ops.push(patch_op("add", &child, Some(b_value)));
+            }
+        }
+    } else if a.is_list() && b.is_list() {
+        let a_list = a.as_list_ref();
+        let b_list = b.as_list_ref();
+        let (a_len, b_len) = (a_list.values.len(), b_list.values.len());
+        for i in 0..a_len.min(b_len) {
+            diff_into(
+                &a_list.values[i],
+                &b_list.values[i],
+                &format!("{path}/{i}"),
+                ops,
+            );
+        }
+        for i in a_len..b_len {
+            ops.push(patch_op(
+                "add",
+                &format!("{path}/{i}"),
+                Some(&b_list.values[i]),
+            ));
+        }
+        // Remove the trailing items from the end to keep the indexes valid.
+        for i in (b_len..a_len).rev() {
+            ops.push(patch_op("remove", &format!("{path}/{i}"), None));
+        }
+    } else {
+        ops.push(patch_op("replace", path, Some(b)));
+    }
+}
+
+/// Returns the JSON patch operations which transform `a` into `b`.
+pub fn json_diff(a: &ValueRef, b: &ValueRef) -> ValueRef {
+    let mut ops = vec![];
+    diff_into(a, b, "", &mut ops);
+    ValueRef::list(Some(&ops.iter().collect::<Vec<&ValueRef>>()))
+}
+
+#[cfg(test)]
+mod test_json_patch {
+    use super::*;
+
+    fn json(s: &str) -> ValueRef {
+        let mut ctx = Context::new();
+        ValueRef::from_json(&mut ctx, s).unwrap()
+    }
+
+    fn patch(doc: &str, ops: &str) -> Result<String, String> {
+        json_patch(&json(doc), &json(ops)).map(|v| v.to_json_string())
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        // RFC 6901 section 5 examples.
+        let doc = json(r#"{"foo": ["bar", "baz"], "": 0, "a/b": 1, "m~n": 8, "k\"l": 6}"#);
+        let get = |p: &str| {
+            json_pointer_get(&doc, p)
+                .unwrap()
+                .map(|v| v.to_json_string())
+        };
+        assert_eq!(get("/foo/0"), Some("\"bar\"".to_string()));
+        assert_eq!(get("/"), Some("0".to_string()));
+        assert_eq!(get("/a~1b"), Some("1".to_string()));
+        assert_eq!(get("/m~0n"), Some("8".to_string()));
+        assert_eq!(get("/k\"l"), Some("6".to_string()));
+        assert_eq!(get("/foo/2"), None);
+        assert_eq!(get("/foo/01"), None);
+        assert_eq!(get("/foo/-"), None);
+        assert_eq!(get(""), Some(doc.to_json_string()));
+        assert!(json_pointer_get(&doc, "foo").is_err());
+        assert_eq!(escape_json_pointer_token("a/b~c"), "a~1b~0c");
+    }
+
+    #[test]
+    fn test_json_patch() {
+        // RFC 6902 appendix A examples.
+        assert_eq!(
+            patch(
+                r#"{"foo": "bar"}"#,
+                r#"[{"op": "add", "path": "/baz", "value": "qux"}]"#
+            ),
+            Ok(r#"{"foo": "bar", "baz": "qux"}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"foo": ["bar", "baz"]}"#,
+                r#"[{"op": "add", "path": "/foo/1", "value": "qux"}]"#
+            ),
+            Ok(r#"{"foo": ["bar", "qux", "baz"]}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"baz": "qux", "foo": "bar"}"#,
+                r#"[{"op": "remove", "path": "/baz"}]"#
+            ),
+            Ok(r#"{"foo": "bar"}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"baz": "qux", "foo": "bar"}"#,
+                r#"[{"op": "replace", "path": "/baz", "value": "boo"}]"#
+            ),
+            Ok(r#"{"baz": "boo", "foo": "bar"}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}"#,
+                r#"[{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]"#
+            ),
+            Ok(
+                r#"{"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}}"#
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            patch(
+                r#"{"foo": ["all", "grass", "cows", "eat"]}"#,
+                r#"[{"op": "move", "from": "/foo/1", "path": "/foo/3"}]"#
+            ),
+            Ok(r#"{"foo": ["all", "cows", "eat", "grass"]}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"foo": ["bar"]}"#,
+                r#"[{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}]"#
+            ),
+            Ok(r#"{"foo": ["bar", ["abc", "def"]]}"#.to_string())
+        );
+        assert_eq!(
+            patch(
+                r#"{"baz": "qux", "foo": ["a", 2, "c"]}"#,
+                r#"[{"op": "test", "path": "/baz", "value": "qux"}, {"op": "test", "path": "/foo/1", "value": 2}]"#
+            ),
+            Ok(r#"{"baz": "qux", "foo": ["a", 2, "c"]}"#.to_string())
+        );
+        assert!(patch(
+            r#"{"baz": "qux"}"#,
+            r#"[{"op": "test", "path": "/baz", "value": "bar"}]"#
+        )
+        .is_err());
+        assert!(patch(
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "add", "path": "/baz/bat", "value": "qux"}]"#
+        )
+        .is_err());
+        assert!(patch(r#"{"foo": "bar"}"#, r#"[{"op": "remove", "path": "/baz"}]"#).is_err());
+        assert!(patch(
+            r#"{"foo": [1]}"#,
+            r#"[{"op": "add", "path": "/foo/2", "value": 1}]"#
+        )
+        .is_err());
+        assert!(patch(
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "invalid", "path": "/foo"}]"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_json_patch_does_not_modify_doc() {
+        let doc = json(r#"{"a": {"b": 1}}"#);
+        let result = json_patch(
+            &doc,
+            &json(r#"[{"op": "replace", "path": "/a/b", "value": 2}]"#),
+        )
+        .unwrap();
+        assert_eq!(doc.to_json_string(), r#"{"a": {"b": 1}}"#);
+        assert_eq!(result.to_json_string(), r#"{"a": {"b": 2}}"#);
+    }
+
+    #[test]
+    fn test_json_merge_patch() {
+        // RFC 7386 section 3 example.
+        let target = json(
+            r#"{"title": "Goodbye!", "author": {"givenName": "John", "familyName": "Doe"}, "tags": ["example", "sample"], "content": "This will be unchanged"}"#,
+        );
+        let patch = json(
+            r#"{"title": "Hello!", "phoneNumber": "+01-123-456-7890", "author": {"familyName": null}, "tags": ["example"]}"#,
+        );
+        assert_eq!(
+            json_merge_patch(&target, &patch).to_json_string(),
+            r#"{"title": "Hello!", "author": {"givenName": "John"}, "tags": ["example"], "content": "This will be unchanged", "phoneNumber": "+01-123-456-7890"}"#
+        );
+        assert_eq!(
+            json_merge_patch(
+                &json(r#"{"a": "b"}"#),
+                &json(r#"{"a": {"bb": {"ccc": null}}}"#)
+            )
+            .to_json_string(),
+            r#"{"a": {"bb": {}}}"#
+        );
+        assert_eq!(
+            json_merge_patch(&json(r#"["a", "b"]"#), &json(r#"{"a": "c"}"#)).to_json_string(),
+            r#"{"a": "c"}"#
+        );
+    }
+
+    #[test]
+    fn test_json_diff() {
+        let cases = [
+            (
+                r#"{"a": 1, "b": [1, 2, 3], "c": {"d": 1}}"#,
+                r#"{"a": 2, "b": [1, 4], "c": {"e": 1}, "f": null}"#,
+            ),
+            (r#"[1, 2]"#, r#"[1, 2, 3, 4]"#),
+            (r#"{"a": 1}"#, r#"[1]"#),
+            (r#"{"a/b": {"~": 1}}"#, r#"{"a/b": {"~": 2}}"#),
+        ];
+        for (a, b) in cases {
+            let (a, b) = (json(a), json(b));
+            let ops = json_diff(&a, &b);
+            assert!(
+                json_patch(&a, &ops).unwrap().cmp_equal(&b),
+                "{}",
+                ops.to_json_string()
+            );
+        }
+        assert_eq!(
+            json_diff(
+                &json(r#"{"a": 1, "b": [1, 2, 3]}"#),
+                &json(r#"{"a": 2, "b": [1]}"#)
+            )
+            .to_json_string(),
+            r#"[{"op": "replace", "path": "/a", "value": 2}, {"op": "remove", "path": "/b/2"}, {"op": "remove", "path": "/b/1"}]"#
+        );
+        assert_eq!(json_diff(&json("[1]"), &json("[1]")).to_json_string(), "[]");
+    }
+}
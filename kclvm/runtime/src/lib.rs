@@ -92,15 +92,24 @@ pub use self::math::*;
 pub mod net;
 pub use self::net::*;
 
+pub mod random;
+pub use self::random::*;
+
 pub mod regex;
 pub use self::regex::*;
 
+pub mod semver;
+pub use self::semver::*;
+
 pub mod stdlib;
 pub use self::stdlib::*;
 
 pub mod units;
 pub use self::units::*;
 
+pub mod uuid;
+pub use self::uuid::*;
+
 pub mod yaml;
 pub use self::yaml::*;
 
@@ -409,3 +409,505 @@ pub extern "C" fn kclvm_net_is_unspecified_IP(
     }
     panic!("is_unspecified_IP() missing 1 required positional argument: 'ip'");
 }
+
+// parse_CIDR(cidr: str) -> {str:}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_parse_CIDR(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        let cidr = must_parse_cidr(&cidr);
+        let version = if cidr.v6 { 6 } else { 4 };
+        return ValueRef::dict(Some(&[
+            ("ip", &ValueRef::str(&cidr.ip_string())),
+            ("network", &ValueRef::str(&cidr.network_string())),
+            ("prefix", &ValueRef::int(cidr.prefix as i64)),
+            ("netmask", &ValueRef::str(&cidr.addr_string(cidr.mask()))),
+            ("first", &ValueRef::str(&cidr.addr_string(cidr.first()))),
+            ("last", &ValueRef::str(&cidr.addr_string(cidr.last()))),
+            ("version", &ValueRef::int(version)),
+        ]))
+        .into_raw(ctx);
+    }
+
+    panic!("parse_CIDR() missing 1 required positional argument: 'cidr'");
+}
+
+// CIDR_contains(cidr: str, ip: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_contains(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        if let Some(ip) = get_call_arg_str(args, kwargs, 1, Some("ip")) {
+            let cidr = must_parse_cidr(&cidr);
+            let other = must_parse_cidr(&ip);
+            return kclvm_value_Bool(ctx, cidr.contains(&other) as i8);
+        }
+    }
+
+    panic!("CIDR_contains() missing 2 required positional arguments: 'cidr' and 'ip'");
+}
+
+// CIDR_subnets(cidr: str, newbits: int) -> [str]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_subnets(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        if let Some(newbits) = get_call_arg_int(args, kwargs, 1, Some("newbits")) {
+            let cidr = must_parse_cidr(&cidr);
+            if !(0..=MAX_ENUMERATION_BITS as i64).contains(&newbits) {
+                panic!(
+                    "CIDR_subnets() argument 'newbits' must be between 0 and {}, got {}",
+                    MAX_ENUMERATION_BITS, newbits
+                );
+            }
+            let subnets: Vec<String> = (0..1u128 << newbits)
+                .map(|netnum| {
+                    cidr.subnet(newbits as u8, netnum)
+                        .unwrap_or_else(|err| panic!("{}", err))
+                        .network_string()
+                })
+                .collect();
+            return ValueRef::list_str(&subnets).into_raw(ctx);
+        }
+    }
+
+    panic!("CIDR_subnets() missing 2 required positional arguments: 'cidr' and 'newbits'");
+}
+
+// CIDR_subnet(cidr: str, newbits: int, netnum: int) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_subnet(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        if let Some(newbits) = get_call_arg_int(args, kwargs, 1, Some("newbits")) {
+            if let Some(netnum) = get_call_arg_int(args, kwargs, 2, Some("netnum")) {
+                let cidr = must_parse_cidr(&cidr);
+                if newbits < 0 || netnum < 0 || newbits > cidr.bits() as i64 {
+                    panic!(
+                        "CIDR_subnet() invalid 'newbits' {} or 'netnum' {} for the network {}",
+                        newbits,
+                        netnum,
+                        cidr.network_string()
+                    );
+                }
+                let subnet = cidr
+                    .subnet(newbits as u8, netnum as u128)
+                    .unwrap_or_else(|err| panic!("{}", err));
+                return ValueRef::str(&subnet.network_string()).into_raw(ctx);
+            }
+        }
+    }
+
+    panic!("CIDR_subnet() missing 3 required positional arguments: 'cidr', 'newbits' and 'netnum'");
+}
+
+// CIDR_host(cidr: str, hostnum: int) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_host(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        if let Some(hostnum) = get_call_arg_int(args, kwargs, 1, Some("hostnum")) {
+            let cidr = must_parse_cidr(&cidr);
+            let host = cidr.host(hostnum).unwrap_or_else(|err| panic!("{}", err));
+            return ValueRef::str(&cidr.addr_string(host)).into_raw(ctx);
+        }
+    }
+
+    panic!("CIDR_host() missing 2 required positional arguments: 'cidr' and 'hostnum'");
+}
+
+// CIDR_hosts(cidr: str) -> [str]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_hosts(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(cidr) = get_call_arg_str(args, kwargs, 0, Some("cidr")) {
+        let cidr = must_parse_cidr(&cidr);
+        let host_bits = cidr.bits() - cidr.prefix;
+        if host_bits > MAX_ENUMERATION_BITS {
+            panic!(
+                "CIDR_hosts() the network {} has more than 2^{} addresses",
+                cidr.network_string(),
+                MAX_ENUMERATION_BITS
+            );
+        }
+        // The network and broadcast addresses of IPv4 networks are not hosts,
+        // except for the point-to-point /31 and single host /32 networks.
+        let (first, last) = if !cidr.v6 && host_bits >= 2 {
+            (cidr.first() + 1, cidr.last() - 1)
+        } else {
+            (cidr.first(), cidr.last())
+        };
+        let hosts: Vec<String> = (first..=last).map(|v| cidr.addr_string(v)).collect();
+        return ValueRef::list_str(&hosts).into_raw(ctx);
+    }
+
+    panic!("CIDR_hosts() missing 1 required positional argument: 'cidr'");
+}
+
+// CIDR_overlaps(cidrs: [str], others: [str] = None) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_overlaps(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(cidrs) = get_call_arg(args, kwargs, 0, Some("cidrs")) {
+        let cidrs = must_parse_cidr_list(&cidrs);
+        let overlapped = match get_call_arg(args, kwargs, 1, Some("others")) {
+            Some(others) if !others.is_none() => {
+                let others = must_parse_cidr_list(&others);
+                cidrs.iter().any(|a| others.iter().any(|b| a.overlaps(b)))
+            }
+            _ => cidrs
+                .iter()
+                .enumerate()
+                .any(|(i, a)| cidrs[i + 1..].iter().any(|b| a.overlaps(b))),
+        };
+        return kclvm_value_Bool(ctx, overlapped as i8);
+    }
+
+    panic!("CIDR_overlaps() missing 1 required positional argument: 'cidrs'");
+}
+
+// IP_to_int(ip: str) -> int | str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_IP_to_int(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(ip) = get_call_arg_str(args, kwargs, 0, Some("ip")) {
+        if let Ok(addr) = Ipv4Addr::from_str(ip.as_ref()) {
+            return ValueRef::int(u32::from(addr) as i64).into_raw(ctx);
+        }
+        if let Ok(addr) = Ipv6Addr::from_str(ip.as_ref()) {
+            // IPv6 addresses may exceed the int range, so they are returned as decimal strings.
+            return ValueRef::str(&u128::from(addr).to_string()).into_raw(ctx);
+        }
+        panic!("IP_to_int() invalid IP address '{}'", ip);
+    }
+
+    panic!("IP_to_int() missing 1 required positional argument: 'ip'");
+}
+
+// int_to_IP(value: int | str, version: int = 4) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_int_to_IP(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(value) = get_call_arg(args, kwargs, 0, Some("value")) {
+        let version = get_call_arg_int(args, kwargs, 1, Some("version")).unwrap_or(4);
+        let int_value: Option<u128> = if value.is_int() {
+            u128::try_from(value.as_int()).ok()
+        } else if value.is_str() {
+            value.as_str().trim().parse().ok()
+        } else {
+            panic!(
+                "int_to_IP() argument 'value' must be int or str, not {}",
+                value.type_str()
+            )
+        };
+        let ip = match (int_value, version) {
+            (Some(v), 4) if v <= u32::MAX as u128 => Ipv4Addr::from(v as u32).to_string(),
+            (Some(v), 6) => Ipv6Addr::from(v).to_string(),
+            (_, 4 | 6) => panic!(
+                "int_to_IP() value {} is out of the IPv{} address range",
+                value, version
+            ),
+            _ => panic!(
+                "int_to_IP() argument 'version' must be 4 or 6, got {}",
+                version
+            ),
+        };
+        return ValueRef::str(&ip).into_raw(ctx);
+    }
+
+    panic!("int_to_IP() missing 1 required positional argument: 'value'");
+}
+
+/// The maximum bits of the subnets and hosts enumerated at once.
+const MAX_ENUMERATION_BITS: u8 = 16;
+
+/// An IPv4 or IPv6 network address with the prefix length, and the address bits
+/// are stored in a [u128] for both the IP versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cidr {
+    addr: u128,
+    prefix: u8,
+    v6: bool,
+}
+
+impl Cidr {
+    /// Parse the CIDR notation e.g., `10.0.0.0/16` and `fd00::/8`, and an IP address
+    /// without the prefix length is a single address network.
+    fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let (ip, prefix) = match s.split_once('/') {
+            Some((ip, prefix)) => (ip, Some(prefix)),
+            None => (s, None),
+        };
+        let (addr, v6) = if let Ok(addr) = Ipv4Addr::from_str(ip) {
+            (u32::from(addr) as u128, false)
+        } else if let Ok(addr) = Ipv6Addr::from_str(ip) {
+            (u128::from(addr), true)
+        } else {
+            return Err(format!("invalid CIDR address '{}'", s));
+        };
+        let bits = if v6 { 128 } else { 32 };
+        let prefix = match prefix {
+            Some(prefix) => match prefix.parse::<u8>() {
+                Ok(prefix) if prefix <= bits => prefix,
+                _ => return Err(format!("invalid CIDR prefix length in '{}'", s)),
+            },
+            None => bits,
+        };
+        Ok(Cidr { addr, prefix, v6 })
+    }
+
+    fn bits(&self) -> u8 {
+        if self.v6 {
+            128
+        } else {
+            32
+        }
+    }
+
+    fn mask(&self) -> u128 {
+        prefix_mask(self.prefix, self.bits())
+    }
+
+    fn first(&self) -> u128 {
+        self.addr & self.mask()
+    }
+
+    fn last(&self) -> u128 {
+        self.first() | (prefix_mask(self.bits(), self.bits()) & !self.mask())
+    }
+
+    fn contains(&self, other: &Cidr) -> bool {
+        self.v6 == other.v6
+            && self.prefix <= other.prefix
+            && other.addr & self.mask() == self.first()
+    }
+
+    fn overlaps(&self, other: &Cidr) -> bool {
+        self.contains(other) || other.contains(self)
+    }
+
+    /// Returns the `netnum`-th subnet with the prefix length extended by `newbits`.
+    fn subnet(&self, newbits: u8, netnum: u128) -> Result<Cidr, String> {
+        let prefix = self.prefix as u16 + newbits as u16;
+        if prefix > self.bits() as u16 {
+            return Err(format!(
+                "the prefix length {} of the network {} extended by {} bits exceeds {}",
+                self.prefix,
+                self.network_string(),
+                newbits,
+                self.bits()
+            ));
+        }
+        let prefix = prefix as u8;
+        if newbits < 128 && netnum >> newbits != 0 {
+            return Err(format!(
+                "the subnet number {} of the network {} exceeds {} bits",
+                netnum,
+                self.network_string(),
+                newbits
+            ));
+        }
+        let shift = self.bits() - prefix;
+        let addr = if shift >= 128 {
+            self.first()
+        } else {
+            self.first() | (netnum << shift)
+        };
+        Ok(Cidr {
+            addr,
+            prefix,
+            v6: self.v6,
+        })
+    }
+
+    /// Returns the `hostnum`-th address of the network, and the negative number
+    /// counts backwards from the last address.
+    fn host(&self, hostnum: i64) -> Result<u128, String> {
+        let size = self.last() - self.first();
+        let offset = if hostnum >= 0 {
+            Some(hostnum as u128).filter(|n| *n <= size)
+        } else {
+            size.checked_sub(hostnum.unsigned_abs() as u128 - 1)
+        };
+        match offset {
+            Some(offset) => Ok(self.first() + offset),
+            None => Err(format!(
+                "the host number {} is out of the network {}",
+                hostnum,
+                self.network_string()
+            )),
+        }
+    }
+
+    fn addr_string(&self, addr: u128) -> String {
+        if self.v6 {
+            Ipv6Addr::from(addr).to_string()
+        } else {
+            Ipv4Addr::from(addr as u32).to_string()
+        }
+    }
+
+    fn ip_string(&self) -> String {
+        self.addr_string(self.addr)
+    }
+
+    fn network_string(&self) -> String {
+        format!("{}/{}", self.addr_string(self.first()), self.prefix)
+    }
+}
+
+/// Returns the network mask with `prefix` leading ones in the `bits` address bits.
+fn prefix_mask(prefix: u8, bits: u8) -> u128 {
+    if prefix == 0 {
+        0
+    } else {
+        (u128::MAX << (128 - prefix as u32)) >> (128 - bits as u32)
+    }
+}
+
+fn must_parse_cidr(s: &str) -> Cidr {
+    Cidr::parse(s).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn must_parse_cidr_list(value: &ValueRef) -> Vec<Cidr> {
+    if value.is_str() {
+        return vec![must_parse_cidr(&value.as_str())];
+    }
+    if !value.is_list() {
+        panic!("expected a list of CIDR strings, got {}", value.type_str());
+    }
+    value
+        .as_list_ref()
+        .values
+        .iter()
+        .map(|v| must_parse_cidr(&v.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_net {
+    use super::*;
+
+    #[test]
+    fn test_cidr() {
+        let cidr = Cidr::parse("10.1.2.3/16").unwrap();
+        assert_eq!(cidr.network_string(), "10.1.0.0/16");
+        assert_eq!(cidr.ip_string(), "10.1.2.3");
+        assert_eq!(cidr.addr_string(cidr.mask()), "255.255.0.0");
+        assert_eq!(cidr.addr_string(cidr.last()), "10.1.255.255");
+        assert!(cidr.contains(&Cidr::parse("10.1.200.0/24").unwrap()));
+        assert!(!cidr.contains(&Cidr::parse("10.0.0.0/8").unwrap()));
+        assert!(!cidr.contains(&Cidr::parse("fd00::1").unwrap()));
+        assert!(Cidr::parse("10.0.0.0/33").is_err());
+        assert!(Cidr::parse("10.0.0/8").is_err());
+
+        let v6 = Cidr::parse("fd00::/8").unwrap();
+        assert_eq!(v6.addr_string(v6.mask()), "ff00::");
+        assert_eq!(
+            v6.addr_string(v6.last()),
+            "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff"
+        );
+        let all = Cidr::parse("0.0.0.0/0").unwrap();
+        assert_eq!(all.addr_string(all.last()), "255.255.255.255");
+        assert!(all.contains(&cidr));
+    }
+
+    #[test]
+    fn test_cidr_subnet_and_host() {
+        let cidr = Cidr::parse("10.0.0.0/16").unwrap();
+        assert_eq!(cidr.subnet(8, 3).unwrap().network_string(), "10.0.3.0/24");
+        assert!(cidr.subnet(8, 256).is_err());
+        assert!(cidr.subnet(17, 0).is_err());
+        assert_eq!(cidr.addr_string(cidr.host(5).unwrap()), "10.0.0.5");
+        assert_eq!(cidr.addr_string(cidr.host(-1).unwrap()), "10.0.255.255");
+        assert!(cidr.host(65536).is_err());
+        assert!(cidr.host(-65537).is_err());
+
+        let v6 = Cidr::parse("2001:db8::/32").unwrap();
+        assert_eq!(
+            v6.subnet(16, 0xab).unwrap().network_string(),
+            "2001:db8:ab::/48"
+        );
+        assert_eq!(v6.addr_string(v6.host(1).unwrap()), "2001:db8::1");
+    }
+}
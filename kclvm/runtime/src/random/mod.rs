@@ -0,0 +1,337 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! The random numbers are produced by a deterministic SplitMix64 generator, so
+//! the same program and seed always produce the same outputs. Functions without
+//! the `seed` argument share the context generator, which is seeded with the
+//! `random_seed` config (0 by default) and can be reseeded with `random.seed()`.
+//! Functions with the `seed` argument use a fresh generator and do not depend on
+//! the evaluation order.
+
+use sha2::{Digest, Sha256};
+
+use crate::*;
+
+/// The SplitMix64 pseudo random number generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform float in the range [0.0, 1.0).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a uniform integer in the range [0, n) without the modulo bias.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0);
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let v = self.next_u64();
+            if v < zone {
+                return v % n;
+            }
+        }
+    }
+
+    /// Fill the bytes with random values.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let v = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    }
+
+    /// Shuffle the items in place with the Fisher-Yates algorithm.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Returns the generator seed of the int or string `seed` value, strings are
+/// hashed so that any name can be used as a seed.
+pub fn seed_of(seed: &ValueRef) -> u64 {
+    match &*seed.rc.borrow() {
+        Value::int_value(v) => *v as u64,
+        Value::bool_value(v) => *v as u64,
+        Value::str_value(s) => {
+            let digest = Sha256::digest(s.as_bytes());
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&digest[..8]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => panic!("seed must be an int or a str, not '{}'", seed.type_str()),
+    }
+}
+
+/// Call `f` with the generator of the `seed` value when it is given, or with the
+/// context generator otherwise.
+pub fn with_rng<T>(
+    ctx: &mut Context,
+    seed: Option<ValueRef>,
+    f: impl FnOnce(&mut SplitMix64) -> T,
+) -> T {
+    match seed {
+        Some(seed) => f(&mut SplitMix64::new(seed_of(&seed))),
+        None => {
+            let state = ctx.random_state.unwrap_or(ctx.cfg.random_seed as u64);
+            let mut rng = SplitMix64::new(state);
+            let result = f(&mut rng);
+            ctx.random_state = Some(rng.state);
+            result
+        }
+    }
+}
+
+/// Returns the list items, the config keys or the string characters.
+fn sequence_items(value: &ValueRef, fn_name: &str, arg_name: &str) -> Vec<ValueRef> {
+    match &*value.rc.borrow() {
+        Value::list_value(list) => list.values.clone(),
+        Value::dict_value(dict) => dict.values.keys().map(|k| ValueRef::str(k)).collect(),
+        Value::schema_value(schema) => schema
+            .config
+            .values
+            .keys()
+            .map(|k| ValueRef::str(k))
+            .collect(),
+        Value::str_value(s) => s.chars().map(|c| ValueRef::str(&c.to_string())).collect(),
+        _ => panic!(
+            "{fn_name}() argument '{arg_name}' must be a sequence, not '{}'",
+            value.type_str()
+        ),
+    }
+}
+
+// seed(a: int|str) -> None
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_seed(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(seed) = get_call_arg(args, kwargs, 0, Some("a")) {
+        ctx.random_state = Some(seed_of(&seed));
+        return ValueRef::none().into_raw(ctx);
+    }
+
+    panic!("seed() missing 1 required positional argument: 'a'");
+}
+
+// random(seed: int|str = None) -> float
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_random(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let seed = get_call_arg(args, kwargs, 0, Some("seed"));
+    let value = with_rng(ctx, seed, |rng| rng.next_f64());
+    ValueRef::float(value).into_raw(ctx)
+}
+
+// randint(a: int, b: int, seed: int|str = None) -> int
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_randint(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let a = match get_call_arg_int(args, kwargs, 0, Some("a")) {
+        Some(a) => a,
+        None => panic!("randint() missing 2 required positional arguments: 'a' and 'b'"),
+    };
+    let b = match get_call_arg_int(args, kwargs, 1, Some("b")) {
+        Some(b) => b,
+        None => panic!("randint() missing 1 required positional argument: 'b'"),
+    };
+    if a > b {
+        panic!("empty range for randint({a}, {b})");
+    }
+    let seed = get_call_arg(args, kwargs, 2, Some("seed"));
+    let width = (b as i128 - a as i128 + 1) as u128;
+    let value = with_rng(ctx, seed, |rng| {
+        if width > u64::MAX as u128 {
+            rng.next_u64()
+        } else {
+            rng.below(width as u64)
+        }
+    });
+    ValueRef::int((a as i128 + value as i128) as i64).into_raw(ctx)
+}
+
+// choice(seq: [any]|str, seed: int|str = None) -> any
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_choice(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(seq) = get_call_arg(args, kwargs, 0, Some("seq")) {
+        let items = sequence_items(&seq, "choice", "seq");
+        if items.is_empty() {
+            panic!("cannot choose from an empty sequence");
+        }
+        let seed = get_call_arg(args, kwargs, 1, Some("seed"));
+        let index = with_rng(ctx, seed, |rng| rng.below(items.len() as u64));
+        return items[index as usize].clone().into_raw(ctx);
+    }
+
+    panic!("choice() missing 1 required positional argument: 'seq'");
+}
+
+// shuffle(items: [any]|str, seed: int|str = None) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_shuffle(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(items) = get_call_arg(args, kwargs, 0, Some("items")) {
+        let mut items = sequence_items(&items, "shuffle", "items");
+        let seed = get_call_arg(args, kwargs, 1, Some("seed"));
+        with_rng(ctx, seed, |rng| rng.shuffle(&mut items));
+        return ValueRef::list(Some(&items.iter().collect::<Vec<&ValueRef>>())).into_raw(ctx);
+    }
+
+    panic!("shuffle() missing 1 required positional argument: 'items'");
+}
+
+// sample(population: [any]|str, k: int, seed: int|str = None) -> [any]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_random_sample(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let population = match get_call_arg(args, kwargs, 0, Some("population")) {
+        Some(population) => sequence_items(&population, "sample", "population"),
+        None => panic!("sample() missing 2 required positional arguments: 'population' and 'k'"),
+    };
+    let k = match get_call_arg_int(args, kwargs, 1, Some("k")) {
+        Some(k) => k,
+        None => panic!("sample() missing 1 required positional argument: 'k'"),
+    };
+    if k < 0 || k as usize > population.len() {
+        panic!("sample larger than population or is negative");
+    }
+    let seed = get_call_arg(args, kwargs, 2, Some("seed"));
+    let mut items = population;
+    let k = k as usize;
+    with_rng(ctx, seed, |rng| {
+        // Partial Fisher-Yates shuffle of the first k positions.
+        for i in 0..k {
+            let j = i + rng.below((items.len() - i) as u64) as usize;
+            items.swap(i, j);
+        }
+    });
+    ValueRef::list(Some(&items[..k].iter().collect::<Vec<&ValueRef>>())).into_raw(ctx)
+}
+
+#[cfg(test)]
+mod test_random {
+    use super::*;
+
+    #[test]
+    fn test_split_mix64() {
+        // The reference outputs of SplitMix64 with the seed 0.
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+        assert_eq!(rng.next_u64(), 0x06c45d188009454f);
+    }
+
+    #[test]
+    fn test_below_and_float_range() {
+        let mut rng = SplitMix64::new(42);
+        for n in [1, 2, 3, 7, 10, 1000] {
+            for _ in 0..100 {
+                assert!(rng.below(n) < n);
+            }
+        }
+        for _ in 0..100 {
+            let v = rng.next_f64();
+            assert!((0.0..1.0).contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_shuffle_is_deterministic_permutation() {
+        let mut a: Vec<i32> = (0..20).collect();
+        let mut b = a.clone();
+        SplitMix64::new(7).shuffle(&mut a);
+        SplitMix64::new(7).shuffle(&mut b);
+        assert_eq!(a, b);
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_with_rng() {
+        let mut ctx = Context::new();
+        ctx.cfg.random_seed = 3;
+        let first = with_rng(&mut ctx, None, |rng| rng.next_u64());
+        let second = with_rng(&mut ctx, None, |rng| rng.next_u64());
+        assert_ne!(first, second);
+        // The explicit seed does not advance the context generator.
+        let seeded = with_rng(&mut ctx, Some(ValueRef::int(3)), |rng| rng.next_u64());
+        assert_eq!(seeded, first);
+        assert_eq!(
+            seed_of(&ValueRef::str("rollout")),
+            seed_of(&ValueRef::str("rollout"))
+        );
+        assert_ne!(seed_of(&ValueRef::str("a")), seed_of(&ValueRef::str("b")));
+    }
+}
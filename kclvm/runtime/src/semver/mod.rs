@@ -0,0 +1,427 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! Semantic versions follow the [SemVer 2.0.0](https://semver.org) specification with
+//! an optional leading `v` e.g., `v1.2.3`, and the version numbers can't exceed the max
+//! KCL int. Version constraints are comparators separated by spaces or commas e.g.,
+//! `>=1.0 <2.0`, `^1.2` and `~1.4`, optionally joined with `||`. A bare version matches
+//! like `=` e.g., `1.2.3` only matches `1.2.3`, and `1.2` matches all the `1.2.x` versions.
+
+use std::cmp::Ordering;
+
+use ::semver::{BuildMetadata, Prerelease, Version, VersionReq};
+
+use crate::*;
+
+// parse(version: str) -> {str:}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_parse(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(version) = get_call_arg_str(args, kwargs, 0, Some("version")) {
+        let version = must_parse_version(&version);
+        return ValueRef::dict(Some(&[
+            // The version numbers are checked to fit the int values when parsed.
+            ("major", &ValueRef::int(version.major as i64)),
+            ("minor", &ValueRef::int(version.minor as i64)),
+            ("patch", &ValueRef::int(version.patch as i64)),
+            ("prerelease", &ValueRef::str(version.pre.as_str())),
+            ("build", &ValueRef::str(version.build.as_str())),
+        ]))
+        .into_raw(ctx);
+    }
+
+    panic!("parse() missing 1 required positional argument: 'version'");
+}
+
+// is_valid(version: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_is_valid(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(version) = get_call_arg_str(args, kwargs, 0, Some("version")) {
+        return kclvm_value_Bool(ctx, parse_version(&version).is_ok() as i8);
+    }
+
+    panic!("is_valid() missing 1 required positional argument: 'version'");
+}
+
+// compare(a: str, b: str) -> int
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_compare(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(a) = get_call_arg_str(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg_str(args, kwargs, 1, Some("b")) {
+            let ordering = compare_versions(&must_parse_version(&a), &must_parse_version(&b));
+            return ValueRef::int(ordering as i64).into_raw(ctx);
+        }
+    }
+
+    panic!("compare() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// satisfies(version: str, constraint: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_satisfies(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(version) = get_call_arg_str(args, kwargs, 0, Some("version")) {
+        if let Some(constraint) = get_call_arg_str(args, kwargs, 1, Some("constraint")) {
+            let version = must_parse_version(&version);
+            let constraint = must_parse_constraint(&constraint);
+            return kclvm_value_Bool(ctx, constraint.matches(&version) as i8);
+        }
+    }
+
+    panic!("satisfies() missing 2 required positional arguments: 'version' and 'constraint'");
+}
+
+// max_satisfying(versions: [str], constraint: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_max_satisfying(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(versions) = get_call_arg(args, kwargs, 0, Some("versions")) {
+        if let Some(constraint) = get_call_arg_str(args, kwargs, 1, Some("constraint")) {
+            let constraint = must_parse_constraint(&constraint);
+            let max = must_parse_version_list(&versions)
+                .into_iter()
+                .filter(|(_, version)| constraint.matches(version))
+                .max_by(|(_, a), (_, b)| compare_versions(a, b));
+            return match max {
+                Some((s, _)) => ValueRef::str(&s).into_raw(ctx),
+                None => ValueRef::none().into_raw(ctx),
+            };
+        }
+    }
+
+    panic!("max_satisfying() missing 2 required positional arguments: 'versions' and 'constraint'");
+}
+
+// bump(version: str, part: str = "patch") -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_bump(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(version) = get_call_arg_str(args, kwargs, 0, Some("version")) {
+        let part =
+            get_call_arg_str(args, kwargs, 1, Some("part")).unwrap_or_else(|| "patch".to_string());
+        let mut version = must_parse_version(&version);
+        match part.as_str() {
+            "major" => {
+                version.major += 1;
+                version.minor = 0;
+                version.patch = 0;
+            }
+            "minor" => {
+                version.minor += 1;
+                version.patch = 0;
+            }
+            "patch" => version.patch += 1,
+            _ => panic!(
+                "bump() argument 'part' must be 'major', 'minor' or 'patch', got '{}'",
+                part
+            ),
+        }
+        version.pre = Prerelease::EMPTY;
+        version.build = BuildMetadata::EMPTY;
+        let version = version.to_string();
+        // Check the bumped version number is not too large.
+        must_parse_version(&version);
+        return ValueRef::str(&version).into_raw(ctx);
+    }
+
+    panic!("bump() missing 1 required positional argument: 'version'");
+}
+
+// sort(versions: [str], reverse: bool = False) -> [str]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_sort(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(versions) = get_call_arg(args, kwargs, 0, Some("versions")) {
+        let reverse = get_call_arg(args, kwargs, 1, Some("reverse"))
+            .map(|v| v.is_truthy())
+            .unwrap_or_default();
+        let mut versions = must_parse_version_list(&versions);
+        // The stable sort keeps the original order of the versions with equal precedence.
+        versions.sort_by(|(_, a), (_, b)| compare_versions(a, b));
+        if reverse {
+            versions.reverse();
+        }
+        let versions: Vec<String> = versions.into_iter().map(|(s, _)| s).collect();
+        return ValueRef::list_str(&versions).into_raw(ctx);
+    }
+
+    panic!("sort() missing 1 required positional argument: 'versions'");
+}
+
+/// A version constraint with the alternative requirements joined with `||`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionConstraint {
+    pub alternatives: Vec<VersionReq>,
+}
+
+impl VersionConstraint {
+    /// Whether the version satisfies any of the alternative requirements.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|req| req.matches(version))
+    }
+}
+
+/// Parse the semantic version with an optional leading `v`.
+pub fn parse_version(s: &str) -> Result<Version, String> {
+    let version = Version::parse(strip_v_prefix(s.trim()))
+        .map_err(|err| format!("invalid semantic version '{}': {}", s, err))?;
+    if [version.major, version.minor, version.patch]
+        .iter()
+        .any(|n| *n > i64::MAX as u64)
+    {
+        return Err(format!(
+            "invalid semantic version '{}': version number exceeds {}",
+            s,
+            i64::MAX
+        ));
+    }
+    Ok(version)
+}
+
+/// Parse the version constraint e.g., `^1.2`, `>=1.0 <2.0`, `1.2 - 1.4` and
+/// `~1.4 || ^2`. Bare versions are exact requirements instead of the caret
+/// requirements of Cargo.
+pub fn parse_constraint(s: &str) -> Result<VersionConstraint, String> {
+    let err = |msg: String| format!("invalid version constraint '{}': {}", s, msg);
+    let mut alternatives = vec![];
+    for alternative in s.split("||") {
+        let tokens: Vec<&str> = alternative
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tokens.is_empty() {
+            return Err(err("empty requirement".to_string()));
+        }
+        let mut comparators: Vec<String> = vec![];
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = tokens[i];
+            if token == "-" {
+                // The hyphen range `a - b` means `>=a, <=b`, and a partial upper bound
+                // e.g., `1.4` includes all its patch versions.
+                let (lower, upper) = match (comparators.pop(), tokens.get(i + 1)) {
+                    (Some(lower), Some(upper)) if is_version_token(&lower) => (lower, upper),
+                    _ => return Err(err("invalid hyphen range".to_string())),
+                };
+                comparators.push(format!(">={}", lower));
+                comparators.push(format!("<={}", strip_v_prefix(upper)));
+                i += 2;
+                continue;
+            }
+            let op_len = token
+                .find(|c: char| !matches!(c, '=' | '>' | '<' | '~' | '^'))
+                .unwrap_or(token.len());
+            let (op, version) = if op_len == token.len() {
+                // The operator is separated from the version e.g., `>= 1.0`.
+                match tokens.get(i + 1) {
+                    Some(version) => {
+                        i += 1;
+                        (token, *version)
+                    }
+                    None => return Err(err(format!("missing version after '{}'", token))),
+                }
+            } else {
+                token.split_at(op_len)
+            };
+            comparators.push(format!("{}{}", op, strip_v_prefix(version)));
+            i += 1;
+        }
+        let comparators: Vec<String> = comparators
+            .into_iter()
+            .map(|comparator| {
+                if is_version_token(&comparator) && !comparator.contains(['*', 'x', 'X']) {
+                    format!("={}", comparator)
+                } else {
+                    comparator
+                }
+            })
+            .collect();
+        let req = VersionReq::parse(&comparators.join(", ")).map_err(|e| err(e.to_string()))?;
+        alternatives.push(req);
+    }
+    Ok(VersionConstraint { alternatives })
+}
+
+/// Compare the versions by the SemVer precedence, which ignores the build metadata.
+pub fn compare_versions(a: &Version, b: &Version) -> Ordering {
+    (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre))
+}
+
+fn strip_v_prefix(s: &str) -> &str {
+    match s.strip_prefix(['v', 'V']) {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => s,
+    }
+}
+
+fn is_version_token(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn must_parse_version(s: &str) -> Version {
+    parse_version(s).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn must_parse_constraint(s: &str) -> VersionConstraint {
+    parse_constraint(s).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn must_parse_version_list(value: &ValueRef) -> Vec<(String, Version)> {
+    if !value.is_list() {
+        panic!(
+            "expected a list of version strings, got {}",
+            value.type_str()
+        );
+    }
+    value
+        .as_list_ref()
+        .values
+        .iter()
+        .map(|v| {
+            let s = v.as_str();
+            let version = must_parse_version(&s);
+            (s, version)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_semver {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        let version = parse_version("v1.2.3-rc.1+build.5").unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+        assert_eq!(version.pre.as_str(), "rc.1");
+        assert_eq!(version.build.as_str(), "build.5");
+        for s in [
+            "1.2",
+            "1.2.3.4",
+            "01.2.3",
+            "latest",
+            "v",
+            "",
+            "9223372036854775808.0.0",
+        ] {
+            assert!(parse_version(s).is_err(), "{s}");
+        }
+        assert!(parse_version("9223372036854775807.0.0").is_ok());
+    }
+
+    #[test]
+    fn test_parse_constraint() {
+        let cases = [
+            ("^1.2", "1.9.0", true),
+            ("^1.2", "2.0.0", false),
+            ("~1.4", "1.4.9", true),
+            ("~1.4", "1.5.0", false),
+            (">=1.0 <2.0", "1.5.0", true),
+            (">=1.0 <2.0", "2.0.0", false),
+            (">= 1.0, < 2.0", "1.0.0", true),
+            ("1.2 - 1.4", "1.4.0", true),
+            ("1.2 - 1.4", "1.4.1", true),
+            ("1.2 - 1.4", "1.5.0", false),
+            ("~1.4 || ^3", "3.1.0", true),
+            ("~1.4 || ^3", "2.0.0", false),
+            ("=v1.2.3", "1.2.3", true),
+            ("1.2.3", "1.2.3", true),
+            ("1.2.3", "1.5.0", false),
+            ("v1.2", "1.2.9", true),
+            ("1.2", "1.3.0", false),
+            ("1.x", "1.9.0", true),
+            ("*", "0.0.1", true),
+        ];
+        for (constraint, version, expected) in cases {
+            let constraint = parse_constraint(constraint).unwrap();
+            let version = parse_version(version).unwrap();
+            assert_eq!(constraint.matches(&version), expected, "{version}");
+        }
+        for s in ["", ">=", "1.2 -", "^1.x.y", "|| ^1"] {
+            assert!(parse_constraint(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        let v = |s| parse_version(s).unwrap();
+        assert_eq!(
+            compare_versions(&v("1.0.0-alpha"), &v("1.0.0")),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions(&v("1.0.0-alpha.1"), &v("1.0.0-alpha.beta")),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions(&v("1.0.0+a"), &v("1.0.0+b")),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_versions(&v("1.10.0"), &v("1.9.0")),
+            Ordering::Greater
+        );
+    }
+}
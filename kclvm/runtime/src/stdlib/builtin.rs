@@ -154,12 +154,42 @@ impl ValueRef {
         }
     }
 
+    /// Sort the items of the iterable value by the results of the `key` function,
+    /// items with equal keys keep their original order.
+    pub fn sorted_by_key(&self, ctx: &mut Context, key: &ValueRef, reverse: bool) -> ValueRef {
+        let items: Vec<ValueRef> = match &*self.rc.borrow() {
+            Value::str_value(s) => s.chars().map(|c| ValueRef::str(&c.to_string())).collect(),
+            Value::list_value(list) => list.values.clone(),
+            Value::dict_value(dict) => dict.values.keys().map(|k| ValueRef::str(k)).collect(),
+            _ => panic!("sorted only for str|list|dict type"),
+        };
+        let mut pairs: Vec<(ValueRef, ValueRef)> = items
+            .into_iter()
+            .map(|item| (key.call(ctx, &[item.clone()]), item))
+            .collect();
+        if reverse {
+            pairs.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        } else {
+            pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        }
+        let values: Vec<&ValueRef> = pairs.iter().map(|(_, item)| item).collect();
+        ValueRef::list(Some(&values))
+    }
+
     pub fn convert_to_int(&self, ctx: &mut Context, base: Option<&ValueRef>) -> ValueRef {
         let strict_range_check_i32 = ctx.cfg.strict_range_check;
         let strict_range_check_i64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
         match &*self.rc.borrow() {
             Value::int_value(ref v) => ValueRef::int(*v),
+            Value::bigint_value(ref v) => ValueRef::bigint(v.clone()),
+            Value::decimal_value(ref v) => {
+                if ctx.cfg.big_int {
+                    ValueRef::bigint(v.trunc())
+                } else {
+                    ValueRef::int(self.as_int())
+                }
+            }
             Value::float_value(ref v) => ValueRef::int(*v as i64),
             Value::unit_value(ref v, raw, unit) => {
                 let v_i128 = crate::real_uint_value(*raw, unit);
@@ -185,11 +215,18 @@ impl ValueRef {
             Value::str_value(ref v) => {
                 let base = if let Some(v) = base { v.as_int() } else { 10 };
                 let number_str = to_quantity(v.as_str()).to_string();
-                let v: i64 =
-                    i64::from_str_radix(number_str.as_str(), base as u32).unwrap_or_else(|_| {
-                        panic!("invalid literal for int() with base {base}: '{self}'")
-                    });
-                ValueRef::int(v)
+                match i64::from_str_radix(number_str.as_str(), base as u32) {
+                    Ok(v) => ValueRef::int(v),
+                    Err(_) => {
+                        // Parse the int literal which overflows i64 to a big integer.
+                        match num_bigint::BigInt::parse_bytes(number_str.as_bytes(), base as u32) {
+                            Some(v) if ctx.cfg.big_int && !ctx.cfg.strict_range_check => {
+                                ValueRef::bigint(v)
+                            }
+                            _ => panic!("invalid literal for int() with base {base}: '{self}'"),
+                        }
+                    }
+                }
             }
             _ => panic!(
                 "int() argument must be a string, a bytes-like object or a number, not '{}'",
@@ -204,6 +241,7 @@ impl ValueRef {
 
         match &*self.rc.borrow() {
             Value::int_value(ref v) => ValueRef::float(*v as f64),
+            Value::bigint_value(_) | Value::decimal_value(_) => ValueRef::float(self.as_float()),
             Value::float_value(ref v) => {
                 let float32_overflow = strict_range_check_i32 && (*v as f32).is_infinite();
                 let float64_overflow = strict_range_check_i64 && (*v).is_infinite();
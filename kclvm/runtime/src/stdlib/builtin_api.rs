@@ -334,6 +334,13 @@ pub unsafe extern "C" fn kclvm_builtin_sorted(
 
     if let Some(arg0) = args.arg_0() {
         let reverse = kwargs.kwarg("reverse");
+        if let Some(key) = kwargs
+            .kwarg("key")
+            .filter(|key| !key.is_none_or_undefined())
+        {
+            let reverse = reverse.map(|v| v.as_bool()).unwrap_or_default();
+            return arg0.sorted_by_key(ctx, &key, reverse).into_raw(ctx);
+        }
         return arg0.sorted(reverse.as_ref()).into_raw(ctx);
     }
     panic!("sorted() takes exactly one argument (0 given)");
@@ -374,6 +381,23 @@ pub unsafe extern "C" fn kclvm_builtin_float(
     panic!("float() takes exactly one argument (0 given)");
 }
 
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_decimal(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *mut kclvm_value_ref_t {
+    let ctx = mut_ptr_as_ref(ctx);
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    match get_call_arg(args, kwargs, 0, Some("value")) {
+        Some(value) => value.convert_to_decimal().into_raw(ctx),
+        None => ValueRef::decimal(Decimal::from(0)).into_raw(ctx),
+    }
+}
+
 #[no_mangle]
 #[runtime_fn]
 pub unsafe extern "C" fn kclvm_builtin_bool(
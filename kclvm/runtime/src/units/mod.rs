@@ -2,6 +2,9 @@
 
 use crate::*;
 
+mod quantity;
+pub use quantity::*;
+
 #[derive(Debug)]
 #[allow(non_camel_case_types, dead_code)]
 enum to_unit_suffix {
@@ -279,6 +282,211 @@ pub extern "C" fn kclvm_units_to_Pi(
     panic!("to_Pi() missing 1 required positional argument: 'num'");
 }
 
+// parse_quantity(quantity: str) -> float
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_parse_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(quantity) = get_call_arg(args, kwargs, 0, Some("quantity")) {
+        let quantity = must_quantity_from_value(&quantity);
+        return ValueRef::float(quantity.as_f64()).into_raw(ctx);
+    }
+    panic!("parse_quantity() missing 1 required positional argument: 'quantity'");
+}
+
+// format_quantity(quantity: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_format_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(quantity) = get_call_arg(args, kwargs, 0, Some("quantity")) {
+        let quantity = must_quantity_from_value(&quantity);
+        return ValueRef::str(&quantity.to_string()).into_raw(ctx);
+    }
+    panic!("format_quantity() missing 1 required positional argument: 'quantity'");
+}
+
+// add_quantity(a: str, b: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_add_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(a) = get_call_arg(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg(args, kwargs, 1, Some("b")) {
+            let result = must_quantity_from_value(&a)
+                .checked_add(&must_quantity_from_value(&b))
+                .unwrap_or_else(|err| panic!("{}", err));
+            return ValueRef::str(&result.to_string()).into_raw(ctx);
+        }
+    }
+    panic!("add_quantity() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// sub_quantity(a: str, b: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_sub_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(a) = get_call_arg(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg(args, kwargs, 1, Some("b")) {
+            let result = must_quantity_from_value(&a)
+                .checked_sub(&must_quantity_from_value(&b))
+                .unwrap_or_else(|err| panic!("{}", err));
+            return ValueRef::str(&result.to_string()).into_raw(ctx);
+        }
+    }
+    panic!("sub_quantity() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// scale_quantity(quantity: str, factor: int | float) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_scale_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(quantity) = get_call_arg(args, kwargs, 0, Some("quantity")) {
+        if let Some(factor) = get_call_arg(args, kwargs, 1, Some("factor")) {
+            let quantity = must_quantity_from_value(&quantity);
+            let result = if factor.is_int() {
+                quantity.checked_mul_int(factor.as_int())
+            } else if factor.is_float() {
+                quantity.checked_mul_float(factor.as_float())
+            } else {
+                panic!(
+                    "scale_quantity() argument 'factor' must be int or float, not {}",
+                    factor.type_str()
+                )
+            }
+            .unwrap_or_else(|err| panic!("{}", err));
+            return ValueRef::str(&result.to_string()).into_raw(ctx);
+        }
+    }
+    panic!("scale_quantity() missing 2 required positional arguments: 'quantity' and 'factor'");
+}
+
+// compare_quantity(a: str, b: str) -> int
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_compare_quantity(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(a) = get_call_arg(args, kwargs, 0, Some("a")) {
+        if let Some(b) = get_call_arg(args, kwargs, 1, Some("b")) {
+            let ordering = must_quantity_from_value(&a).cmp(&must_quantity_from_value(&b));
+            return ValueRef::int(ordering as i64).into_raw(ctx);
+        }
+    }
+    panic!("compare_quantity() missing 2 required positional arguments: 'a' and 'b'");
+}
+
+// sum_quantities(quantities: [str]) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_units_sum_quantities(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    if let Some(quantities) = get_call_arg(args, kwargs, 0, Some("quantities")) {
+        if !quantities.is_list() {
+            panic!(
+                "sum_quantities() argument 'quantities' must be a list, not {}",
+                quantities.type_str()
+            );
+        }
+        let mut sum: Option<Quantity> = None;
+        for value in &quantities.as_list_ref().values {
+            let quantity = must_quantity_from_value(value);
+            sum = Some(match sum {
+                Some(sum) => sum
+                    .checked_add(&quantity)
+                    .unwrap_or_else(|err| panic!("{}", err)),
+                None => quantity,
+            });
+        }
+        let sum = sum.unwrap_or_else(|| Quantity::from_int(0));
+        return ValueRef::str(&sum.to_string()).into_raw(ctx);
+    }
+    panic!("sum_quantities() missing 1 required positional argument: 'quantities'");
+}
+
+/// Convert the quantity string, int, float or number multiplier value to the quantity.
+pub fn quantity_from_value(value: &ValueRef) -> Result<Quantity, String> {
+    match &*value.rc.borrow() {
+        Value::str_value(s) => Quantity::parse(s),
+        Value::int_value(v) => Ok(Quantity::from_int(*v)),
+        Value::float_value(v) => Quantity::from_float(*v),
+        Value::unit_value(_, raw, unit) => Quantity::from_unit(*raw, unit),
+        _ => Err(format!(
+            "expected a quantity of str, int, float or units.NumberMultiplier, got {}",
+            value.type_str()
+        )),
+    }
+}
+
+/// Convert the quantity to the number multiplier value with the canonical suffix.
+pub fn quantity_to_value(quantity: &Quantity) -> Result<ValueRef, String> {
+    let (mantissa, suffix) = quantity.canonical();
+    let raw =
+        i64::try_from(mantissa).map_err(|_| format!("quantity {} is out of range", quantity))?;
+    Ok(ValueRef::unit(quantity.as_f64(), raw, suffix))
+}
+
+fn must_quantity_from_value(value: &ValueRef) -> Quantity {
+    quantity_from_value(value).unwrap_or_else(|err| panic!("{}", err))
+}
+
 fn to_unit(num: f64, suffix: to_unit_suffix) -> String {
     match suffix {
         to_unit_suffix::n => format!("{}{:?}", (num / 1e-09) as i64, suffix),
@@ -0,0 +1,306 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! Kubernetes-style resource quantities e.g., `500m`, `1.5Gi` and `2e3`, which are
+//! stored as exact nano units to avoid the floating point rounding errors.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The decimal SI suffixes with the base 10 exponents in the canonical formatting order.
+const DECIMAL_SUFFIXES: [(&str, i32); 10] = [
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("", 0),
+    ("m", -3),
+    ("u", -6),
+    ("n", -9),
+];
+
+/// The binary SI suffixes with the base 2 exponents in the canonical formatting order.
+const BINARY_SUFFIXES: [(&str, u32); 6] = [
+    ("Ei", 60),
+    ("Pi", 50),
+    ("Ti", 40),
+    ("Gi", 30),
+    ("Mi", 20),
+    ("Ki", 10),
+];
+
+const NANOS_EXPONENT: i32 = 9;
+
+/// A resource quantity with the exact value in nano units, and whether it is
+/// formatted with the binary SI suffixes e.g., `Ki` and `Mi`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quantity {
+    pub nanos: i128,
+    pub binary: bool,
+}
+
+impl Quantity {
+    /// Construct a quantity from the integer value.
+    pub fn from_int(value: i64) -> Self {
+        Quantity {
+            nanos: value as i128 * pow10(NANOS_EXPONENT),
+            binary: false,
+        }
+    }
+
+    /// Construct a quantity from the float value, rounded to nano units.
+    pub fn from_float(value: f64) -> Result<Self, String> {
+        let nanos = value * 1e9;
+        if !nanos.is_finite() || nanos.abs() >= i128::MAX as f64 {
+            return Err(format!("quantity {} is out of range", value));
+        }
+        Ok(Quantity {
+            nanos: nanos.round() as i128,
+            binary: false,
+        })
+    }
+
+    /// Construct a quantity from the number multiplier value e.g., `500m` and `1Gi`.
+    pub fn from_unit(raw: i64, unit: &str) -> Result<Self, String> {
+        Self::parse(&format!("{}{}", raw, unit))
+    }
+
+    /// Parse the quantity string, which is a signed decimal number followed by an
+    /// optional decimal SI suffix (`n`, `u`, `m`, `k`, `K`, `M`, `G`, `T`, `P`, `E`),
+    /// binary SI suffix (`Ki`, `Mi`, `Gi`, `Ti`, `Pi`, `Ei`) or decimal exponent
+    /// (`e3`, `E-2`). Values finer than nano units are rounded up.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let err = |msg: &str| format!("invalid quantity '{}': {}", s, msg);
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let (number, suffix) = rest.split_at(number_len);
+        let (int_part, frac_part) = number.split_once('.').unwrap_or((number, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(err("missing the number"));
+        }
+        if frac_part.contains('.') {
+            return Err(err("multiple decimal points"));
+        }
+        let (exponent, binary_exponent) = parse_suffix(suffix).ok_or_else(|| {
+            err(&format!(
+                "unknown suffix '{}', expected one of n, u, m, k, M, G, T, P, E, Ki, Mi, Gi, Ti, Pi, Ei or a decimal exponent",
+                suffix
+            ))
+        })?;
+        let digits = format!("{}{}", int_part, frac_part);
+        let digits = digits.trim_start_matches('0');
+        let mantissa: i128 = if digits.is_empty() {
+            0
+        } else {
+            digits.parse().map_err(|_| err("the number is too large"))?
+        };
+        let overflow = || err("the number is too large");
+        let scale = exponent + NANOS_EXPONENT - frac_part.len() as i32;
+        let mut nanos = mantissa
+            .checked_mul(1i128.checked_shl(binary_exponent).ok_or_else(overflow)?)
+            .ok_or_else(overflow)?;
+        if scale >= 0 {
+            nanos = checked_pow10(scale)
+                .and_then(|p| nanos.checked_mul(p))
+                .ok_or_else(overflow)?;
+        } else {
+            nanos = match checked_pow10(-scale) {
+                Some(p) => (nanos + p - 1) / p,
+                // The value is less than one nano unit.
+                None => (nanos > 0) as i128,
+            };
+        }
+        Ok(Quantity {
+            nanos: if negative { -nanos } else { nanos },
+            binary: binary_exponent > 0,
+        })
+    }
+
+    /// Returns the value in the base units.
+    pub fn as_f64(&self) -> f64 {
+        self.nanos as f64 / 1e9
+    }
+
+    pub fn checked_add(&self, other: &Quantity) -> Result<Quantity, String> {
+        self.nanos
+            .checked_add(other.nanos)
+            .map(|nanos| Quantity {
+                nanos,
+                binary: self.binary,
+            })
+            .ok_or_else(|| "quantity addition overflows".to_string())
+    }
+
+    pub fn checked_sub(&self, other: &Quantity) -> Result<Quantity, String> {
+        self.nanos
+            .checked_sub(other.nanos)
+            .map(|nanos| Quantity {
+                nanos,
+                binary: self.binary,
+            })
+            .ok_or_else(|| "quantity subtraction overflows".to_string())
+    }
+
+    /// Multiply the quantity by the integer factor exactly.
+    pub fn checked_mul_int(&self, factor: i64) -> Result<Quantity, String> {
+        self.nanos
+            .checked_mul(factor as i128)
+            .map(|nanos| Quantity {
+                nanos,
+                binary: self.binary,
+            })
+            .ok_or_else(|| "quantity multiplication overflows".to_string())
+    }
+
+    /// Multiply the quantity by the float factor, rounded to nano units.
+    pub fn checked_mul_float(&self, factor: f64) -> Result<Quantity, String> {
+        let nanos = self.nanos as f64 * factor;
+        if !nanos.is_finite() || nanos.abs() >= i128::MAX as f64 {
+            return Err("quantity multiplication overflows".to_string());
+        }
+        Ok(Quantity {
+            nanos: nanos.round() as i128,
+            binary: self.binary,
+        })
+    }
+
+    /// Returns the canonical mantissa and suffix of the quantity, which has the
+    /// largest suffix with an integer mantissa. Binary quantities fall back to the
+    /// decimal suffixes when the value is not an integer multiple of 1024.
+    pub fn canonical(&self) -> (i128, &'static str) {
+        if self.nanos == 0 {
+            return (0, "");
+        }
+        let one = pow10(NANOS_EXPONENT);
+        if self.binary && self.nanos % one == 0 {
+            let value = self.nanos / one;
+            for (suffix, exponent) in BINARY_SUFFIXES {
+                let base = 1i128 << exponent;
+                if value % base == 0 {
+                    return (value / base, suffix);
+                }
+            }
+        }
+        for (suffix, exponent) in DECIMAL_SUFFIXES {
+            let base = pow10(exponent + NANOS_EXPONENT);
+            if self.nanos % base == 0 {
+                return (self.nanos / base, suffix);
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mantissa, suffix) = self.canonical();
+        write!(f, "{}{}", mantissa, suffix)
+    }
+}
+
+impl PartialOrd for Quantity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Quantity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.nanos.cmp(&other.nanos)
+    }
+}
+
+/// Returns the base 10 and base 2 exponents of the quantity suffix.
+fn parse_suffix(suffix: &str) -> Option<(i32, u32)> {
+    if suffix == "K" {
+        return Some((3, 0));
+    }
+    if let Some((_, exponent)) = DECIMAL_SUFFIXES.iter().find(|(s, _)| *s == suffix) {
+        return Some((*exponent, 0));
+    }
+    if let Some((_, exponent)) = BINARY_SUFFIXES.iter().find(|(s, _)| *s == suffix) {
+        return Some((0, *exponent));
+    }
+    let exponent = suffix.strip_prefix(['e', 'E'])?;
+    let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    exponent
+        .parse::<i32>()
+        .ok()
+        .filter(|e| e.abs() <= 30)
+        .map(|e| (e, 0))
+}
+
+fn checked_pow10(exponent: i32) -> Option<i128> {
+    if exponent < 0 {
+        return None;
+    }
+    10i128.checked_pow(exponent as u32)
+}
+
+fn pow10(exponent: i32) -> i128 {
+    checked_pow10(exponent).unwrap()
+}
+
+#[cfg(test)]
+mod test_quantity {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let cases = [
+            ("500m", 500_000_000, "500m"),
+            ("0.5", 500_000_000, "500m"),
+            ("1.5Gi", 1_610_612_736_000_000_000, "1536Mi"),
+            ("2Gi", 2_147_483_648_000_000_000, "2Gi"),
+            ("1000", 1_000_000_000_000, "1k"),
+            ("1K", 1_000_000_000_000, "1k"),
+            ("1500m", 1_500_000_000, "1500m"),
+            ("2e3", 2_000_000_000_000, "2k"),
+            ("12E-1", 1_200_000_000, "1200m"),
+            ("1E", 1_000_000_000_000_000_000_000_000_000, "1E"),
+            ("-100Mi", -104_857_600_000_000_000, "-100Mi"),
+            ("100n", 100, "100n"),
+            ("0.1n", 1, "1n"),
+            ("0", 0, "0"),
+            ("+.5k", 500_000_000_000, "500"),
+            ("512Ki", 524_288_000_000_000, "512Ki"),
+            ("0.5Ki", 512_000_000_000, "512"),
+        ];
+        for (s, nanos, canonical) in cases {
+            let quantity = Quantity::parse(s).unwrap();
+            assert_eq!(quantity.nanos, nanos, "{s}");
+            assert_eq!(quantity.to_string(), canonical, "{s}");
+        }
+        for s in ["", "m", "1.2.3", "1x", "1mi", "1e", "1e100", "Gi", "1 Gi"] {
+            assert!(Quantity::parse(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let q = |s| Quantity::parse(s).unwrap();
+        assert_eq!(q("500m").checked_add(&q("1.5")).unwrap().to_string(), "2");
+        assert_eq!(
+            q("1Gi").checked_sub(&q("512Mi")).unwrap().to_string(),
+            "512Mi"
+        );
+        assert_eq!(q("100m").checked_mul_int(3).unwrap().to_string(), "300m");
+        assert_eq!(
+            q("1Gi").checked_mul_float(0.5).unwrap().to_string(),
+            "512Mi"
+        );
+        assert!(q("500m") < q("1"));
+        assert!(q("1Gi") > q("1G"));
+        assert_eq!(q("1000m").cmp(&q("1")), Ordering::Equal);
+    }
+}
@@ -0,0 +1,194 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! UUIDs follow [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122). The version 4
+//! UUIDs are generated by the deterministic generator of the random module, so
+//! they are reproducible for the same program and seed, and the version 5 UUIDs
+//! are the SHA-1 hashes of the namespace and the name.
+
+use crate::random::with_rng;
+use crate::*;
+
+/// The well-known namespaces of RFC 4122 Appendix C.
+const NAMESPACES: &[(&str, &str)] = &[
+    ("dns", "6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+    ("url", "6ba7b811-9dad-11d1-80b4-00c04fd430c8"),
+    ("oid", "6ba7b812-9dad-11d1-80b4-00c04fd430c8"),
+    ("x500", "6ba7b814-9dad-11d1-80b4-00c04fd430c8"),
+];
+
+/// Parse the UUID string in the hyphenated or the simple 32 hex digits form.
+pub fn parse_uuid(s: &str) -> Result<[u8; 16], String> {
+    let invalid = || format!("invalid uuid '{s}'");
+    let hex: String = match s.len() {
+        32 => s.to_string(),
+        36 => {
+            let bytes = s.as_bytes();
+            if [8, 13, 18, 23].iter().any(|&i| bytes[i] != b'-') {
+                return Err(invalid());
+            }
+            s.replace('-', "")
+        }
+        _ => return Err(invalid()),
+    };
+    if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let mut uuid = [0u8; 16];
+    for (i, byte) in uuid.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(uuid)
+}
+
+/// Format the UUID bytes as the lowercase hyphenated string.
+pub fn format_uuid(uuid: &[u8; 16]) -> String {
+    let hex: String = uuid.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Set the version and the RFC 4122 variant bits.
+fn set_version(uuid: &mut [u8; 16], version: u8) {
+    uuid[6] = (uuid[6] & 0x0f) | (version << 4);
+    uuid[8] = (uuid[8] & 0x3f) | 0x80;
+}
+
+/// Returns the version 5 UUID of the `name` in the `namespace`, which is one of
+/// `dns`, `url`, `oid` and `x500` or a UUID string.
+pub fn uuid5(namespace: &str, name: &str) -> Result<[u8; 16], String> {
+    let namespace = NAMESPACES
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(namespace))
+        .map(|(_, uuid)| *uuid)
+        .unwrap_or(namespace);
+    let namespace = parse_uuid(namespace)?;
+    let mut hasher = sha1::Sha1::new();
+    hasher.update(&namespace);
+    hasher.update(name.as_bytes());
+    let digest = hasher.digest().bytes();
+    let mut uuid = [0u8; 16];
+    uuid.copy_from_slice(&digest[..16]);
+    set_version(&mut uuid, 5);
+    Ok(uuid)
+}
+
+// uuid4(seed: int|str = None) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_uuid_uuid4(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let seed = get_call_arg(args, kwargs, 0, Some("seed"));
+    let mut uuid = [0u8; 16];
+    with_rng(ctx, seed, |rng| rng.fill_bytes(&mut uuid));
+    set_version(&mut uuid, 4);
+    ValueRef::str(&format_uuid(&uuid)).into_raw(ctx)
+}
+
+// uuid5(namespace: str, name: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_uuid_uuid5(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let ctx = mut_ptr_as_ref(ctx);
+
+    let namespace = match get_call_arg_str(args, kwargs, 0, Some("namespace")) {
+        Some(namespace) => namespace,
+        None => panic!("uuid5() missing 2 required positional arguments: 'namespace' and 'name'"),
+    };
+    let name = match get_call_arg_str(args, kwargs, 1, Some("name")) {
+        Some(name) => name,
+        None => panic!("uuid5() missing 1 required positional argument: 'name'"),
+    };
+    match uuid5(&namespace, &name) {
+        Ok(uuid) => ValueRef::str(&format_uuid(&uuid)).into_raw(ctx),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+// is_valid(value: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_uuid_is_valid(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(value) = get_call_arg_str(args, kwargs, 0, Some("value")) {
+        return kclvm_value_Bool(ctx, parse_uuid(&value).is_ok() as i8);
+    }
+
+    panic!("is_valid() missing 1 required positional argument: 'value'");
+}
+
+#[cfg(test)]
+mod test_uuid {
+    use super::*;
+
+    #[test]
+    fn test_uuid5() {
+        // The same outputs as Python `uuid.uuid5(uuid.NAMESPACE_DNS, "python.org")`.
+        assert_eq!(
+            format_uuid(&uuid5("dns", "python.org").unwrap()),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+        assert_eq!(
+            format_uuid(
+                &uuid5(
+                    "6ba7b811-9dad-11d1-80b4-00c04fd430c8",
+                    "https://kcl-lang.io"
+                )
+                .unwrap()
+            ),
+            format_uuid(&uuid5("url", "https://kcl-lang.io").unwrap()),
+        );
+        assert!(uuid5("unknown", "name").is_err());
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let uuid = parse_uuid("6BA7B810-9DAD-11D1-80B4-00C04FD430C8").unwrap();
+        assert_eq!(format_uuid(&uuid), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(
+            parse_uuid("6ba7b8109dad11d180b400c04fd430c8").unwrap(),
+            uuid
+        );
+        assert!(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c").is_err());
+        assert!(parse_uuid("6ba7b810+9dad-11d1-80b4-00c04fd430c8").is_err());
+        assert!(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430cg").is_err());
+    }
+
+    #[test]
+    fn test_uuid4_version_and_variant() {
+        let mut ctx = Context::new();
+        let mut uuid = [0u8; 16];
+        with_rng(&mut ctx, None, |rng| rng.fill_bytes(&mut uuid));
+        set_version(&mut uuid, 4);
+        let s = format_uuid(&uuid);
+        assert_eq!(&s[14..15], "4");
+        assert!(matches!(&s[19..20], "8" | "9" | "a" | "b"));
+    }
+}
@@ -530,6 +530,24 @@ pub unsafe extern "C" fn kclvm_value_to_str_value(
     return new_mut_ptr(ctx, ValueRef::str(s.as_ref()));
 }
 
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_value_to_str_value_with_spec(
+    ctx: *mut kclvm_context_t,
+    p: *const kclvm_value_ref_t,
+    spec: *const kclvm_char_t,
+) -> *mut kclvm_value_ref_t {
+    if p.is_null() {
+        return kclvm_value_Str(ctx, std::ptr::null());
+    }
+
+    let ctx = mut_ptr_as_ref(ctx);
+    let p = ptr_as_ref(p);
+    let s = p.to_string_with_spec(c2str(spec));
+
+    return new_mut_ptr(ctx, ValueRef::str(s.as_ref()));
+}
+
 // ----------------------------------------------------------------------------
 // values: value pointer
 // ----------------------------------------------------------------------------
@@ -1982,6 +2000,17 @@ pub unsafe extern "C" fn kclvm_value_load_attr(
             "startswith" => kclvm_builtin_str_startswith,
             "strip" => kclvm_builtin_str_strip,
             "title" => kclvm_builtin_str_title,
+            "partition" => kclvm_builtin_str_partition,
+            "rpartition" => kclvm_builtin_str_rpartition,
+            "zfill" => kclvm_builtin_str_zfill,
+            "ljust" => kclvm_builtin_str_ljust,
+            "rjust" => kclvm_builtin_str_rjust,
+            "center" => kclvm_builtin_str_center,
+            "expandtabs" => kclvm_builtin_str_expandtabs,
+            "casefold" => kclvm_builtin_str_casefold,
+            "swapcase" => kclvm_builtin_str_swapcase,
+            "translate" => kclvm_builtin_str_translate,
+            "encode" => kclvm_builtin_str_encode,
             _ => panic!("str object attr '{key}' not found"),
         };
         let closure = ValueRef::list(Some(&[p]));
@@ -3155,6 +3184,207 @@ pub unsafe extern "C" fn kclvm_builtin_str_title(
     }
 }
 
+/// Split the string at the first occurrence of sep, and return a list of the part before
+/// the separator, the separator itself, and the part after the separator.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_partition(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let sep = args.arg_i(0).expect("expect 1 argument, found 0");
+        val.str_partition(&sep).into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_partition");
+    }
+}
+
+/// Split the string at the last occurrence of sep, and return a list of the part before
+/// the separator, the separator itself, and the part after the separator.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_rpartition(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let sep = args.arg_i(0).expect("expect 1 argument, found 0");
+        val.str_rpartition(&sep).into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_rpartition");
+    }
+}
+
+/// Return a copy of the string left filled with ASCII '0' digits to make a string of length width.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_zfill(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let width = args.arg_i(0).expect("expect 1 argument, found 0");
+        val.str_zfill(&width).into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_zfill");
+    }
+}
+
+/// Return the string left justified in a string of length width, padding is done using the
+/// specified fillchar (default is a space).
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_ljust(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let width = args.arg_i(0).expect("expect 1 argument, found 0");
+        let fillchar = args.arg_i(1);
+        val.str_ljust(&width, fillchar.as_ref())
+            .into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_ljust");
+    }
+}
+
+/// Return the string right justified in a string of length width, padding is done using the
+/// specified fillchar (default is a space).
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_rjust(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let width = args.arg_i(0).expect("expect 1 argument, found 0");
+        let fillchar = args.arg_i(1);
+        val.str_rjust(&width, fillchar.as_ref())
+            .into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_rjust");
+    }
+}
+
+/// Return the string centered in a string of length width, padding is done using the
+/// specified fillchar (default is a space).
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_center(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let width = args.arg_i(0).expect("expect 1 argument, found 0");
+        let fillchar = args.arg_i(1);
+        val.str_center(&width, fillchar.as_ref())
+            .into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_center");
+    }
+}
+
+/// Return a copy of the string where all tab characters are replaced by spaces.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_expandtabs(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    if let Some(val) = args.pop_arg_first() {
+        let tabsize = args.arg_i(0).or_else(|| kwargs.kwarg("tabsize"));
+        val.str_expandtabs(tabsize.as_ref())
+            .into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_expandtabs");
+    }
+}
+
+/// Return a casefolded copy of the string.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_casefold(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        val.str_casefold().into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_casefold");
+    }
+}
+
+/// Return a copy of the string with uppercase characters converted to lowercase and vice versa.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_swapcase(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        val.str_swapcase().into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_swapcase");
+    }
+}
+
+/// Return a copy of the string in which each character has been mapped through the given
+/// translation table.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_translate(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    if let Some(val) = args.pop_arg_first() {
+        let table = args.arg_i(0).expect("expect 1 argument, found 0");
+        val.str_translate(&table).into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_translate");
+    }
+}
+
+/// Return the string encoded to bytes as a list of integers.
+#[no_mangle]
+#[runtime_fn]
+pub unsafe extern "C" fn kclvm_builtin_str_encode(
+    ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    if let Some(val) = args.pop_arg_first() {
+        let encoding = args.arg_i(0).or_else(|| kwargs.kwarg("encoding"));
+        val.str_encode(encoding.as_ref())
+            .into_raw(mut_ptr_as_ref(ctx))
+    } else {
+        panic!("invalid self value in str_encode");
+    }
+}
+
 // ----------------------------------------------------------------------------
 // END
 // ----------------------------------------------------------------------------
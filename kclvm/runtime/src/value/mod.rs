@@ -5,6 +5,9 @@ pub mod val_panic;
 pub mod val_overflow;
 pub use val_overflow::*;
 
+pub mod val_decimal;
+pub use val_decimal::*;
+
 pub mod api;
 pub use api::*;
 
@@ -31,6 +34,8 @@ pub mod val_cmp;
 pub mod val_decorator;
 pub use val_decorator::*;
 
+pub mod val_func;
+
 pub mod val_is_in;
 
 pub mod val_list;
@@ -47,6 +52,12 @@ pub mod val_get_set;
 pub mod val_schema;
 pub use val_schema::*;
 
+pub mod val_serde;
+pub use val_serde::*;
+
+pub mod val_source_map;
+pub use val_source_map::*;
+
 pub mod val_json;
 pub use val_json::*;
 
@@ -59,6 +70,9 @@ pub mod val_bin;
 pub mod val_plan;
 pub use val_plan::*;
 
+pub mod val_protobuf;
+pub use val_protobuf::*;
+
 pub mod val_str;
 
 pub mod val_type;
@@ -19,6 +19,20 @@ impl ValueRef {
         Self::from(Value::int_value(v))
     }
 
+    /// Construct an int value from the big integer, which is only stored as a big
+    /// integer when it overflows i64.
+    pub fn bigint(v: num_bigint::BigInt) -> Self {
+        use num_traits::ToPrimitive;
+        match v.to_i64() {
+            Some(v) => Self::int(v),
+            None => Self::from(Value::bigint_value(v)),
+        }
+    }
+
+    pub fn decimal(v: Decimal) -> Self {
+        Self::from(Value::decimal_value(v))
+    }
+
     pub fn float(v: f64) -> Self {
         Self::from(Value::float_value(v))
     }
@@ -16,6 +16,71 @@ pub fn adjust_parameter(value: Option<&ValueRef>) -> Option<&ValueRef> {
     })
 }
 
+/// Get the call argument by the keyword `key` or the positional `index`, and the
+/// KCL None or Undefined argument is mapped to Rust None.
+pub fn get_call_arg(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    index: usize,
+    key: Option<&str>,
+) -> Option<ValueRef> {
+    if let Some(key) = key {
+        if let Some(value) = kwargs.kwarg(key) {
+            if !value.is_none_or_undefined() {
+                return Some(value);
+            }
+        }
+    }
+    args.arg_i(index).filter(|v| !v.is_none_or_undefined())
+}
+
+/// Get the string call argument by the keyword `key` or the positional `index`,
+/// and panic with a type error if the argument is not a string.
+pub fn get_call_arg_str(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    index: usize,
+    key: Option<&str>,
+) -> Option<String> {
+    get_call_arg(args, kwargs, index, key).map(|v| {
+        if !v.is_str() {
+            panic!(
+                "{} must be str, not {}",
+                call_arg_name(index, key),
+                v.type_str()
+            )
+        }
+        v.as_str()
+    })
+}
+
+/// Get the int call argument by the keyword `key` or the positional `index`,
+/// and panic with a type error if the argument is not an int or a unit value.
+pub fn get_call_arg_int(
+    args: &ValueRef,
+    kwargs: &ValueRef,
+    index: usize,
+    key: Option<&str>,
+) -> Option<i64> {
+    get_call_arg(args, kwargs, index, key).map(|v| {
+        if !v.is_int() && !v.is_unit() {
+            panic!(
+                "{} must be int, not {}",
+                call_arg_name(index, key),
+                v.type_str()
+            )
+        }
+        v.as_int()
+    })
+}
+
+fn call_arg_name(index: usize, key: Option<&str>) -> String {
+    match key {
+        Some(key) => format!("argument '{}'", key),
+        None => format!("argument {}", index),
+    }
+}
+
 impl ValueRef {
     pub fn arg_0(&self) -> Option<Self> {
         self.arg_i(0)
@@ -245,4 +310,37 @@ mod test_value_args {
             "2"
         );
     }
+
+    #[test]
+    fn test_get_call_arg() {
+        let call_args = || {
+            (
+                ValueRef::list(Some(&[&ValueRef::str("s"), &ValueRef::int(1)])),
+                ValueRef::dict(None),
+            )
+        };
+        let (args, kwargs) = call_args();
+        assert_eq!(
+            get_call_arg_str(&args, &kwargs, 0, Some("key")),
+            Some("s".to_string())
+        );
+        assert_eq!(get_call_arg_int(&args, &kwargs, 1, Some("n")), Some(1));
+        assert_eq!(get_call_arg_int(&args, &kwargs, 2, Some("n")), None);
+        let err = std::panic::catch_unwind(|| {
+            let (args, kwargs) = call_args();
+            get_call_arg_str(&args, &kwargs, 1, Some("key"))
+        });
+        assert_eq!(
+            err.unwrap_err().downcast_ref::<String>().unwrap(),
+            "argument 'key' must be str, not int"
+        );
+        let err = std::panic::catch_unwind(|| {
+            let (args, kwargs) = call_args();
+            get_call_arg_int(&args, &kwargs, 0, None)
+        });
+        assert_eq!(
+            err.unwrap_err().downcast_ref::<String>().unwrap(),
+            "argument 0 must be int, not str"
+        );
+    }
 }
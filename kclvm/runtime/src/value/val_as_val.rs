@@ -1,6 +1,7 @@
 //! Copyright The KCL Authors. All rights reserved.
 
 use crate::*;
+use num_traits::ToPrimitive;
 use std::cell::Ref;
 use std::cell::RefMut;
 impl ValueRef {
@@ -13,7 +14,13 @@ impl ValueRef {
     pub fn as_int(&self) -> i64 {
         match *self.rc.borrow() {
             Value::int_value(ref v) => *v,
+            Value::bigint_value(ref v) => {
+                panic!("int {} is too large to convert to a 64 bit integer", v)
+            }
             Value::float_value(ref v) => *v as i64,
+            Value::decimal_value(ref v) => v.trunc().to_i64().unwrap_or_else(|| {
+                panic!("decimal {} is too large to convert to a 64 bit integer", v)
+            }),
             Value::unit_value(ref v, _, _) => *v as i64,
             _ => 0,
         }
@@ -23,7 +30,9 @@ impl ValueRef {
     pub fn as_float(&self) -> f64 {
         match *self.rc.borrow() {
             Value::int_value(ref v) => *v as f64,
+            Value::bigint_value(ref v) => v.to_f64().unwrap_or(f64::NAN),
             Value::float_value(ref v) => *v,
+            Value::decimal_value(ref v) => v.to_f64(),
             Value::unit_value(ref v, _, _) => *v,
             _ => 0.0,
         }
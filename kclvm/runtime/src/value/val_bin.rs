@@ -4,6 +4,12 @@ use crate::*;
 
 impl ValueRef {
     pub fn bin_add(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self
+            .bin_big_number(x, "+")
+            .or_else(|| self.bin_int_overflow(ctx, x, "+"))
+        {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -61,6 +67,12 @@ impl ValueRef {
     }
 
     pub fn bin_sub(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self
+            .bin_big_number(x, "-")
+            .or_else(|| self.bin_int_overflow(ctx, x, "-"))
+        {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -97,6 +109,12 @@ impl ValueRef {
     }
 
     pub fn bin_mul(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self
+            .bin_big_number(x, "*")
+            .or_else(|| self.bin_int_overflow(ctx, x, "*"))
+        {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -154,6 +172,9 @@ impl ValueRef {
     }
 
     pub fn bin_div(&self, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "/") {
+            return result;
+        }
         match (&*self.rc.borrow(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => Self::float((*a as f64) / (*b as f64)),
             (Value::float_value(a), Value::float_value(b)) => Self::float(*a / *b),
@@ -164,6 +185,9 @@ impl ValueRef {
     }
 
     pub fn bin_mod(&self, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "%") {
+            return result;
+        }
         match (&*self.rc.borrow(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 let x = *a;
@@ -182,6 +206,12 @@ impl ValueRef {
     }
 
     pub fn bin_pow(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self
+            .bin_big_number(x, "**")
+            .or_else(|| self.bin_int_overflow(ctx, x, "**"))
+        {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -218,6 +248,9 @@ impl ValueRef {
     }
 
     pub fn bin_floor_div(&self, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "//") {
+            return result;
+        }
         match (&*self.rc.borrow(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 let x = *a;
@@ -236,6 +269,12 @@ impl ValueRef {
     }
 
     pub fn bin_bit_lshift(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self
+            .bin_big_number(x, "<<")
+            .or_else(|| self.bin_int_overflow(ctx, x, "<<"))
+        {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -254,6 +293,9 @@ impl ValueRef {
     }
 
     pub fn bin_bit_rshift(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, ">>") {
+            return result;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -272,6 +314,9 @@ impl ValueRef {
     }
 
     pub fn bin_bit_and(&self, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "&") {
+            return result;
+        }
         match (&*self.rc.borrow(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => Self::int(*a & *b),
             _ => panic_unsupported_bin_op!("&", self.type_str(), x.type_str()),
@@ -279,6 +324,9 @@ impl ValueRef {
     }
 
     pub fn bin_bit_xor(&self, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "^") {
+            return result;
+        }
         match (&*self.rc.borrow(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => Self::int(*a ^ *b),
             _ => panic_unsupported_bin_op!("^", self.type_str(), x.type_str()),
@@ -286,6 +334,9 @@ impl ValueRef {
     }
 
     pub fn bin_bit_or(&self, ctx: &mut Context, x: &Self) -> Self {
+        if let Some(result) = self.bin_big_number(x, "|") {
+            return result;
+        }
         if let (Value::int_value(a), Value::int_value(b)) = (&*self.rc.borrow(), &*x.rc.borrow()) {
             return Self::int(*a | *b);
         };
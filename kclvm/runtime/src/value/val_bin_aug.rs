@@ -4,6 +4,14 @@ use crate::*;
 
 impl ValueRef {
     pub fn bin_aug_add(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self
+            .bin_big_number(x, "+")
+            .or_else(|| self.bin_int_overflow(ctx, x, "+"))
+        {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -61,6 +69,14 @@ impl ValueRef {
     }
 
     pub fn bin_aug_sub(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self
+            .bin_big_number(x, "-")
+            .or_else(|| self.bin_int_overflow(ctx, x, "-"))
+        {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -107,6 +123,14 @@ impl ValueRef {
     }
 
     pub fn bin_aug_mul(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self
+            .bin_big_number(x, "*")
+            .or_else(|| self.bin_int_overflow(ctx, x, "*"))
+        {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -167,6 +191,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_div(&mut self, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "/") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 *a /= *b;
@@ -193,6 +222,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_mod(&mut self, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "%") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 let x = *a;
@@ -225,6 +259,14 @@ impl ValueRef {
     }
 
     pub fn bin_aug_pow(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self
+            .bin_big_number(x, "**")
+            .or_else(|| self.bin_int_overflow(ctx, x, "**"))
+        {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -269,6 +311,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_floor_div(&mut self, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "//") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 let x = *a;
@@ -301,6 +348,14 @@ impl ValueRef {
     }
 
     pub fn bin_aug_bit_lshift(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self
+            .bin_big_number(x, "<<")
+            .or_else(|| self.bin_int_overflow(ctx, x, "<<"))
+        {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -324,6 +379,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_bit_rshift(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, ">>") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let strict_range_check_32 = ctx.cfg.strict_range_check;
         let strict_range_check_64 = ctx.cfg.debug_mode || !ctx.cfg.strict_range_check;
 
@@ -347,6 +407,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_bit_and(&mut self, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "&") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 *a &= *b;
@@ -361,6 +426,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_bit_xor(&mut self, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "^") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 *a ^= *b;
@@ -375,6 +445,11 @@ impl ValueRef {
     }
 
     pub fn bin_aug_bit_or(&mut self, ctx: &mut Context, x: &Self) -> &mut Self {
+        if let Some(result) = self.bin_big_number(x, "|") {
+            let value = result.rc.borrow().clone();
+            *self.rc.borrow_mut() = value;
+            return self;
+        }
         let valid = match (&mut *self.rc.borrow_mut(), &*x.rc.borrow()) {
             (Value::int_value(a), Value::int_value(b)) => {
                 *a |= *b;
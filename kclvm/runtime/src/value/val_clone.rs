@@ -31,9 +31,15 @@ impl ValueRef {
             Value::int_value(ref v) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::int_value(*v))),
             },
+            Value::bigint_value(ref v) => ValueRef {
+                rc: Rc::new(RefCell::new(Value::bigint_value(v.clone()))),
+            },
             Value::float_value(ref v) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::float_value(*v))),
             },
+            Value::decimal_value(ref v) => ValueRef {
+                rc: Rc::new(RefCell::new(Value::decimal_value(v.clone()))),
+            },
             Value::unit_value(ref v, ref raw, ref unit) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::unit_value(*v, *raw, unit.clone()))),
             },
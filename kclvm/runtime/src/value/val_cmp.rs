@@ -1,10 +1,22 @@
 //! Copyright The KCL Authors. All rights reserved.
 
+use std::cmp::Ordering;
+
 use crate::*;
 
 // cmp
 impl ValueRef {
     pub fn cmp_equal(&self, x: &Self) -> bool {
+        // Number multipliers are equal to the number multipliers with the same quantities
+        // e.g., `1024Mi == 1Gi`, but never equal to the int or float values e.g., `1Ki != 1024`.
+        if self.is_unit() && x.is_unit() {
+            if let Some(ordering) = self.cmp_quantity(x) {
+                return ordering == Ordering::Equal;
+            }
+        }
+        if let Some(ordering) = self.cmp_big_number(x) {
+            return ordering == Ordering::Equal;
+        }
         match *self.rc.borrow() {
             Value::int_value(a) => match *x.rc.borrow() {
                 Value::int_value(b) => a == b,
@@ -66,11 +78,29 @@ impl ValueRef {
         }
     }
 
+    /// Compare the number multiplier values with the numbers by the exact quantities,
+    /// and returns None when neither value is a number multiplier.
+    fn cmp_quantity(&self, x: &Self) -> Option<Ordering> {
+        let is_number = |v: &Self| v.is_unit() || v.is_int() || v.is_float();
+        if !(self.is_unit() || x.is_unit()) || !is_number(self) || !is_number(x) {
+            return None;
+        }
+        let a = quantity_from_value(self).ok()?;
+        let b = quantity_from_value(x).ok()?;
+        Some(a.cmp(&b))
+    }
+
     pub fn cmp_not_equal(&self, x: &Self) -> bool {
         !self.cmp_equal(x)
     }
 
     pub fn cmp_less_than(&self, x: &Self) -> bool {
+        if let Some(ordering) = self.cmp_quantity(x) {
+            return ordering == Ordering::Less;
+        }
+        if let Some(ordering) = self.cmp_big_number(x) {
+            return ordering == Ordering::Less;
+        }
         match &*self.rc.borrow() {
             Value::int_value(a) => match &*x.rc.borrow() {
                 Value::int_value(b) => *a < *b,
@@ -139,6 +169,12 @@ impl ValueRef {
     }
 
     pub fn cmp_less_than_or_equal(&self, x: &Self) -> bool {
+        if let Some(ordering) = self.cmp_quantity(x) {
+            return ordering != Ordering::Greater;
+        }
+        if let Some(ordering) = self.cmp_big_number(x) {
+            return ordering != Ordering::Greater;
+        }
         match &*self.rc.borrow() {
             Value::int_value(a) => match &*x.rc.borrow() {
                 Value::int_value(b) => *a <= *b,
@@ -207,6 +243,12 @@ impl ValueRef {
     }
 
     pub fn cmp_greater_than(&self, x: &Self) -> bool {
+        if let Some(ordering) = self.cmp_quantity(x) {
+            return ordering == Ordering::Greater;
+        }
+        if let Some(ordering) = self.cmp_big_number(x) {
+            return ordering == Ordering::Greater;
+        }
         match &*self.rc.borrow() {
             Value::int_value(a) => match &*x.rc.borrow() {
                 Value::int_value(b) => *a > *b,
@@ -275,6 +317,12 @@ impl ValueRef {
     }
 
     pub fn cmp_greater_than_or_equal(&self, x: &Self) -> bool {
+        if let Some(ordering) = self.cmp_quantity(x) {
+            return ordering != Ordering::Less;
+        }
+        if let Some(ordering) = self.cmp_big_number(x) {
+            return ordering != Ordering::Less;
+        }
         match &*self.rc.borrow() {
             Value::int_value(a) => match &*x.rc.borrow() {
                 Value::int_value(b) => *a >= *b,
@@ -365,8 +413,15 @@ mod test_value_cmp {
                 ValueRef::dict_int(&[("k1", 1), ("k2", 2)]),
                 true,
             ),
+            (
+                ValueRef::unit(1024.0, 1, "Ki"),
+                ValueRef::unit(1024.0, 1024, ""),
+                true,
+            ),
             // false cases
             (ValueRef::int(123), ValueRef::int(1234), false),
+            (ValueRef::unit(1024.0, 1, "Ki"), ValueRef::int(1024), false),
+            (ValueRef::float(1024.0), ValueRef::unit(1024.0, 1, "Ki"), false),
             (ValueRef::int(123), ValueRef::float(1234.0), false),
             (ValueRef::str("abc"), ValueRef::str("abcd"), false),
             (ValueRef::bool(true), ValueRef::bool(false), false),
@@ -442,6 +497,18 @@ mod test_value_cmp {
             // <=
             (ValueRef::int(123), ValueRef::int(12), "<=", false),
             (ValueRef::int(1234), ValueRef::float(123.0), "<=", false),
+            (
+                ValueRef::unit(1.0, 1000, "m"),
+                ValueRef::unit(0.5, 500, "m"),
+                "<=",
+                false,
+            ),
+            (
+                ValueRef::unit(2048.0, 2, "Ki"),
+                ValueRef::int(2000),
+                "<=",
+                false,
+            ),
             (ValueRef::str("abc"), ValueRef::str("ab"), "<=", false),
             (ValueRef::bool(true), ValueRef::bool(false), "<=", false),
             (
@@ -0,0 +1,627 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! Arbitrary-precision integers and exact decimal numbers. Integers are stored as
+//! `i64` values and only promoted to big integers on overflow when the context
+//! config `big_int` is enabled, while decimals are constructed with the builtin
+//! `decimal()` function or the `decimal` type annotation.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use crate::*;
+
+/// The number of significant digits of the inexact decimal division results.
+pub const DECIMAL_DIVISION_PRECISION: usize = 28;
+/// The maximum absolute exponent of the decimal literals e.g., `1e1000`.
+const MAX_DECIMAL_EXPONENT: i64 = 1000;
+/// The maximum number of bits of the `**` and `<<` results, e.g., `2 ** 4000000000`
+/// raises an error instead of exhausting the memory.
+const MAX_BIG_INT_BITS: u64 = 1 << 22;
+
+/// Returns the absolute `exponent` when the power of a base with `bits` bits does not
+/// exceed the maximum bits. The powers of 0, 1 and -1 are always allowed.
+fn pow_exponent(exponent: &BigInt, bits: u64) -> Option<u32> {
+    let n = exponent.abs().to_u32()?;
+    if bits > 1 && bits.saturating_mul(n as u64) > MAX_BIG_INT_BITS {
+        None
+    } else {
+        Some(n)
+    }
+}
+
+/// An exact decimal number `mantissa * 10^-scale`. The scale is kept by the
+/// arithmetic operations like Python decimals, e.g., `decimal("1.10") + 1` is `2.10`.
+#[derive(Debug, Clone)]
+pub struct Decimal {
+    mantissa: BigInt,
+    scale: u32,
+}
+
+impl Decimal {
+    pub fn new(mantissa: BigInt, scale: u32) -> Self {
+        Decimal { mantissa, scale }
+    }
+
+    /// Construct a decimal from the float value using its shortest representation,
+    /// e.g., `0.1` is converted to `0.1` instead of `0.1000000000000000055511151231257827`.
+    pub fn from_f64(value: f64) -> Result<Self, String> {
+        if !value.is_finite() {
+            return Err(format!("cannot convert float {} to decimal", value));
+        }
+        Self::from_str(&value.to_string())
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    /// Returns whether the decimal has no fractional part e.g., `2.00`.
+    pub fn is_integer(&self) -> bool {
+        self.mantissa.is_multiple_of(&pow10(self.scale))
+    }
+
+    /// Returns the integer part of the decimal truncated toward zero.
+    pub fn trunc(&self) -> BigInt {
+        &self.mantissa / pow10(self.scale)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    pub fn neg(&self) -> Decimal {
+        Decimal::new(-&self.mantissa, self.scale)
+    }
+
+    /// Returns the decimal without the trailing zeros of the fractional part,
+    /// which is the same for all the equal decimals.
+    pub fn normalize(&self) -> Decimal {
+        let ten = BigInt::from(10);
+        let mut mantissa = self.mantissa.clone();
+        let mut scale = self.scale;
+        while scale > 0 && mantissa.is_multiple_of(&ten) {
+            mantissa /= &ten;
+            scale -= 1;
+        }
+        Decimal::new(mantissa, scale)
+    }
+
+    pub fn add(&self, other: &Decimal) -> Decimal {
+        let (a, b, scale) = self.align(other);
+        Decimal::new(a + b, scale)
+    }
+
+    pub fn sub(&self, other: &Decimal) -> Decimal {
+        let (a, b, scale) = self.align(other);
+        Decimal::new(a - b, scale)
+    }
+
+    pub fn mul(&self, other: &Decimal) -> Decimal {
+        Decimal::new(&self.mantissa * &other.mantissa, self.scale + other.scale)
+    }
+
+    /// Divide the decimal by another one. The exact quotient keeps the scale
+    /// difference of the operands when possible, and the inexact quotient is
+    /// rounded half to even to [DECIMAL_DIVISION_PRECISION] significant digits.
+    pub fn div(&self, other: &Decimal) -> Result<Decimal, String> {
+        if other.is_zero() {
+            return Err("decimal division by zero".to_string());
+        }
+        let ideal_scale = self.scale as i64 - other.scale as i64;
+        if self.is_zero() {
+            return Ok(Decimal::new(BigInt::zero(), ideal_scale.max(0) as u32));
+        }
+        let negative = self.is_negative() != other.is_negative();
+        let dividend = self.mantissa.abs();
+        let divisor = other.mantissa.abs();
+        // Shift the dividend to get at least one more digit than the precision.
+        let shift = (DECIMAL_DIVISION_PRECISION as i64 + 1 + digits(&divisor) - digits(&dividend))
+            .max(-ideal_scale)
+            .max(0);
+        let (mut quotient, remainder) = (dividend * pow10(shift as u32)).div_rem(&divisor);
+        let mut scale = ideal_scale + shift;
+        if remainder.is_zero() {
+            let ten = BigInt::from(10);
+            while scale > ideal_scale.max(0) && quotient.is_multiple_of(&ten) {
+                quotient /= &ten;
+                scale -= 1;
+            }
+        } else {
+            let excess = (digits(&quotient) - DECIMAL_DIVISION_PRECISION as i64) as u32;
+            let unit = pow10(excess);
+            let (rounded, rest) = quotient.div_rem(&unit);
+            // The dropped digits are followed by the non-zero remainder, so there is
+            // only an exact tie when no digit is dropped.
+            let round_up = if excess == 0 {
+                match (&remainder * 2u32).cmp(&divisor) {
+                    Ordering::Equal => rounded.is_odd(),
+                    ordering => ordering == Ordering::Greater,
+                }
+            } else {
+                &rest * 2u32 >= unit
+            };
+            quotient = if round_up { rounded + 1 } else { rounded };
+            scale -= excess as i64;
+            if scale < 0 {
+                quotient *= pow10((-scale) as u32);
+                scale = 0;
+            }
+        }
+        let quotient = if negative { -quotient } else { quotient };
+        Ok(Decimal::new(quotient, scale as u32))
+    }
+
+    /// Returns the floored quotient like the int floor division.
+    pub fn floor_div(&self, other: &Decimal) -> Result<Decimal, String> {
+        let (a, b, _) = self.align(other);
+        if b.is_zero() {
+            return Err("decimal division or modulo by zero".to_string());
+        }
+        Ok(Decimal::new(a.div_floor(&b), 0))
+    }
+
+    /// Returns the remainder of the floored division like the int modulo.
+    pub fn rem(&self, other: &Decimal) -> Result<Decimal, String> {
+        let (a, b, scale) = self.align(other);
+        if b.is_zero() {
+            return Err("decimal division or modulo by zero".to_string());
+        }
+        Ok(Decimal::new(a.mod_floor(&b), scale))
+    }
+
+    /// Raise the decimal to the integer power.
+    pub fn pow(&self, exponent: &BigInt) -> Result<Decimal, String> {
+        // A decimal digit of the scale takes less than 4 bits.
+        let bits = self.mantissa.bits().max(self.scale as u64 * 4);
+        let n = pow_exponent(exponent, bits)
+            .ok_or_else(|| format!("decimal exponent {} is too large", exponent))?;
+        let result = Decimal::new(self.mantissa.pow(n), self.scale * n);
+        if exponent.is_negative() {
+            Decimal::new(BigInt::one(), 0).div(&result)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Returns the mantissas of the two decimals with the same scale.
+    fn align(&self, other: &Decimal) -> (BigInt, BigInt, u32) {
+        let scale = self.scale.max(other.scale);
+        (
+            &self.mantissa * pow10(scale - self.scale),
+            &other.mantissa * pow10(scale - other.scale),
+            scale,
+        )
+    }
+}
+
+impl From<BigInt> for Decimal {
+    fn from(value: BigInt) -> Self {
+        Decimal::new(value, 0)
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal::new(BigInt::from(value), 0)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = String;
+
+    /// Parse the decimal literal e.g., `1.10`, `-.5` and `2.5e3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid literal for decimal(): '{}'", s);
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let (number, exponent) = match rest.split_once(['e', 'E']) {
+            Some((number, exponent)) => (
+                number,
+                exponent
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|e| e.abs() <= MAX_DECIMAL_EXPONENT)
+                    .ok_or_else(err)?,
+            ),
+            None => (rest, 0),
+        };
+        let (int_part, frac_part) = number.split_once('.').unwrap_or((number, ""));
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !is_digits(int_part)
+            || !is_digits(frac_part)
+        {
+            return Err(err());
+        }
+        let mantissa =
+            BigInt::from_str(&format!("{}{}", int_part, frac_part)).map_err(|_| err())?;
+        let mantissa = if negative { -mantissa } else { mantissa };
+        let scale = frac_part.len() as i64 - exponent;
+        Ok(if scale < 0 {
+            Decimal::new(mantissa * pow10((-scale) as u32), 0)
+        } else {
+            Decimal::new(mantissa, scale as u32)
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let digits = self.mantissa.magnitude().to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else if digits.len() > scale {
+            let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+            write!(f, "{}{}.{}", sign, int_part, frac_part)
+        } else {
+            write!(
+                f,
+                "{}0.{}{}",
+                sign,
+                "0".repeat(scale - digits.len()),
+                digits
+            )
+        }
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = self.align(other);
+        a.cmp(&b)
+    }
+}
+
+#[inline]
+fn pow10(exponent: u32) -> BigInt {
+    BigInt::from(10).pow(exponent)
+}
+
+/// Returns the number of the decimal digits of the integer magnitude.
+#[inline]
+fn digits(value: &BigInt) -> i64 {
+    value.magnitude().to_string().len() as i64
+}
+
+impl ValueRef {
+    /// Returns the big integer of the int value.
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match &*self.rc.borrow() {
+            Value::int_value(v) => Some(BigInt::from(*v)),
+            Value::bigint_value(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the exact decimal of the int or decimal value.
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        match &*self.rc.borrow() {
+            Value::int_value(v) => Some(Decimal::from(*v)),
+            Value::bigint_value(v) => Some(Decimal::from(v.clone())),
+            Value::decimal_value(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    /// Convert a string or number to a decimal, which is the builtin `decimal()`.
+    pub fn convert_to_decimal(&self) -> ValueRef {
+        let result = match &*self.rc.borrow() {
+            Value::int_value(v) => Ok(Decimal::from(*v)),
+            Value::bigint_value(v) => Ok(Decimal::from(v.clone())),
+            Value::decimal_value(v) => Ok(v.clone()),
+            Value::bool_value(v) => Ok(Decimal::from(*v as i64)),
+            Value::float_value(v) => Decimal::from_f64(*v),
+            Value::str_value(v) => Decimal::from_str(v),
+            _ => Err(format!(
+                "decimal() argument must be a string or a number, not '{}'",
+                self.type_str()
+            )),
+        };
+        match result {
+            Ok(decimal) => ValueRef::decimal(decimal),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Compare the numbers exactly when either value is a big integer or decimal,
+    /// and returns None when neither value is.
+    pub(crate) fn cmp_big_number(&self, x: &Self) -> Option<Ordering> {
+        let is_big = |v: &Self| v.is_bigint() || v.is_decimal();
+        if !(is_big(self) || is_big(x)) {
+            return None;
+        }
+        let to_decimal = |v: &Self| match &*v.rc.borrow() {
+            Value::bool_value(b) => Some(Decimal::from(*b as i64)),
+            // Integral floats are compared exactly, because their shortest
+            // representation e.g., `9223372036854776000` drops the low digits.
+            Value::float_value(f) if f.fract() == 0.0 => {
+                BigInt::from_f64(*f).map(|i| Decimal::new(i, 0))
+            }
+            Value::float_value(f) => Decimal::from_f64(*f).ok(),
+            _ => v.to_decimal(),
+        };
+        Some(to_decimal(self)?.cmp(&to_decimal(x)?))
+    }
+
+    /// Returns the result of the binary operation `op` when either operand is a
+    /// big integer or decimal, and returns None when neither operand is.
+    pub(crate) fn bin_big_number(&self, x: &Self, op: &str) -> Option<Self> {
+        if self.is_decimal() || x.is_decimal() {
+            Some(self.bin_decimal(x, op))
+        } else if self.is_bigint() || x.is_bigint() {
+            Some(self.bin_bigint(x, op))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the big integer result of the int binary operation `op` which
+    /// overflows the 64 bit integer, when the context config `big_int` is enabled.
+    /// The strict range check still raises the 32 bit integer overflow errors.
+    pub(crate) fn bin_int_overflow(&self, ctx: &Context, x: &Self, op: &str) -> Option<Self> {
+        if !ctx.cfg.big_int || ctx.cfg.strict_range_check {
+            return None;
+        }
+        let (a, b) = match (&*self.rc.borrow(), &*x.rc.borrow()) {
+            (Value::int_value(a), Value::int_value(b)) => (*a, *b),
+            _ => return None,
+        };
+        let overflow = match op {
+            "+" => is_i64_overflow_add(a, b),
+            "-" => is_i64_overflow_sub(a, b),
+            "*" => is_i64_overflow_mul(a, b),
+            "**" => b >= 0 && is_i64_overflow_pow(a, b),
+            "<<" => (0..64).contains(&b) && (a << b) >> b != a || b >= 64 && a != 0,
+            _ => false,
+        };
+        if overflow {
+            Some(self.bin_bigint(x, op))
+        } else {
+            None
+        }
+    }
+
+    fn bin_bigint(&self, x: &Self, op: &str) -> Self {
+        if self.is_float() || x.is_float() {
+            let a = self.as_float();
+            let b = x.as_float();
+            return match op {
+                "+" => Self::float(a + b),
+                "-" => Self::float(a - b),
+                "*" => Self::float(a * b),
+                "/" => Self::float(a / b),
+                "//" => Self::float((a / b).floor()),
+                "%" => Self::float(a % b),
+                "**" => Self::float(a.powf(b)),
+                _ => panic_unsupported_bin_op!(op, self.type_str(), x.type_str()),
+            };
+        }
+        let (a, b) = match (self.to_bigint(), x.to_bigint()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => panic_unsupported_bin_op!(op, self.type_str(), x.type_str()),
+        };
+        let check_divisor = || {
+            if b.is_zero() {
+                panic!("integer division or modulo by zero")
+            }
+        };
+        let shift_count = || {
+            b.to_usize()
+                .unwrap_or_else(|| panic!("invalid shift count {}", b))
+        };
+        let lshift_count = || {
+            let n = shift_count();
+            if !a.is_zero() && a.bits().saturating_add(n as u64) > MAX_BIG_INT_BITS {
+                panic!("shift count {} is too large", b)
+            }
+            n
+        };
+        match op {
+            "+" => Self::bigint(a + b),
+            "-" => Self::bigint(a - b),
+            "*" => Self::bigint(a * b),
+            "/" => {
+                check_divisor();
+                Self::float(a.to_f64().unwrap_or(f64::NAN) / b.to_f64().unwrap_or(f64::NAN))
+            }
+            "//" => {
+                check_divisor();
+                Self::bigint(a.div_floor(&b))
+            }
+            "%" => {
+                check_divisor();
+                Self::bigint(a.mod_floor(&b))
+            }
+            "**" => {
+                if b.is_negative() {
+                    let a = a.to_f64().unwrap_or(f64::NAN);
+                    Self::float(a.powf(b.to_f64().unwrap_or(f64::NAN)))
+                } else {
+                    let n = pow_exponent(&b, a.bits())
+                        .unwrap_or_else(|| panic!("int exponent {} is too large", b));
+                    Self::bigint(a.pow(n))
+                }
+            }
+            "<<" => {
+                let n = lshift_count();
+                Self::bigint(a << n)
+            }
+            ">>" => Self::bigint(a >> shift_count()),
+            "&" => Self::bigint(a & b),
+            "|" => Self::bigint(a | b),
+            "^" => Self::bigint(a ^ b),
+            _ => panic_unsupported_bin_op!(op, self.type_str(), x.type_str()),
+        }
+    }
+
+    fn bin_decimal(&self, x: &Self, op: &str) -> Self {
+        let (a, b) = match (self.to_decimal(), x.to_decimal()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => panic_unsupported_bin_op!(op, self.type_str(), x.type_str()),
+        };
+        let result = match op {
+            "+" => Ok(a.add(&b)),
+            "-" => Ok(a.sub(&b)),
+            "*" => Ok(a.mul(&b)),
+            "/" => a.div(&b),
+            "//" => a.floor_div(&b),
+            "%" => a.rem(&b),
+            "**" if b.is_integer() => a.pow(&b.trunc()),
+            "**" => Err("decimal exponent must be an integer".to_string()),
+            _ => panic_unsupported_bin_op!(op, self.type_str(), x.type_str()),
+        };
+        match result {
+            Ok(decimal) => Self::decimal(decimal),
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_value_decimal {
+    use super::*;
+
+    fn d(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_decimal_parse_and_format() {
+        let cases = [
+            ("1.10", "1.10"),
+            ("-0.5", "-0.5"),
+            ("+.5", "0.5"),
+            ("2.5e3", "2500"),
+            ("25E-3", "0.025"),
+            ("007", "7"),
+            ("0.000", "0.000"),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(d(s).to_string(), expected, "{s}");
+        }
+        for s in ["", ".", "1.2.3", "1e", "abc", "1_000", "nan", "1e1001"] {
+            assert!(Decimal::from_str(s).is_err(), "{s}");
+        }
+        assert_eq!(Decimal::from_f64(0.1).unwrap().to_string(), "0.1");
+        assert!(Decimal::from_f64(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        assert_eq!(d("0.1").add(&d("0.2")).to_string(), "0.3");
+        assert_eq!(d("1.10").add(&d("1")).to_string(), "2.10");
+        assert_eq!(d("1.5").sub(&d("2.25")).to_string(), "-0.75");
+        assert_eq!(d("1.5").mul(&d("0.20")).to_string(), "0.300");
+        assert_eq!(d("1").div(&d("4")).unwrap().to_string(), "0.25");
+        assert_eq!(d("6.00").div(&d("3")).unwrap().to_string(), "2.00");
+        assert_eq!(
+            d("1").div(&d("3")).unwrap().to_string(),
+            "0.3333333333333333333333333333"
+        );
+        assert_eq!(
+            d("-2").div(&d("3")).unwrap().to_string(),
+            "-0.6666666666666666666666666667"
+        );
+        assert!(d("1").div(&d("0.0")).is_err());
+        assert_eq!(d("-7").floor_div(&d("2")).unwrap().to_string(), "-4");
+        assert_eq!(d("-7.5").rem(&d("2")).unwrap().to_string(), "0.5");
+        assert_eq!(d("1.1").pow(&BigInt::from(2)).unwrap().to_string(), "1.21");
+        assert_eq!(d("2").pow(&BigInt::from(-2)).unwrap().to_string(), "0.25");
+        assert_eq!(
+            d("-1")
+                .pow(&BigInt::from(4_000_000_001u64))
+                .unwrap()
+                .to_string(),
+            "-1"
+        );
+        assert!(d("2").pow(&BigInt::from(4_000_000_000u64)).is_err());
+        assert!(d("0.1").pow(&BigInt::from(2_000_000)).is_err());
+        assert_eq!(d("1.50"), d("1.5"));
+        assert!(d("0.1") < d("0.11"));
+        assert_eq!(d("1.500").normalize().to_string(), "1.5");
+    }
+
+    #[test]
+    fn test_bigint_bin() {
+        let mut ctx = Context::new();
+        ctx.cfg.big_int = true;
+        let max = ValueRef::int(i64::MAX);
+        let one = ValueRef::int(1);
+        let sum = max.bin_add(&mut ctx, &one);
+        assert!(sum.is_bigint());
+        assert_eq!(sum.to_string(), "9223372036854775808");
+        // Big integers are normalized to int values when they fit.
+        let diff = sum.bin_sub(&mut ctx, &one);
+        assert!(diff.is_int());
+        assert_eq!(diff.as_int(), i64::MAX);
+        let pow = ValueRef::int(2).bin_pow(&mut ctx, &ValueRef::int(100));
+        assert_eq!(pow.to_string(), "1267650600228229401496703205376");
+        let shl = ValueRef::int(1).bin_bit_lshift(&mut ctx, &ValueRef::int(64));
+        assert_eq!(shl.to_string(), "18446744073709551616");
+        assert!(sum.cmp_greater_than(&max));
+        assert!(sum.cmp_equal(&ValueRef::float(9223372036854775808.0)));
+    }
+
+    #[test]
+    fn test_bigint_bin_too_large() {
+        let big_int_ctx = || {
+            let mut ctx = Context::new();
+            ctx.cfg.big_int = true;
+            ctx
+        };
+        let one = ValueRef::int(1).bin_pow(&mut big_int_ctx(), &ValueRef::int(4_000_000_000));
+        assert_eq!(one.as_int(), 1);
+        let pow = std::panic::catch_unwind(|| {
+            ValueRef::int(2).bin_pow(&mut big_int_ctx(), &ValueRef::int(4_000_000_000))
+        });
+        assert!(pow.is_err());
+        let shl = std::panic::catch_unwind(|| {
+            ValueRef::int(1).bin_bit_lshift(&mut big_int_ctx(), &ValueRef::int(4_000_000_000))
+        });
+        assert!(shl.is_err());
+    }
+
+    #[test]
+    fn test_decimal_bin() {
+        let mut ctx = Context::new();
+        let a = ValueRef::str("0.1").convert_to_decimal();
+        let b = ValueRef::str("0.2").convert_to_decimal();
+        let sum = a.bin_add(&mut ctx, &b);
+        assert_eq!(sum.type_str(), "decimal");
+        assert_eq!(sum.to_string(), "0.3");
+        assert!(sum.cmp_equal(&ValueRef::str("0.30").convert_to_decimal()));
+        assert!(sum.cmp_less_than(&ValueRef::int(1)));
+        let product = sum.bin_mul(&mut ctx, &ValueRef::int(3));
+        assert_eq!(product.to_string(), "0.9");
+    }
+}
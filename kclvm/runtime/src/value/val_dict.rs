@@ -332,6 +332,10 @@ impl ValueRef {
         if self.is_config() {
             let mut dict: DictValue = Default::default();
             dict.values.insert(key.to_string(), v.clone());
+            if ctx.cfg.provenance {
+                dict.sources
+                    .insert(key.to_string(), vec![ctx.current_source_location(&op)]);
+            }
             dict.ops.insert(key.to_string(), op);
             dict.insert_indexs.insert(key.to_string(), insert_index);
             self.union_entry(
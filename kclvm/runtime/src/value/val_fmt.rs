@@ -3,6 +3,8 @@
 //! Copyright The KCL Authors. All rights reserved.
 
 use itertools::{Itertools, PeekingNext};
+use num_bigint::{BigInt, Sign};
+use num_traits::ToPrimitive;
 use std::cmp;
 use std::fmt;
 use std::str::FromStr;
@@ -144,7 +146,7 @@ pub fn format_exponent(precision: usize, magnitude: f64, case: Case) -> String {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum FormatPreconversor {
+pub(crate) enum FormatPreconversor {
     Str,
     Repr,
     Ascii,
@@ -494,17 +496,15 @@ impl FormatSpec {
             Some(FormatType::Character) => {
                 Err("Unknown format code 'c' for object of type 'float'")
             }
-            Some(FormatType::Number) => {
-                Err("Format code 'n' for object of type 'float' not implemented yet")
+            // The locale-aware number format uses the general format with the C locale.
+            Some(FormatType::Number) | Some(FormatType::GeneralFormatLower) => {
+                let precision = if precision == 0 { 1 } else { precision };
+                Ok(format_general(precision, magnitude, Case::Lower))
             }
             Some(FormatType::GeneralFormatUpper) => {
                 let precision = if precision == 0 { 1 } else { precision };
                 Ok(format_general(precision, magnitude, Case::Upper))
             }
-            Some(FormatType::GeneralFormatLower) => {
-                let precision = if precision == 0 { 1 } else { precision };
-                Ok(format_general(precision, magnitude, Case::Lower))
-            }
             Some(FormatType::ExponentUpper) => {
                 Ok(format_exponent(precision, magnitude, Case::Upper))
             }
@@ -519,7 +519,19 @@ impl FormatSpec {
             None => match magnitude {
                 magnitude if magnitude.is_nan() => Ok("nan".to_owned()),
                 magnitude if magnitude.is_infinite() => Ok("inf".to_owned()),
-                _ => Ok(float_to_string(magnitude)),
+                // Like the general format, except that the fixed-point notation
+                // has at least one digit past the decimal point.
+                _ => match self.precision {
+                    Some(precision) => {
+                        let precision = if precision == 0 { 1 } else { precision };
+                        let mut result = format_general(precision, magnitude, Case::Lower);
+                        if !result.contains(&['.', 'e'][..]) {
+                            result.push_str(".0");
+                        }
+                        Ok(result)
+                    }
+                    None => Ok(float_to_string(magnitude)),
+                },
             },
         };
 
@@ -536,22 +548,73 @@ impl FormatSpec {
             }
         };
 
-        self.format_sign_and_align(&magnitude_string, sign_str)
+        self.format_sign_and_align(&magnitude_string, sign_str, FormatAlign::Right)
     }
 
     pub(crate) fn format_int(&self, num: &i64) -> Result<String, &'static str> {
-        let magnitude = num.abs();
+        if let Some(FormatType::Character) = self.format_type {
+            return self.format_character(*num);
+        }
+        self.format_integer(*num < 0, num.unsigned_abs(), *num as f64)
+    }
+
+    pub(crate) fn format_bigint(&self, num: &BigInt) -> Result<String, &'static str> {
+        if let Some(FormatType::Character) = self.format_type {
+            return match num.to_i64() {
+                Some(num) => self.format_character(num),
+                None => Err("%c arg not in range(0x110000)"),
+            };
+        }
+        let as_float = num.to_f64().unwrap_or(f64::NAN);
+        self.format_integer(num.sign() == Sign::Minus, num.magnitude(), as_float)
+    }
+
+    fn format_character(&self, num: i64) -> Result<String, &'static str> {
+        if self.sign.is_some() {
+            return Err("Sign not allowed with integer format specifier 'c'");
+        }
+        if self.alternate_form {
+            return Err("Alternate form (#) not allowed with integer format specifier 'c'");
+        }
+        match u32::try_from(num).ok().and_then(char::from_u32) {
+            Some(c) => self.format_sign_and_align(&c.to_string(), "", FormatAlign::Right),
+            None => Err("%c arg not in range(0x110000)"),
+        }
+    }
+
+    fn format_integer<T>(
+        &self,
+        is_negative: bool,
+        magnitude: T,
+        as_float: f64,
+    ) -> Result<String, &'static str>
+    where
+        T: fmt::Display + fmt::Binary + fmt::Octal + fmt::LowerHex,
+    {
         let prefix = if self.alternate_form {
             match self.format_type {
                 Some(FormatType::Binary) => "0b",
                 Some(FormatType::Octal) => "0o",
                 Some(FormatType::HexLower) => "0x",
-                Some(FormatType::HexUpper) => "0x",
+                Some(FormatType::HexUpper) => "0X",
                 _ => "",
             }
         } else {
             ""
         };
+        if self.precision.is_some()
+            && matches!(
+                self.format_type,
+                None | Some(FormatType::Binary)
+                    | Some(FormatType::Decimal)
+                    | Some(FormatType::Octal)
+                    | Some(FormatType::HexLower)
+                    | Some(FormatType::HexUpper)
+                    | Some(FormatType::Number)
+            )
+        {
+            return Err("Precision not allowed in integer format specifier");
+        }
         let raw_magnitude_string_result: Result<String, &'static str> = match self.format_type {
             Some(FormatType::Binary) => Ok(format!("{magnitude:b}")),
             Some(FormatType::Decimal) => Ok(format!("{magnitude}")),
@@ -565,17 +628,13 @@ impl FormatSpec {
             Some(FormatType::Number) => Ok(format!("{magnitude}")),
             Some(FormatType::String) => Err("Unknown format code 's' for object of type 'int'"),
             Some(FormatType::Character) => Err("Unknown format code 'c' for object of type 'int'"),
-            Some(FormatType::GeneralFormatUpper) => {
-                Err("Unknown format code 'G' for object of type 'int'")
-            }
-            Some(FormatType::GeneralFormatLower) => {
-                Err("Unknown format code 'g' for object of type 'int'")
-            }
-            Some(FormatType::FixedPointUpper)
+            Some(FormatType::GeneralFormatUpper)
+            | Some(FormatType::GeneralFormatLower)
+            | Some(FormatType::FixedPointUpper)
             | Some(FormatType::FixedPointLower)
             | Some(FormatType::ExponentUpper)
             | Some(FormatType::ExponentLower)
-            | Some(FormatType::Percentage) => self.format_float(*num as f64),
+            | Some(FormatType::Percentage) => return self.format_float(as_float),
             None => Ok(magnitude.to_string()),
         };
         let raw_magnitude_string = raw_magnitude_string_result?;
@@ -586,27 +645,123 @@ impl FormatSpec {
         );
 
         let format_sign = self.sign.unwrap_or(FormatSign::Minus);
-        let sign_str = match num.signum() {
-            -1 => "-",
-            _ => match format_sign {
+        let sign_str = if is_negative {
+            "-"
+        } else {
+            match format_sign {
                 FormatSign::Plus => "+",
                 FormatSign::Minus => "",
                 FormatSign::MinusOrSpace => " ",
-            },
+            }
         };
 
-        self.format_sign_and_align(&magnitude_string, sign_str)
+        self.format_sign_and_align(&magnitude_string, sign_str, FormatAlign::Right)
+    }
+
+    /// Decimals without the presentation type and precision keep the exact digits,
+    /// otherwise they are formatted as floats.
+    pub(crate) fn format_decimal(&self, num: &Decimal) -> Result<String, &'static str> {
+        if self.format_type.is_some() || self.precision.is_some() {
+            return self.format_float(num.to_f64());
+        }
+        let magnitude = if num.is_negative() {
+            num.neg().to_string()
+        } else {
+            num.to_string()
+        };
+        let magnitude_string = self.add_magnitude_separators(magnitude);
+        let sign_str = if num.is_negative() {
+            "-"
+        } else {
+            match self.sign.unwrap_or(FormatSign::Minus) {
+                FormatSign::Plus => "+",
+                FormatSign::Minus => "",
+                FormatSign::MinusOrSpace => " ",
+            }
+        };
+        self.format_sign_and_align(&magnitude_string, sign_str, FormatAlign::Right)
+    }
+
+    pub(crate) fn format_string(&self, s: &str) -> Result<String, &'static str> {
+        if self.sign.is_some() {
+            return Err("Sign not allowed in string format specifier");
+        }
+        if self.alternate_form {
+            return Err("Alternate form (#) not allowed in string format specifier");
+        }
+        if self.grouping_option.is_some() {
+            return Err("Cannot specify ',' or '_' with 's'.");
+        }
+        match self.format_type {
+            None | Some(FormatType::String) => {}
+            Some(FormatType::Binary) => {
+                return Err("Unknown format code 'b' for object of type 'str'")
+            }
+            Some(FormatType::Character) => {
+                return Err("Unknown format code 'c' for object of type 'str'")
+            }
+            Some(FormatType::Decimal) => {
+                return Err("Unknown format code 'd' for object of type 'str'")
+            }
+            Some(FormatType::Octal) => {
+                return Err("Unknown format code 'o' for object of type 'str'")
+            }
+            Some(FormatType::HexLower) => {
+                return Err("Unknown format code 'x' for object of type 'str'")
+            }
+            Some(FormatType::HexUpper) => {
+                return Err("Unknown format code 'X' for object of type 'str'")
+            }
+            Some(FormatType::Number) => {
+                return Err("Unknown format code 'n' for object of type 'str'")
+            }
+            Some(FormatType::ExponentLower) => {
+                return Err("Unknown format code 'e' for object of type 'str'")
+            }
+            Some(FormatType::ExponentUpper) => {
+                return Err("Unknown format code 'E' for object of type 'str'")
+            }
+            Some(FormatType::GeneralFormatLower) => {
+                return Err("Unknown format code 'g' for object of type 'str'")
+            }
+            Some(FormatType::GeneralFormatUpper) => {
+                return Err("Unknown format code 'G' for object of type 'str'")
+            }
+            Some(FormatType::FixedPointLower) => {
+                return Err("Unknown format code 'f' for object of type 'str'")
+            }
+            Some(FormatType::FixedPointUpper) => {
+                return Err("Unknown format code 'F' for object of type 'str'")
+            }
+            Some(FormatType::Percentage) => {
+                return Err("Unknown format code '%' for object of type 'str'")
+            }
+        }
+        // The zero padding e.g., `{:05}` pads the strings on the right.
+        if self.align == Some(FormatAlign::AfterSign) && self.fill != Some('0') {
+            return Err("'=' alignment not allowed in string format specifier");
+        }
+        let value: String = match self.precision {
+            Some(precision) => s.chars().take(precision).collect(),
+            None => s.to_string(),
+        };
+        self.format_sign_and_align(&value, "", FormatAlign::Left)
     }
 
     fn format_sign_and_align(
         &self,
         magnitude_string: &str,
         sign_str: &str,
+        default_align: FormatAlign,
     ) -> Result<String, &'static str> {
-        let align = self.align.unwrap_or(FormatAlign::Right);
+        let align = match self.align {
+            // The zero padding of strings is on the right.
+            Some(FormatAlign::AfterSign) if default_align == FormatAlign::Left => FormatAlign::Left,
+            Some(align) => align,
+            None => default_align,
+        };
 
-        // Use the byte length as the string length since we're in ascii
-        let num_chars = magnitude_string.len();
+        let num_chars = magnitude_string.chars().count();
         let fill_char = self.fill.unwrap_or(' ');
         let fill_chars_needed: i32 = self.width.map_or(0, |w| {
             cmp::max(0, (w as i32) - (num_chars as i32) - (sign_str.len() as i32))
@@ -750,6 +905,7 @@ impl FieldName {
 pub(crate) enum FormatPart {
     Field {
         field_name: String,
+        preconversor: Option<FormatPreconversor>,
         format_spec: String,
     },
     Literal(String),
@@ -814,9 +970,30 @@ impl FormatString {
         let parts: Vec<&str> = arg_part.splitn(2, '!').collect();
         // before the bang is a keyword or arg index, after the comma is maybe a conversor spec.
         let arg_part = parts[0];
+        let preconversor = match parts.get(1) {
+            Some(conversion) => {
+                let mut chars = conversion.chars();
+                match (
+                    chars.next().and_then(FormatPreconversor::from_char),
+                    chars.next(),
+                ) {
+                    (
+                        Some(
+                            preconversor @ (FormatPreconversor::Str
+                            | FormatPreconversor::Repr
+                            | FormatPreconversor::Ascii),
+                        ),
+                        None,
+                    ) => Some(preconversor),
+                    _ => return Err(FormatParseError::InvalidFormatSpecifier),
+                }
+            }
+            None => None,
+        };
 
         Ok(FormatPart::Field {
             field_name: arg_part.to_owned(),
+            preconversor,
             format_spec,
         })
     }
@@ -869,6 +1046,7 @@ impl FormatString {
             let result_string = match part {
                 FormatPart::Field {
                     field_name,
+                    preconversor,
                     format_spec,
                 } => {
                     let FieldName {
@@ -908,7 +1086,20 @@ impl FormatString {
                             }
                         }
                     }
-                    argument.to_string_with_spec(format_spec)
+                    match preconversor {
+                        Some(FormatPreconversor::Repr) => {
+                            ValueRef::str(&value_to_quoted_string(&argument))
+                                .to_string_with_spec(format_spec)
+                        }
+                        Some(FormatPreconversor::Ascii) => {
+                            ValueRef::str(&ascii_escaped(&value_to_quoted_string(&argument)))
+                                .to_string_with_spec(format_spec)
+                        }
+                        Some(_) => {
+                            ValueRef::str(&argument.to_string()).to_string_with_spec(format_spec)
+                        }
+                        None => argument.to_string_with_spec(format_spec),
+                    }
                 }
                 FormatPart::Literal(literal) => literal.clone(),
             };
@@ -959,6 +1150,20 @@ pub fn value_to_quoted_string(value: &ValueRef) -> String {
     }
 }
 
+/// Escape the non-ASCII characters with `\x`, `\u` or `\U` escapes like the Python `ascii()`.
+fn ascii_escaped(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c as u32 {
+            0..=0x7f => result.push(c),
+            code @ 0x80..=0xff => result.push_str(&format!("\\x{code:02x}")),
+            code @ 0x100..=0xffff => result.push_str(&format!("\\u{code:04x}")),
+            code => result.push_str(&format!("\\U{code:08x}")),
+        }
+    }
+    result
+}
+
 /// Convert a Rust string to a quoted string e.g., abc -> 'abc'
 pub fn quoted_string(value: &str) -> String {
     let has_double_quote = value.contains('\'');
@@ -985,6 +1190,8 @@ impl fmt::Display for ValueRef {
                 }
             }
             Value::int_value(ref v) => write!(f, "{v}"),
+            Value::bigint_value(ref v) => write!(f, "{v}"),
+            Value::decimal_value(ref v) => write!(f, "{v}"),
             Value::float_value(ref v) => {
                 let mut float_str = v.to_string();
                 if !float_str.contains('.') {
@@ -1025,24 +1232,33 @@ impl fmt::Display for ValueRef {
 impl ValueRef {
     /// to_string_with_spec e.g., "{:.0f}".format(1.0)
     pub fn to_string_with_spec(&self, spec: &str) -> String {
+        match self.format_with_spec(spec) {
+            Ok(string) => string,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn format_with_spec(&self, spec: &str) -> Result<String, &'static str> {
+        let format_spec = FormatSpec::parse(spec)?;
         match &*self.rc.borrow() {
-            Value::int_value(ref v) => {
-                match FormatSpec::parse(spec).and_then(|format_spec| format_spec.format_int(v)) {
-                    Ok(string) => string,
-                    Err(err) => panic!("{}", err),
-                }
-            }
-            Value::float_value(ref v) => {
-                match FormatSpec::parse(spec).and_then(|format_spec| format_spec.format_float(*v)) {
-                    Ok(string) => string,
-                    Err(err) => panic!("{}", err),
-                }
-            }
-            _ => self.to_string(),
+            Value::int_value(ref v) => format_spec.format_int(v),
+            Value::bigint_value(ref v) => format_spec.format_bigint(v),
+            Value::float_value(ref v) => format_spec.format_float(*v),
+            Value::decimal_value(ref v) => format_spec.format_decimal(v),
+            // Booleans are formatted as integers with the non-empty specs like Python.
+            Value::bool_value(ref v) if !spec.is_empty() => format_spec.format_int(&(*v as i64)),
+            Value::str_value(ref v) => format_spec.format_string(v),
+            _ if spec.is_empty() => Ok(self.to_string()),
+            _ => format_spec.format_string(&self.to_string()),
         }
     }
 }
 
+/// Returns whether the spec is a valid format spec e.g., `>10`, `.2f` and `#x`.
+pub fn is_valid_format_spec(spec: &str) -> bool {
+    FormatSpec::parse(spec).is_ok()
+}
+
 #[cfg(test)]
 mod test_value_fmt {
     use crate::*;
@@ -1059,6 +1275,12 @@ mod test_value_fmt {
                 r#"[["0","1"],{ "Hello": "World" }]"#,
                 "\"00, 11, HelloWorld\"",
             ),
+            (
+                r#""{!r} {!s} {!a}""#,
+                r#"["ab","ab","é"]"#,
+                "\"'ab' ab '\\xe9'\"",
+            ),
+            (r#""{0!r:>6}""#, r#"["ab"]"#, "\"  'ab'\""),
         ];
         for (format_string, args, expected) in cases {
             let format_string = FormatString::from_str(format_string).unwrap();
@@ -1067,5 +1289,83 @@ mod test_value_fmt {
             let result = format_string.format(&args, &kwargs);
             assert_eq!(&result, expected)
         }
+        assert!(FormatString::from_str("{!x}").is_err());
+    }
+
+    #[test]
+    fn test_format_spec() {
+        let mut ctx = Context::new();
+        // The expected results are the same as Python `format(value, spec)`.
+        let cases = [
+            (r#""ab""#, ">6", "    ab"),
+            (r#""ab""#, "<6", "ab    "),
+            (r#""ab""#, "*^7", "**ab***"),
+            (r#""abcdef""#, ".2", "ab"),
+            (r#""ab""#, "05", "ab000"),
+            (r#""é""#, "^3", " é "),
+            ("42", "6", "    42"),
+            ("42", "<6", "42    "),
+            ("-42", "=6", "-   42"),
+            ("42", "+05d", "+0042"),
+            ("42", " d", " 42"),
+            ("1234567", ",", "1,234,567"),
+            ("1234567", "_", "1_234_567"),
+            ("255", "#x", "0xff"),
+            ("255", "#X", "0XFF"),
+            ("8", "#o", "0o10"),
+            ("5", "#b", "0b101"),
+            ("1024", "_b", "100_0000_0000"),
+            ("75", "c", "K"),
+            ("-9223372036854775808", "d", "-9223372036854775808"),
+            ("3", ".2f", "3.00"),
+            ("-3.14159", "06.2f", "-03.14"),
+            ("3.14159", ".3", "3.14"),
+            ("1234.5", ".2", "1.2e+03"),
+            ("1.0", ".2", "1.0"),
+            ("1.5", "n", "1.5"),
+            ("0.25", "%", "25.000000%"),
+            ("0.25", ".1%", "25.0%"),
+            ("3.5", "=+8.2f", "+   3.50"),
+            ("1234.5", "^10,.2f", " 1,234.50 "),
+            ("12345.678", "e", "1.234568e+04"),
+            ("12345.678", "G", "12345.7"),
+            ("true", "d", "1"),
+            ("false", ">3", "  0"),
+            ("true", "", "True"),
+            ("null", ">6", "  None"),
+        ];
+        for (value, spec, expected) in cases {
+            let value = ValueRef::from_json(&mut ctx, value).unwrap();
+            assert_eq!(value.to_string_with_spec(spec), expected, "{value} {spec}");
+        }
+        for (value, spec) in [
+            (r#""ab""#, "+"),
+            (r#""ab""#, "d"),
+            (r#""ab""#, "=5"),
+            ("42", ".2"),
+            ("42", "s"),
+            ("1.5", "x"),
+            ("42", "+c"),
+        ] {
+            let value = ValueRef::from_json(&mut ctx, value).unwrap();
+            assert!(value.format_with_spec(spec).is_err(), "{value} {spec}");
+        }
+        assert!(is_valid_format_spec(">10"));
+        assert!(is_valid_format_spec("#x"));
+        assert!(!is_valid_format_spec("#js"));
+        assert!(!is_valid_format_spec(" json"));
+    }
+
+    #[test]
+    fn test_format_big_number() {
+        let big = ValueRef::bigint("123456789012345678901234567890".parse().unwrap());
+        assert_eq!(
+            big.to_string_with_spec(","),
+            "123,456,789,012,345,678,901,234,567,890"
+        );
+        assert_eq!(big.to_string_with_spec("#x"), "0x18ee90ff6c373e0ee4e3f0ad2");
+        let decimal = ValueRef::decimal("-1234.50".parse().unwrap());
+        assert_eq!(decimal.to_string_with_spec(">12,"), "   -1,234.50");
+        assert_eq!(decimal.to_string_with_spec(".1f"), "-1234.5");
     }
 }
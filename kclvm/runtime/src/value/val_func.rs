@@ -0,0 +1,27 @@
+//! Copyright The KCL Authors. All rights reserved.
+
+use crate::*;
+
+impl ValueRef {
+    /// Call the function value e.g., a KCL lambda with the positional
+    /// arguments `args` and return the result value.
+    pub fn call(&self, ctx: &mut Context, args: &[ValueRef]) -> ValueRef {
+        if !self.is_func() {
+            panic!("'{}' object is not callable", self.type_str());
+        }
+        let args = ValueRef::list(Some(&args.iter().collect::<Vec<&ValueRef>>())).into_raw(ctx);
+        let kwargs = ValueRef::dict(None).into_raw(ctx);
+        let is_in_schema = ValueRef::bool(false).into_raw(ctx);
+        let value = unsafe {
+            kclvm_value_function_invoke(
+                self as *const ValueRef,
+                ctx as *mut Context,
+                args,
+                kwargs,
+                "\0".as_ptr() as *const kclvm_char_t,
+                is_in_schema,
+            )
+        };
+        ptr_as_ref(value).clone()
+    }
+}
@@ -54,10 +54,23 @@ impl ValueRef {
     pub fn is_number(&self) -> bool {
         matches!(
             &*self.rc.borrow(),
-            Value::int_value(_) | Value::float_value(_)
+            Value::int_value(_)
+                | Value::bigint_value(_)
+                | Value::float_value(_)
+                | Value::decimal_value(_)
         )
     }
 
+    #[inline]
+    pub fn is_bigint(&self) -> bool {
+        matches!(&*self.rc.borrow(), Value::bigint_value(_))
+    }
+
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        self.kind() == Kind::Decimal
+    }
+
     #[inline]
     pub fn is_config(&self) -> bool {
         matches!(
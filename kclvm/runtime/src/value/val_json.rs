@@ -2,6 +2,8 @@
 
 use bstr::ByteSlice;
 use indexmap::IndexMap;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use serde::{
     de::{DeserializeSeed, MapAccess, SeqAccess, Visitor},
     Deserialize, Serialize,
@@ -40,6 +42,9 @@ pub(crate) enum JsonValue {
 
     Number(serde_json::Number),
 
+    /// The integer which overflows i64 and u64.
+    BigInt(BigInt),
+
     String(String),
 
     Array(Vec<JsonValue>),
@@ -111,6 +116,24 @@ impl<'de> Deserialize<'de> for JsonValue {
                 Ok(Self::Value::Number(value.into()))
             }
 
+            // serde_yaml decodes the integers which overflow i64 and u64 as i128 and u128,
+            // but serde_json decodes them as floats, see [parse_json_with_big_ints].
+            #[inline]
+            fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E> {
+                Ok(match i64::try_from(value) {
+                    Ok(value) => Self::Value::Number(value.into()),
+                    Err(_) => Self::Value::BigInt(value.into()),
+                })
+            }
+
+            #[inline]
+            fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E> {
+                Ok(match u64::try_from(value) {
+                    Ok(value) => Self::Value::Number(value.into()),
+                    Err(_) => Self::Value::BigInt(value.into()),
+                })
+            }
+
             #[inline]
             fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
                 Ok(serde_json::Number::from_f64(value)
@@ -196,6 +219,11 @@ impl Serialize for JsonValue {
             JsonValue::Null => serializer.serialize_unit(),
             JsonValue::Bool(b) => serializer.serialize_bool(*b),
             JsonValue::Number(n) => n.serialize(serializer),
+            JsonValue::BigInt(n) => match (n.to_i128(), n.to_u128()) {
+                (Some(n), _) => serializer.serialize_i128(n),
+                (_, Some(n)) => serializer.serialize_u128(n),
+                _ => serializer.serialize_str(&n.to_string()),
+            },
             JsonValue::String(s) => serializer.serialize_str(s),
             JsonValue::Array(v) => v.serialize(serializer),
             JsonValue::Object(m) => {
@@ -355,9 +383,113 @@ where
     Ok(())
 }
 
+/// Parse the JSON string which has been validated by serde_json, and keep the integers
+/// which overflow i64 and u64 as big integers, because serde_json decodes them as floats
+/// unless the `arbitrary_precision` feature is enabled.
+fn parse_json_with_big_ints(s: &str) -> Result<JsonValue, serde_json::Error> {
+    let mut parser = BigIntJsonParser { s, pos: 0 };
+    parser.parse_value()
+}
+
+struct BigIntJsonParser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> BigIntJsonParser<'a> {
+    /// Skip the whitespaces and return the next byte.
+    fn peek(&mut self) -> u8 {
+        let bytes = self.s.as_bytes();
+        while self.pos < bytes.len() && matches!(bytes[self.pos], b' ' | b'\t' | b'\n' | b'\r') {
+            self.pos += 1;
+        }
+        bytes.get(self.pos).copied().unwrap_or_default()
+    }
+
+    /// Consume the bytes while the predicate is true and return the token.
+    fn token(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        let bytes = self.s.as_bytes();
+        while self.pos < bytes.len() && predicate(bytes[self.pos]) {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, serde_json::Error> {
+        match self.peek() {
+            b'{' => {
+                self.pos += 1;
+                let mut values = IndexMap::new();
+                while self.peek() != b'}' {
+                    let key = self.parse_string()?;
+                    self.peek();
+                    // Skip the colon.
+                    self.pos += 1;
+                    values.insert(key, self.parse_value()?);
+                    if self.peek() == b',' {
+                        self.pos += 1;
+                    }
+                }
+                self.pos += 1;
+                Ok(JsonValue::Object(values))
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut values = Vec::new();
+                while self.peek() != b']' {
+                    values.push(self.parse_value()?);
+                    if self.peek() == b',' {
+                        self.pos += 1;
+                    }
+                }
+                self.pos += 1;
+                Ok(JsonValue::Array(values))
+            }
+            b'"' => Ok(JsonValue::String(self.parse_string()?)),
+            b'-' | b'0'..=b'9' => {
+                let token =
+                    self.token(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'));
+                let number: serde_json::Number = serde_json::from_str(token)?;
+                let is_integer = !token.contains(['.', 'e', 'E']);
+                if is_integer && number.is_f64() {
+                    match token.parse::<BigInt>() {
+                        Ok(value) => Ok(JsonValue::BigInt(value)),
+                        Err(err) => Err(serde::de::Error::custom(err)),
+                    }
+                } else {
+                    Ok(JsonValue::Number(number))
+                }
+            }
+            _ => {
+                let token = self.token(|b| b.is_ascii_alphabetic());
+                serde_json::from_str(token)
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, serde_json::Error> {
+        self.peek();
+        let start = self.pos;
+        let bytes = self.s.as_bytes();
+        self.pos += 1;
+        while self.pos < bytes.len() && bytes[self.pos] != b'"' {
+            // Skip the escaped character e.g., `\"`.
+            if bytes[self.pos] == b'\\' {
+                self.pos += 1;
+            }
+            self.pos += 1;
+        }
+        self.pos += 1;
+        serde_json::from_str(&self.s[start..self.pos.min(bytes.len())])
+    }
+}
+
 impl ValueRef {
     pub fn from_json(ctx: &mut Context, s: &str) -> Result<Self, serde_json::Error> {
         match serde_json::de::from_str::<JsonValue>(s) {
+            // Parse the validated JSON string again to keep the big integers.
+            Ok(_) if ctx.cfg.big_int => Ok(Self::parse_json(ctx, &parse_json_with_big_ints(s)?)),
             Ok(json) => Ok(Self::parse_json(ctx, &json)),
             Err(err) => Err(err),
         }
@@ -380,6 +512,13 @@ impl ValueRef {
                 list
             }
             JsonValue::String(val) => Self::str((*val).as_ref()),
+            JsonValue::BigInt(val) => {
+                if ctx.cfg.big_int {
+                    Self::bigint(val.clone())
+                } else {
+                    Self::float(val.to_f64().unwrap_or(f64::NAN))
+                }
+            }
             JsonValue::Number(val) => {
                 if val.is_i64() {
                     Self::int(val.as_i64().unwrap())
@@ -387,6 +526,8 @@ impl ValueRef {
                     let n = val.as_u64().unwrap();
                     if n <= i64::max_value() as u64 {
                         Self::int(n as i64)
+                    } else if ctx.cfg.big_int {
+                        Self::bigint(n.into())
                     } else {
                         Self::float(n as f64)
                     }
@@ -438,13 +579,16 @@ impl ValueRef {
         writer.to_str().unwrap().to_string()
     }
 
-    fn build_json(&self, opt: &JsonEncodeOptions) -> JsonValue {
+    pub(crate) fn build_json(&self, opt: &JsonEncodeOptions) -> JsonValue {
         match &*self.rc.borrow() {
             crate::Value::undefined => JsonValue::Null,
             crate::Value::none => JsonValue::Null,
 
             crate::Value::bool_value(ref v) => JsonValue::Bool(*v),
             crate::Value::int_value(ref v) => JsonValue::Number(serde_json::Number::from(*v)),
+            crate::Value::bigint_value(ref v) => JsonValue::BigInt(v.clone()),
+            // Decimals are encoded as strings to keep the exact values.
+            crate::Value::decimal_value(ref v) => JsonValue::String(v.to_string()),
             crate::Value::float_value(ref v) => match serde_json::Number::from_f64(*v) {
                 Some(n) => JsonValue::Number(n),
                 None => JsonValue::Null,
@@ -586,6 +730,44 @@ mod test_value_json {
         }
     }
 
+    #[test]
+    fn test_value_json_big_int_round_trip() {
+        let mut ctx = Context::new();
+        ctx.cfg.big_int = true;
+        let cases = [
+            "[1, -9223372036854775808, 18446744073709551615]",
+            "[-9223372036854775809, 18446744073709551616]",
+            "{\"id\": 123456789012345678901234567890, \"s\": \"\\\"1e400\\\"\", \"f\": 1.5}",
+            "[-170141183460469231731687303715884105728, 340282366920938463463374607431768211455]",
+        ];
+        for json_str in cases {
+            let value = ValueRef::from_json(&mut ctx, json_str).unwrap();
+            assert_eq!(value.to_json_string(), json_str);
+        }
+        // Big integers are decoded as floats when the config `big_int` is disabled.
+        ctx.cfg.big_int = false;
+        let value = ValueRef::from_json(&mut ctx, "18446744073709551616").unwrap();
+        assert!(value.is_float());
+    }
+
+    #[test]
+    fn test_value_json_decimal() {
+        // Decimals are encoded as strings to keep the exact values, so they are
+        // decoded as strings instead of decimals.
+        let mut ctx = Context::new();
+        let value = ValueRef::list(Some(&[
+            &ValueRef::decimal("0.1".parse().unwrap()),
+            &ValueRef::decimal("-12.50".parse().unwrap()),
+        ]));
+        let json_str = value.to_json_string();
+        assert_eq!(json_str, "[\"0.1\", \"-12.50\"]");
+        let result = ValueRef::from_json(&mut ctx, &json_str).unwrap();
+        assert_eq!(
+            result,
+            ValueRef::list(Some(&[&ValueRef::str("0.1"), &ValueRef::str("-12.50")]))
+        );
+    }
+
     #[test]
     fn test_value_to_json_string() {
         let cases = [
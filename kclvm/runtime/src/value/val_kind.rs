@@ -10,7 +10,9 @@ impl ValueRef {
             Value::none => Kind::None,
             Value::bool_value(_) => Kind::Bool,
             Value::int_value(_) => Kind::Int,
+            Value::bigint_value(_) => Kind::Int,
             Value::float_value(_) => Kind::Float,
+            Value::decimal_value(_) => Kind::Decimal,
             Value::str_value(_) => Kind::Str,
             Value::list_value(_) => Kind::List,
             Value::dict_value(_) => Kind::Dict,
@@ -10,7 +10,9 @@ impl ValueRef {
             Value::none => false,
             Value::bool_value(ref v) => *v,
             Value::int_value(ref v) => *v != 0,
+            Value::bigint_value(_) => true,
             Value::float_value(ref v) => *v != 0.0,
+            Value::decimal_value(ref v) => !v.is_zero(),
             Value::str_value(ref v) => !v.is_empty(),
             Value::list_value(ref v) => !v.values.is_empty(),
             Value::dict_value(ref v) => !v.values.is_empty(),
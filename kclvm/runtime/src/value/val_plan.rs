@@ -213,6 +213,9 @@ impl ValueRef {
     }
 
     pub fn plan_to_json_string(&self, ctx: &mut Context) -> String {
+        if ctx.cfg.provenance {
+            ctx.buffer.source_map = Some(self.source_map_json_string(ctx));
+        }
         let result = self.filter_results(ctx);
         if result.is_planned_empty() {
             return "".to_string();
@@ -271,6 +274,34 @@ impl ValueRef {
         }
     }
 
+    /// Plan the value and encode the planned document into the protobuf binary
+    /// or text format of the message, e.g., `pkg.Config`. The text format is
+    /// returned as UTF-8 bytes.
+    pub fn plan_to_protobuf(
+        &self,
+        ctx: &Context,
+        descriptors: &ProtoDescriptors,
+        message: &str,
+        format: ProtobufFormat,
+    ) -> Result<Vec<u8>, String> {
+        let results = filter_results(ctx, self, &PlanOptions::default());
+        let result = match results.as_slice() {
+            [result] => result,
+            _ => {
+                return Err(format!(
+                    "the protobuf output requires a single document, got {}",
+                    results.len()
+                ))
+            }
+        };
+        match format {
+            ProtobufFormat::Binary => result.to_protobuf_bytes(descriptors, message),
+            ProtobufFormat::Text => result
+                .to_protobuf_text(descriptors, message)
+                .map(String::into_bytes),
+        }
+    }
+
     /// Filter values using path selectors.
     pub fn filter_by_path(&self, path_selector: &[String]) -> Result<ValueRef, String> {
         if self.is_config() && !path_selector.is_empty() {
@@ -319,9 +350,15 @@ impl ValueRef {
             Value::int_value(ref v) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::int_value(*v))),
             },
+            Value::bigint_value(ref v) => ValueRef {
+                rc: Rc::new(RefCell::new(Value::bigint_value(v.clone()))),
+            },
             Value::float_value(ref v) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::float_value(*v))),
             },
+            Value::decimal_value(ref v) => ValueRef {
+                rc: Rc::new(RefCell::new(Value::decimal_value(v.clone()))),
+            },
             Value::unit_value(ref v, _, _) => ValueRef {
                 rc: Rc::new(RefCell::new(Value::float_value(*v))),
             },
@@ -348,6 +385,7 @@ impl ValueRef {
                         ops: IndexMap::default(),
                         insert_indexs: IndexMap::default(),
                         attr_map: IndexMap::default(),
+                        sources: IndexMap::default(),
                     })))),
                 };
                 for (key, val) in v.values.iter() {
@@ -371,6 +409,7 @@ impl ValueRef {
                             ops: IndexMap::default(),
                             insert_indexs: IndexMap::default(),
                             attr_map: IndexMap::default(),
+                            sources: IndexMap::default(),
                         }),
                         config_keys: vec![],
                         config_meta: v.config_meta.clone(),
@@ -0,0 +1,699 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! Encode config values into the protobuf binary and text formats with the
+//! message descriptors of a `FileDescriptorSet`, e.g., the output of
+//! `protoc --descriptor_set_out`. Config keys are matched with the field names
+//! or the JSON names of the message fields, enum values can be written with
+//! the value names or numbers, and bytes fields are written as base64 strings
+//! following the protobuf JSON mapping.
+
+use crate::*;
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use std::collections::HashMap;
+
+/// The protobuf output formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtobufFormat {
+    Binary,
+    Text,
+}
+
+/// The message and enum descriptors indexed by the fully qualified names,
+/// e.g., `.pkg.Config`.
+#[derive(Debug, Default, Clone)]
+pub struct ProtoDescriptors {
+    /// The message descriptors and whether they are defined with the proto3 syntax.
+    messages: HashMap<String, (DescriptorProto, bool)>,
+    enums: HashMap<String, EnumDescriptorProto>,
+}
+
+impl ProtoDescriptors {
+    /// Decode the descriptors from the binary `FileDescriptorSet`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let set = FileDescriptorSet::decode(bytes)
+            .map_err(|err| format!("invalid protobuf descriptor set: {err}"))?;
+        Ok(Self::from_descriptor_set(&set))
+    }
+
+    pub fn from_descriptor_set(set: &FileDescriptorSet) -> Self {
+        let mut descriptors = Self::default();
+        for file in &set.file {
+            let scope = match file.package() {
+                "" => String::new(),
+                package => format!(".{package}"),
+            };
+            let proto3 = file.syntax() == "proto3";
+            for message in &file.message_type {
+                descriptors.add_message(&scope, message, proto3);
+            }
+            for enum_ty in &file.enum_type {
+                descriptors
+                    .enums
+                    .insert(format!("{scope}.{}", enum_ty.name()), enum_ty.clone());
+            }
+        }
+        descriptors
+    }
+
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto, proto3: bool) {
+        let name = format!("{scope}.{}", message.name());
+        for nested in &message.nested_type {
+            self.add_message(&name, nested, proto3);
+        }
+        for enum_ty in &message.enum_type {
+            self.enums
+                .insert(format!("{name}.{}", enum_ty.name()), enum_ty.clone());
+        }
+        self.messages.insert(name, (message.clone(), proto3));
+    }
+
+    /// Get the message descriptor by the fully qualified name, the leading
+    /// dot is optional.
+    fn message(&self, name: &str) -> Result<&(DescriptorProto, bool), String> {
+        let name = name.trim_start_matches('.');
+        self.messages
+            .get(&format!(".{name}"))
+            .ok_or_else(|| format!("protobuf message '{name}' not found in the descriptor set"))
+    }
+
+    fn enum_ty(&self, name: &str) -> Result<&EnumDescriptorProto, String> {
+        self.enums.get(name).ok_or_else(|| {
+            format!(
+                "protobuf enum '{}' not found in the descriptor set",
+                name.trim_start_matches('.')
+            )
+        })
+    }
+
+    /// Returns the key and value fields of the map entry message when the field
+    /// is a map field.
+    fn map_entry(
+        &self,
+        field: &FieldDescriptorProto,
+    ) -> Result<Option<(&FieldDescriptorProto, &FieldDescriptorProto)>, String> {
+        if field.r#type() != Type::Message || field.label() != Label::Repeated {
+            return Ok(None);
+        }
+        let (entry, _) = self.message(field.type_name())?;
+        let is_map_entry = entry
+            .options
+            .as_ref()
+            .map(|options| options.map_entry())
+            .unwrap_or_default();
+        match (is_map_entry, entry.field.as_slice()) {
+            (true, [key, value]) => Ok(Some((key, value))),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl ValueRef {
+    /// Encode the config value into the protobuf binary format of the message.
+    pub fn to_protobuf_bytes(
+        &self,
+        descriptors: &ProtoDescriptors,
+        message: &str,
+    ) -> Result<Vec<u8>, String> {
+        let mut buf = vec![];
+        encode_message(&mut buf, self, descriptors, message)?;
+        Ok(buf)
+    }
+
+    /// Encode the config value into the protobuf text format of the message.
+    pub fn to_protobuf_text(
+        &self,
+        descriptors: &ProtoDescriptors,
+        message: &str,
+    ) -> Result<String, String> {
+        let mut out = String::new();
+        text_message(&mut out, self, descriptors, message, 0)?;
+        Ok(out)
+    }
+}
+
+/// Returns the message fields and the values set in the config in the field
+/// declaration order. `None` and `Undefined` values are not set.
+fn message_fields<'a>(
+    value: &ValueRef,
+    descriptor: &'a DescriptorProto,
+) -> Result<Vec<(&'a FieldDescriptorProto, ValueRef)>, String> {
+    if !value.is_config() {
+        return Err(format!(
+            "invalid value {} for protobuf message '{}', expected a config",
+            value.to_json_string(),
+            descriptor.name()
+        ));
+    }
+    let mut fields = vec![];
+    for (key, value) in &value.as_dict_ref().values {
+        if value.is_none_or_undefined() || value.is_func() {
+            continue;
+        }
+        let index = descriptor
+            .field
+            .iter()
+            .position(|field| field.name() == key || field.json_name() == key)
+            .ok_or_else(|| {
+                format!(
+                    "unknown field '{key}' of protobuf message '{}'",
+                    descriptor.name()
+                )
+            })?;
+        fields.push((index, value.clone()));
+    }
+    fields.sort_by_key(|(index, _)| *index);
+    Ok(fields
+        .into_iter()
+        .map(|(index, value)| (&descriptor.field[index], value))
+        .collect())
+}
+
+fn list_items(field: &FieldDescriptorProto, value: &ValueRef) -> Result<Vec<ValueRef>, String> {
+    if !value.is_list() {
+        return Err(invalid_field_value(field, value, "a list"));
+    }
+    Ok(value
+        .as_list_ref()
+        .values
+        .iter()
+        .filter(|item| !item.is_none_or_undefined())
+        .cloned()
+        .collect())
+}
+
+/// Returns the map entries of the config, keys are converted into the key
+/// field type.
+fn map_items(
+    field: &FieldDescriptorProto,
+    key_field: &FieldDescriptorProto,
+    value: &ValueRef,
+) -> Result<Vec<(ValueRef, ValueRef)>, String> {
+    if !value.is_config() {
+        return Err(invalid_field_value(field, value, "a config"));
+    }
+    let mut items = vec![];
+    for (key, value) in &value.as_dict_ref().values {
+        if value.is_none_or_undefined() {
+            continue;
+        }
+        let key = match key_field.r#type() {
+            Type::String => ValueRef::str(key),
+            Type::Bool => match key.as_str() {
+                "true" => ValueRef::bool(true),
+                "false" => ValueRef::bool(false),
+                _ => {
+                    return Err(invalid_field_value(
+                        field,
+                        &ValueRef::str(key),
+                        "a bool key",
+                    ))
+                }
+            },
+            _ => match key.parse::<i64>() {
+                Ok(key) => ValueRef::int(key),
+                Err(_) => {
+                    return Err(invalid_field_value(
+                        field,
+                        &ValueRef::str(key),
+                        "an integer key",
+                    ))
+                }
+            },
+        };
+        items.push((key, value.clone()));
+    }
+    Ok(items)
+}
+
+fn invalid_field_value(field: &FieldDescriptorProto, value: &ValueRef, expected: &str) -> String {
+    format!(
+        "invalid value {} for protobuf field '{}', expected {}",
+        value.to_json_string(),
+        field.name(),
+        expected
+    )
+}
+
+fn int_value(field: &FieldDescriptorProto, value: &ValueRef) -> Result<i64, String> {
+    let (min, max, expected) = match field.r#type() {
+        Type::Int32 | Type::Sint32 | Type::Sfixed32 => {
+            (i32::MIN as i64, i32::MAX as i64, "an int32 value")
+        }
+        Type::Uint32 | Type::Fixed32 => (0, u32::MAX as i64, "a uint32 value"),
+        Type::Uint64 | Type::Fixed64 => (0, i64::MAX, "a uint64 value"),
+        _ => (i64::MIN, i64::MAX, "an int64 value"),
+    };
+    match value.kind() {
+        Kind::Int => {
+            let v = value.as_int();
+            if v < min || v > max {
+                Err(invalid_field_value(field, value, expected))
+            } else {
+                Ok(v)
+            }
+        }
+        _ => Err(invalid_field_value(field, value, expected)),
+    }
+}
+
+fn float_value(field: &FieldDescriptorProto, value: &ValueRef) -> Result<f64, String> {
+    match value.kind() {
+        Kind::Int | Kind::Float => Ok(value.as_float()),
+        _ => Err(invalid_field_value(field, value, "a number")),
+    }
+}
+
+fn bool_value(field: &FieldDescriptorProto, value: &ValueRef) -> Result<bool, String> {
+    match value.kind() {
+        Kind::Bool => Ok(value.as_bool()),
+        _ => Err(invalid_field_value(field, value, "a bool value")),
+    }
+}
+
+fn str_value(field: &FieldDescriptorProto, value: &ValueRef) -> Result<String, String> {
+    match value.kind() {
+        Kind::Str => Ok(value.as_str()),
+        _ => Err(invalid_field_value(field, value, "a string")),
+    }
+}
+
+fn bytes_value(field: &FieldDescriptorProto, value: &ValueRef) -> Result<Vec<u8>, String> {
+    ::base64::decode(str_value(field, value)?)
+        .map_err(|_| invalid_field_value(field, value, "a base64 string"))
+}
+
+/// Returns the enum number and name of the enum field value.
+fn enum_value(
+    field: &FieldDescriptorProto,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+) -> Result<(i32, Option<String>), String> {
+    let enum_ty = descriptors.enum_ty(field.type_name())?;
+    match value.kind() {
+        Kind::Str => {
+            let name = value.as_str();
+            enum_ty
+                .value
+                .iter()
+                .find(|v| v.name() == name)
+                .map(|v| (v.number(), Some(name)))
+                .ok_or_else(|| invalid_field_value(field, value, "an enum value"))
+        }
+        Kind::Int => {
+            let number = value.as_int();
+            if number < i32::MIN as i64 || number > i32::MAX as i64 {
+                return Err(invalid_field_value(field, value, "an enum value"));
+            }
+            let number = number as i32;
+            let name = enum_ty
+                .value
+                .iter()
+                .find(|v| v.number() == number)
+                .map(|v| v.name().to_string());
+            Ok((number, name))
+        }
+        _ => Err(invalid_field_value(field, value, "an enum value")),
+    }
+}
+
+/* Binary format */
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_FIXED64: u32 = 1;
+const WIRE_LEN: u32 = 2;
+const WIRE_FIXED32: u32 = 5;
+
+fn wire_type(ty: Type) -> u32 {
+    match ty {
+        Type::Double | Type::Fixed64 | Type::Sfixed64 => WIRE_FIXED64,
+        Type::Float | Type::Fixed32 | Type::Sfixed32 => WIRE_FIXED32,
+        Type::String | Type::Bytes | Type::Message | Type::Group => WIRE_LEN,
+        _ => WIRE_VARINT,
+    }
+}
+
+fn encode_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn encode_key(buf: &mut Vec<u8>, number: i32, wire_type: u32) {
+    encode_varint(buf, ((number as u64) << 3) | wire_type as u64);
+}
+
+fn encode_len_delimited(buf: &mut Vec<u8>, bytes: &[u8]) {
+    encode_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn encode_message(
+    buf: &mut Vec<u8>,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+    message: &str,
+) -> Result<(), String> {
+    let (descriptor, proto3) = descriptors.message(message)?;
+    for (field, value) in message_fields(value, descriptor)? {
+        if let Some((key_field, value_field)) = descriptors.map_entry(field)? {
+            for (k, v) in map_items(field, key_field, &value)? {
+                let mut entry = vec![];
+                encode_field(&mut entry, key_field, &k, descriptors)?;
+                encode_field(&mut entry, value_field, &v, descriptors)?;
+                encode_key(buf, field.number(), WIRE_LEN);
+                encode_len_delimited(buf, &entry);
+            }
+        } else if field.label() == Label::Repeated {
+            let items = list_items(field, &value)?;
+            let packed = wire_type(field.r#type()) != WIRE_LEN
+                && field
+                    .options
+                    .as_ref()
+                    .and_then(|options| options.packed)
+                    .unwrap_or(*proto3);
+            if packed {
+                if items.is_empty() {
+                    continue;
+                }
+                let mut payload = vec![];
+                for item in &items {
+                    encode_payload(&mut payload, field, item, descriptors)?;
+                }
+                encode_key(buf, field.number(), WIRE_LEN);
+                encode_len_delimited(buf, &payload);
+            } else {
+                for item in &items {
+                    encode_field(buf, field, item, descriptors)?;
+                }
+            }
+        } else {
+            encode_field(buf, field, &value, descriptors)?;
+        }
+    }
+    Ok(())
+}
+
+fn encode_field(
+    buf: &mut Vec<u8>,
+    field: &FieldDescriptorProto,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+) -> Result<(), String> {
+    encode_key(buf, field.number(), wire_type(field.r#type()));
+    encode_payload(buf, field, value, descriptors)
+}
+
+/// Encode the field value without the field key.
+fn encode_payload(
+    buf: &mut Vec<u8>,
+    field: &FieldDescriptorProto,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+) -> Result<(), String> {
+    match field.r#type() {
+        Type::Double => buf.extend_from_slice(&float_value(field, value)?.to_le_bytes()),
+        Type::Float => buf.extend_from_slice(&(float_value(field, value)? as f32).to_le_bytes()),
+        // Negative int32 values are sign-extended to 64 bits.
+        Type::Int32 | Type::Int64 | Type::Uint32 | Type::Uint64 => {
+            encode_varint(buf, int_value(field, value)? as u64)
+        }
+        Type::Sint32 => {
+            let v = int_value(field, value)? as i32;
+            encode_varint(buf, ((v << 1) ^ (v >> 31)) as u32 as u64)
+        }
+        Type::Sint64 => {
+            let v = int_value(field, value)?;
+            encode_varint(buf, ((v << 1) ^ (v >> 63)) as u64)
+        }
+        Type::Fixed32 | Type::Sfixed32 => {
+            buf.extend_from_slice(&(int_value(field, value)? as u32).to_le_bytes())
+        }
+        Type::Fixed64 | Type::Sfixed64 => {
+            buf.extend_from_slice(&(int_value(field, value)? as u64).to_le_bytes())
+        }
+        Type::Bool => encode_varint(buf, bool_value(field, value)? as u64),
+        Type::Enum => encode_varint(buf, enum_value(field, value, descriptors)?.0 as u64),
+        Type::String => encode_len_delimited(buf, str_value(field, value)?.as_bytes()),
+        Type::Bytes => encode_len_delimited(buf, &bytes_value(field, value)?),
+        Type::Message => {
+            let mut message = vec![];
+            encode_message(&mut message, value, descriptors, field.type_name())?;
+            encode_len_delimited(buf, &message);
+        }
+        Type::Group => {
+            return Err(format!(
+                "protobuf group field '{}' is not supported",
+                field.name()
+            ))
+        }
+    }
+    Ok(())
+}
+
+/* Text format */
+
+const TEXT_INDENT: &str = "  ";
+
+fn text_message(
+    out: &mut String,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+    message: &str,
+    indent: usize,
+) -> Result<(), String> {
+    let (descriptor, _) = descriptors.message(message)?;
+    for (field, value) in message_fields(value, descriptor)? {
+        if let Some((key_field, value_field)) = descriptors.map_entry(field)? {
+            let prefix = TEXT_INDENT.repeat(indent);
+            for (k, v) in map_items(field, key_field, &value)? {
+                out.push_str(&format!("{prefix}{} {{\n", field.name()));
+                text_field(out, key_field, &k, descriptors, indent + 1)?;
+                text_field(out, value_field, &v, descriptors, indent + 1)?;
+                out.push_str(&format!("{prefix}}}\n"));
+            }
+        } else if field.label() == Label::Repeated {
+            for item in list_items(field, &value)? {
+                text_field(out, field, &item, descriptors, indent)?;
+            }
+        } else {
+            text_field(out, field, &value, descriptors, indent)?;
+        }
+    }
+    Ok(())
+}
+
+fn text_field(
+    out: &mut String,
+    field: &FieldDescriptorProto,
+    value: &ValueRef,
+    descriptors: &ProtoDescriptors,
+    indent: usize,
+) -> Result<(), String> {
+    let prefix = TEXT_INDENT.repeat(indent);
+    let text = match field.r#type() {
+        Type::Message => {
+            out.push_str(&format!("{prefix}{} {{\n", field.name()));
+            text_message(out, value, descriptors, field.type_name(), indent + 1)?;
+            out.push_str(&format!("{prefix}}}\n"));
+            return Ok(());
+        }
+        Type::Double | Type::Float => {
+            let v = float_value(field, value)?;
+            if v.is_nan() {
+                "nan".to_string()
+            } else if v.is_infinite() {
+                if v > 0.0 { "inf" } else { "-inf" }.to_string()
+            } else {
+                v.to_string()
+            }
+        }
+        Type::Bool => bool_value(field, value)?.to_string(),
+        Type::Enum => match enum_value(field, value, descriptors)? {
+            (_, Some(name)) => name,
+            (number, None) => number.to_string(),
+        },
+        Type::String => escape_text_bytes(str_value(field, value)?.as_bytes(), true),
+        Type::Bytes => escape_text_bytes(&bytes_value(field, value)?, false),
+        Type::Group => {
+            return Err(format!(
+                "protobuf group field '{}' is not supported",
+                field.name()
+            ))
+        }
+        _ => int_value(field, value)?.to_string(),
+    };
+    out.push_str(&format!("{prefix}{}: {text}\n", field.name()));
+    Ok(())
+}
+
+/// Quote the bytes with the C-style escapes of the text format. UTF-8 sequences
+/// are kept for strings and non-printable bytes are escaped in octal.
+fn escape_text_bytes(bytes: &[u8], utf8: bool) -> String {
+    let mut out = vec![b'"'];
+    for &b in bytes {
+        match b {
+            b'"' => out.extend_from_slice(b"\\\""),
+            b'\\' => out.extend_from_slice(b"\\\\"),
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\r' => out.extend_from_slice(b"\\r"),
+            b'\t' => out.extend_from_slice(b"\\t"),
+            0x20..=0x7e => out.push(b),
+            0x80..=0xff if utf8 => out.push(b),
+            _ => out.extend_from_slice(format!("\\{:03o}", b).as_bytes()),
+        }
+    }
+    out.push(b'"');
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod test_value_protobuf {
+    use crate::*;
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet, MessageOptions,
+    };
+
+    fn field(
+        name: &str,
+        number: i32,
+        label: Label,
+        ty: Type,
+        type_name: &str,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(label as i32),
+            r#type: Some(ty as i32),
+            type_name: if type_name.is_empty() {
+                None
+            } else {
+                Some(type_name.to_string())
+            },
+            ..Default::default()
+        }
+    }
+
+    fn test_descriptors() -> ProtoDescriptors {
+        let optional = Label::Optional;
+        let labels_entry = DescriptorProto {
+            name: Some("LabelsEntry".to_string()),
+            field: vec![
+                field("key", 1, optional, Type::String, ""),
+                field("value", 2, optional, Type::String, ""),
+            ],
+            options: Some(MessageOptions {
+                map_entry: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let inner = DescriptorProto {
+            name: Some("Inner".to_string()),
+            field: vec![field("x", 1, optional, Type::Bool, "")],
+            ..Default::default()
+        };
+        let config = DescriptorProto {
+            name: Some("Config".to_string()),
+            field: vec![
+                field("name", 1, optional, Type::String, ""),
+                field("replicas", 2, optional, Type::Int32, ""),
+                field("ports", 3, Label::Repeated, Type::Int32, ""),
+                field(
+                    "labels",
+                    4,
+                    Label::Repeated,
+                    Type::Message,
+                    ".test.Config.LabelsEntry",
+                ),
+                field("kind", 5, optional, Type::Enum, ".test.Kind"),
+                field("inner", 6, optional, Type::Message, ".test.Config.Inner"),
+            ],
+            nested_type: vec![labels_entry, inner],
+            ..Default::default()
+        };
+        let kind = EnumDescriptorProto {
+            name: Some("Kind".to_string()),
+            value: ["A", "B"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| EnumValueDescriptorProto {
+                    name: Some(name.to_string()),
+                    number: Some(i as i32),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        ProtoDescriptors::from_descriptor_set(&FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test".to_string()),
+                syntax: Some("proto3".to_string()),
+                message_type: vec![config],
+                enum_type: vec![kind],
+                ..Default::default()
+            }],
+        })
+    }
+
+    #[test]
+    fn test_to_protobuf_bytes() {
+        let mut ctx = Context::new();
+        let descriptors = test_descriptors();
+        let value = ValueRef::from_json(
+            &mut ctx,
+            r#"{"inner": {"x": true}, "name": "a", "replicas": 3, "ports": [80, 443], "labels": {"k": "v"}, "kind": "B", "unset": null}"#,
+        )
+        .unwrap();
+        let bytes = value
+            .to_protobuf_bytes(&descriptors, "test.Config")
+            .unwrap();
+        assert_eq!(
+            bytes,
+            vec![
+                0x0a, 0x01, b'a', // name
+                0x10, 0x03, // replicas
+                0x1a, 0x03, 0x50, 0xbb, 0x03, // packed ports
+                0x22, 0x06, 0x0a, 0x01, b'k', 0x12, 0x01, b'v', // labels
+                0x28, 0x01, // kind
+                0x32, 0x02, 0x08, 0x01, // inner
+            ]
+        );
+        let value = ValueRef::from_json(&mut ctx, r#"{"unknown": 1}"#).unwrap();
+        assert_eq!(
+            value
+                .to_protobuf_bytes(&descriptors, "test.Config")
+                .unwrap_err(),
+            "unknown field 'unknown' of protobuf message 'Config'"
+        );
+    }
+
+    #[test]
+    fn test_to_protobuf_text() {
+        let mut ctx = Context::new();
+        let descriptors = test_descriptors();
+        let value = ValueRef::from_json(
+            &mut ctx,
+            r#"{"name": "a\"b", "ports": [80, 443], "labels": {"k": "v"}, "kind": 1, "inner": {"x": true}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            value.to_protobuf_text(&descriptors, "test.Config").unwrap(),
+            "name: \"a\\\"b\"\nports: 80\nports: 443\nlabels {\n  key: \"k\"\n  value: \"v\"\n}\nkind: B\ninner {\n  x: true\n}\n"
+        );
+        let value = ValueRef::from_json(&mut ctx, r#"{"replicas": 4294967296}"#).unwrap();
+        assert_eq!(
+            value
+                .to_protobuf_text(&descriptors, "test.Config")
+                .unwrap_err(),
+            "invalid value 4294967296 for protobuf field 'replicas', expected an int32 value"
+        );
+    }
+}
@@ -205,6 +205,7 @@ impl ValueRef {
     pub fn attr_str(&self) -> String {
         match &*self.rc.borrow() {
             Value::int_value(v) => v.to_string(),
+            Value::bigint_value(v) => v.to_string(),
             Value::float_value(v) => v.to_string(),
             Value::str_value(v) => v.clone(),
             _ => panic!("invalid attribute {}", self.type_str()),
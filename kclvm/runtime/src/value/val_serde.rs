@@ -0,0 +1,702 @@
+//! Copyright The KCL Authors. All rights reserved.
+//!
+//! Serde data model implementations of [ValueRef], so that the KCL values can be
+//! converted from and into the Rust types directly, e.g., the program results can
+//! be deserialized into the host structs with [from_value] and the host values
+//! can be passed into the program with [to_value].
+
+use crate::*;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer, Serializer};
+use std::fmt;
+
+/// The error of converting Rust values from and into [ValueRef]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueSerdeError(String);
+
+impl fmt::Display for ValueSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ValueSerdeError {}
+
+impl ser::Error for ValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ValueSerdeError(msg.to_string())
+    }
+}
+
+impl de::Error for ValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ValueSerdeError(msg.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, ValueSerdeError>;
+
+/// Convert the serializable Rust value into a [ValueRef]. Structs and maps are
+/// converted into dicts, sequences into lists, and `None` into `None`.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<ValueRef> {
+    value.serialize(ValueSerializer)
+}
+
+/// Convert the [ValueRef] into the Rust value. Schema values are deserialized
+/// like dicts, and `Undefined` values like `None`.
+pub fn from_value<T: DeserializeOwned>(value: &ValueRef) -> Result<T> {
+    T::deserialize(value.clone())
+}
+
+impl Serialize for ValueRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match &*self.rc.borrow() {
+            Value::undefined | Value::none => serializer.serialize_unit(),
+            Value::bool_value(v) => serializer.serialize_bool(*v),
+            Value::int_value(v) => serializer.serialize_i64(*v),
+            Value::bigint_value(v) => match num_traits::ToPrimitive::to_i128(v) {
+                Some(v) => serializer.serialize_i128(v),
+                None => serializer.serialize_str(&v.to_string()),
+            },
+            Value::decimal_value(v) => serializer.serialize_str(&v.to_string()),
+            Value::float_value(v) | Value::unit_value(v, ..) => serializer.serialize_f64(*v),
+            Value::str_value(v) => serializer.serialize_str(v),
+            Value::list_value(list) => {
+                let mut seq = serializer.serialize_seq(Some(list.values.len()))?;
+                for v in &list.values {
+                    seq.serialize_element(v)?;
+                }
+                seq.end()
+            }
+            Value::dict_value(dict) => serialize_dict(dict, serializer),
+            Value::schema_value(schema) => serialize_dict(&schema.config, serializer),
+            Value::func_value(_) => Err(ser::Error::custom("function values can't be serialized")),
+        }
+    }
+}
+
+fn serialize_dict<S: Serializer>(
+    dict: &DictValue,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(dict.values.len()))?;
+    for (k, v) in &dict.values {
+        map.serialize_entry(k, v)?;
+    }
+    map.end()
+}
+
+impl<'de> Deserialize<'de> for ValueRef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueRefVisitor)
+    }
+}
+
+struct ValueRefVisitor;
+
+impl<'de> Visitor<'de> for ValueRefVisitor {
+    type Value = ValueRef;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a KCL value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::int(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<ValueRef, E> {
+        i64::try_from(v)
+            .map(ValueRef::int)
+            .map_err(|_| E::custom(format!("integer {v} overflows the KCL int")))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::float(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::str(v))
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::none())
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<ValueRef, E> {
+        Ok(ValueRef::none())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<ValueRef, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<ValueRef, A::Error> {
+        let mut list = ValueRef::list(None);
+        while let Some(v) = seq.next_element::<ValueRef>()? {
+            list.list_append(&v);
+        }
+        Ok(list)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<ValueRef, A::Error> {
+        let mut dict = ValueRef::dict(None);
+        while let Some((k, v)) = map.next_entry::<String, ValueRef>()? {
+            dict.dict_update_key_value(&k, v);
+        }
+        Ok(dict)
+    }
+}
+
+/* Serializer */
+
+/// The serializer converting Rust values into [ValueRef]s.
+pub struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = VariantSerializer<ListSerializer>;
+    type SerializeMap = DictSerializer;
+    type SerializeStruct = DictSerializer;
+    type SerializeStructVariant = VariantSerializer<DictSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<ValueRef> {
+        Ok(ValueRef::bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<ValueRef> {
+        Ok(ValueRef::int(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<ValueRef> {
+        Ok(ValueRef::int(v as i64))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<ValueRef> {
+        i64::try_from(v)
+            .map(ValueRef::int)
+            .map_err(|_| ValueSerdeError(format!("integer {v} overflows the KCL int")))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<ValueRef> {
+        Ok(ValueRef::float(v as f64))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<ValueRef> {
+        Ok(ValueRef::float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<ValueRef> {
+        Ok(ValueRef::str(&v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<ValueRef> {
+        Ok(ValueRef::str(v))
+    }
+
+    /// Bytes are converted into the list of integers like `serde_json`.
+    fn serialize_bytes(self, v: &[u8]) -> Result<ValueRef> {
+        let values: Vec<i64> = v.iter().map(|b| *b as i64).collect();
+        Ok(ValueRef::list_int(&values))
+    }
+
+    fn serialize_none(self) -> Result<ValueRef> {
+        Ok(ValueRef::none())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<ValueRef> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<ValueRef> {
+        Ok(ValueRef::none())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<ValueRef> {
+        Ok(ValueRef::none())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<ValueRef> {
+        Ok(ValueRef::str(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<ValueRef> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<ValueRef> {
+        let value = value.serialize(self)?;
+        Ok(ValueRef::dict(Some(&[(variant, &value)])))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer> {
+        Ok(ListSerializer {
+            list: ValueRef::list(None),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ListSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<ListSerializer>> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<DictSerializer> {
+        Ok(DictSerializer {
+            dict: ValueRef::dict(None),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<DictSerializer> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<DictSerializer>> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+pub struct ListSerializer {
+    list: ValueRef,
+}
+
+impl SerializeSeq for ListSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.list.list_append(&to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        Ok(self.list)
+    }
+}
+
+impl ser::SerializeTuple for ListSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ListSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        SerializeSeq::end(self)
+    }
+}
+
+pub struct DictSerializer {
+    dict: ValueRef,
+    key: Option<String>,
+}
+
+/// Map keys are converted into strings, numbers and bools are written in the
+/// JSON format.
+fn key_string(key: ValueRef) -> Result<String> {
+    match key.kind() {
+        Kind::Str => Ok(key.as_str()),
+        Kind::Int | Kind::Float | Kind::Bool => Ok(key.to_json_string()),
+        _ => Err(ValueSerdeError(format!(
+            "invalid dict key {}, expected a string",
+            key.to_json_string()
+        ))),
+    }
+}
+
+impl SerializeMap for DictSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key_string(to_value(key)?)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ValueSerdeError("dict value without a key".to_string()))?;
+        self.dict.dict_update_key_value(&key, to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        Ok(self.dict)
+    }
+}
+
+impl ser::SerializeStruct for DictSerializer {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.dict.dict_update_key_value(key, to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        Ok(self.dict)
+    }
+}
+
+/// Enum variants with values are converted into dicts with the variant name
+/// as the single key like `serde_json`.
+pub struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<ListSerializer> {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        let value = SerializeSeq::end(self.inner)?;
+        Ok(ValueRef::dict(Some(&[(self.variant, &value)])))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<DictSerializer> {
+    type Ok = ValueRef;
+    type Error = ValueSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<ValueRef> {
+        let value = ser::SerializeStruct::end(self.inner)?;
+        Ok(ValueRef::dict(Some(&[(self.variant, &value)])))
+    }
+}
+
+/* Deserializer */
+
+impl<'de> IntoDeserializer<'de, ValueSerdeError> for ValueRef {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl ValueRef {
+    fn dict_entries(&self) -> Vec<(String, ValueRef)> {
+        self.as_dict_ref()
+            .values
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    fn invalid_type_error(&self, expected: &str) -> ValueSerdeError {
+        ValueSerdeError(format!(
+            "invalid type {}, expected {}",
+            self.type_str(),
+            expected
+        ))
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueRef {
+    type Error = ValueSerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.kind() {
+            Kind::Undefined | Kind::None => visitor.visit_unit(),
+            Kind::Bool => visitor.visit_bool(self.as_bool()),
+            Kind::Int if self.is_bigint() => {
+                let value = self.to_bigint().unwrap_or_default();
+                match num_traits::ToPrimitive::to_i128(&value) {
+                    Some(value) => visitor.visit_i128(value),
+                    None => visitor.visit_string(value.to_string()),
+                }
+            }
+            Kind::Int => visitor.visit_i64(self.as_int()),
+            Kind::Decimal => visitor.visit_string(self.to_string()),
+            Kind::Float | Kind::Unit => visitor.visit_f64(self.as_float()),
+            Kind::Str => visitor.visit_string(self.as_str()),
+            Kind::List => {
+                let values = self.as_list_ref().values.clone();
+                visitor.visit_seq(de::value::SeqDeserializer::new(values.into_iter()))
+            }
+            Kind::Dict | Kind::Schema => visitor.visit_map(de::value::MapDeserializer::new(
+                self.dict_entries().into_iter(),
+            )),
+            _ => Err(self.invalid_type_error("a serializable value")),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_none_or_undefined() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.kind() {
+            Kind::Str => visitor.visit_enum(self.as_str().into_deserializer()),
+            Kind::Dict | Kind::Schema => {
+                let mut entries = self.dict_entries();
+                if entries.len() != 1 {
+                    return Err(self.invalid_type_error("a dict with a single variant key"));
+                }
+                let (variant, value) = entries.remove(0);
+                visitor.visit_enum(VariantDeserializer { variant, value })
+            }
+            _ => Err(self.invalid_type_error("an enum variant")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct VariantDeserializer {
+    variant: String,
+    value: ValueRef,
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer {
+    type Error = ValueSerdeError;
+    type Variant = ValueRef;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, ValueRef)> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueRef {
+    type Error = ValueSerdeError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod test_value_serde {
+    use crate::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Container {
+        name: String,
+        container_port: Option<u16>,
+        env: HashMap<String, String>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Strategy {
+        Recreate,
+        RollingUpdate { max_surge: i32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct App {
+        replicas: i64,
+        weight: f64,
+        enabled: bool,
+        containers: Vec<Container>,
+        strategy: Strategy,
+        labels: Option<Vec<String>>,
+    }
+
+    fn test_app() -> App {
+        App {
+            replicas: 2,
+            weight: 0.5,
+            enabled: true,
+            containers: vec![Container {
+                name: "nginx".to_string(),
+                container_port: Some(80),
+                env: HashMap::from([("A".to_string(), "1".to_string())]),
+            }],
+            strategy: Strategy::RollingUpdate { max_surge: 1 },
+            labels: None,
+        }
+    }
+
+    #[test]
+    fn test_to_value() {
+        let value = to_value(&test_app()).unwrap();
+        assert_eq!(
+            value.to_json_string(),
+            r#"{"replicas": 2, "weight": 0.5, "enabled": true, "containers": [{"name": "nginx", "containerPort": 80, "env": {"A": "1"}}], "strategy": {"RollingUpdate": {"max_surge": 1}}, "labels": null}"#
+        );
+        assert_eq!(
+            to_value(&Strategy::Recreate).unwrap(),
+            ValueRef::str("Recreate")
+        );
+        assert!(to_value(&u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_from_value() {
+        let mut ctx = Context::new();
+        let value = ValueRef::from_json(
+            &mut ctx,
+            r#"{"replicas": 2, "weight": 0.5, "enabled": true, "containers": [{"name": "nginx", "containerPort": 80, "env": {"A": "1"}}], "strategy": {"RollingUpdate": {"max_surge": 1}}}"#,
+        )
+        .unwrap();
+        let app: App = from_value(&value).unwrap();
+        assert_eq!(app, test_app());
+        // Round trip through the value.
+        let app: App = from_value(&to_value(&app).unwrap()).unwrap();
+        assert_eq!(app, test_app());
+
+        let err = from_value::<App>(&ValueRef::str("app")).unwrap_err();
+        assert!(err.to_string().contains("expected struct App"));
+    }
+
+    #[test]
+    fn test_value_ref_serde() {
+        let json = r#"{"a":[1,2.5,"s",true,null],"b":{"c":1}}"#;
+        let value: ValueRef = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            value.dict_get_value("b"),
+            Some(ValueRef::dict_int(&[("c", 1)]))
+        );
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+}
@@ -0,0 +1,139 @@
+//! Copyright The KCL Authors. All rights reserved.
+
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+/// SourceMapEntry denotes an output path e.g., `app.spec.replicas` or
+/// `app.containers[0].name` and the source locations that set it in order.
+#[derive(PartialEq, Eq, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct SourceMapEntry {
+    pub path: String,
+    pub sources: Vec<SourceLocation>,
+}
+
+impl ValueRef {
+    /// Returns the source map of the value. Source locations are only recorded
+    /// when the context config `provenance` is enabled.
+    pub fn source_map(&self, ctx: &Context) -> Vec<SourceMapEntry> {
+        let mut entries = vec![];
+        collect_source_map(ctx, self, "", &mut entries);
+        entries
+    }
+
+    /// Returns the source map of the value in the JSON format.
+    pub fn source_map_json_string(&self, ctx: &Context) -> String {
+        serde_json::to_string(&self.source_map(ctx)).unwrap_or_default()
+    }
+}
+
+fn collect_source_map(
+    ctx: &Context,
+    value: &ValueRef,
+    path: &str,
+    entries: &mut Vec<SourceMapEntry>,
+) {
+    match &*value.rc.borrow() {
+        Value::dict_value(dict) => collect_dict_source_map(ctx, dict, path, entries),
+        Value::schema_value(schema) => collect_dict_source_map(ctx, &schema.config, path, entries),
+        Value::list_value(list) => {
+            for (i, v) in list.values.iter().enumerate() {
+                collect_source_map(ctx, v, &format!("{path}[{i}]"), entries);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_dict_source_map(
+    ctx: &Context,
+    dict: &DictValue,
+    path: &str,
+    entries: &mut Vec<SourceMapEntry>,
+) {
+    for (key, value) in &dict.values {
+        // Omit the values which are not planned.
+        if key.starts_with(KCL_PRIVATE_VAR_PREFIX)
+            || value.is_undefined()
+            || value.is_func()
+            || (value.is_none() && ctx.cfg.disable_none)
+        {
+            continue;
+        }
+        let path = if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        };
+        if let Some(sources) = dict.sources.get(key) {
+            if !sources.is_empty() {
+                entries.push(SourceMapEntry {
+                    path: path.clone(),
+                    sources: sources.clone(),
+                });
+            }
+        }
+        collect_source_map(ctx, value, &path, entries);
+    }
+}
+
+#[cfg(test)]
+mod test_value_source_map {
+    use crate::*;
+
+    #[test]
+    fn test_source_map() {
+        let mut ctx = Context::new();
+        ctx.cfg.provenance = true;
+        let mut dict = ValueRef::dict(None);
+        ctx.set_kcl_location_info(None, Some("base.k"), Some(1), Some(0));
+        dict.dict_insert(
+            &mut ctx,
+            "app",
+            &ValueRef::dict(Some(&[("replicas", &ValueRef::int(1))])),
+            ConfigEntryOperationKind::Union,
+            -1,
+        );
+        let mut replicas = ValueRef::dict(None);
+        ctx.set_kcl_location_info(None, Some("prod.k"), Some(3), Some(0));
+        replicas.dict_insert(
+            &mut ctx,
+            "replicas",
+            &ValueRef::int(3),
+            ConfigEntryOperationKind::Override,
+            -1,
+        );
+        ctx.set_kcl_location_info(None, Some("prod.k"), Some(2), Some(0));
+        dict.dict_insert(
+            &mut ctx,
+            "app",
+            &replicas,
+            ConfigEntryOperationKind::Union,
+            -1,
+        );
+        let source_map = dict.source_map(&ctx);
+        let paths: Vec<&str> = source_map.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["app", "app.replicas"]);
+        let app_sources: Vec<(&str, i32)> = source_map[0]
+            .sources
+            .iter()
+            .map(|s| (s.filename.as_str(), s.line))
+            .collect();
+        assert_eq!(app_sources, vec![("base.k", 1), ("prod.k", 2)]);
+        assert_eq!(
+            source_map[1].sources,
+            vec![SourceLocation {
+                filename: "prod.k".to_string(),
+                line: 3,
+                op: "=".to_string(),
+            }]
+        );
+        assert_eq!(
+            dict.dict_get_value("app")
+                .unwrap()
+                .dict_get_value("replicas")
+                .unwrap()
+                .as_int(),
+            3
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Copyright The KCL Authors. All rights reserved.
 
+use std::collections::HashMap;
+
 use crate::*;
 use bstr::ByteSlice;
 use unic_ucd_bidi::BidiClass;
@@ -734,6 +736,289 @@ impl ValueRef {
         }
     }
 
+    /// Split the string at the first occurrence of sep, and return a list containing the part
+    /// before the separator, the separator itself, and the part after the separator.
+    pub fn str_partition(&self, sep: &ValueRef) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let sep = sep.as_str();
+                if sep.is_empty() {
+                    panic!("empty separator");
+                }
+                match v.split_once(sep.as_str()) {
+                    Some((head, tail)) => {
+                        ValueRef::list_str(&[head.to_string(), sep, tail.to_string()])
+                    }
+                    None => ValueRef::list_str(&[v.clone(), "".to_string(), "".to_string()]),
+                }
+            }
+            _ => panic!("Invalid str object in str_partition"),
+        }
+    }
+
+    /// Split the string at the last occurrence of sep, and return a list containing the part
+    /// before the separator, the separator itself, and the part after the separator.
+    pub fn str_rpartition(&self, sep: &ValueRef) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let sep = sep.as_str();
+                if sep.is_empty() {
+                    panic!("empty separator");
+                }
+                match v.rsplit_once(sep.as_str()) {
+                    Some((head, tail)) => {
+                        ValueRef::list_str(&[head.to_string(), sep, tail.to_string()])
+                    }
+                    None => ValueRef::list_str(&["".to_string(), "".to_string(), v.clone()]),
+                }
+            }
+            _ => panic!("Invalid str object in str_rpartition"),
+        }
+    }
+
+    /// Return a copy of the string left filled with ASCII '0' digits to make a string of length width.
+    /// A leading sign prefix ('+'/'-') is handled by inserting the padding after the sign character.
+    pub fn str_zfill(&self, width: &ValueRef) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let width = width.as_int();
+                let len = v.chars().count() as i64;
+                if width <= len {
+                    return ValueRef::str(v);
+                }
+                let fill = "0".repeat((width - len) as usize);
+                let value = match v.strip_prefix(&['+', '-'][..]) {
+                    Some(rest) => format!("{}{}{}", &v[..1], fill, rest),
+                    None => format!("{fill}{v}"),
+                };
+                ValueRef::str(&value)
+            }
+            _ => panic!("Invalid str object in str_zfill"),
+        }
+    }
+
+    /// Return the string left justified in a string of length width.
+    pub fn str_ljust(&self, width: &ValueRef, fillchar: Option<&ValueRef>) -> ValueRef {
+        self.str_pad("ljust", width, fillchar, |marg| (0, marg))
+    }
+
+    /// Return the string right justified in a string of length width.
+    pub fn str_rjust(&self, width: &ValueRef, fillchar: Option<&ValueRef>) -> ValueRef {
+        self.str_pad("rjust", width, fillchar, |marg| (marg, 0))
+    }
+
+    /// Return the string centered in a string of length width.
+    pub fn str_center(&self, width: &ValueRef, fillchar: Option<&ValueRef>) -> ValueRef {
+        let width_value = width.as_int();
+        // The same as CPython, the odd margin puts the extra fill char on the left
+        // when the width is odd.
+        self.str_pad("center", width, fillchar, |marg| {
+            let left = marg / 2 + (marg & width_value as usize & 1);
+            (left, marg - left)
+        })
+    }
+
+    fn str_pad<F>(
+        &self,
+        name: &str,
+        width: &ValueRef,
+        fillchar: Option<&ValueRef>,
+        margins: F,
+    ) -> ValueRef
+    where
+        F: Fn(usize) -> (usize, usize),
+    {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let fillchar = match fillchar {
+                    Some(fillchar) if !fillchar.is_none_or_undefined() => {
+                        let fillchar = fillchar.as_str();
+                        let mut chars = fillchar.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None) => c,
+                            _ => panic!("The fill character must be exactly one character long"),
+                        }
+                    }
+                    _ => ' ',
+                };
+                let width = width.as_int();
+                let len = v.chars().count() as i64;
+                if width <= len {
+                    return ValueRef::str(v);
+                }
+                let (left, right) = margins((width - len) as usize);
+                let fill = |n: usize| std::iter::repeat(fillchar).take(n).collect::<String>();
+                ValueRef::str(&format!("{}{}{}", fill(left), v, fill(right)))
+            }
+            _ => panic!("Invalid str object in str_{name}"),
+        }
+    }
+
+    /// Return a copy of the string where all tab characters are replaced by one or more spaces,
+    /// depending on the current column and the given tab size.
+    pub fn str_expandtabs(&self, tabsize: Option<&ValueRef>) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let tabsize = match tabsize {
+                    Some(tabsize) if !tabsize.is_none_or_undefined() => tabsize.as_int(),
+                    _ => 8,
+                };
+                let mut result = String::with_capacity(v.len());
+                let mut column: i64 = 0;
+                for c in v.chars() {
+                    match c {
+                        '\t' => {
+                            if tabsize > 0 {
+                                let spaces = tabsize - column % tabsize;
+                                result.push_str(&" ".repeat(spaces as usize));
+                                column += spaces;
+                            }
+                        }
+                        '\n' | '\r' => {
+                            result.push(c);
+                            column = 0;
+                        }
+                        _ => {
+                            result.push(c);
+                            column += 1;
+                        }
+                    }
+                }
+                ValueRef::str(&result)
+            }
+            _ => panic!("Invalid str object in str_expandtabs"),
+        }
+    }
+
+    /// Return a casefolded copy of the string. Casefolded strings may be used for caseless matching.
+    pub fn str_casefold(&self) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let mut result = String::with_capacity(v.len());
+                for c in v.chars() {
+                    // Full case folding mappings that differ from the lowercase mappings.
+                    match c {
+                        'ß' | 'ẞ' => result.push_str("ss"),
+                        'ς' => result.push('σ'),
+                        _ => result.extend(c.to_lowercase()),
+                    }
+                }
+                ValueRef::str(&result)
+            }
+            _ => panic!("Invalid str object in str_casefold"),
+        }
+    }
+
+    /// Return a copy of the string with uppercase characters converted to lowercase and vice versa.
+    pub fn str_swapcase(&self) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let mut result = String::with_capacity(v.len());
+                for c in v.chars() {
+                    if c.is_uppercase() {
+                        result.extend(c.to_lowercase());
+                    } else if c.is_lowercase() {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.push(c);
+                    }
+                }
+                ValueRef::str(&result)
+            }
+            _ => panic!("Invalid str object in str_swapcase"),
+        }
+    }
+
+    /// Return a copy of the string in which each character has been mapped through the given
+    /// translation table. The table keys are characters or their Unicode ordinals, and the values
+    /// are the replacement strings, Unicode ordinals or None to delete the characters.
+    pub fn str_translate(&self, table: &ValueRef) -> ValueRef {
+        if !table.is_config() {
+            panic!(
+                "translate() argument must be a dict, not '{}'",
+                table.type_str()
+            );
+        }
+        let mut mapping: HashMap<char, Option<String>> = HashMap::new();
+        for (key, value) in table.as_dict_ref().values.iter() {
+            let mut chars = key.chars();
+            let c = match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => match key.parse::<u32>().ok().and_then(char::from_u32) {
+                    Some(c) => c,
+                    None => panic!(
+                        "translate() table keys must be characters or Unicode ordinals, got '{key}'"
+                    ),
+                },
+            };
+            let replacement = if value.is_none_or_undefined() {
+                None
+            } else if value.is_int() {
+                match u32::try_from(value.as_int()).ok().and_then(char::from_u32) {
+                    Some(c) => Some(c.to_string()),
+                    None => panic!("character mapping must be in range(0x110000)"),
+                }
+            } else if value.is_str() {
+                Some(value.as_str())
+            } else {
+                panic!(
+                    "character mapping must return integer, None or str, not '{}'",
+                    value.type_str()
+                )
+            };
+            mapping.insert(c, replacement);
+        }
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let mut result = String::with_capacity(v.len());
+                for c in v.chars() {
+                    match mapping.get(&c) {
+                        Some(Some(replacement)) => result.push_str(replacement),
+                        Some(None) => {}
+                        None => result.push(c),
+                    }
+                }
+                ValueRef::str(&result)
+            }
+            _ => panic!("Invalid str object in str_translate"),
+        }
+    }
+
+    /// Return the string encoded to bytes as a list of integers in the range 0-255.
+    /// The supported encodings are 'utf-8' (the default), 'ascii' and 'latin-1'.
+    pub fn str_encode(&self, encoding: Option<&ValueRef>) -> ValueRef {
+        match &*self.rc.borrow() {
+            Value::str_value(ref v) => {
+                let encoding = match encoding {
+                    Some(encoding) if !encoding.is_none_or_undefined() => encoding.as_str(),
+                    _ => "utf-8".to_string(),
+                };
+                let limit = match encoding.to_lowercase().replace('_', "-").as_str() {
+                    "utf-8" | "utf8" => None,
+                    "ascii" | "us-ascii" => Some(0x80),
+                    "latin-1" | "latin1" | "iso-8859-1" => Some(0x100),
+                    _ => panic!("unknown encoding: {encoding}"),
+                };
+                let bytes: Vec<i64> = match limit {
+                    None => v.bytes().map(|b| b as i64).collect(),
+                    Some(limit) => v
+                        .chars()
+                        .enumerate()
+                        .map(|(i, c)| {
+                            if (c as u32) < limit {
+                                c as i64
+                            } else {
+                                panic!("'{encoding}' codec can't encode character '{c}' in position {i}: ordinal not in range({limit})")
+                            }
+                        })
+                        .collect(),
+                };
+                ValueRef::list_int(&bytes)
+            }
+            _ => panic!("Invalid str object in str_encode"),
+        }
+    }
+
     pub fn str_equal(&self, value: &str) -> bool {
         match &*self.rc.borrow() {
             Value::str_value(ref v) => *v == *value,
@@ -741,3 +1026,96 @@ impl ValueRef {
         }
     }
 }
+
+#[cfg(test)]
+mod test_value_str {
+    use crate::*;
+
+    fn s(v: &str) -> ValueRef {
+        ValueRef::str(v)
+    }
+
+    #[test]
+    fn test_str_partition() {
+        // The expected results are the same as Python.
+        let cases = [
+            (
+                "key.value.more",
+                ".",
+                ["key", ".", "value.more"],
+                ["key.value", ".", "more"],
+            ),
+            ("abc", ".", ["abc", "", ""], ["", "", "abc"]),
+        ];
+        for (value, sep, partition, rpartition) in cases {
+            let to_list = |v: [&str; 3]| ValueRef::list_str(&v.map(|x| x.to_string()));
+            assert_eq!(s(value).str_partition(&s(sep)), to_list(partition));
+            assert_eq!(s(value).str_rpartition(&s(sep)), to_list(rpartition));
+        }
+    }
+
+    #[test]
+    fn test_str_justify() {
+        let int = ValueRef::int;
+        assert_eq!(s("42").str_zfill(&int(5)), s("00042"));
+        assert_eq!(s("-42").str_zfill(&int(5)), s("-0042"));
+        assert_eq!(s("+abc").str_zfill(&int(6)), s("+00abc"));
+        assert_eq!(s("abc").str_zfill(&int(2)), s("abc"));
+        assert_eq!(s("ab").str_ljust(&int(5), Some(&s("*"))), s("ab***"));
+        assert_eq!(s("ab").str_rjust(&int(4), None), s("  ab"));
+        assert_eq!(s("abc").str_center(&int(6), Some(&s("x"))), s("xabcxx"));
+        assert_eq!(s("ab").str_center(&int(5), Some(&s("x"))), s("xxabx"));
+        assert_eq!(s("é").str_center(&int(3), Some(&s("x"))), s("xéx"));
+        assert_eq!(s("abc").str_center(&int(2), None), s("abc"));
+    }
+
+    #[test]
+    fn test_str_expandtabs() {
+        assert_eq!(s("a\tb").str_expandtabs(None), s("a       b"));
+        assert_eq!(
+            s("a\tbc\td").str_expandtabs(Some(&ValueRef::int(4))),
+            s("a   bc  d")
+        );
+        assert_eq!(
+            s("12\t3\n\t4").str_expandtabs(Some(&ValueRef::int(4))),
+            s("12  3\n    4")
+        );
+        assert_eq!(s("a\tb").str_expandtabs(Some(&ValueRef::int(0))), s("ab"));
+    }
+
+    #[test]
+    fn test_str_case() {
+        assert_eq!(s("Straße").str_casefold(), s("strasse"));
+        assert_eq!(s("HeLLo").str_casefold(), s("hello"));
+        assert_eq!(s("Hello World").str_swapcase(), s("hELLO wORLD"));
+        assert_eq!(s("ß").str_swapcase(), s("SS"));
+    }
+
+    #[test]
+    fn test_str_translate() {
+        let table = ValueRef::dict(Some(&[
+            ("h", &s("J")),
+            ("l", &ValueRef::none()),
+            ("o", &ValueRef::int(97)),
+            ("101", &s("3")),
+        ]));
+        assert_eq!(s("hello").str_translate(&table), s("J3a"));
+    }
+
+    #[test]
+    fn test_str_encode() {
+        assert_eq!(
+            s("hé").str_encode(None),
+            ValueRef::list_int(&[104, 195, 169])
+        );
+        assert_eq!(
+            s("hé").str_encode(Some(&s("latin-1"))),
+            ValueRef::list_int(&[104, 233])
+        );
+        assert_eq!(
+            s("abc").str_encode(Some(&s("ascii"))),
+            ValueRef::list_int(&[97, 98, 99])
+        );
+        assert!(std::panic::catch_unwind(|| s("é").str_encode(Some(&s("ascii")))).is_err());
+    }
+}
@@ -9,11 +9,13 @@ pub const BUILTIN_TYPE_INT: &str = "int";
 pub const BUILTIN_TYPE_FLOAT: &str = "float";
 pub const BUILTIN_TYPE_STR: &str = "str";
 pub const BUILTIN_TYPE_BOOL: &str = "bool";
-pub const BUILTIN_TYPES: [&str; 4] = [
+pub const BUILTIN_TYPE_DECIMAL: &str = "decimal";
+pub const BUILTIN_TYPES: [&str; 5] = [
     BUILTIN_TYPE_STR,
     BUILTIN_TYPE_BOOL,
     BUILTIN_TYPE_INT,
     BUILTIN_TYPE_FLOAT,
+    BUILTIN_TYPE_DECIMAL,
 ];
 pub const KCL_TYPE_NONE: &str = "NoneType";
 pub const KCL_TYPE_UNDEFINED: &str = "UndefinedType";
@@ -33,6 +35,7 @@ pub const KCL_NAME_CONSTANTS: [&str; 4] = [
     KCL_NAME_CONSTANT_FALSE,
 ];
 pub const NUMBER_MULTIPLIER_TYPE: &str = "units.NumberMultiplier";
+pub const QUANTITY_TYPE: &str = "units.Quantity";
 pub const NUMBER_MULTIPLIER_REGEX: &str =
     r"^([1-9][0-9]{0,63})(E|P|T|G|M|K|k|m|u|n|Ei|Pi|Ti|Gi|Mi|Ki)$";
 
@@ -50,7 +53,9 @@ impl ValueRef {
             Value::none => String::from(KCL_TYPE_NONE),
             Value::bool_value(..) => String::from(BUILTIN_TYPE_BOOL),
             Value::int_value(..) => String::from(BUILTIN_TYPE_INT),
+            Value::bigint_value(..) => String::from(BUILTIN_TYPE_INT),
             Value::float_value(..) => String::from(BUILTIN_TYPE_FLOAT),
+            Value::decimal_value(..) => String::from(BUILTIN_TYPE_DECIMAL),
             Value::unit_value(_, raw, suffix) => {
                 format!("{KCL_TYPE_NUMBER_MULTIPLY}({raw}{suffix})")
             }
@@ -204,6 +209,16 @@ pub fn convert_collection_value(ctx: &mut Context, value: &ValueRef, tpe: &str)
     if tpe.is_empty() || tpe == KCL_TYPE_ANY {
         return value.clone();
     }
+    // Convert a quantity string e.g., "500m" to the `units.Quantity` value.
+    if value.is_str() && tpe == QUANTITY_TYPE {
+        return quantity_from_value(value)
+            .and_then(|quantity| quantity_to_value(&quantity))
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+    // Convert an int value to the exact decimal value.
+    if value.is_int() && tpe == BUILTIN_TYPE_DECIMAL {
+        return value.convert_to_decimal();
+    }
     let is_collection = value.is_list() || value.is_dict();
     let invalid_match_dict = is_dict_type(&tpe) && !value.is_dict();
     let invalid_match_list = is_list_type(&tpe) && !value.is_list();
@@ -464,7 +479,7 @@ pub fn check_number_multiplier_type(value: &ValueRef, tpe: &str) -> bool {
             let (_, raw, suffix) = value.as_unit();
             return format!("{raw}{suffix}") == tpe;
         }
-        return tpe == NUMBER_MULTIPLIER_TYPE;
+        return tpe == NUMBER_MULTIPLIER_TYPE || tpe == QUANTITY_TYPE;
     }
     false
 }
@@ -509,7 +524,9 @@ pub fn check_type_list(value: &ValueRef, tpe: &str) -> bool {
 /// match_builtin_type returns the value wether match the given the type string
 #[inline]
 pub fn match_builtin_type(value: &ValueRef, tpe: &str) -> bool {
-    value.type_str() == *tpe || (value.type_str() == BUILTIN_TYPE_INT && tpe == BUILTIN_TYPE_FLOAT)
+    value.type_str() == *tpe
+        || (value.type_str() == BUILTIN_TYPE_INT
+            && (tpe == BUILTIN_TYPE_FLOAT || tpe == BUILTIN_TYPE_DECIMAL))
 }
 
 /// is_literal_type returns the type string whether is a literal type
@@ -774,14 +791,35 @@ mod test_value_type {
                 NUMBER_MULTIPLIER_TYPE,
                 true,
             ),
+            (ValueRef::unit(0.5, 500, "m"), QUANTITY_TYPE, true),
             // false cases
             (ValueRef::unit(1024.0, 1, "Ki"), "1Mi", false),
+            (ValueRef::str("500m"), QUANTITY_TYPE, false),
+            (ValueRef::str("500m"), NUMBER_MULTIPLIER_TYPE, false),
         ];
         for (value, tpe, expected) in cases {
             assert_eq!(check_number_multiplier_type(&value, tpe), expected);
         }
     }
 
+    #[test]
+    fn test_convert_quantity_value() {
+        let mut ctx = Context::new();
+        let cases = [
+            ("500m", QUANTITY_TYPE, ValueRef::unit(0.5, 500, "m")),
+            (
+                "1.5Gi",
+                QUANTITY_TYPE,
+                ValueRef::unit(1610612736.0, 1536, "Mi"),
+            ),
+            ("2", QUANTITY_TYPE, ValueRef::unit(2.0, 2, "")),
+        ];
+        for (value, tpe, expected) in cases {
+            let value = type_pack_and_check(&mut ctx, &ValueRef::str(value), vec![tpe]);
+            assert_eq!(value.as_unit(), expected.as_unit());
+        }
+    }
+
     #[test]
     fn test_check_type_dict() {
         let cases = [
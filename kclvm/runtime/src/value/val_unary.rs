@@ -7,7 +7,9 @@ impl ValueRef {
     pub fn unary_plus(&self) -> Self {
         match &*self.rc.borrow() {
             Value::int_value(ref a) => Self::int(*a),
+            Value::bigint_value(ref a) => Self::bigint(a.clone()),
             Value::float_value(ref a) => Self::float(*a),
+            Value::decimal_value(ref a) => Self::decimal(a.clone()),
             _ => panic!("bad operand type for unary +: '{}'", self.type_str()),
         }
     }
@@ -16,13 +18,18 @@ impl ValueRef {
     pub fn unary_minus(&self) -> Self {
         match &*self.rc.borrow() {
             Value::int_value(ref a) => Self::int(0 - *a),
+            Value::bigint_value(ref a) => Self::bigint(-a),
             Value::float_value(ref a) => Self::float(0.0 - *a),
+            Value::decimal_value(ref a) => Self::decimal(a.neg()),
             _ => panic!("bad operand type for unary -: '{}'", self.type_str()),
         }
     }
 
     // ~ x
     pub fn unary_not(&self) -> Self {
+        if let Value::bigint_value(ref a) = &*self.rc.borrow() {
+            return Self::bigint(-a - 1);
+        }
         Self::int(!self.as_int())
     }
 
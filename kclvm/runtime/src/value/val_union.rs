@@ -55,6 +55,13 @@ impl ValueRef {
             for (k, v) in &delta.insert_indexs {
                 obj.insert_indexs.insert(k.clone(), *v);
             }
+            // Append source locations in order
+            for (k, v) in &delta.sources {
+                obj.sources
+                    .entry(k.clone())
+                    .or_default()
+                    .extend(v.iter().cloned());
+            }
             for (k, v) in &delta.values {
                 let operation = if let Some(op) = delta.ops.get(k) {
                     op
@@ -71,18 +71,14 @@ impl ValueRef {
     }
 
     pub fn to_yaml(&self) -> Vec<u8> {
-        let json = self.to_json_string();
-        let yaml_value: serde_yaml::Value = serde_json::from_str(json.as_ref()).unwrap();
-        match serde_yaml::to_string(&yaml_value) {
+        match self.encode_yaml(&Default::default()) {
             Ok(s) => s.into_bytes(),
             _ => Vec::new(),
         }
     }
 
     pub fn to_yaml_string(&self) -> String {
-        let json = self.to_json_string();
-        let yaml_value: serde_yaml::Value = serde_json::from_str(json.as_ref()).unwrap();
-        match serde_yaml::to_string(&yaml_value) {
+        match self.encode_yaml(&Default::default()) {
             Ok(s) => {
                 let s = s.strip_prefix("---\n").unwrap_or_else(|| s.as_ref());
                 s.to_string()
@@ -100,9 +96,7 @@ impl ValueRef {
             ignore_private: opt.ignore_private,
             ignore_none: opt.ignore_none,
         };
-        let json = self.to_json_string_with_option(&json_opt);
-        let yaml_value: serde_yaml::Value = serde_json::from_str(json.as_ref()).unwrap();
-        match serde_yaml::to_string(&yaml_value) {
+        match self.encode_yaml(&json_opt) {
             Ok(s) => {
                 let s = s.strip_prefix("---\n").unwrap_or_else(|| s.as_ref());
                 s.to_string()
@@ -110,6 +104,31 @@ impl ValueRef {
             Err(err) => panic!("{}", err),
         }
     }
+
+    fn encode_yaml(&self, opt: &JsonEncodeOptions) -> Result<String, serde_yaml::Error> {
+        if self.contains_bigint() {
+            // Serialize the JsonValue directly to keep the big integers which can't be
+            // represented by the YAML value numbers.
+            serde_yaml::to_string(&self.build_json(opt))
+        } else {
+            let json = self.to_json_string_with_option(opt);
+            let yaml_value: serde_yaml::Value = serde_json::from_str(json.as_ref()).unwrap();
+            serde_yaml::to_string(&yaml_value)
+        }
+    }
+
+    /// Whether the value is or contains a big integer.
+    fn contains_bigint(&self) -> bool {
+        match &*self.rc.borrow() {
+            Value::bigint_value(_) => true,
+            Value::list_value(list) => list.values.iter().any(|v| v.contains_bigint()),
+            Value::dict_value(dict) => dict.values.values().any(|v| v.contains_bigint()),
+            Value::schema_value(schema) => {
+                schema.config.values.values().any(|v| v.contains_bigint())
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -227,6 +246,16 @@ mod test_value_yaml {
         }
     }
 
+    #[test]
+    fn test_value_yaml_big_int() {
+        let mut ctx = Context::new();
+        ctx.cfg.big_int = true;
+        let yaml_str = "a: 1\nb: 123456789012345678901234567890\nc:\n- -9223372036854775809\n- s\n";
+        let value = ValueRef::from_yaml(&mut ctx, yaml_str).unwrap();
+        assert!(value.dict_get_value("b").unwrap().is_bigint());
+        assert_eq!(value.to_yaml_string(), yaml_str);
+    }
+
     #[test]
     fn test_value_to_yaml_string_with_opts() {
         let cases = [
@@ -884,6 +884,13 @@ impl<'ctx> AdvancedResolver<'ctx> {
                         let mut unresolved =
                             UnresolvedSymbol::new(name.node.clone(), start_pos, end_pos, None);
                         unresolved.def = Some(def_symbol_ref);
+                        // Record the attribute type at the reference, which is substituted
+                        // with the type arguments for the generic schema instances.
+                        unresolved.sema_info.ty = self
+                            .ctx
+                            .node_ty_map
+                            .get(&self.ctx.get_node_key(&name.id))
+                            .cloned();
                         let unresolved_ref = self
                             .gs
                             .get_symbols_mut()
@@ -1058,6 +1065,12 @@ impl<'ctx> AdvancedResolver<'ctx> {
                         self.walk_type_expr(Some(&ret_ty));
                     }
                 }
+                ast::Type::Generic(generic_type) => {
+                    self.walk_identifier(&generic_type.name);
+                    for type_arg in generic_type.type_args.iter() {
+                        self.walk_type_expr(Some(type_arg));
+                    }
+                }
             }
         }
         None
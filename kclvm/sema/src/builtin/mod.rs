@@ -251,6 +251,22 @@ register_builtin! {
                 ty: Arc::new(Type::BOOL),
                 has_default: true,
             },
+            Parameter {
+                name: "key".to_string(),
+                ty: Arc::new(Type::function(
+                    None,
+                    Arc::new(Type::ANY),
+                    &[Parameter {
+                        name: "item".to_string(),
+                        ty: Arc::new(Type::ANY),
+                        has_default: false,
+                    }],
+                    "",
+                    false,
+                    None,
+                )),
+                has_default: true,
+            },
         ],
         r#"Return a new list containing all items from the iterable in ascending order.
 
@@ -420,6 +436,20 @@ register_builtin! {
         false,
         None,
     )
+    decimal => Type::function(
+        None,
+        Arc::new(Type::DECIMAL),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Arc::new(Type::ANY),
+                has_default: true,
+            },
+        ],
+        r#"Convert a string or number to an exact decimal number e.g., `decimal("0.1")`, which is `0` when no argument is given. Decimals are encoded as strings in the JSON and YAML results to keep the exact values, so they are decoded as strings."#,
+        false,
+        None,
+    )
     bool => Type::function(
         None,
         Arc::new(Type::BOOL),
@@ -441,4 +441,161 @@ register_string_member! {
         false,
         None,
     )
+    partition => Type::function(
+        Some(Arc::new(Type::STR)),
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "sep".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Split the string at the first occurrence of sep, and return a list containing the part before the separator, the separator itself, and the part after the separator. If the separator is not found, return a list containing the string itself, followed by two empty strings."#,
+        false,
+        None,
+    )
+    rpartition => Type::function(
+        Some(Arc::new(Type::STR)),
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "sep".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Split the string at the last occurrence of sep, and return a list containing the part before the separator, the separator itself, and the part after the separator. If the separator is not found, return a list containing two empty strings, followed by the string itself."#,
+        false,
+        None,
+    )
+    zfill => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "width".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return a copy of the string left filled with ASCII '0' digits to make a string of length width. A leading sign prefix ('+'/'-') is handled by inserting the padding after the sign character rather than before. The original string is returned if width is less than or equal to len(s)."#,
+        false,
+        None,
+    )
+    ljust => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "width".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "fillchar".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the string left justified in a string of length width. Padding is done using the specified fillchar (default is an ASCII space). The original string is returned if width is less than or equal to len(s)."#,
+        false,
+        None,
+    )
+    rjust => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "width".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "fillchar".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the string right justified in a string of length width. Padding is done using the specified fillchar (default is an ASCII space). The original string is returned if width is less than or equal to len(s)."#,
+        false,
+        None,
+    )
+    center => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "width".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "fillchar".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return centered in a string of length width. Padding is done using the specified fillchar (default is an ASCII space). The original string is returned if width is less than or equal to len(s)."#,
+        false,
+        None,
+    )
+    expandtabs => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "tabsize".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return a copy of the string where all tab characters are replaced by one or more spaces, depending on the current column and the given tab size (default is 8)."#,
+        false,
+        None,
+    )
+    casefold => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[],
+        r#"Return a casefolded copy of the string. Casefolded strings may be used for caseless matching."#,
+        false,
+        None,
+    )
+    swapcase => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[],
+        r#"Return a copy of the string with uppercase characters converted to lowercase and vice versa."#,
+        false,
+        None,
+    )
+    translate => Type::function(
+        Some(Arc::new(Type::STR)),
+        Arc::new(Type::STR),
+        &[
+            Parameter {
+                name: "table".to_string(),
+                ty: Type::dict_ref(Type::str_ref(), Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Return a copy of the string in which each character has been mapped through the given translation table. The table keys are characters or their Unicode ordinals, and the values are strings, Unicode ordinals or None to delete the characters from the return string."#,
+        false,
+        None,
+    )
+    encode => Type::function(
+        Some(Arc::new(Type::STR)),
+        Type::list_ref(Type::int_ref()),
+        &[
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the string encoded to bytes as a list of integers. The encoding is one of 'utf-8' (the default), 'ascii' and 'latin-1'."#,
+        false,
+        None,
+    )
 }
@@ -84,6 +84,15 @@ pub const NET_FUNCTION_NAMES: &[&str] = &[
     "is_link_local_unicast_IP",
     "is_global_unicast_IP",
     "is_unspecified_IP",
+    "parse_CIDR",
+    "CIDR_contains",
+    "CIDR_subnets",
+    "CIDR_subnet",
+    "CIDR_host",
+    "CIDR_hosts",
+    "CIDR_overlaps",
+    "IP_to_int",
+    "int_to_IP",
 ];
 macro_rules! register_net_member {
     ($($name:ident => $ty:expr)*) => (
@@ -324,6 +333,167 @@ register_net_member! {
         false,
         None,
     )
+    parse_CIDR => Type::function(
+        None,
+        Type::dict_ref(Type::str_ref(), Type::any_ref()),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Parse the CIDR notation `cidr` e.g., `10.0.0.0/16`, and return a dict with the `ip`, `network`, `prefix`, `netmask`, `first` and `last` addresses, and the IP `version`."#,
+        false,
+        None,
+    )
+    CIDR_contains => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "ip".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Whether the network `cidr` contains the IP address or the CIDR network `ip`."#,
+        false,
+        None,
+    )
+    CIDR_subnets => Type::function(
+        None,
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "newbits".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Split the network `cidr` into all the subnets with the prefix length extended by `newbits`, e.g., `CIDR_subnets("10.0.0.0/16", 8)` returns the 256 `/24` subnets."#,
+        false,
+        None,
+    )
+    CIDR_subnet => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "newbits".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "netnum".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return the `netnum`-th subnet of the network `cidr` with the prefix length extended by `newbits`, e.g., `CIDR_subnet("10.0.0.0/16", 8, 2)` returns `10.0.2.0/24`."#,
+        false,
+        None,
+    )
+    CIDR_host => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "hostnum".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return the `hostnum`-th IP address of the network `cidr`, and the negative number counts backwards from the last address."#,
+        false,
+        None,
+    )
+    CIDR_hosts => Type::function(
+        None,
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return all the host IP addresses of the network `cidr`. The network and broadcast addresses of the IPv4 networks larger than `/31` are excluded."#,
+        false,
+        None,
+    )
+    CIDR_overlaps => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "cidrs".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::list_ref(Type::str_ref())]),
+                has_default: false,
+            },
+            Parameter {
+                name: "others".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::list_ref(Type::str_ref())]),
+                has_default: true,
+            },
+        ],
+        r#"Whether any two networks in `cidrs` overlap, or any network in `cidrs` overlaps with any network in `others` when `others` is given."#,
+        false,
+        None,
+    )
+    IP_to_int => Type::function(
+        None,
+        Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+        &[
+            Parameter {
+                name: "ip".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Convert the IP address to the integer. IPv6 addresses are returned as decimal strings because they may exceed the int range."#,
+        false,
+        None,
+    )
+    int_to_IP => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: false,
+            },
+            Parameter {
+                name: "version".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Convert the integer or the decimal string `value` to the IP address of the IP `version` 4 or 6, which defaults to 4."#,
+        false,
+        None,
+    )
 }
 
 // ------------------------------
@@ -649,7 +819,23 @@ register_math_member! {
 // ------------------------------
 
 pub const DATETIME: &str = "datetime";
-pub const DATETIME_FUNCTION_NAMES: [&str; 4] = ["today", "now", "ticks", "date"];
+pub const DATETIME_FUNCTION_NAMES: [&str; 15] = [
+    "today",
+    "now",
+    "ticks",
+    "date",
+    "parse",
+    "format",
+    "validate",
+    "timestamp",
+    "from_timestamp",
+    "to_timezone",
+    "duration",
+    "add",
+    "sub",
+    "diff",
+    "compare",
+];
 macro_rules! register_datetime_member {
     ($($name:ident => $ty:expr)*) => (
         pub const DATETIME_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
@@ -679,8 +865,14 @@ register_datetime_member! {
     now => Type::function(
         None,
         Type::str_ref(),
-        &[],
-        r#"Return the local time. e.g. 'Sat Jun 06 16:26:11 1998'."#,
+        &[
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the local time. e.g. 'Sat Jun 06 16:26:11 1998'. When `format` is given, return the time in the strftime format, e.g., `%Y-%m-%d`."#,
         false,
         None,
     )
@@ -692,191 +884,400 @@ register_datetime_member! {
         false,
         None,
     )
-}
-
-// ------------------------------
-// regex system package
-// ------------------------------
-
-pub const REGEX: &str = "regex";
-pub const REGEX_FUNCTION_NAMES: &[&str] =
-    &["replace", "match", "compile", "findall", "search", "split"];
-macro_rules! register_regex_member {
-    ($($name:ident => $ty:expr)*) => (
-        pub const REGEX_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
-            let mut builtin_mapping = IndexMap::default();
-            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
-            builtin_mapping
-        });
-    )
-}
-register_regex_member! {
-    replace => Type::function(
+    parse => Type::function(
         None,
         Type::str_ref(),
         &[
             Parameter {
-                name: "string".to_string(),
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "pattern".to_string(),
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "tz".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Parse the `date` string in the strftime `format`, or in the RFC 3339 and `%Y-%m-%d` formats when `format` is not given, and return the RFC 3339 datetime string. Datetimes without the UTC offset are in the time zone `tz`, which defaults to `UTC`."#,
+        false,
+        None,
+    )
+    format => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "replace".to_string(),
+                name: "format".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "count".to_string(),
-                ty: Type::int_ref(),
+                name: "tz".to_string(),
+                ty: Type::str_ref(),
                 has_default: true,
             },
         ],
-        r#"Return the string obtained by replacing the leftmost non-overlapping occurrences of the pattern in string by the replacement."#,
+        r#"Format the RFC 3339 `date` string in the strftime `format`, e.g., `%Y-%m-%d %H:%M:%S`, optionally converted to the time zone `tz`."#,
         false,
         None,
     )
-    match => Type::function(
+    validate => Type::function(
         None,
         Type::bool_ref(),
         &[
             Parameter {
-                name: "string".to_string(),
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "pattern".to_string(),
+                name: "format".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Try to apply the pattern at the start of the string, returning a bool value `True` if any match was found, or `False` if no match was found."#,
+        r#"Return `True` if the `date` string matches the strftime `format`, otherwise `False`."#,
         false,
         None,
     )
-    compile => Type::function(
+    timestamp => Type::function(
         None,
-        Type::bool_ref(),
+        Type::float_ref(),
         &[
             Parameter {
-                name: "pattern".to_string(),
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Compile a regular expression pattern, returning a bool value denoting whether the pattern is valid."#,
+        r#"Return the seconds since the Epoch of the RFC 3339 `date` string."#,
         false,
         None,
     )
-    findall => Type::function(
+    from_timestamp => Type::function(
         None,
-        Type::list_ref(Type::str_ref()),
+        Type::str_ref(),
         &[
             Parameter {
-                name: "string".to_string(),
-                ty: Type::str_ref(),
+                name: "timestamp".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::float_ref()]),
                 has_default: false,
             },
             Parameter {
-                name: "pattern".to_string(),
+                name: "tz".to_string(),
                 ty: Type::str_ref(),
-                has_default: false,
+                has_default: true,
             },
         ],
-        r#"Return a list of all non-overlapping matches in the string."#,
+        r#"Return the RFC 3339 datetime string of the seconds since the Epoch `timestamp` in the time zone `tz`, which defaults to `UTC`."#,
         false,
         None,
     )
-    search => Type::function(
+    to_timezone => Type::function(
         None,
-        Type::bool_ref(),
+        Type::str_ref(),
         &[
             Parameter {
-                name: "string".to_string(),
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "pattern".to_string(),
+                name: "tz".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Scan through string looking for a match to the pattern, returning a bool value `True` if any match was found, or `False` if no match was found."#,
+        r#"Convert the RFC 3339 `date` string to the time zone `tz`, e.g., `UTC`, `Local`, `Asia/Shanghai` or `+08:00`."#,
         false,
         None,
     )
-    split => Type::function(
+    duration => Type::function(
         None,
-        Type::list_ref(Type::str_ref()),
+        Type::float_ref(),
         &[
             Parameter {
-                name: "string".to_string(),
+                name: "value".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
+        ],
+        r#"Return the seconds of the duration string, e.g., `72h`, `1h30m`, `500ms` or the ISO 8601 duration `P1DT12H`."#,
+        false,
+        None,
+    )
+    add => Type::function(
+        None,
+        Type::str_ref(),
+        &[
             Parameter {
-                name: "pattern".to_string(),
+                name: "date".to_string(),
                 ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "maxsplit".to_string(),
-                ty: Type::int_ref(),
-                has_default: true,
+                name: "duration".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::int_ref(), Type::float_ref()]),
+                has_default: false,
             },
         ],
-        r#"Return a list composed of words from the string, splitting up to a maximum of `maxsplit` times using `pattern` as the separator."#,
+        r#"Add the `duration` string or seconds to the RFC 3339 `date` string. The ISO 8601 years and months are added in calendar months, e.g., `P1M`."#,
         false,
         None,
     )
-}
-
-// ------------------------------
-// yaml system package
-// ------------------------------
-
-pub const YAML: &str = "yaml";
-pub const YAML_FUNCTION_NAMES: &[&str] = &[
-    "encode",
-    "encode_all",
-    "decode",
-    "decode_all",
-    "dump_to_file",
-    "dump_all_to_file",
-    "validate",
-];
-macro_rules! register_yaml_member {
-    ($($name:ident => $ty:expr)*) => (
-        pub const YAML_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
-            let mut builtin_mapping = IndexMap::default();
-            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
-            builtin_mapping
-        });
-    )
-}
-register_yaml_member! {
-    encode => Type::function(
+    sub => Type::function(
         None,
         Type::str_ref(),
         &[
             Parameter {
-                name: "data".to_string(),
-                ty: Type::any_ref(),
+                name: "date".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "sort_keys".to_string(),
-                ty: Type::bool_ref(),
-                has_default: true,
+                name: "duration".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::int_ref(), Type::float_ref()]),
+                has_default: false,
             },
-            Parameter {
-                name: "ignore_private".to_string(),
+        ],
+        r#"Subtract the `duration` string or seconds from the RFC 3339 `date` string. The ISO 8601 years and months are subtracted in calendar months, e.g., `P1M`."#,
+        false,
+        None,
+    )
+    diff => Type::function(
+        None,
+        Type::float_ref(),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return the seconds from the RFC 3339 datetime `b` to `a`."#,
+        false,
+        None,
+    )
+    compare => Type::function(
+        None,
+        Type::int_ref(),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Compare the RFC 3339 datetimes `a` and `b`, and return -1, 0 or 1 when `a` is earlier than, equal to or later than `b`."#,
+        false,
+        None,
+    )
+}
+
+// ------------------------------
+// regex system package
+// ------------------------------
+
+pub const REGEX: &str = "regex";
+pub const REGEX_FUNCTION_NAMES: &[&str] =
+    &["replace", "match", "compile", "findall", "search", "split"];
+macro_rules! register_regex_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const REGEX_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_regex_member! {
+    replace => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "replace".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "count".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the string obtained by replacing the leftmost non-overlapping occurrences of the pattern in string by the replacement."#,
+        false,
+        None,
+    )
+    match => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Try to apply the pattern at the start of the string, returning a bool value `True` if any match was found, or `False` if no match was found."#,
+        false,
+        None,
+    )
+    compile => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Compile a regular expression pattern, returning a bool value denoting whether the pattern is valid."#,
+        false,
+        None,
+    )
+    findall => Type::function(
+        None,
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return a list of all non-overlapping matches in the string."#,
+        false,
+        None,
+    )
+    search => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Scan through string looking for a match to the pattern, returning a bool value `True` if any match was found, or `False` if no match was found."#,
+        false,
+        None,
+    )
+    split => Type::function(
+        None,
+        Type::list_ref(Type::str_ref()),
+        &[
+            Parameter {
+                name: "string".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pattern".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "maxsplit".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return a list composed of words from the string, splitting up to a maximum of `maxsplit` times using `pattern` as the separator."#,
+        false,
+        None,
+    )
+}
+
+// ------------------------------
+// yaml system package
+// ------------------------------
+
+pub const YAML: &str = "yaml";
+pub const YAML_FUNCTION_NAMES: &[&str] = &[
+    "encode",
+    "encode_all",
+    "decode",
+    "decode_all",
+    "dump_to_file",
+    "dump_all_to_file",
+    "validate",
+];
+macro_rules! register_yaml_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const YAML_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_yaml_member! {
+    encode => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "sort_keys".to_string(),
+                ty: Type::bool_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "ignore_private".to_string(),
                 ty: Type::bool_ref(),
                 has_default: true,
             },
@@ -1036,7 +1437,16 @@ register_yaml_member! {
 // ------------------------------
 
 pub const JSON: &str = "json";
-pub const JSON_FUNCTION_NAMES: &[&str] = &["encode", "decode", "dump_to_file", "validate"];
+pub const JSON_FUNCTION_NAMES: &[&str] = &[
+    "encode",
+    "decode",
+    "dump_to_file",
+    "validate",
+    "patch",
+    "merge_patch",
+    "diff",
+    "pointer",
+];
 macro_rules! register_json_member {
     ($($name:ident => $ty:expr)*) => (
         pub const JSON_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
@@ -1148,44 +1558,150 @@ register_json_member! {
         false,
         None,
     )
-}
-
-// ------------------------------
-// crypto system package
-// ------------------------------
-
-pub const CRYPTO: &str = "crypto";
-pub const CRYPTO_FUNCTION_NAMES: &[&str] = &["md5", "sha1", "sha224", "sha256", "sha384", "sha512"];
-macro_rules! register_crypto_member {
-    ($($name:ident => $ty:expr)*) => (
-        pub const CRYPTO_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
-            let mut builtin_mapping = IndexMap::default();
-            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
-            builtin_mapping
-        });
-    )
-}
-register_crypto_member! {
-    md5 => Type::function(
+    patch => Type::function(
         None,
-        Type::str_ref(),
+        Type::any_ref(),
         &[
             Parameter {
-                name: "value".to_string(),
-                ty: Type::str_ref(),
+                name: "doc".to_string(),
+                ty: Type::any_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "encoding".to_string(),
-                ty: Type::str_ref(),
-                has_default: true,
+                name: "ops".to_string(),
+                ty: Type::list_ref(Type::dict_ref(Type::str_ref(), Type::any_ref())),
+                has_default: false,
             },
         ],
-        r#"Encrypt the string `value` using `MD5` and the codec registered for encoding."#,
+        r#"Apply the JSON Patch (RFC 6902) operations `ops` e.g., `[{op = "replace", path = "/spec/replicas", value = 3}]` to a copy of the `doc`. The supported operations are "add", "remove", "replace", "move", "copy" and "test", and the replaced keys keep their positions."#,
         false,
         None,
     )
-    sha1 => Type::function(
+    merge_patch => Type::function(
+        None,
+        Type::any_ref(),
+        &[
+            Parameter {
+                name: "doc".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "patch".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Merge the JSON Merge Patch (RFC 7386) `patch` into a copy of the `doc`, the None values in the `patch` remove the keys."#,
+        false,
+        None,
+    )
+    diff => Type::function(
+        None,
+        Type::list_ref(Type::dict_ref(Type::str_ref(), Type::any_ref())),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Return the JSON Patch (RFC 6902) operations which transform `a` into `b`."#,
+        false,
+        None,
+    )
+    pointer => Type::function(
+        None,
+        Type::any_ref(),
+        &[
+            Parameter {
+                name: "doc".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "pointer".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "default".to_string(),
+                ty: Type::any_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Return the value in the `doc` referenced by the JSON Pointer (RFC 6901) e.g., "/a/0/b", or the `default` value when it does not exist."#,
+        false,
+        None,
+    )
+}
+
+// ------------------------------
+// crypto system package
+// ------------------------------
+
+pub const CRYPTO: &str = "crypto";
+pub const CRYPTO_FUNCTION_NAMES: &[&str] = &[
+    "md5",
+    "sha1",
+    "sha224",
+    "sha256",
+    "sha384",
+    "sha512",
+    "sha3_224",
+    "sha3_256",
+    "sha3_384",
+    "sha3_512",
+    "blake3",
+    "filesha256",
+    "filesha512",
+    "fileblake3",
+    "hmac",
+    "bcrypt",
+    "bcrypt_verify",
+    "argon2",
+    "argon2_verify",
+];
+macro_rules! register_crypto_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const CRYPTO_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_crypto_member! {
+    md5 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `MD5` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha1 => Type::function(
         None,
         Type::str_ref(),
         &[
@@ -1199,8 +1715,13 @@ register_crypto_member! {
                 ty: Type::str_ref(),
                 has_default: true,
             },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
         ],
-        r#"Encrypt the string `value` using `SHA1` and the codec registered for encoding."#,
+        r#"Encrypt the string `value` using `SHA1` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
         false,
         None,
     )
@@ -1218,8 +1739,13 @@ register_crypto_member! {
                 ty: Type::str_ref(),
                 has_default: true,
             },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
         ],
-        r#"Encrypt the string `value` using `SHA224` and the codec registered for encoding."#,
+        r#"Encrypt the string `value` using `SHA224` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
         false,
         None,
     )
@@ -1228,329 +1754,1360 @@ register_crypto_member! {
         Type::str_ref(),
         &[
             Parameter {
-                name: "value".to_string(),
-                ty: Type::str_ref(),
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA256` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha384 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA384` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha512 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA512` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha3_224 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA3-224` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha3_256 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA3-256` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha3_384 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA3-384` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    sha3_512 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `SHA3-512` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    blake3 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Encrypt the string `value` using `BLAKE3` and the codec registered for encoding, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    filesha256 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "filepath".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Calculate the `SHA256` digest of the file content at `filepath`, relative paths are resolved against the working directory, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    filesha512 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "filepath".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Calculate the `SHA512` digest of the file content at `filepath`, relative paths are resolved against the working directory, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    fileblake3 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "filepath".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Calculate the `BLAKE3` digest of the file content at `filepath`, relative paths are resolved against the working directory, the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    hmac => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "key".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "algorithm".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "format".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Calculate the HMAC of the string `value` with the secret `key` using the hash `algorithm` (default "sha256"), which is one of "md5", "sha1", "sha224", "sha256", "sha384", "sha512", "sha3_224", "sha3_256", "sha3_384", "sha3_512" and "blake3", the digest is encoded in the `format` "hex", "base64" or "base64url"."#,
+        false,
+        None,
+    )
+    bcrypt => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "cost".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
+        ],
+        r#"Hash the password `value` using `bcrypt` with the `cost` (default 10) in the `$2y$` format used by htpasswd. The salt is read from the operating system, or generated from `seed` when it is given to reproduce the same hash."#,
+        false,
+        None,
+    )
+    bcrypt_verify => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "hash".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Whether the password `value` matches the `bcrypt` `hash`."#,
+        false,
+        None,
+    )
+    argon2 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
+        ],
+        r#"Hash the password `value` using `Argon2id` in the PHC string format. The salt is read from the operating system, or generated from `seed` when it is given to reproduce the same hash."#,
+        false,
+        None,
+    )
+    argon2_verify => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "hash".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Whether the password `value` matches the `Argon2` `hash` in the PHC string format."#,
+        false,
+        None,
+    )
+}
+
+// ------------------------------
+// units system package
+// ------------------------------
+
+pub const UNITS: &str = "units";
+pub const UNITS_FUNCTION_NAMES: &[&str] = &[
+    "to_n",
+    "to_u",
+    "to_m",
+    "to_K",
+    "to_M",
+    "to_G",
+    "to_T",
+    "to_P",
+    "to_Ki",
+    "to_Mi",
+    "to_Gi",
+    "to_Ti",
+    "to_Pi",
+    "parse_quantity",
+    "format_quantity",
+    "add_quantity",
+    "sub_quantity",
+    "scale_quantity",
+    "compare_quantity",
+    "sum_quantities",
+];
+pub const UNITS_NUMBER_MULTIPLIER: &str = "NumberMultiplier";
+/// The quantity type is the number multiplier type which also accepts the quantity
+/// strings e.g., "500m" and "1.5Gi".
+pub const UNITS_QUANTITY: &str = "Quantity";
+
+/// The quantity argument type of the units functions.
+fn quantity_ty() -> TypeRef {
+    Type::union_ref(&[
+        Type::str_ref(),
+        Type::int_ref(),
+        Type::float_ref(),
+        Arc::new(Type::number_multiplier_non_lit_ty()),
+    ])
+}
+pub const UNITS_FIELD_NAMES: &[&str] = &[
+    "n",
+    "u",
+    "m",
+    "k",
+    "K",
+    "M",
+    "G",
+    "T",
+    "P",
+    "Ki",
+    "Mi",
+    "Gi",
+    "Ti",
+    "Pi",
+    UNITS_NUMBER_MULTIPLIER,
+    UNITS_QUANTITY,
+];
+macro_rules! register_units_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const UNITS_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_units_member! {
+    n => Type::INT
+    u => Type::INT
+    m => Type::INT
+    k => Type::INT
+    K => Type::INT
+    M => Type::INT
+    G => Type::INT
+    T => Type::INT
+    P => Type::INT
+    Ki => Type::INT
+    Mi => Type::INT
+    Gi => Type::INT
+    Ti => Type::INT
+    Pi => Type::INT
+    to_n => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `n` suffix."#,
+        false,
+        None,
+    )
+    to_u => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `u` suffix."#,
+        false,
+        None,
+    )
+    to_m => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `m` suffix."#,
+        false,
+        None,
+    )
+    to_K => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `K` suffix."#,
+        false,
+        None,
+    )
+    to_M => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `M` suffix."#,
+        false,
+        None,
+    )
+    to_G => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `G` suffix."#,
+        false,
+        None,
+    )
+    to_T => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `T` suffix."#,
+        false,
+        None,
+    )
+    to_P => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `P` suffix."#,
+        false,
+        None,
+    )
+    to_Ki => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Ki` suffix."#,
+        false,
+        None,
+    )
+    to_Mi => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Mi` suffix."#,
+        false,
+        None,
+    )
+    to_Gi => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Gi` suffix."#,
+        false,
+        None,
+    )
+    to_Ti => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Ti` suffix."#,
+        false,
+        None,
+    )
+    to_Pi => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+        ],
+        r#"Int literal to string with `Pi` suffix."#,
+        false,
+        None,
+    )
+    parse_quantity => Type::function(
+        None,
+        Type::float_ref(),
+        &[
+            Parameter {
+                name: "quantity".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+        ],
+        r#"Parse the quantity e.g., `500m`, `1.5Gi` and `2e3`, and return the value in the base units."#,
+        false,
+        None,
+    )
+    format_quantity => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "quantity".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+        ],
+        r#"Return the canonical string of the quantity, which has the largest suffix with an integer mantissa, e.g., `1.5Gi` is formatted as `1536Mi`."#,
+        false,
+        None,
+    )
+    add_quantity => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+        ],
+        r#"Return the sum of the quantities `a` and `b` in the canonical format of `a`."#,
+        false,
+        None,
+    )
+    sub_quantity => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+        ],
+        r#"Return the quantity `a` minus `b` in the canonical format of `a`."#,
+        false,
+        None,
+    )
+    scale_quantity => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "quantity".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+            Parameter {
+                name: "factor".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::float_ref()]),
+                has_default: false,
+            },
+        ],
+        r#"Multiply the quantity by the int or float `factor`."#,
+        false,
+        None,
+    )
+    compare_quantity => Type::function(
+        None,
+        Type::int_ref(),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: quantity_ty(),
+                has_default: false,
+            },
+        ],
+        r#"Compare the quantities `a` and `b`, and return -1, 0 or 1 when `a` is less than, equal to or greater than `b`."#,
+        false,
+        None,
+    )
+    sum_quantities => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "quantities".to_string(),
+                ty: Type::list_ref(quantity_ty()),
+                has_default: false,
+            },
+        ],
+        r#"Return the sum of the `quantities` in the canonical format of the first quantity."#,
+        false,
+        None,
+    )
+}
+
+// ------------------------------
+// collection system package
+// ------------------------------
+
+pub const COLLECTION: &str = "collection";
+pub const COLLECTION_FUNCTION_NAMES: &[&str] = &[
+    "union_all",
+    "map",
+    "filter",
+    "reduce",
+    "group_by",
+    "unique_by",
+    "flatten",
+    "chunk",
+    "deep_get",
+    "deep_set",
+    "merge",
+];
+
+/// The function type of the lambda arguments e.g., `(any) -> any` for `map`.
+fn lambda_ref(params: &[&str], return_ty: TypeRef) -> TypeRef {
+    let params: Vec<Parameter> = params
+        .iter()
+        .map(|name| Parameter {
+            name: name.to_string(),
+            ty: Type::any_ref(),
+            has_default: false,
+        })
+        .collect();
+    Arc::new(Type::function(None, return_ty, &params, "", false, None))
+}
+
+macro_rules! register_collection_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const COLLECTION_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_collection_member! {
+    union_all => Type::function(
+        None,
+        Type::any_ref(),
+        &[
+            Parameter {
+                name: "num".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Union all object to one object."#,
+        false,
+        None,
+    )
+    map => Type::function(
+        None,
+        Type::list_ref(Type::any_ref()),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "func".to_string(),
+                ty: lambda_ref(&["item"], Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Apply the function to every item of the items and return a list of the results."#,
+        false,
+        None,
+    )
+    filter => Type::function(
+        None,
+        Type::list_ref(Type::any_ref()),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "func".to_string(),
+                ty: lambda_ref(&["item"], Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Return a list of the items for which the function returns a truthy value."#,
+        false,
+        None,
+    )
+    reduce => Type::function(
+        None,
+        Type::any_ref(),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "func".to_string(),
+                ty: lambda_ref(&["acc", "item"], Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "initial".to_string(),
+                ty: Type::any_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Apply the function of two arguments cumulatively to the items from left to right, so as to reduce the items to a single value. The first item is used as the initial value when `initial` is not given."#,
+        false,
+        None,
+    )
+    group_by => Type::function(
+        None,
+        Type::dict_ref(Type::str_ref(), Type::list_ref(Type::any_ref())),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "key".to_string(),
+                ty: lambda_ref(&["item"], Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Group the items by the string form of the key function results, keeping the order of first occurrence."#,
+        false,
+        None,
+    )
+    unique_by => Type::function(
+        None,
+        Type::list_ref(Type::any_ref()),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "key".to_string(),
+                ty: lambda_ref(&["item"], Type::any_ref()),
+                has_default: false,
+            },
+        ],
+        r#"Return the items with duplicates removed, where two items are duplicates if the key function returns equal values. The first occurrence is kept."#,
+        false,
+        None,
+    )
+    flatten => Type::function(
+        None,
+        Type::list_ref(Type::any_ref()),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "depth".to_string(),
+                ty: Type::int_ref(),
+                has_default: true,
+            },
+        ],
+        r#"Flatten the nested lists in the items up to `depth` levels, which defaults to 1. A negative depth flattens all levels."#,
+        false,
+        None,
+    )
+    chunk => Type::function(
+        None,
+        Type::list_ref(Type::list_ref(Type::any_ref())),
+        &[
+            Parameter {
+                name: "items".to_string(),
+                ty: Type::list_ref(Type::any_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "size".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Split the items into lists of `size` items, the last list may be shorter."#,
+        false,
+        None,
+    )
+    deep_get => Type::function(
+        None,
+        Type::any_ref(),
+        &[
+            Parameter {
+                name: "obj".to_string(),
+                ty: Type::any_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "encoding".to_string(),
-                ty: Type::str_ref(),
+                name: "path".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::list_ref(Type::union_ref(&[Type::str_ref(), Type::int_ref()]))]),
+                has_default: false,
+            },
+            Parameter {
+                name: "default".to_string(),
+                ty: Type::any_ref(),
                 has_default: true,
             },
         ],
-        r#"Encrypt the string `value` using `SHA256` and the codec registered for encoding."#,
+        r#"Get the value at the nested path e.g., `"a.b.0.c"` or `["a", "b", 0, "c"]` of the object, or `default` when the path doesn't exist."#,
         false,
         None,
     )
-    sha384 => Type::function(
+    deep_set => Type::function(
         None,
-        Type::str_ref(),
+        Type::any_ref(),
         &[
             Parameter {
-                name: "value".to_string(),
-                ty: Type::str_ref(),
+                name: "obj".to_string(),
+                ty: Type::any_ref(),
                 has_default: false,
             },
             Parameter {
-                name: "encoding".to_string(),
-                ty: Type::str_ref(),
-                has_default: true,
+                name: "path".to_string(),
+                ty: Type::union_ref(&[Type::str_ref(), Type::list_ref(Type::union_ref(&[Type::str_ref(), Type::int_ref()]))]),
+                has_default: false,
+            },
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::any_ref(),
+                has_default: false,
             },
         ],
-        r#"Encrypt the string `value` using `SHA384` and the codec registered for encoding."#,
+        r#"Return a copy of the object with the value set at the nested path, missing intermediate dicts are created."#,
         false,
         None,
     )
-    sha512 => Type::function(
+    merge => Type::function(
         None,
-        Type::str_ref(),
+        Type::dict_ref(Type::str_ref(), Type::any_ref()),
         &[
             Parameter {
-                name: "value".to_string(),
-                ty: Type::str_ref(),
+                name: "configs".to_string(),
+                ty: Type::list_ref(Type::dict_ref(Type::str_ref(), Type::any_ref())),
                 has_default: false,
             },
             Parameter {
-                name: "encoding".to_string(),
+                name: "strategy".to_string(),
                 ty: Type::str_ref(),
                 has_default: true,
             },
         ],
-        r#"Encrypt the string `value` using `SHA512` and the codec registered for encoding."#,
+        r#"Deep merge the configs from left to right with the strategy, one of `override` (default, later values override earlier ones), `append` (lists are concatenated), `keep` (earlier values are kept) and `union` (the same as the `|` operator)."#,
         false,
         None,
     )
 }
 
 // ------------------------------
-// units system package
+// semver system package
 // ------------------------------
 
-pub const UNITS: &str = "units";
-pub const UNITS_FUNCTION_NAMES: &[&str] = &[
-    "to_n", "to_u", "to_m", "to_K", "to_M", "to_G", "to_T", "to_P", "to_Ki", "to_Mi", "to_Gi",
-    "to_Ti", "to_Pi",
+pub const SEMVER: &str = "semver";
+pub const SEMVER_FUNCTION_NAMES: &[&str] = &[
+    "parse",
+    "is_valid",
+    "compare",
+    "satisfies",
+    "max_satisfying",
+    "bump",
+    "sort",
 ];
-pub const UNITS_NUMBER_MULTIPLIER: &str = "NumberMultiplier";
-pub const UNITS_FIELD_NAMES: &[&str] = &[
-    "n",
-    "u",
-    "m",
-    "k",
-    "K",
-    "M",
-    "G",
-    "T",
-    "P",
-    "Ki",
-    "Mi",
-    "Gi",
-    "Ti",
-    "Pi",
-    UNITS_NUMBER_MULTIPLIER,
-];
-macro_rules! register_units_member {
+macro_rules! register_semver_member {
     ($($name:ident => $ty:expr)*) => (
-        pub const UNITS_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+        pub const SEMVER_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
             let mut builtin_mapping = IndexMap::default();
             $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
             builtin_mapping
         });
     )
 }
-register_units_member! {
-    n => Type::INT
-    u => Type::INT
-    m => Type::INT
-    k => Type::INT
-    K => Type::INT
-    M => Type::INT
-    G => Type::INT
-    T => Type::INT
-    P => Type::INT
-    Ki => Type::INT
-    Mi => Type::INT
-    Gi => Type::INT
-    Ti => Type::INT
-    Pi => Type::INT
-    to_n => Type::function(
+register_semver_member! {
+    parse => Type::function(
         None,
-        Type::str_ref(),
+        Type::dict_ref(Type::str_ref(), Type::any_ref()),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "version".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `n` suffix."#,
+        r#"Parse the semantic version e.g., `v1.2.3-rc.1+build.5`, and return a dict with the `major`, `minor` and `patch` numbers and the `prerelease` and `build` strings."#,
         false,
         None,
     )
-    to_u => Type::function(
+    is_valid => Type::function(
         None,
-        Type::str_ref(),
+        Type::bool_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "version".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `u` suffix."#,
+        r#"Whether the `version` is a valid semantic version."#,
         false,
         None,
     )
-    to_m => Type::function(
+    compare => Type::function(
         None,
-        Type::str_ref(),
+        Type::int_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "a".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `m` suffix."#,
+        r#"Compare the semantic versions `a` and `b` by precedence, and return -1, 0 or 1 when `a` is lower than, equal to or higher than `b`."#,
         false,
         None,
     )
-    to_K => Type::function(
+    satisfies => Type::function(
         None,
-        Type::str_ref(),
+        Type::bool_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "version".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "constraint".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `K` suffix."#,
+        r#"Whether the `version` satisfies the `constraint` e.g., `^1.2`, `~1.4`, `>=1.0 <2.0`, `1.2 - 1.4` and `^1 || ^2`."#,
         false,
         None,
     )
-    to_M => Type::function(
+    max_satisfying => Type::function(
         None,
         Type::str_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "versions".to_string(),
+                ty: Type::list_ref(Type::str_ref()),
+                has_default: false,
+            },
+            Parameter {
+                name: "constraint".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `M` suffix."#,
+        r#"Return the highest version in `versions` which satisfies the `constraint`, or None when no version satisfies it."#,
         false,
         None,
     )
-    to_G => Type::function(
+    bump => Type::function(
         None,
         Type::str_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "version".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
+            Parameter {
+                name: "part".to_string(),
+                ty: Type::str_ref(),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `G` suffix."#,
+        r#"Increment the `major`, `minor` or `patch` part of the `version`, which defaults to `patch`, and reset the lower parts, the prerelease and the build metadata."#,
         false,
         None,
     )
-    to_T => Type::function(
+    sort => Type::function(
         None,
-        Type::str_ref(),
+        Type::list_ref(Type::str_ref()),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "versions".to_string(),
+                ty: Type::list_ref(Type::str_ref()),
                 has_default: false,
             },
+            Parameter {
+                name: "reverse".to_string(),
+                ty: Type::bool_ref(),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `T` suffix."#,
+        r#"Sort the `versions` by the semantic version precedence in the ascending order, or the descending order when `reverse` is True."#,
         false,
         None,
     )
-    to_P => Type::function(
+}
+
+// ------------------------------
+// random system package
+// ------------------------------
+
+pub const RANDOM: &str = "random";
+pub const RANDOM_FUNCTION_NAMES: &[&str] =
+    &["seed", "random", "randint", "choice", "shuffle", "sample"];
+macro_rules! register_random_member {
+    ($($name:ident => $ty:expr)*) => (
+        pub const RANDOM_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+register_random_member! {
+    seed => Type::function(
         None,
-        Type::str_ref(),
+        Arc::new(Type::NONE),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "a".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
                 has_default: false,
             },
         ],
-        r#"Int literal to string with `P` suffix."#,
+        r#"Reseed the context random generator with the int or str `a`, the generator is seeded with the `random_seed` option (0 by default) before the first use."#,
         false,
         None,
     )
-    to_Ki => Type::function(
+    random => Type::function(
         None,
-        Type::str_ref(),
+        Type::float_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
-                has_default: false,
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
             },
         ],
-        r#"Int literal to string with `Ki` suffix."#,
+        r#"Return the next random float in the range [0.0, 1.0). When `seed` is given, a fresh generator seeded with it is used instead of the context generator."#,
         false,
         None,
     )
-    to_Mi => Type::function(
+    randint => Type::function(
         None,
-        Type::str_ref(),
+        Type::int_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "a".to_string(),
+                ty: Type::int_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Type::int_ref(),
                 has_default: false,
             },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `Mi` suffix."#,
+        r#"Return a random int N such that `a` <= N <= `b`. When `seed` is given, a fresh generator seeded with it is used instead of the context generator."#,
         false,
         None,
     )
-    to_Gi => Type::function(
+    choice => Type::function(
         None,
-        Type::str_ref(),
+        Type::any_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "seq".to_string(),
+                ty: Type::union_ref(&[Type::list_ref(Type::any_ref()), Type::str_ref()]),
                 has_default: false,
             },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `Gi` suffix."#,
+        r#"Return a random element from the non-empty list, the config keys or the string characters. When `seed` is given, a fresh generator seeded with it is used instead of the context generator."#,
         false,
         None,
     )
-    to_Ti => Type::function(
+    shuffle => Type::function(
         None,
-        Type::str_ref(),
+        Type::list_ref(Type::any_ref()),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "items".to_string(),
+                ty: Type::union_ref(&[Type::list_ref(Type::any_ref()), Type::str_ref()]),
                 has_default: false,
             },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `Ti` suffix."#,
+        r#"Return a new list of the items in a random order. When `seed` is given, a fresh generator seeded with it is used instead of the context generator."#,
         false,
         None,
     )
-    to_Pi => Type::function(
+    sample => Type::function(
         None,
-        Type::str_ref(),
+        Type::list_ref(Type::any_ref()),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::number(),
+                name: "population".to_string(),
+                ty: Type::union_ref(&[Type::list_ref(Type::any_ref()), Type::str_ref()]),
+                has_default: false,
+            },
+            Parameter {
+                name: "k".to_string(),
+                ty: Type::int_ref(),
                 has_default: false,
             },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
         ],
-        r#"Int literal to string with `Pi` suffix."#,
+        r#"Return a list of `k` unique elements chosen from the population. When `seed` is given, a fresh generator seeded with it is used instead of the context generator."#,
         false,
         None,
     )
 }
 
 // ------------------------------
-// collection system package
+// uuid system package
 // ------------------------------
 
-pub const COLLECTION: &str = "collection";
-pub const COLLECTION_FUNCTION_NAMES: &[&str] = &["union_all"];
-macro_rules! register_collection_member {
+pub const UUID: &str = "uuid";
+pub const UUID_FUNCTION_NAMES: &[&str] = &["uuid4", "uuid5", "is_valid"];
+macro_rules! register_uuid_member {
     ($($name:ident => $ty:expr)*) => (
-        pub const COLLECTION_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+        pub const UUID_FUNCTION_TYPES: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
             let mut builtin_mapping = IndexMap::default();
             $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
             builtin_mapping
         });
     )
 }
-register_collection_member! {
-    union_all => Type::function(
+register_uuid_member! {
+    uuid4 => Type::function(
         None,
-        Type::any_ref(),
+        Type::str_ref(),
         &[
             Parameter {
-                name: "num".to_string(),
-                ty: Type::list_ref(Type::any_ref()),
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Type::int_ref(), Type::str_ref()]),
+                has_default: true,
+            },
+        ],
+        r#"Generate a version 4 UUID from the context random generator, which is reproducible for the same `random_seed` option. When `seed` is given, a fresh generator seeded with it is used instead."#,
+        false,
+        None,
+    )
+    uuid5 => Type::function(
+        None,
+        Type::str_ref(),
+        &[
+            Parameter {
+                name: "namespace".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+            Parameter {
+                name: "name".to_string(),
+                ty: Type::str_ref(),
                 has_default: false,
             },
         ],
-        r#"Union all object to one object."#,
+        r#"Generate the name-based version 5 UUID of `name` in the `namespace`, which is one of `dns`, `url`, `oid` and `x500` or a UUID string."#,
+        false,
+        None,
+    )
+    is_valid => Type::function(
+        None,
+        Type::bool_ref(),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Type::str_ref(),
+                has_default: false,
+            },
+        ],
+        r#"Whether the `value` is a valid UUID string in the hyphenated or the 32 hex digits form."#,
         false,
         None,
     )
 }
 
 pub const STANDARD_SYSTEM_MODULES: &[&str] = &[
-    COLLECTION, NET, MANIFESTS, MATH, DATETIME, REGEX, YAML, JSON, CRYPTO, BASE64, UNITS,
+    COLLECTION, NET, MANIFESTS, MATH, DATETIME, REGEX, YAML, JSON, CRYPTO, BASE64, UNITS, SEMVER,
+    RANDOM, UUID,
 ];
 
 pub const STANDARD_SYSTEM_MODULE_NAMES_WITH_AT: &[&str] = &[
@@ -1565,6 +3122,9 @@ pub const STANDARD_SYSTEM_MODULE_NAMES_WITH_AT: &[&str] = &[
     "@crypto",
     "@base64",
     "@units",
+    "@semver",
+    "@random",
+    "@uuid",
 ];
 
 /// Get the system module members
@@ -1585,6 +3145,9 @@ pub fn get_system_module_members(name: &str) -> Vec<&str> {
             members
         }
         COLLECTION => COLLECTION_FUNCTION_NAMES.to_vec(),
+        SEMVER => SEMVER_FUNCTION_NAMES.to_vec(),
+        RANDOM => RANDOM_FUNCTION_NAMES.to_vec(),
+        UUID => UUID_FUNCTION_NAMES.to_vec(),
         _ => bug!("invalid system module name '{}'", name),
     }
 }
@@ -1636,6 +3199,18 @@ pub fn get_system_member_function_ty(name: &str, func: &str) -> TypeRef {
             let types = COLLECTION_FUNCTION_TYPES;
             types.get(func).cloned()
         }
+        SEMVER => {
+            let types = SEMVER_FUNCTION_TYPES;
+            types.get(func).cloned()
+        }
+        RANDOM => {
+            let types = RANDOM_FUNCTION_TYPES;
+            types.get(func).cloned()
+        }
+        UUID => {
+            let types = UUID_FUNCTION_TYPES;
+            types.get(func).cloned()
+        }
         _ => None,
     };
     optional_ty
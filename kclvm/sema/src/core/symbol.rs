@@ -245,6 +245,7 @@ impl SymbolData {
             TypeKind::IntLit(_) => None,
             TypeKind::Float => None,
             TypeKind::FloatLit(_) => None,
+            TypeKind::Decimal => None,
             TypeKind::Str => self.get_symbol_by_fully_qualified_name(BUILTIN_STR_PACKAGE),
             TypeKind::StrLit(_) => self.get_symbol_by_fully_qualified_name(BUILTIN_STR_PACKAGE),
             TypeKind::List(_) => None,
@@ -252,6 +253,7 @@ impl SymbolData {
             TypeKind::NumberMultiplier(_) => None,
             TypeKind::Function(_) => None,
             TypeKind::Union(_) => None,
+            TypeKind::TypeParam(_) => None,
 
             TypeKind::Schema(schema_ty) => {
                 let fully_qualified_ty_name = schema_ty.pkgpath.clone() + "." + &schema_ty.name;
@@ -295,6 +297,7 @@ impl SymbolData {
             TypeKind::IntLit(_) => vec![],
             TypeKind::Float => vec![],
             TypeKind::FloatLit(_) => vec![],
+            TypeKind::Decimal => vec![],
             TypeKind::Str => {
                 let mut result = vec![];
                 if let Some(symbol_ref) = self.get_type_symbol(ty, module_info) {
@@ -309,6 +312,7 @@ impl SymbolData {
             TypeKind::Dict(_) => vec![],
             TypeKind::NumberMultiplier(_) => vec![],
             TypeKind::Function(_) => vec![],
+            TypeKind::TypeParam(_) => vec![],
             TypeKind::Union(tys) => {
                 let mut result = vec![];
                 for ty in tys.iter() {
@@ -362,6 +366,7 @@ impl SymbolData {
             TypeKind::IntLit(_) => None,
             TypeKind::Float => None,
             TypeKind::FloatLit(_) => None,
+            TypeKind::Decimal => None,
             TypeKind::Str => self
                 .get_symbol(self.get_type_symbol(ty, module_info)?)?
                 .get_attribute(name, self, module_info),
@@ -372,6 +377,7 @@ impl SymbolData {
             TypeKind::Dict(_) => None,
             TypeKind::NumberMultiplier(_) => None,
             TypeKind::Function(_) => None,
+            TypeKind::TypeParam(_) => None,
             TypeKind::Union(tys) => {
                 for ty in tys.iter() {
                     if let Some(symbol_ref) = self.get_type_attribute(ty, name, module_info) {
@@ -46,8 +46,9 @@ use crate::builtin::{
 use crate::core::global_state::GlobalState;
 use crate::core::package::{ModuleInfo, PackageInfo};
 use crate::core::symbol::{PackageSymbol, SymbolRef, ValueSymbol, BUILTIN_STR_PACKAGE};
+use crate::plugin::{load_plugin_stubs, PluginStub, PLUGIN_MODULE_PREFIX};
 use crate::resolver::scope::NodeKey;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use kclvm_ast::ast::AstIndex;
 use kclvm_ast::ast::Program;
 use kclvm_ast::walker::MutSelfTypedResultWalker;
@@ -98,12 +99,23 @@ impl<'ctx> Namer<'ctx> {
 
     // serial namer pass
     pub fn find_symbols(program: &'ctx Program, gs: GlobalState) -> GlobalState {
+        Self::find_symbols_with_plugin_stubs(program, gs, &load_plugin_stubs(&program.root))
+    }
+
+    /// Find symbols with the plugin stubs already loaded by the resolver, see
+    /// [crate::resolver::scope::ProgramScope::plugin_stubs].
+    pub fn find_symbols_with_plugin_stubs(
+        program: &'ctx Program,
+        gs: GlobalState,
+        plugin_stubs: &IndexMap<String, Result<PluginStub, String>>,
+    ) -> GlobalState {
         let mut namer = Self::new(program, gs);
         namer.ctx.current_package_info = Some(PackageInfo::new(
             BUILTIN_SYMBOL_PKG_PATH.to_string(),
             "".to_string(),
         ));
         namer.init_builtin_symbols();
+        namer.init_plugin_symbols(plugin_stubs);
 
         for (name, modules) in namer.ctx.program.pkgs.iter() {
             {
@@ -253,6 +265,48 @@ impl<'ctx> Namer<'ctx> {
         }
     }
 
+    /// Add the plugin modules with stubs, e.g., `kcl_plugin.hello`, and the
+    /// declared functions.
+    fn init_plugin_symbols(&mut self, plugin_stubs: &IndexMap<String, Result<PluginStub, String>>) {
+        for (name, stub) in plugin_stubs {
+            let stub = match stub {
+                Ok(stub) => stub,
+                Err(_) => continue,
+            };
+            let package_symbol_ref =
+                self.gs
+                    .get_symbols_mut()
+                    .alloc_package_symbol(PackageSymbol::new(
+                        format!("{}{}", PLUGIN_MODULE_PREFIX, name),
+                        Position::dummy_pos(),
+                        Position::dummy_pos(),
+                    ));
+            for (func_name, func) in &stub.functions {
+                let func_ty = func.ty();
+                let mut value_symbol = ValueSymbol::new(
+                    func_name.to_string(),
+                    Position::dummy_pos(),
+                    Position::dummy_pos(),
+                    Some(package_symbol_ref),
+                    false,
+                );
+                value_symbol.sema_info.ty = Some(func_ty.clone());
+                value_symbol.sema_info.doc = func_ty.ty_doc();
+                let func_symbol_ref = self
+                    .gs
+                    .get_symbols_mut()
+                    .alloc_value_symbol(value_symbol, self.ctx.get_node_key(&AstIndex::default()));
+                self.gs
+                    .get_symbols_mut()
+                    .packages
+                    .get_mut(package_symbol_ref.get_id())
+                    .unwrap()
+                    .members
+                    .insert(func_name.to_string(), func_symbol_ref);
+            }
+        }
+    }
+
     fn define_symbols(&mut self) {
         self.gs.get_symbols_mut().build_fully_qualified_name_map();
     }
@@ -1,2 +1,233 @@
+//! Plugin stubs declare the function signatures of the `kcl_plugin.<name>` modules,
+//! which are used to type check the plugin calls. The stub file `<name>.kpi` is
+//! shipped in the same directory as the plugin library declared in the `[plugins]`
+//! table of the kcl.mod file, and each function is declared with the KCL types
+//! of the parameters and the return value, e.g.,
+//!
+//! ```text
+//! # Repeat the string `s` for `n` times.
+//! repeat(s: str, n?: int) -> str
+//! ```
+//!
+//! The comment lines before a function are the function document and the optional
+//! parameters are marked with `?`.
+use std::path::Path;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use kclvm_config::modfile::{load_mod_file, KCL_MOD_FILE};
+
+use crate::ty::{parser::parse_type_str, Parameter, Type, TypeRef};
+
+#[cfg(test)]
+mod tests;
+
 pub const PLUGIN_MODULE_PREFIX: &str = "kcl_plugin.";
 pub const PLUGIN_PREFIX_WITH_AT: &str = "@kcl_plugin";
+pub const PLUGIN_STUB_FILE_EXTENSION: &str = "kpi";
+
+/// The function declarations of a plugin.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginStub {
+    pub name: String,
+    pub functions: IndexMap<String, PluginStubFunction>,
+}
+
+/// The function signature declared in the plugin stub.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginStubFunction {
+    pub name: String,
+    pub doc: String,
+    /// The parameter name, type string and whether it is optional.
+    pub params: Vec<(String, String, bool)>,
+    pub return_ty: String,
+}
+
+impl PluginStubFunction {
+    /// Returns the function type of the plugin function.
+    pub fn ty(&self) -> TypeRef {
+        let params: Vec<Parameter> = self
+            .params
+            .iter()
+            .map(|(name, ty, optional)| Parameter {
+                name: name.to_string(),
+                ty: parse_type_str(ty),
+                has_default: *optional,
+            })
+            .collect();
+        Arc::new(Type::function(
+            None,
+            parse_type_str(&self.return_ty),
+            &params,
+            &self.doc,
+            false,
+            None,
+        ))
+    }
+}
+
+/// Load the plugin stubs of the plugins declared in the kcl.mod file at `root`,
+/// keyed by the plugin name. Plugins without stubs are omitted and invalid stubs
+/// are returned as errors.
+pub fn load_plugin_stubs(root: &str) -> IndexMap<String, Result<PluginStub, String>> {
+    let mut stubs = IndexMap::new();
+    if root.is_empty() || !Path::new(root).join(KCL_MOD_FILE).is_file() {
+        return stubs;
+    }
+    let mut plugins: Vec<(String, String)> = load_mod_file(root)
+        .plugins
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    plugins.sort();
+    for (name, lib_path) in plugins {
+        let lib_path = Path::new(root).join(lib_path);
+        let stub_path = match lib_path.parent() {
+            Some(dir) => dir.join(format!("{}.{}", name, PLUGIN_STUB_FILE_EXTENSION)),
+            None => continue,
+        };
+        if !stub_path.is_file() {
+            continue;
+        }
+        let stub = std::fs::read_to_string(&stub_path)
+            .map_err(|err| err.to_string())
+            .and_then(|source| parse_plugin_stub(&name, &source))
+            .map_err(|err| format!("{}: {}", stub_path.display(), err));
+        stubs.insert(name, stub);
+    }
+    stubs
+}
+
+/// Parse the plugin stub source of the plugin `name`.
+pub fn parse_plugin_stub(name: &str, source: &str) -> Result<PluginStub, String> {
+    let mut stub = PluginStub {
+        name: name.to_string(),
+        functions: IndexMap::new(),
+    };
+    let mut doc: Vec<&str> = vec![];
+    for (i, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            doc.clear();
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            doc.push(comment.strip_prefix(' ').unwrap_or(comment));
+            continue;
+        }
+        let mut func =
+            parse_stub_function(line).map_err(|err| format!("line {}: {}", i + 1, err))?;
+        func.doc = doc.join("\n");
+        doc.clear();
+        if stub.functions.contains_key(&func.name) {
+            return Err(format!(
+                "line {}: the function '{}' is declared multiple times",
+                i + 1,
+                func.name
+            ));
+        }
+        stub.functions.insert(func.name.clone(), func);
+    }
+    Ok(stub)
+}
+
+/// Parse the function signature like `name(a: str, b?: int) -> str`.
+fn parse_stub_function(line: &str) -> Result<PluginStubFunction, String> {
+    let (name, rest) = line
+        .split_once('(')
+        .ok_or_else(|| format!("expected a function signature, got '{}'", line))?;
+    let name = name.trim();
+    if !is_identifier(name) {
+        return Err(format!("invalid function name '{}'", name));
+    }
+    let close = find_closing_paren(rest).ok_or("unclosed parameter list")?;
+    let return_ty = rest[close + 1..].trim();
+    let return_ty = if return_ty.is_empty() {
+        "any"
+    } else {
+        return_ty
+            .strip_prefix("->")
+            .ok_or_else(|| format!("expected '->', got '{}'", return_ty))?
+            .trim()
+    };
+    let mut params = vec![];
+    for param in split_top_level(&rest[..close]) {
+        let param = param.trim();
+        if param.is_empty() {
+            continue;
+        }
+        let (param_name, ty) = match param.split_once(':') {
+            Some((param_name, ty)) => (param_name.trim(), ty.trim()),
+            None => (param, "any"),
+        };
+        let (param_name, optional) = match param_name.strip_suffix('?') {
+            Some(param_name) => (param_name.trim(), true),
+            None => (param_name, false),
+        };
+        if !is_identifier(param_name) {
+            return Err(format!("invalid parameter name '{}'", param_name));
+        }
+        if !optional && params.iter().any(|(_, _, optional)| *optional) {
+            return Err(format!(
+                "the required parameter '{}' follows the optional parameters",
+                param_name
+            ));
+        }
+        params.push((param_name.to_string(), ty.to_string(), optional));
+    }
+    Ok(PluginStubFunction {
+        name: name.to_string(),
+        doc: String::new(),
+        params,
+        return_ty: return_ty.to_string(),
+    })
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Returns the index of the parenthesis closing the parameter list.
+fn find_closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ']' | '}') => depth -= 1,
+            (None, ')') if depth == 0 => return Some(i),
+            (None, ')') => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the parameters at the commas which are not in the nested types.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+fn test_parse_plugin_stub() {
+    let stub = parse_plugin_stub(
+        "hello",
+        r#"# Say hello.
+# Returns the greeting.
+say_hello(name: str, times?: int) -> str
+
+merge(data: {str:[int]}, mode?: "union" | "override") -> {str:any}
+now()
+"#,
+    )
+    .unwrap();
+    assert_eq!(stub.name, "hello");
+    assert_eq!(
+        stub.functions.keys().collect::<Vec<&String>>(),
+        vec!["say_hello", "merge", "now"]
+    );
+    let say_hello = &stub.functions["say_hello"];
+    assert_eq!(say_hello.doc, "Say hello.\nReturns the greeting.");
+    assert_eq!(
+        say_hello.params,
+        vec![
+            ("name".to_string(), "str".to_string(), false),
+            ("times".to_string(), "int".to_string(), true),
+        ]
+    );
+    assert_eq!(say_hello.ty().ty_str(), "(str, int) -> str");
+    let merge = &stub.functions["merge"];
+    assert_eq!(
+        merge.params,
+        vec![
+            ("data".to_string(), "{str:[int]}".to_string(), false),
+            (
+                "mode".to_string(),
+                "\"union\" | \"override\"".to_string(),
+                true
+            ),
+        ]
+    );
+    assert_eq!(merge.return_ty, "{str:any}");
+    assert_eq!(stub.functions["now"].doc, "");
+    assert_eq!(stub.functions["now"].return_ty, "any");
+}
+
+#[test]
+fn test_parse_plugin_stub_fail() {
+    let cases = [
+        (
+            "say_hello",
+            "line 1: expected a function signature, got 'say_hello'",
+        ),
+        ("say_hello(name: str", "line 1: unclosed parameter list"),
+        (
+            "say_hello(name: str) str",
+            "line 1: expected '->', got 'str'",
+        ),
+        ("say hello()", "line 1: invalid function name 'say hello'"),
+        (
+            "say_hello(times?: int, name: str)",
+            "line 1: the required parameter 'name' follows the optional parameters",
+        ),
+        (
+            "f()\nf(a)",
+            "line 2: the function 'f' is declared multiple times",
+        ),
+    ];
+    for (source, err) in cases {
+        assert_eq!(parse_plugin_stub("hello", source).unwrap_err(), err);
+    }
+}
@@ -4,6 +4,7 @@ mod multi_assign;
 
 use indexmap::IndexMap;
 use kclvm_ast::ast;
+use rayon::prelude::*;
 
 #[cfg(test)]
 mod tests;
@@ -15,8 +16,10 @@ pub use multi_assign::transform_multi_assign;
 use crate::resolver::Options;
 
 /// Pre-process AST program.
+///
+/// Packages are independent of each other in these passes, so they are processed concurrently.
 pub fn pre_process_program(program: &mut ast::Program, opts: &Options) {
-    for (pkgpath, modules) in program.pkgs.iter_mut() {
+    program.pkgs.par_iter_mut().for_each(|(pkgpath, modules)| {
         let mut import_names = IndexMap::default();
         if pkgpath == kclvm_ast::MAIN_PKG {
             for module in modules.iter_mut() {
@@ -37,7 +40,7 @@ pub fn pre_process_program(program: &mut ast::Program, opts: &Options) {
             fix_qualified_identifier(module, &mut import_names);
             fix_config_expr_nest_attr(module);
         }
-    }
+    });
     if opts.merge_program {
         merge_program(program);
     }
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
-use crate::builtin::system_module::{get_system_module_members, UNITS, UNITS_NUMBER_MULTIPLIER};
+use crate::builtin::system_module::{
+    get_system_module_members, UNITS, UNITS_NUMBER_MULTIPLIER, UNITS_QUANTITY,
+};
 use crate::builtin::{get_system_member_function_ty, STRING_MEMBER_FUNCTIONS};
+use crate::plugin::PLUGIN_MODULE_PREFIX;
 use crate::resolver::Resolver;
 use crate::ty::TypeKind::Schema;
 use crate::ty::{DictType, ModuleKind, Type, TypeKind, TypeRef, SCHEMA_MEMBER_FUNCTIONS};
@@ -31,7 +34,7 @@ impl<'ctx> Resolver<'ctx> {
 
     pub fn load_attr(&mut self, obj: TypeRef, attr: &str, range: Range) -> ResolvedResult {
         let (result, return_ty) = match &obj.kind {
-            TypeKind::Any => (true, self.any_ty()),
+            TypeKind::Any | TypeKind::TypeParam(_) => (true, self.any_ty()),
             TypeKind::None
             | TypeKind::Bool
             | TypeKind::BoolLit(_)
@@ -39,6 +42,7 @@ impl<'ctx> Resolver<'ctx> {
             | TypeKind::IntLit(_)
             | TypeKind::Float
             | TypeKind::FloatLit(_)
+            | TypeKind::Decimal
             | TypeKind::List(_)
             | TypeKind::NumberMultiplier(_)
             | TypeKind::Function(_)
@@ -100,6 +104,8 @@ impl<'ctx> Resolver<'ctx> {
                     ModuleKind::System => {
                         if module_ty.pkgpath == UNITS && attr == UNITS_NUMBER_MULTIPLIER {
                             (true, Arc::new(Type::number_multiplier_non_lit_ty()))
+                        } else if module_ty.pkgpath == UNITS && attr == UNITS_QUANTITY {
+                            (true, Arc::new(Type::quantity_ty()))
                         } else {
                             let members = get_system_module_members(&module_ty.pkgpath);
                             (
@@ -108,7 +114,17 @@ impl<'ctx> Resolver<'ctx> {
                             )
                         }
                     }
-                    ModuleKind::Plugin => (true, self.any_ty()),
+                    ModuleKind::Plugin => {
+                        let name = module_ty.pkgpath.trim_start_matches(PLUGIN_MODULE_PREFIX);
+                        match self.plugin_stubs.get(name) {
+                            // Plugins without stubs are not type checked.
+                            Some(Ok(stub)) => match stub.functions.get(attr) {
+                                Some(func) => (true, func.ty()),
+                                None => (false, self.any_ty()),
+                            },
+                            _ => (true, self.any_ty()),
+                        }
+                    }
                 }
             }
         };
@@ -78,6 +78,23 @@ impl<'ctx> Resolver<'ctx> {
                 Arc::new(Type::INT)
             }
         };
+        // Decimal arithmetic with ints is exact and returns decimals.
+        if (t1.is_decimal() || t2.is_decimal())
+            && matches!(
+                op,
+                ast::BinOp::Add
+                    | ast::BinOp::Sub
+                    | ast::BinOp::Mul
+                    | ast::BinOp::Div
+                    | ast::BinOp::FloorDiv
+                    | ast::BinOp::Mod
+                    | ast::BinOp::Pow
+            )
+            && self.ctx.ty_ctx.is_decimal_or_int_type(t1.clone())
+            && self.ctx.ty_ctx.is_decimal_or_int_type(t2.clone())
+        {
+            return Arc::new(Type::DECIMAL);
+        }
         let (result, return_ty) = match op {
             ast::BinOp::Add => {
                 if t1.is_number() && t2.is_number() {
@@ -211,8 +228,8 @@ impl<'ctx> Resolver<'ctx> {
 
     /// Unary operator calculation table
     ///
-    /// + number        unary positive          (int, float)
-    /// - number        unary negation          (int, float)
+    /// + number        unary positive          (int, float, decimal)
+    /// - number        unary negation          (int, float, decimal)
     /// ~ number        unary bitwise inversion (int)
     /// not x           logical negation        (any type)
     pub fn unary(&mut self, ty: TypeRef, op: &ast::UnaryOp, range: Range) -> TypeRef {
@@ -224,7 +241,7 @@ impl<'ctx> Resolver<'ctx> {
             .ty_ctx
             .literal_union_type_to_variable_type(ty.clone());
         let result = match op {
-            ast::UnaryOp::UAdd | ast::UnaryOp::USub => var_ty.is_number(),
+            ast::UnaryOp::UAdd | ast::UnaryOp::USub => var_ty.is_number() || var_ty.is_decimal(),
             ast::UnaryOp::Invert => var_ty.is_int() || var_ty.is_bool(),
             ast::UnaryOp::Not => true,
         };
@@ -275,6 +292,28 @@ impl<'ctx> Resolver<'ctx> {
         {
             return self.bool_ty();
         }
+        // Number multipliers e.g., `units.Quantity` values are compared by the quantities.
+        if (t1.is_number_multiplier() || t2.is_number_multiplier())
+            && self
+                .ctx
+                .ty_ctx
+                .is_number_multiplier_or_number_type(t1.clone())
+            && self
+                .ctx
+                .ty_ctx
+                .is_number_multiplier_or_number_type(t2.clone())
+            && !matches!(op, ast::CmpOp::In | ast::CmpOp::NotIn)
+        {
+            return self.bool_ty();
+        }
+        // Decimals are compared with the numbers by the exact values.
+        if (t1.is_decimal() || t2.is_decimal())
+            && self.ctx.ty_ctx.is_decimal_or_number_type(t1.clone())
+            && self.ctx.ty_ctx.is_decimal_or_number_type(t2.clone())
+            && !matches!(op, ast::CmpOp::In | ast::CmpOp::NotIn)
+        {
+            return self.bool_ty();
+        }
         if self
             .ctx
             .ty_ctx
@@ -359,7 +359,15 @@ impl<'ctx> Resolver<'ctx> {
         let runtime_type = kclvm_runtime::schema_runtime_type(&schema_ty.name, &schema_ty.pkgpath);
         match self.ctx.schema_mapping.get(&runtime_type) {
             Some(schema_mapping_ty) => {
-                let schema_ty = schema_mapping_ty.borrow();
+                let schema_mapping_ty = schema_mapping_ty.borrow();
+                // Substitute the type parameters for the generic schema instances.
+                let instantiated_ty;
+                let schema_ty = if schema_ty.type_args.is_empty() {
+                    &*schema_mapping_ty
+                } else {
+                    instantiated_ty = schema_mapping_ty.instantiate(&schema_ty.type_args);
+                    &instantiated_ty
+                };
                 match schema_ty.get_type_of_attr(attr) {
                     Some(ty) => (true, ty),
                     None => {
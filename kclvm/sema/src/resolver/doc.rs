@@ -202,6 +202,11 @@ fn is_at_section(doc: &mut Reader) -> bool {
     l2.starts_with(&"-".repeat(l1.len())) || l2.starts_with(&"=".repeat(l1.len()))
 }
 
+/// Checks if current line is at the beginning of the example section
+fn is_at_examples_section(doc: &mut Reader) -> bool {
+    is_at_section(doc) && doc.peek(0, true).trim() == "Examples"
+}
+
 /// read lines before next section beginning, continuous empty lines will be merged to one
 fn read_to_next_section(doc: &mut Reader) -> Vec<String> {
     let mut section = doc.read_to_next_empty_line();
@@ -269,11 +274,15 @@ pub fn parse_doc_string(ori: &String) -> Doc {
     doc.reset();
     let summary = parse_summary(&mut doc);
 
-    let attr_section = read_to_next_section(&mut doc);
-
-    let attr_content = attr_section.join("\n");
-
-    let attrs = parse_attr_list(attr_content);
+    // Docstrings without the attribute section e.g., lambda docstrings may
+    // start with the example section directly.
+    let attrs = if is_at_examples_section(&mut doc) {
+        vec![]
+    } else {
+        let attr_section = read_to_next_section(&mut doc);
+        let attr_content = attr_section.join("\n");
+        parse_attr_list(attr_content)
+    };
 
     let mut examples = HashMap::new();
     let example_section = read_to_next_section(&mut doc);
@@ -672,4 +681,27 @@ unindented line
             ))
         );
     }
+
+    #[test]
+    fn test_parse_doc_without_attrs() {
+        let doc = parse_doc_string(
+            &r#""""Add two numbers.
+
+    Examples
+    --------
+    assert add(1, 2) == 3
+    """"#
+                .to_string(),
+        );
+        assert_eq!(doc.summary, "Add two numbers.");
+        assert!(doc.attrs.is_empty());
+        assert_eq!(
+            doc.examples.get("Default example"),
+            Some(&Example::new(
+                "".to_string(),
+                "".to_string(),
+                "assert add(1, 2) == 3".to_string()
+            ))
+        );
+    }
 }
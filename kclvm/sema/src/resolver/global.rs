@@ -36,36 +36,39 @@ impl<'ctx> Resolver<'ctx> {
                     self.change_package_context(pkgpath, filename);
                     for stmt in &module.body {
                         let (start, end) = stmt.get_span_pos();
-                        let (name, doc, is_mixin, is_protocol, is_rule) = match &stmt.node {
-                            ast::Stmt::Schema(schema_stmt) => (
-                                &schema_stmt.name.node,
-                                {
-                                    if let Some(doc) = &schema_stmt.doc {
-                                        doc.node.clone()
-                                    } else {
-                                        "".to_string()
-                                    }
-                                },
-                                schema_stmt.is_mixin,
-                                schema_stmt.is_protocol,
-                                false,
-                            ),
-                            ast::Stmt::Rule(rule_stmt) => (
-                                &rule_stmt.name.node,
-                                {
-                                    if let Some(doc) = &rule_stmt.doc {
-                                        doc.node.clone()
-                                    } else {
-                                        "".to_string()
-                                    }
-                                },
-                                false,
-                                false,
-                                true,
-                            ),
+                        let (name, doc, is_mixin, is_protocol, is_rule, type_params) =
+                            match &stmt.node {
+                                ast::Stmt::Schema(schema_stmt) => (
+                                    &schema_stmt.name.node,
+                                    {
+                                        if let Some(doc) = &schema_stmt.doc {
+                                            doc.node.clone()
+                                        } else {
+                                            "".to_string()
+                                        }
+                                    },
+                                    schema_stmt.is_mixin,
+                                    schema_stmt.is_protocol,
+                                    false,
+                                    schema_stmt.get_type_param_names(),
+                                ),
+                                ast::Stmt::Rule(rule_stmt) => (
+                                    &rule_stmt.name.node,
+                                    {
+                                        if let Some(doc) = &rule_stmt.doc {
+                                            doc.node.clone()
+                                        } else {
+                                            "".to_string()
+                                        }
+                                    },
+                                    false,
+                                    false,
+                                    true,
+                                    vec![],
+                                ),
 
-                            _ => continue,
-                        };
+                                _ => continue,
+                            };
                         if self.contains_object(name) {
                             self.handler.add_error(
                                 ErrorKind::UniqueKeyError,
@@ -104,6 +107,8 @@ impl<'ctx> Resolver<'ctx> {
                             }),
                             index_signature: None,
                             decorators: vec![],
+                            type_params,
+                            type_args: vec![],
                         };
                         self.insert_object(
                             name,
@@ -512,6 +517,9 @@ impl<'ctx> Resolver<'ctx> {
                 }],
             );
         }
+        // Type parameters are visible in all the type annotations of the schema.
+        let type_params = schema_stmt.get_type_param_names();
+        let outer_type_params = std::mem::replace(&mut self.ctx.type_params, type_params.clone());
         let schema_attr_names = schema_stmt.get_left_identifier_list();
         let schema_attr_names: Vec<String> = schema_attr_names
             .iter()
@@ -812,7 +820,10 @@ impl<'ctx> Resolver<'ctx> {
             }),
             index_signature,
             decorators,
+            type_params,
+            type_args: vec![],
         };
+        self.ctx.type_params = outer_type_params;
         self.ctx
             .schema_mapping
             .insert(schema_runtime_ty, Arc::new(RefCell::new(schema_ty.clone())));
@@ -939,6 +950,8 @@ impl<'ctx> Resolver<'ctx> {
             }),
             index_signature,
             decorators,
+            type_params: vec![],
+            type_args: vec![],
         }
     }
 }
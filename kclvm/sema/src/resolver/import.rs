@@ -32,6 +32,13 @@ impl<'ctx> Resolver<'ctx> {
                         }
                         // Plugin module.
                         if pkgpath.starts_with(PLUGIN_MODULE_PREFIX) {
+                            let name = pkgpath.trim_start_matches(PLUGIN_MODULE_PREFIX);
+                            if let Some(Err(err)) = self.plugin_stubs.get(name) {
+                                self.handler.add_compile_error(
+                                    &format!("invalid stub of the plugin '{}': {}", name, err),
+                                    stmt.get_span_pos(),
+                                );
+                            }
                             continue;
                         }
                         let real_path =
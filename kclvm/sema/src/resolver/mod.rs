@@ -9,6 +9,7 @@ mod import;
 mod r#loop;
 mod node;
 mod para;
+mod parallel;
 mod schema;
 pub mod scope;
 pub(crate) mod ty;
@@ -25,6 +26,7 @@ use std::sync::{Arc, Mutex};
 use std::{cell::RefCell, rc::Rc};
 
 use crate::lint::{CombinedLintPass, Linter};
+use crate::plugin::{load_plugin_stubs, PluginStub};
 use crate::pre_process::pre_process_program;
 use crate::resolver::scope::ScopeObject;
 use crate::resolver::ty_alias::type_alias_pass;
@@ -49,10 +51,21 @@ pub struct Resolver<'ctx> {
     pub options: Options,
     pub handler: Handler,
     pub linter: Linter<CombinedLintPass>,
+    /// The plugin stubs declared in the kcl.mod file keyed by the plugin name.
+    pub plugin_stubs: IndexMap<String, Result<PluginStub, String>>,
 }
 
 impl<'ctx> Resolver<'ctx> {
     pub fn new(program: &'ctx Program, options: Options) -> Self {
+        Self::new_with_plugin_stubs(program, options, load_plugin_stubs(&program.root))
+    }
+
+    /// New a resolver with the plugin stubs loaded from the kcl.mod file.
+    pub(crate) fn new_with_plugin_stubs(
+        program: &'ctx Program,
+        options: Options,
+        plugin_stubs: IndexMap<String, Result<PluginStub, String>>,
+    ) -> Self {
         let builtin_scope = Rc::new(RefCell::new(builtin_scope()));
         let scope = Rc::clone(&builtin_scope);
         Resolver {
@@ -66,6 +79,7 @@ impl<'ctx> Resolver<'ctx> {
             options,
             handler: Handler::default(),
             linter: Linter::<CombinedLintPass>::new(),
+            plugin_stubs,
         }
     }
 
@@ -99,6 +113,7 @@ impl<'ctx> Resolver<'ctx> {
             import_names: self.ctx.import_names.clone(),
             node_ty_map: self.node_ty_map.clone(),
             handler: self.handler.clone(),
+            plugin_stubs: self.plugin_stubs.clone(),
         };
         self.lint_check_scope_map();
         for diag in &self.linter.handler.diagnostics {
@@ -139,6 +154,8 @@ pub struct Context {
     pub ty_ctx: TypeContext,
     /// Type alias mapping
     pub type_alias_mapping: IndexMap<String, IndexMap<String, String>>,
+    /// Type parameters of the generic schema we are in.
+    pub type_params: Vec<String>,
 }
 
 /// Resolve options.
@@ -186,6 +203,7 @@ pub fn resolve_program_with_opts(
             resolver.node_ty_map = cached_scope.node_ty_map.clone()
         }
     }
+    resolver.resolve_packages_in_parallel();
     let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
     if let Some(cached_scope) = cached_scope.as_ref() {
         if let Ok(mut cached_scope) = cached_scope.try_lock() {
@@ -844,6 +844,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             );
         }
         let mut range = schema_expr.name.get_span_pos();
+        let mut instance_ty = def_ty.clone();
         let ret_ty = match &def_ty.kind {
             TypeKind::Dict(DictType { .. }) => {
                 let obj = self.new_config_expr_context_item(
@@ -866,17 +867,33 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     let name = schema_expr.name.node.get_name();
                     self.add_type_alias(&name, &ty_annotation_str);
                 }
+                // A generic schema is instantiated with the type arguments of the expected
+                // type, or the type arguments inferred from the config values.
+                let expected_ty = if schema_ty.is_generic() {
+                    self.expected_schema_instance(schema_ty)
+                } else {
+                    None
+                };
                 let obj = self.new_config_expr_context_item(
                     &schema_ty.name,
-                    def_ty.clone(),
+                    expected_ty.clone().unwrap_or_else(|| def_ty.clone()),
                     Position::dummy_pos(),
                     Position::dummy_pos(),
                 );
                 let init_stack_depth = self.switch_config_expr_context(Some(obj));
-                self.expr(&schema_expr.config);
+                let config_ty = self.expr(&schema_expr.config);
+                if schema_ty.is_generic() {
+                    instance_ty = match expected_ty {
+                        Some(expected_ty) => expected_ty,
+                        None => {
+                            let type_args = self.infer_schema_type_args(schema_ty, &config_ty);
+                            Arc::new(Type::schema(schema_ty.instantiate(&type_args)))
+                        }
+                    };
+                }
                 self.node_ty_map.insert(
                     self.get_node_key(schema_expr.config.id.clone()),
-                    def_ty.clone(),
+                    instance_ty.clone(),
                 );
                 self.clear_config_expr_context(init_stack_depth as usize, false);
                 if schema_ty.is_instance {
@@ -910,7 +927,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                 return self.any_ty();
             }
         };
-        let mut def_ty_clone = def_ty.as_ref().clone();
+        let mut def_ty_clone = instance_ty.as_ref().clone();
         if let TypeKind::Schema(schema_ty) = &mut def_ty_clone.kind {
             schema_ty.is_instance = true;
         }
@@ -1122,7 +1139,9 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
     fn walk_formatted_value(&mut self, formatted_value: &'ctx ast::FormattedValue) -> Self::Result {
         if let Some(spec) = &formatted_value.format_spec {
             let spec_lower = spec.to_lowercase();
-            if !VALID_FORMAT_SPEC_SET.contains(&spec_lower.as_str()) {
+            if !VALID_FORMAT_SPEC_SET.contains(&spec_lower.as_str())
+                && !kclvm_runtime::is_valid_format_spec(spec)
+            {
                 self.handler.add_compile_error(
                     &format!("{} is a invalid format spec", spec),
                     formatted_value.value.get_span_pos(),
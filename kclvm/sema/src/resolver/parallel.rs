@@ -0,0 +1,305 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast;
+use kclvm_ast::MAIN_PKG;
+use kclvm_error::diagnostic::Range;
+use kclvm_error::{Diagnostic, Position};
+use rayon::prelude::*;
+
+use super::scope::{NodeTyMap, Scope, ScopeKind, ScopeObject};
+use super::{Options, Resolver};
+use crate::builtin::system_module::STANDARD_SYSTEM_MODULES;
+use crate::plugin::{PluginStub, PLUGIN_MODULE_PREFIX};
+use crate::ty::SchemaType;
+
+/// A scope tree which owns its objects and child scopes. The resolver scopes are
+/// reference counted without atomics, so they are copied into the scope data to be
+/// sent to another thread and rebuilt there.
+struct ScopeData {
+    elems: IndexMap<String, ScopeObject>,
+    children: Vec<ScopeData>,
+    start: Position,
+    end: Position,
+    kind: ScopeKind,
+}
+
+impl ScopeData {
+    /// Copies the scope and all its child scopes.
+    fn from_scope(scope: &Rc<RefCell<Scope>>) -> Self {
+        let mut data = Self::package_objects(scope);
+        data.children = scope
+            .borrow()
+            .children
+            .iter()
+            .map(Self::from_scope)
+            .collect();
+        data
+    }
+
+    /// Copies the package level objects of the package scope, which are used to
+    /// resolve the packages importing it.
+    fn package_objects(scope: &Rc<RefCell<Scope>>) -> Self {
+        let scope = scope.borrow();
+        Self {
+            elems: scope
+                .elems
+                .iter()
+                .map(|(name, obj)| (name.to_string(), obj.borrow().clone()))
+                .collect(),
+            children: vec![],
+            start: scope.start.clone(),
+            end: scope.end.clone(),
+            kind: scope.kind.clone(),
+        }
+    }
+
+    /// Builds the scope tree in the current thread under the `parent` scope.
+    fn into_scope(self, parent: &Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
+        let scope = Rc::new(RefCell::new(Scope {
+            parent: Some(Rc::downgrade(parent)),
+            children: vec![],
+            elems: self
+                .elems
+                .into_iter()
+                .map(|(name, obj)| (name, Rc::new(RefCell::new(obj))))
+                .collect(),
+            start: self.start,
+            end: self.end,
+            kind: self.kind,
+        }));
+        let children = self
+            .children
+            .into_iter()
+            .map(|child| child.into_scope(&scope))
+            .collect();
+        scope.borrow_mut().children = children;
+        scope
+    }
+}
+
+/// A package to resolve with the scopes of its imported packages and the schema
+/// types of all its transitively imported packages.
+struct PackageTask {
+    pkgpath: String,
+    dep_scopes: Vec<(String, ScopeData)>,
+    schema_mapping: IndexMap<String, SchemaType>,
+}
+
+/// The resolved results of a package which are merged into the main resolver.
+struct PackageResult {
+    pkgpath: String,
+    scope: ScopeData,
+    schema_mapping: IndexMap<String, SchemaType>,
+    node_ty_map: NodeTyMap,
+    import_names: IndexMap<String, IndexMap<String, String>>,
+    global_names: IndexMap<String, IndexMap<String, Range>>,
+    type_alias_mapping: IndexMap<String, IndexMap<String, String>>,
+    diagnostics: IndexSet<Diagnostic>,
+    lint_diagnostics: IndexSet<Diagnostic>,
+}
+
+impl<'ctx> Resolver<'ctx> {
+    /// Resolve the packages imported by the main package layer by layer in the topological
+    /// order of the package imports. Packages in the same layer only import packages in the
+    /// previous layers, so they are resolved concurrently, each by its own resolver. Results
+    /// are merged in the package path order to keep the diagnostics deterministic.
+    ///
+    /// Packages in an import cycle and the packages importing them are left to the check of
+    /// the main package, which reports the cycle.
+    pub(crate) fn resolve_packages_in_parallel(&mut self) {
+        // Keep the main package scope at the first of all the package scopes.
+        let filename = self.ctx.filename.clone();
+        self.change_package_context(MAIN_PKG, &filename);
+        let deps = self.package_dependencies();
+        let layers = package_layers(&deps, self.scope_map.keys().cloned().collect());
+        for layer in layers {
+            let tasks = layer
+                .iter()
+                .map(|pkgpath| self.package_task(pkgpath, &deps))
+                .collect::<Vec<PackageTask>>();
+            let program = self.program;
+            let options = &self.options;
+            let plugin_stubs = &self.plugin_stubs;
+            let results = tasks
+                .into_par_iter()
+                .map(|task| resolve_package(program, options, plugin_stubs, task))
+                .collect::<Vec<PackageResult>>();
+            for result in results {
+                self.merge_package_result(result);
+            }
+        }
+    }
+
+    /// Returns the user packages imported by each package which is reachable from the main
+    /// package, sorted by the package path.
+    fn package_dependencies(&self) -> IndexMap<String, IndexSet<String>> {
+        let mut deps: IndexMap<String, IndexSet<String>> = IndexMap::default();
+        let mut stack = vec![MAIN_PKG.to_string()];
+        while let Some(pkgpath) = stack.pop() {
+            if deps.contains_key(&pkgpath) {
+                continue;
+            }
+            let mut imports = IndexSet::default();
+            for module in self.program.pkgs.get(&pkgpath).into_iter().flatten() {
+                for stmt in &module.body {
+                    if let ast::Stmt::Import(import_stmt) = &stmt.node {
+                        let path = &import_stmt.path.node;
+                        if !STANDARD_SYSTEM_MODULES.contains(&path.as_str())
+                            && !path.starts_with(PLUGIN_MODULE_PREFIX)
+                            && self.program.pkgs.contains_key(path)
+                        {
+                            imports.insert(path.to_string());
+                            stack.push(path.to_string());
+                        }
+                    }
+                }
+            }
+            deps.insert(pkgpath, imports);
+        }
+        deps.shift_remove(MAIN_PKG);
+        deps.sort_keys();
+        deps
+    }
+
+    /// Copies the imported package scopes and the schema types which are used to resolve
+    /// the package.
+    fn package_task(
+        &self,
+        pkgpath: &str,
+        deps: &IndexMap<String, IndexSet<String>>,
+    ) -> PackageTask {
+        let imports = &deps[pkgpath];
+        let dep_scopes = imports
+            .iter()
+            .filter_map(|dep| {
+                self.scope_map
+                    .get(dep)
+                    .map(|scope| (dep.to_string(), ScopeData::package_objects(scope)))
+            })
+            .collect();
+        let transitive_deps = transitive_dependencies(pkgpath, deps);
+        let schema_mapping = self
+            .ctx
+            .schema_mapping
+            .iter()
+            .filter(|(_, schema_ty)| transitive_deps.contains(&schema_ty.borrow().pkgpath))
+            .map(|(name, schema_ty)| (name.to_string(), schema_ty.borrow().clone()))
+            .collect();
+        PackageTask {
+            pkgpath: pkgpath.to_string(),
+            dep_scopes,
+            schema_mapping,
+        }
+    }
+
+    fn merge_package_result(&mut self, result: PackageResult) {
+        let scope = result.scope.into_scope(&self.builtin_scope);
+        self.scope_map.insert(result.pkgpath, scope);
+        self.ctx.schema_mapping.extend(
+            result
+                .schema_mapping
+                .into_iter()
+                .map(|(name, schema_ty)| (name, Arc::new(RefCell::new(schema_ty)))),
+        );
+        self.node_ty_map.extend(result.node_ty_map);
+        self.ctx.import_names.extend(result.import_names);
+        self.ctx.global_names.extend(result.global_names);
+        self.ctx
+            .type_alias_mapping
+            .extend(result.type_alias_mapping);
+        self.handler.diagnostics.extend(result.diagnostics);
+        self.linter
+            .handler
+            .diagnostics
+            .extend(result.lint_diagnostics);
+    }
+}
+
+/// Resolve a package with a new resolver in the current thread.
+fn resolve_package(
+    program: &ast::Program,
+    options: &Options,
+    plugin_stubs: &IndexMap<String, Result<PluginStub, String>>,
+    task: PackageTask,
+) -> PackageResult {
+    let mut resolver =
+        Resolver::new_with_plugin_stubs(program, options.clone(), plugin_stubs.clone());
+    for (pkgpath, scope) in task.dep_scopes {
+        let scope = scope.into_scope(&resolver.builtin_scope);
+        resolver.scope_map.insert(pkgpath, scope);
+    }
+    let dep_schema_names: IndexSet<String> = task.schema_mapping.keys().cloned().collect();
+    resolver.ctx.schema_mapping = task
+        .schema_mapping
+        .into_iter()
+        .map(|(name, schema_ty)| (name, Arc::new(RefCell::new(schema_ty))))
+        .collect();
+    resolver.check(&task.pkgpath);
+    let scope = ScopeData::from_scope(&resolver.scope_map[&task.pkgpath]);
+    PackageResult {
+        pkgpath: task.pkgpath,
+        scope,
+        schema_mapping: resolver
+            .ctx
+            .schema_mapping
+            .iter()
+            .filter(|(name, _)| !dep_schema_names.contains(*name))
+            .map(|(name, schema_ty)| (name.to_string(), schema_ty.borrow().clone()))
+            .collect(),
+        node_ty_map: std::mem::take(&mut resolver.node_ty_map),
+        import_names: std::mem::take(&mut resolver.ctx.import_names),
+        global_names: std::mem::take(&mut resolver.ctx.global_names),
+        type_alias_mapping: std::mem::take(&mut resolver.ctx.type_alias_mapping),
+        diagnostics: std::mem::take(&mut resolver.handler.diagnostics),
+        lint_diagnostics: std::mem::take(&mut resolver.linter.handler.diagnostics),
+    }
+}
+
+/// Splits the packages into layers. Packages in a layer only import the resolved packages
+/// and the packages in the previous layers. Packages which can't be placed in any layer,
+/// e.g., the packages in an import cycle are not returned.
+fn package_layers(
+    deps: &IndexMap<String, IndexSet<String>>,
+    mut resolved: IndexSet<String>,
+) -> Vec<Vec<String>> {
+    let mut pending = deps
+        .keys()
+        .filter(|pkgpath| !resolved.contains(*pkgpath))
+        .cloned()
+        .collect::<Vec<String>>();
+    let mut layers = vec![];
+    loop {
+        let layer = pending
+            .iter()
+            .filter(|pkgpath| deps[*pkgpath].iter().all(|dep| resolved.contains(dep)))
+            .cloned()
+            .collect::<Vec<String>>();
+        if layer.is_empty() {
+            break;
+        }
+        pending.retain(|pkgpath| !layer.contains(pkgpath));
+        resolved.extend(layer.iter().cloned());
+        layers.push(layer);
+    }
+    layers
+}
+
+/// Returns the package and all the packages it imports directly or indirectly.
+fn transitive_dependencies(
+    pkgpath: &str,
+    deps: &IndexMap<String, IndexSet<String>>,
+) -> IndexSet<String> {
+    let mut visited = IndexSet::default();
+    let mut stack = vec![pkgpath.to_string()];
+    while let Some(pkgpath) = stack.pop() {
+        if visited.insert(pkgpath.to_string()) {
+            if let Some(imports) = deps.get(&pkgpath) {
+                stack.extend(imports.iter().cloned());
+            }
+        }
+    }
+    visited
+}
@@ -41,6 +41,7 @@ impl<'ctx> Resolver<'ctx> {
             return ty;
         };
         self.ctx.schema = Some(Rc::new(RefCell::new(scope_ty.clone())));
+        self.ctx.type_params = scope_ty.type_params.clone();
         if let Some(args) = &schema_stmt.args {
             for (i, arg) in args.node.args.iter().enumerate() {
                 let ty = args.node.get_arg_type_node(i);
@@ -129,6 +130,7 @@ impl<'ctx> Resolver<'ctx> {
         }
         self.leave_scope();
         self.ctx.schema = None;
+        self.ctx.type_params.clear();
         ty
     }
 
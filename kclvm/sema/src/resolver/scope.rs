@@ -16,6 +16,7 @@ use std::{
     rc::{Rc, Weak},
 };
 
+use crate::plugin::PluginStub;
 use crate::resolver::Resolver;
 use crate::ty::TypeRef;
 use crate::{builtin::BUILTIN_FUNCTIONS, ty::TypeInferMethods};
@@ -285,6 +286,8 @@ pub struct ProgramScope {
     pub import_names: IndexMap<String, IndexMap<String, String>>,
     pub node_ty_map: NodeTyMap,
     pub handler: Handler,
+    /// The plugin stubs loaded by the resolver, which are reused by the namer.
+    pub plugin_stubs: IndexMap<String, Result<PluginStub, String>>,
 }
 
 unsafe impl Send for ProgramScope {}
@@ -0,0 +1,5 @@
+[package]
+name = "plugin_stub"
+
+[plugins]
+strings = "./plugins/libstrings.so"
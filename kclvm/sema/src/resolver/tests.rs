@@ -499,6 +499,51 @@ See also: kusion_models/core/v1/metadata.k."
     );
 }
 
+#[test]
+fn test_parallel_resolve_diagnostics_order() {
+    let resolve_errors = || {
+        let sess = Arc::new(ParseSession::default());
+        let mut program = load_program(
+            sess.clone(),
+            &["./src/resolver/test_data/parallel_resolve/main.k"],
+            None,
+            None,
+        )
+        .unwrap()
+        .program;
+        let scope = resolve_program(&mut program);
+        assert_eq!(
+            scope.pkgpaths(),
+            vec![
+                "__main__".to_string(),
+                "parallel_resolve.a".to_string(),
+                "parallel_resolve.b".to_string(),
+                "parallel_resolve.c".to_string(),
+                "parallel_resolve.d".to_string(),
+            ]
+        );
+        scope
+            .handler
+            .diagnostics
+            .iter()
+            .filter(|diag| diag.level == Level::Error)
+            .map(|diag| diag.messages[0].message.clone())
+            .collect::<Vec<String>>()
+    };
+    // Packages a, b and c are resolved concurrently before d which imports a, and the
+    // diagnostics are always ordered by the package layers and paths.
+    let expected = vec![
+        "expected int, got str(a)".to_string(),
+        "expected int, got str(b)".to_string(),
+        "expected int, got str(c)".to_string(),
+        "expected str, got int".to_string(),
+        "expected int, got str(main)".to_string(),
+    ];
+    for _ in 0..10 {
+        assert_eq!(resolve_errors(), expected);
+    }
+}
+
 #[test]
 fn test_pkg_scope() {
     let sess = Arc::new(ParseSession::default());
@@ -849,3 +894,93 @@ fn test_pkg_asname() {
     assert_eq!(diags[0].messages[0].message, "name 'pkg' is not defined");
     assert_eq!(diags[2].messages[0].message, "name 'subpkg' is not defined");
 }
+
+#[test]
+fn test_generic_schema() {
+    let mut program = parse_program("./src/resolver/test_data/generic_schema.k").unwrap();
+    let scope = resolve_program(&mut program);
+    assert_eq!(scope.handler.diagnostics.len(), 0);
+    let main_scope = scope.main_scope().unwrap();
+    let main_scope = main_scope.borrow();
+    let ty_str = |name: &str| main_scope.lookup(name).unwrap().borrow().ty.ty_str();
+    assert_eq!(ty_str("ints"), "Page[int]");
+    assert_eq!(ty_str("first"), "int");
+    assert_eq!(ty_str("pair"), "Pair[str, Page[str]]");
+    assert_eq!(ty_str("pairs"), "[Pair[str, int]]");
+    assert_eq!(ty_str("inferred"), "Page[str]");
+    assert_eq!(ty_str("bare"), "Page");
+    let ints_ty = main_scope
+        .lookup("ints")
+        .unwrap()
+        .borrow()
+        .ty
+        .into_schema_type();
+    assert_eq!(ints_ty.get_type_of_attr("items").unwrap().ty_str(), "[int]");
+    assert_eq!(
+        ints_ty.get_type_of_attr("next").unwrap().ty_str(),
+        "Page[int]"
+    );
+    // Generic types are erased to the schema names and type parameters are erased to any.
+    let module = &program.pkgs[kclvm_ast::MAIN_PKG][0];
+    if let ast::Stmt::Schema(schema_stmt) = &module.body[0].node {
+        assert_eq!(schema_stmt.get_type_param_names(), vec!["T".to_string()]);
+        if let ast::Stmt::SchemaAttr(attr) = &schema_stmt.body[0].node {
+            assert_eq!(attr.ty.node.to_string(), "[any]");
+        } else {
+            panic!("invalid schema attr")
+        }
+    } else {
+        panic!("invalid schema statement")
+    }
+    if let ast::Stmt::Assign(assign_stmt) = &module.body[2].node {
+        assert_eq!(assign_stmt.ty.as_ref().unwrap().node.to_string(), "Page");
+    } else {
+        panic!("invalid assign statement")
+    }
+}
+
+#[test]
+fn test_generic_schema_fail() {
+    let mut program = parse_program("./src/resolver/test_fail_data/generic_schema.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "schema 'Page' expected 1 type arguments, got 2",
+        "schema 'Person' is not a generic schema",
+        "expected [int], got [str(a)]",
+        "expected Page[int], got Page",
+    ];
+    for msg in err_messages {
+        assert!(
+            scope
+                .handler
+                .diagnostics
+                .iter()
+                .any(|diag| diag.messages[0].message == msg),
+            "missing diagnostic: {msg}"
+        );
+    }
+}
+
+#[test]
+fn test_plugin_stub() {
+    let mut program = parse_program("./src/resolver/test_fail_data/plugin_stub/main.k").unwrap();
+    let scope = resolve_program(&mut program);
+    let err_messages = [
+        "expected str, got int(1)",
+        "expected 1 positional argument, found 0",
+    ];
+    for msg in err_messages {
+        assert!(
+            scope
+                .handler
+                .diagnostics
+                .iter()
+                .any(|diag| diag.messages[0].message == msg),
+            "missing diagnostic: {msg}"
+        );
+    }
+    assert!(scope.handler.diagnostics.iter().any(|diag| diag.messages[0]
+        .message
+        .starts_with("attribute 'join' not found")));
+    assert_eq!(scope.handler.diagnostics.len(), 3);
+}
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::resolver::ty_erasure::erase_generic_type;
 use crate::resolver::Resolver;
-use crate::ty::parser::parse_type_str;
+use crate::ty::parser::{parse_type_str, split_generic_type_str};
 use crate::ty::{
-    assignable_to, is_upper_bound, Attr, DictType, Parameter, SchemaType, Type, TypeKind, TypeRef,
+    assignable_to, is_upper_bound, Attr, DictType, Parameter, SchemaType, Type, TypeInferMethods,
+    TypeKind, TypeRef,
 };
 use indexmap::IndexMap;
 use kclvm_ast::ast;
@@ -71,10 +74,21 @@ impl<'ctx> Resolver<'ctx> {
         };
         // If a named type, find it from scope to get the specific type
         let ret_ty = self.upgrade_named_ty_with_scope(ty.clone(), &range, ty_node);
-        self.add_type_alias(
-            &ty.into_type_annotation_str(),
-            &ret_ty.into_type_annotation_str(),
-        );
+        if !ret_ty.contains_type_param() {
+            self.add_type_alias(
+                &ty.into_type_annotation_str(),
+                &ret_ty.into_type_annotation_str(),
+            );
+        }
+        if let Some(ty) = ty_node {
+            // The generic types are erased before the type alias pass, so add the
+            // type alias of the erased type annotation as well.
+            let mut erased_ty = ty.node.clone();
+            erase_generic_type(&mut erased_ty, &self.ctx.type_params);
+            if erased_ty != ty.node {
+                self.add_type_alias(&erased_ty.to_string(), &ret_ty.into_type_annotation_str());
+            }
+        }
         if let Some(ty) = ty_node {
             self.node_ty_map
                 .insert(self.get_node_key(ty.id.clone()), ret_ty.clone());
@@ -86,7 +100,9 @@ impl<'ctx> Resolver<'ctx> {
         let ty: TypeRef = parse_type_str(ty_str);
         // If a named type, find it from scope to get the specific type
         let ret_ty = self.upgrade_named_ty_with_scope(ty, &range, None);
-        self.add_type_alias(ty_str, &ret_ty.into_type_annotation_str());
+        if !ret_ty.contains_type_param() {
+            self.add_type_alias(ty_str, &ret_ty.into_type_annotation_str());
+        }
         ret_ty
     }
 
@@ -336,6 +352,18 @@ impl<'ctx> Resolver<'ctx> {
                     })
                     .collect::<Vec<TypeRef>>(),
             ),
+            TypeKind::Named(ty_str) if self.ctx.type_params.contains(ty_str) => {
+                let ty = Arc::new(Type::type_param(ty_str));
+                if let Some(ty_node) = ty_node {
+                    self.node_ty_map
+                        .insert(self.get_node_key(ty_node.id.clone()), ty.clone());
+                }
+                ty
+            }
+            TypeKind::Named(ty_str) if split_generic_type_str(ty_str).is_some() => {
+                let (name, type_args) = split_generic_type_str(ty_str).unwrap();
+                self.upgrade_generic_ty_with_scope(&name, &type_args, range, ty_node)
+            }
             TypeKind::Named(ty_str) => {
                 let ty_str = ty_str_replace_pkgpath(ty_str, &self.ctx.pkgpath);
                 let names: Vec<&str> = if ty_str.starts_with('@') {
@@ -423,6 +451,127 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 
+    /// Resolve the generic schema type e.g., `Page[int]` and instantiate the schema
+    /// with the type arguments.
+    fn upgrade_generic_ty_with_scope(
+        &mut self,
+        name: &str,
+        type_args: &[String],
+        range: &Range,
+        ty_node: Option<&ast::Node<ast::Type>>,
+    ) -> ResolvedResult {
+        let generic_node = ty_node.and_then(|ty_node| match &ty_node.node {
+            ast::Type::Generic(generic_ty) => Some((ty_node, generic_ty)),
+            _ => None,
+        });
+        // Resolve the schema name with the identifier node to record the identifier types.
+        let name_node = generic_node.map(|(ty_node, generic_ty)| {
+            ast::Node::node_with_pos_and_id(
+                ast::Type::Named(generic_ty.name.clone()),
+                ty_node.pos(),
+                ty_node.id.clone(),
+            )
+        });
+        let base_ty = self.upgrade_named_ty_with_scope(
+            Arc::new(Type::named(name)),
+            range,
+            name_node.as_ref(),
+        );
+        // Register the type alias of the schema name for the type erasure.
+        self.add_type_alias(name, &base_ty.into_type_annotation_str());
+        let type_args: Vec<TypeRef> = type_args
+            .iter()
+            .enumerate()
+            .map(|(i, type_arg)| {
+                let arg_node = generic_node.and_then(|(_, generic_ty)| generic_ty.type_args.get(i));
+                let ty = match arg_node {
+                    Some(arg_node) => Arc::new(arg_node.node.clone().into()),
+                    None => parse_type_str(type_arg),
+                };
+                let ty = self.upgrade_named_ty_with_scope(ty, range, arg_node.map(|n| n.as_ref()));
+                if let Some(arg_node) = arg_node {
+                    self.node_ty_map
+                        .insert(self.get_node_key(arg_node.id.clone()), ty.clone());
+                }
+                ty
+            })
+            .collect();
+        match &base_ty.kind {
+            TypeKind::Schema(schema_ty) if !schema_ty.type_params.is_empty() => {
+                if schema_ty.type_params.len() != type_args.len() {
+                    self.handler.add_type_error(
+                        &format!(
+                            "schema '{}' expected {} type arguments, got {}",
+                            schema_ty.name,
+                            schema_ty.type_params.len(),
+                            type_args.len()
+                        ),
+                        range.clone(),
+                    );
+                    return base_ty;
+                }
+                Arc::new(Type::schema(schema_ty.instantiate(&type_args)))
+            }
+            TypeKind::Schema(schema_ty) => {
+                self.handler.add_type_error(
+                    &format!("schema '{}' is not a generic schema", schema_ty.name),
+                    range.clone(),
+                );
+                base_ty
+            }
+            // The error has been reported when resolving the name.
+            _ if base_ty.is_any() => base_ty,
+            _ => {
+                self.handler.add_type_error(
+                    &format!("'{}' is not a generic schema", base_ty.ty_str()),
+                    range.clone(),
+                );
+                base_ty
+            }
+        }
+    }
+
+    /// Returns the expected instantiation of the generic schema in the config expression
+    /// context, e.g., `Page[int]` for the schema expression in `p: Page[int] = Page {}`.
+    pub(crate) fn expected_schema_instance(&self, schema_ty: &SchemaType) -> Option<TypeRef> {
+        match self.ctx.config_expr_context.last() {
+            Some(Some(obj)) => match &obj.ty.kind {
+                TypeKind::Schema(expected_ty)
+                    if !expected_ty.type_args.is_empty()
+                        && expected_ty.ty_str_with_pkgpath() == schema_ty.ty_str_with_pkgpath() =>
+                {
+                    Some(obj.ty.clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Infers the type arguments of the generic schema from the types of the config
+    /// values e.g., `Page {items = [1]}` is `Page[int]`. The type parameters which can't
+    /// be inferred are `any`.
+    pub(crate) fn infer_schema_type_args(
+        &self,
+        schema_ty: &SchemaType,
+        config_ty: &TypeRef,
+    ) -> Vec<TypeRef> {
+        let mut bindings = HashMap::new();
+        if let TypeKind::Dict(DictType { attrs, .. }) = &config_ty.kind {
+            for (name, attr) in attrs {
+                if let Some(attr_ty) = schema_ty.get_type_of_attr(name) {
+                    let value_ty = self.ctx.ty_ctx.infer_to_variable_type(attr.ty.clone());
+                    bind_type_params(&attr_ty, &value_ty, &mut bindings);
+                }
+            }
+        }
+        schema_ty
+            .type_params
+            .iter()
+            .map(|name| bindings.get(name).cloned().unwrap_or_else(|| self.any_ty()))
+            .collect()
+    }
+
     pub fn add_type_alias(&mut self, name: &str, alias: &str) {
         if alias.starts_with('@') {
             if name == &alias[1..] {
@@ -445,3 +594,52 @@ impl<'ctx> Resolver<'ctx> {
         }
     }
 }
+
+/// Binds the type parameters in `param_ty` to the matched parts of `value_ty`. The first
+/// binding of a type parameter wins.
+fn bind_type_params(
+    param_ty: &TypeRef,
+    value_ty: &TypeRef,
+    bindings: &mut HashMap<String, TypeRef>,
+) {
+    if value_ty.is_any() && !matches!(value_ty.kind, TypeKind::TypeParam(_)) {
+        return;
+    }
+    match (&param_ty.kind, &value_ty.kind) {
+        (TypeKind::TypeParam(name), _) => {
+            bindings
+                .entry(name.to_string())
+                .or_insert_with(|| value_ty.clone());
+        }
+        (TypeKind::List(param_item_ty), TypeKind::List(value_item_ty)) => {
+            bind_type_params(param_item_ty, value_item_ty, bindings)
+        }
+        (
+            TypeKind::Dict(DictType {
+                key_ty: param_key_ty,
+                val_ty: param_val_ty,
+                ..
+            }),
+            TypeKind::Dict(DictType {
+                key_ty: value_key_ty,
+                val_ty: value_val_ty,
+                ..
+            }),
+        ) => {
+            bind_type_params(param_key_ty, value_key_ty, bindings);
+            bind_type_params(param_val_ty, value_val_ty, bindings);
+        }
+        (TypeKind::Schema(param_schema_ty), TypeKind::Schema(value_schema_ty))
+            if param_schema_ty.ty_str_with_pkgpath() == value_schema_ty.ty_str_with_pkgpath() =>
+        {
+            for (param_arg, value_arg) in param_schema_ty
+                .type_args
+                .iter()
+                .zip(value_schema_ty.type_args.iter())
+            {
+                bind_type_params(param_arg, value_arg, bindings);
+            }
+        }
+        _ => {}
+    }
+}
@@ -2,6 +2,7 @@ use indexmap::IndexMap;
 use kclvm_ast::ast::Node;
 use kclvm_ast::walker::MutSelfMutWalker;
 use kclvm_ast::{ast, walk_if_mut, walk_list_mut};
+use rayon::prelude::*;
 
 #[derive(Default)]
 struct TypeAliasTransformer {
@@ -145,11 +146,11 @@ pub fn type_alias_pass(
     program: &mut ast::Program,
     type_alias_mapping: IndexMap<String, IndexMap<String, String>>,
 ) {
-    for (pkgpath, modules) in program.pkgs.iter_mut() {
-        for module in modules.iter_mut() {
-            if let Some(type_alias_mapping) = type_alias_mapping.get(pkgpath) {
-                fix_type_alias_identifier(module, type_alias_mapping.clone());
-            }
+    program.pkgs.par_iter_mut().for_each(|(pkgpath, modules)| {
+        if let Some(type_alias_mapping) = type_alias_mapping.get(pkgpath) {
+            modules
+                .par_iter_mut()
+                .for_each(|module| fix_type_alias_identifier(module, type_alias_mapping.clone()));
         }
-    }
+    });
 }
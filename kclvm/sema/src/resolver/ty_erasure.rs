@@ -1,30 +1,50 @@
 use kclvm_ast::ast;
 use kclvm_ast::walker::MutSelfMutWalker;
+use rayon::prelude::*;
 
 #[derive(Default)]
-struct TypeErasureTransformer;
+struct TypeErasureTransformer {
+    /// Type parameters of the generic schema we are in.
+    type_params: Vec<String>,
+}
 const FUNCTION: &str = "function";
 
 impl<'ctx> MutSelfMutWalker<'ctx> for TypeErasureTransformer {
     fn walk_schema_stmt(&mut self, schema_stmt: &'ctx mut ast::SchemaStmt) {
+        self.type_params = schema_stmt.get_type_param_names();
         if let Some(schema_index_signature) = schema_stmt.index_signature.as_deref_mut() {
             if let kclvm_ast::ast::Type::Function(_) =
                 &mut schema_index_signature.node.value_ty.node
             {
                 schema_index_signature.node.value_ty.node = FUNCTION.to_string().into();
             }
+            erase_generic_type(
+                &mut schema_index_signature.node.key_ty.node,
+                &self.type_params,
+            );
+            erase_generic_type(
+                &mut schema_index_signature.node.value_ty.node,
+                &self.type_params,
+            );
+        }
+        if let Some(args) = &mut schema_stmt.args {
+            for ty in args.node.ty_list.iter_mut().flatten() {
+                erase_generic_type(&mut ty.node, &self.type_params);
+            }
         }
         for item in schema_stmt.body.iter_mut() {
             if let kclvm_ast::ast::Stmt::SchemaAttr(attr) = &mut item.node {
                 self.walk_schema_attr(attr);
             }
         }
+        self.type_params.clear();
     }
 
     fn walk_schema_attr(&mut self, schema_attr: &'ctx mut ast::SchemaAttr) {
         if let kclvm_ast::ast::Type::Function(_) = schema_attr.ty.as_ref().node {
             schema_attr.ty.node = FUNCTION.to_string().into();
         }
+        erase_generic_type(&mut schema_attr.ty.node, &self.type_params);
     }
     fn walk_assign_stmt(&mut self, assign_stmt: &'ctx mut ast::AssignStmt) {
         if let Some(ty) = &mut assign_stmt.ty {
@@ -34,6 +54,9 @@ impl<'ctx> MutSelfMutWalker<'ctx> for TypeErasureTransformer {
                 }
             }
         }
+        if let Some(ty) = &mut assign_stmt.ty {
+            erase_generic_type(&mut ty.node, &self.type_params);
+        }
     }
     fn walk_type_alias_stmt(&mut self, type_alias_stmt: &'ctx mut ast::TypeAliasStmt) {
         if let kclvm_ast::ast::Type::Function(_) = type_alias_stmt.ty.as_ref().node {
@@ -45,15 +68,60 @@ impl<'ctx> MutSelfMutWalker<'ctx> for TypeErasureTransformer {
             if let kclvm_ast::ast::Type::Function(_) = ty.as_ref().node {
                 ty.node = FUNCTION.to_string().into();
             }
+            erase_generic_type(&mut ty.node, &self.type_params);
         }
     }
+    fn walk_type(&mut self, ty: &'ctx mut ast::Type) {
+        erase_generic_type(ty, &self.type_params);
+    }
 }
 
-/// Run a pass on AST and change the function type to the `Named("function")` type
-pub fn type_func_erasure_pass<'ctx>(program: &'ctx mut ast::Program) {
-    for (_, modules) in program.pkgs.iter_mut() {
-        for module in modules.iter_mut() {
-            TypeErasureTransformer::default().walk_module(module);
+/// Erase the generic schema types e.g., `Page[int]` to the schema names e.g., `Page`,
+/// and erase the type parameters of the generic schema to the any type.
+pub(crate) fn erase_generic_type(ty: &mut ast::Type, type_params: &[String]) {
+    match ty {
+        ast::Type::Named(identifier) if type_params.contains(&identifier.get_name()) => {
+            *ty = ast::Type::Any;
+        }
+        ast::Type::Generic(generic_ty) => {
+            *ty = ast::Type::Named(generic_ty.name.clone());
         }
+        ast::Type::List(list_ty) => {
+            if let Some(ty) = &mut list_ty.inner_type {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+        }
+        ast::Type::Dict(dict_ty) => {
+            if let Some(ty) = &mut dict_ty.key_type {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+            if let Some(ty) = &mut dict_ty.value_type {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+        }
+        ast::Type::Union(union_ty) => {
+            for ty in union_ty.type_elements.iter_mut() {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+        }
+        ast::Type::Function(func_ty) => {
+            for ty in func_ty.params_ty.iter_mut().flatten() {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+            if let Some(ty) = &mut func_ty.ret_ty {
+                erase_generic_type(&mut ty.node, type_params);
+            }
+        }
+        ast::Type::Any | ast::Type::Named(_) | ast::Type::Basic(_) | ast::Type::Literal(_) => {}
     }
 }
+
+/// Run a pass on AST and change the function type to the `Named("function")` type,
+/// the generic schema type to the schema name and the type parameter to the `any` type.
+pub fn type_func_erasure_pass<'ctx>(program: &'ctx mut ast::Program) {
+    program
+        .pkgs
+        .par_iter_mut()
+        .flat_map(|(_, modules)| modules.par_iter_mut())
+        .for_each(|module| TypeErasureTransformer::default().walk_module(module));
+}
@@ -11,6 +11,7 @@ pub const INT_TYPE_STR: &str = "int";
 pub const FLOAT_TYPE_STR: &str = "float";
 pub const STR_TYPE_STR: &str = "str";
 pub const BOOL_TYPE_STR: &str = "bool";
+pub const DECIMAL_TYPE_STR: &str = "decimal";
 pub const ANY_TYPE_STR: &str = "any";
 pub const NONE_TYPE_STR: &str = "NoneType";
 pub const UNDEFINED_TYPE_STR: &str = "UndefinedType";
@@ -20,6 +21,7 @@ pub const DICT_TYPE_STR: &str = "dict";
 pub const SCHEMA_TYPE_STR: &str = "schema";
 pub const NUMBER_MULTIPLIER_TYPE_STR: &str = "number_multiplier";
 pub const NUMBER_MULTIPLIER_PKG_TYPE_STR: &str = "units.NumberMultiplier";
+pub const QUANTITY_PKG_TYPE_STR: &str = "units.Quantity";
 pub const NUMBER_MULTIPLIER_REGEX: &str =
     r"^([1-9][0-9]{0,63})(E|P|T|G|M|K|k|m|u|n|Ei|Pi|Ti|Gi|Mi|Ki)$";
 
@@ -33,7 +35,13 @@ pub const RESERVED_TYPE_IDENTIFIERS: [&str; 5] = [
     STR_TYPE_STR,
     BOOL_TYPE_STR,
 ];
-pub const BUILTIN_TYPES: [&str; 4] = [INT_TYPE_STR, FLOAT_TYPE_STR, STR_TYPE_STR, BOOL_TYPE_STR];
+pub const BUILTIN_TYPES: [&str; 5] = [
+    INT_TYPE_STR,
+    FLOAT_TYPE_STR,
+    STR_TYPE_STR,
+    BOOL_TYPE_STR,
+    DECIMAL_TYPE_STR,
+];
 
 pub const MODULE_TYPE_STR: &str = "module";
 pub const NAMED_TYPE_STR: &str = "named";
@@ -56,6 +64,7 @@ pub const TYPES_MAPPING: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
     mapping.insert(FLOAT_TYPE_STR.to_string(), Type::FLOAT);
     mapping.insert(STR_TYPE_STR.to_string(), Type::STR);
     mapping.insert(BOOL_TYPE_STR.to_string(), Type::BOOL);
+    mapping.insert(DECIMAL_TYPE_STR.to_string(), Type::DECIMAL);
     mapping.insert(ANY_TYPE_STR.to_string(), Type::ANY);
     mapping.insert("[]".to_string(), Type::list(Arc::new(Type::ANY)));
     mapping.insert("[any]".to_string(), Type::list(Arc::new(Type::ANY)));
@@ -120,6 +129,12 @@ impl Type {
         flags: TypeFlags::BOOL,
         is_type_alias: false,
     };
+    /// Type constant `decimal`.
+    pub const DECIMAL: Type = Type {
+        kind: TypeKind::Decimal,
+        flags: TypeFlags::DECIMAL,
+        is_type_alias: false,
+    };
     /// Type constant `any`.
     pub const ANY: Type = Type {
         kind: TypeKind::Any,
@@ -143,6 +143,15 @@ impl Type {
             is_type_alias: false,
         }
     }
+    /// Construct a type parameter type, which is checked as the any type.
+    #[inline]
+    pub fn type_param(name: &str) -> Type {
+        Type {
+            kind: TypeKind::TypeParam(name.to_string()),
+            flags: TypeFlags::ANY | TypeFlags::TYPE_PARAM,
+            is_type_alias: false,
+        }
+    }
     /// Construct a number multiplier type.
     #[inline]
     pub fn number_multiplier(value: f64, raw_value: i64, binary_suffix: &str) -> Type {
@@ -152,6 +161,7 @@ impl Type {
                 raw_value,
                 binary_suffix: binary_suffix.to_string(),
                 is_literal: true,
+                is_quantity: false,
             }),
             flags: TypeFlags::NUMBER_MULTIPLIER,
             is_type_alias: false,
@@ -165,6 +175,22 @@ impl Type {
                 raw_value: 0,
                 binary_suffix: "".to_string(),
                 is_literal: false,
+                is_quantity: false,
+            }),
+            flags: TypeFlags::NUMBER_MULTIPLIER,
+            is_type_alias: false,
+        }
+    }
+    /// Construct the `units.Quantity` type, which is a non-literal number multiplier type.
+    #[inline]
+    pub fn quantity_ty() -> Type {
+        Type {
+            kind: TypeKind::NumberMultiplier(NumberMultiplierType {
+                value: 0.0,
+                raw_value: 0,
+                binary_suffix: "".to_string(),
+                is_literal: false,
+                is_quantity: true,
             }),
             flags: TypeFlags::NUMBER_MULTIPLIER,
             is_type_alias: false,
@@ -242,6 +268,11 @@ impl Type {
     pub fn is_float(&self) -> bool {
         self.flags.contains(TypeFlags::FLOAT)
     }
+    /// Whether is a decimal type.
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        self.flags.contains(TypeFlags::DECIMAL)
+    }
     /// Whether is a bool type.
     #[inline]
     pub fn is_bool(&self) -> bool {
@@ -271,7 +302,7 @@ impl Type {
     pub fn is_primitive(&self) -> bool {
         matches!(
             &self.kind,
-            TypeKind::Bool | TypeKind::Int | TypeKind::Float | TypeKind::Str
+            TypeKind::Bool | TypeKind::Int | TypeKind::Float | TypeKind::Decimal | TypeKind::Str
         )
     }
     /// Whether is a None type.
@@ -343,6 +374,11 @@ impl Type {
     pub fn is_number_multiplier(&self) -> bool {
         self.flags.contains(TypeFlags::NUMBER_MULTIPLIER)
     }
+    /// Whether is a type parameter type.
+    #[inline]
+    pub fn is_type_param(&self) -> bool {
+        self.flags.contains(TypeFlags::TYPE_PARAM)
+    }
     /// Whether is a module type.
     #[inline]
     pub fn is_module(&self) -> bool {
@@ -360,6 +396,7 @@ impl Type {
             | TypeKind::IntLit(_)
             | TypeKind::Float
             | TypeKind::FloatLit(_)
+            | TypeKind::Decimal
             | TypeKind::Str
             | TypeKind::StrLit(_)
             | TypeKind::List(_)
@@ -367,7 +404,8 @@ impl Type {
             | TypeKind::Union(_)
             | TypeKind::Schema(_)
             | TypeKind::NumberMultiplier(_)
-            | TypeKind::Function(_) => true,
+            | TypeKind::Function(_)
+            | TypeKind::TypeParam(_) => true,
             TypeKind::Void | TypeKind::Module(_) | TypeKind::Named(_) => false,
         }
     }
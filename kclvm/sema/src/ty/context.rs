@@ -104,6 +104,36 @@ impl TypeContext {
         )
     }
 
+    #[inline]
+    pub fn is_number_multiplier_or_number_type(&self, ty: TypeRef) -> bool {
+        self.is_kind_type_or_kind_union_type(
+            ty,
+            &[
+                TypeFlags::INT,
+                TypeFlags::FLOAT,
+                TypeFlags::NUMBER_MULTIPLIER,
+            ],
+        )
+    }
+
+    #[inline]
+    pub fn is_decimal_or_int_type(&self, ty: TypeRef) -> bool {
+        self.is_kind_type_or_kind_union_type(ty, &[TypeFlags::INT, TypeFlags::DECIMAL])
+    }
+
+    #[inline]
+    pub fn is_decimal_or_number_type(&self, ty: TypeRef) -> bool {
+        self.is_kind_type_or_kind_union_type(
+            ty,
+            &[
+                TypeFlags::INT,
+                TypeFlags::FLOAT,
+                TypeFlags::BOOL,
+                TypeFlags::DECIMAL,
+            ],
+        )
+    }
+
     #[inline]
     pub fn is_config_type_or_config_union_type(&self, ty: TypeRef) -> bool {
         self.is_kind_type_or_kind_union_type(ty, &[TypeFlags::DICT, TypeFlags::SCHEMA])
@@ -123,6 +153,7 @@ impl TypeContext {
                 TypeFlags::FLOAT,
                 TypeFlags::BOOL,
                 TypeFlags::STR,
+                TypeFlags::DECIMAL,
             ],
         )
     }
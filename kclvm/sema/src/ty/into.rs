@@ -110,11 +110,15 @@ impl Type {
                         number_multiplier.raw_value,
                         number_multiplier.binary_suffix
                     )
+                } else if number_multiplier.is_quantity {
+                    QUANTITY_PKG_TYPE_STR.to_string()
                 } else {
                     NUMBER_MULTIPLIER_PKG_TYPE_STR.to_string()
                 }
             }
             TypeKind::Function(fn_ty) => fn_ty.ty_str(),
+            // Type parameters are erased to the any type at runtime.
+            TypeKind::TypeParam(_) => ANY_TYPE_STR.to_string(),
             _ => self.ty_str(),
         }
     }
@@ -130,7 +134,17 @@ impl From<ast::Type> for Type {
                 ast::BasicType::Float => Type::FLOAT,
                 ast::BasicType::Str => Type::STR,
             },
-            ast::Type::Named(identifier) => Type::named(&identifier.get_name()),
+            ast::Type::Named(identifier) => {
+                let name = identifier.get_name();
+                if name == DECIMAL_TYPE_STR {
+                    Type::DECIMAL
+                } else {
+                    Type::named(&name)
+                }
+            }
+            ast::Type::Generic(generic_ty) => {
+                Type::named(&ast::Type::Generic(generic_ty).to_string())
+            }
             ast::Type::List(list_ty) => Type::list(
                 list_ty
                     .inner_type
@@ -69,6 +69,7 @@ impl Type {
             TypeKind::IntLit(v) => format!("{}({})", INT_TYPE_STR, v),
             TypeKind::Float => FLOAT_TYPE_STR.to_string(),
             TypeKind::FloatLit(v) => format!("{}({})", FLOAT_TYPE_STR, v),
+            TypeKind::Decimal => DECIMAL_TYPE_STR.to_string(),
             TypeKind::Str => STR_TYPE_STR.to_string(),
             TypeKind::StrLit(v) => format!("{}({})", STR_TYPE_STR, v),
             TypeKind::List(item_ty) => format!("[{}]", item_ty.ty_str()),
@@ -80,12 +81,37 @@ impl Type {
                 .map(|ty| ty.ty_str())
                 .collect::<Vec<String>>()
                 .join(" | "),
-            TypeKind::Schema(schema_ty) => schema_ty.name.to_string(),
+            TypeKind::Schema(schema_ty) => schema_ty.ty_str(),
             TypeKind::NumberMultiplier(number_multiplier) => number_multiplier.ty_str(),
             TypeKind::Function(func_ty) => func_ty.ty_str(),
             TypeKind::Void => VOID_TYPE_STR.to_string(),
             TypeKind::Module(module_ty) => format!("{} '{}'", MODULE_TYPE_STR, module_ty.pkgpath),
             TypeKind::Named(name) => name.to_string(),
+            TypeKind::TypeParam(name) => name.to_string(),
+        }
+    }
+
+    /// Whether the type contains any type parameter of a generic schema.
+    pub fn contains_type_param(&self) -> bool {
+        match &self.kind {
+            TypeKind::TypeParam(_) => true,
+            TypeKind::List(item_ty) => item_ty.contains_type_param(),
+            TypeKind::Dict(DictType { key_ty, val_ty, .. }) => {
+                key_ty.contains_type_param() || val_ty.contains_type_param()
+            }
+            TypeKind::Union(types) => types.iter().any(|ty| ty.contains_type_param()),
+            TypeKind::Function(fn_ty) => {
+                fn_ty.return_ty.contains_type_param()
+                    || fn_ty
+                        .params
+                        .iter()
+                        .any(|param| param.ty.contains_type_param())
+            }
+            TypeKind::Schema(schema_ty) => schema_ty
+                .type_args
+                .iter()
+                .any(|ty| ty.contains_type_param()),
+            _ => false,
         }
     }
 
@@ -125,6 +151,8 @@ pub enum TypeKind {
     Float,
     /// A primitive float literal type.
     FloatLit(f64),
+    /// The exact decimal number type. Written as `decimal`.
+    Decimal,
     /// The primitive string type; holds a Unicode scalar value
     /// (a non-surrogate code point). Written as `str`.
     Str,
@@ -148,12 +176,14 @@ pub enum TypeKind {
     Module(ModuleType),
     /// A named type alias.
     Named(String),
+    /// A type parameter of a generic schema e.g., `T` in `schema Page[type T]`.
+    TypeParam(String),
 }
 
 bitflags::bitflags! {
     /// TypeFlags provides fast access to information that is also contained
     /// in `kind`.
-    pub struct TypeFlags: u16 {
+    pub struct TypeFlags: u32 {
         const VOID = 1 << 0;
         const INT = 1 << 1;
         const FLOAT = 1 << 2;
@@ -170,6 +200,8 @@ bitflags::bitflags! {
         const FUNCTION = 1 << 13;
         const MODULE = 1 << 14;
         const NAMED = 1 << 15;
+        const TYPE_PARAM = 1 << 16;
+        const DECIMAL = 1 << 17;
     }
 }
 
@@ -236,9 +268,67 @@ pub struct SchemaType {
     pub index_signature: Option<Box<SchemaIndexSignature>>,
     /// Schema decorators including self and attribute decorators.
     pub decorators: Vec<Decorator>,
+    /// Type parameters of a generic schema e.g., `T` in `schema Page[type T]`.
+    pub type_params: Vec<String>,
+    /// Type arguments of an instantiated generic schema e.g., `int` in `Page[int]`.
+    pub type_args: Vec<TypeRef>,
 }
 
 impl SchemaType {
+    /// Get the schema type string with the type arguments e.g., `Page[int]`.
+    pub fn ty_str(&self) -> String {
+        if self.type_args.is_empty() {
+            self.name.to_string()
+        } else {
+            format!(
+                "{}[{}]",
+                self.name,
+                self.type_args
+                    .iter()
+                    .map(|ty| ty.ty_str())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+    /// Whether the schema is a generic schema which is not instantiated.
+    #[inline]
+    pub fn is_generic(&self) -> bool {
+        !self.type_params.is_empty() && self.type_args.is_empty()
+    }
+    /// Instantiate the generic schema with the type arguments. The type parameters in the
+    /// attribute, parameter and index signature types are substituted by the type arguments.
+    pub fn instantiate(&self, type_args: &[TypeRef]) -> SchemaType {
+        let mapping: HashMap<String, TypeRef> = self
+            .type_params
+            .iter()
+            .cloned()
+            .zip(type_args.iter().cloned())
+            .collect();
+        let mut schema_ty = self.substitute_type_params(&mapping);
+        schema_ty.type_args = type_args.to_vec();
+        schema_ty
+    }
+
+    fn substitute_type_params(&self, mapping: &HashMap<String, TypeRef>) -> SchemaType {
+        let mut schema_ty = self.clone();
+        for attr in schema_ty.attrs.values_mut() {
+            attr.ty = substitute_type_params(&attr.ty, mapping);
+        }
+        for param in schema_ty.func.params.iter_mut() {
+            param.ty = substitute_type_params(&param.ty, mapping);
+        }
+        if let Some(index_signature) = schema_ty.index_signature.as_mut() {
+            index_signature.key_ty = substitute_type_params(&index_signature.key_ty, mapping);
+            index_signature.val_ty = substitute_type_params(&index_signature.val_ty, mapping);
+        }
+        schema_ty.type_args = schema_ty
+            .type_args
+            .iter()
+            .map(|ty| substitute_type_params(ty, mapping))
+            .collect();
+        schema_ty
+    }
     /// Get the object type string with pkgpath
     pub fn ty_str_with_pkgpath(&self) -> String {
         if self.pkgpath.is_empty() || self.pkgpath == MAIN_PKG {
@@ -306,15 +396,19 @@ impl SchemaType {
         } else {
             "".to_string()
         };
-        let params: String = if self.func.params.is_empty() {
+        let params: String = if self.type_params.is_empty() && self.func.params.is_empty() {
             "".to_string()
         } else {
             format!(
                 "[{}]",
-                self.func
-                    .params
+                self.type_params
                     .iter()
-                    .map(|p| format!("{}: {}", p.name.clone(), p.ty.ty_str()))
+                    .map(|name| format!("type {}", name))
+                    .chain(self.func.params.iter().map(|p| format!(
+                        "{}: {}",
+                        p.name.clone(),
+                        p.ty.ty_str()
+                    )))
                     .collect::<Vec<String>>()
                     .join(", ")
             )
@@ -323,6 +417,63 @@ impl SchemaType {
     }
 }
 
+/// Substitute the type parameters in the type with the types in the mapping.
+pub fn substitute_type_params(ty: &TypeRef, mapping: &HashMap<String, TypeRef>) -> TypeRef {
+    if !ty.contains_type_param() {
+        return ty.clone();
+    }
+    match &ty.kind {
+        TypeKind::TypeParam(name) => mapping.get(name).cloned().unwrap_or_else(|| ty.clone()),
+        TypeKind::List(item_ty) => Arc::new(Type::list(substitute_type_params(item_ty, mapping))),
+        TypeKind::Dict(DictType {
+            key_ty,
+            val_ty,
+            attrs,
+        }) => Arc::new(Type::dict_with_attrs(
+            substitute_type_params(key_ty, mapping),
+            substitute_type_params(val_ty, mapping),
+            attrs
+                .iter()
+                .map(|(key, attr)| {
+                    (
+                        key.to_string(),
+                        Attr {
+                            ty: substitute_type_params(&attr.ty, mapping),
+                            range: attr.range.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        )),
+        TypeKind::Union(types) => Arc::new(Type::union(
+            &types
+                .iter()
+                .map(|ty| substitute_type_params(ty, mapping))
+                .collect::<Vec<TypeRef>>(),
+        )),
+        TypeKind::Function(fn_ty) => Arc::new(Type::function(
+            fn_ty.self_ty.clone(),
+            substitute_type_params(&fn_ty.return_ty, mapping),
+            &fn_ty
+                .params
+                .iter()
+                .map(|param| Parameter {
+                    name: param.name.clone(),
+                    ty: substitute_type_params(&param.ty, mapping),
+                    has_default: param.has_default,
+                })
+                .collect::<Vec<Parameter>>(),
+            &fn_ty.doc,
+            fn_ty.is_variadic,
+            fn_ty.kw_only_index,
+        )),
+        TypeKind::Schema(schema_ty) => {
+            Arc::new(Type::schema(schema_ty.substitute_type_params(mapping)))
+        }
+        _ => ty.clone(),
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAttr {
     pub is_optional: bool,
@@ -403,6 +554,8 @@ pub struct NumberMultiplierType {
     pub raw_value: i64,
     pub binary_suffix: String,
     pub is_literal: bool,
+    /// Whether is the `units.Quantity` type, which also accepts the quantity strings e.g., "500m".
+    pub is_quantity: bool,
 }
 
 impl NumberMultiplierType {
@@ -412,6 +565,8 @@ impl NumberMultiplierType {
                 "{}({}{})",
                 NUMBER_MULTIPLIER_TYPE_STR, self.raw_value, self.binary_suffix
             )
+        } else if self.is_quantity {
+            QUANTITY_PKG_TYPE_STR.to_string()
         } else {
             NUMBER_MULTIPLIER_TYPE_STR.to_string()
         }
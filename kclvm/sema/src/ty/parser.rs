@@ -245,6 +245,49 @@ pub fn separate_kv(expected_type: &str) -> (String, String) {
     ("".to_string(), "".to_string())
 }
 
+/// split_generic_type_str splits the generic schema type string into the schema name
+/// and the type argument strings e.g., "pkg.Page[int, [str]]" -> ("pkg.Page", ["int", "[str]"]).
+/// Returns `None` if the type string is not a generic schema type.
+pub fn split_generic_type_str(ty_str: &str) -> Option<(String, Vec<String>)> {
+    let ty_str = ty_str_strip(ty_str);
+    if !ty_str.ends_with(']') || is_list_type_str(ty_str) || is_union_type_str(ty_str) {
+        return None;
+    }
+    let open = ty_str.find('[')?;
+    let name = &ty_str[..open];
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '@')
+    {
+        return None;
+    }
+    let inner = &ty_str[open + 1..ty_str.len() - 1];
+    let mut args = vec![];
+    let mut stack = String::new();
+    let mut start = 0;
+    for (n, c) in inner.char_indices() {
+        match c {
+            '[' | '{' | '(' => stack.push(c),
+            ']' | '}' | ')' => {
+                // The brackets of the type arguments are not matched e.g., "A[int][str]".
+                stack.pop()?;
+            }
+            ',' if stack.is_empty() => {
+                args.push(inner[start..n].trim().to_string());
+                start = n + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(inner[start..].trim().to_string());
+    if args.iter().any(|arg| arg.is_empty()) {
+        None
+    } else {
+        Some((name.to_string(), args))
+    }
+}
+
 /// dereference_type function removes the first and last [] {} in the type string
 /// e.g., "\[int\]" -> "int"
 pub fn dereference_type(tpe: &str) -> String {
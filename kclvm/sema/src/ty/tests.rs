@@ -68,6 +68,52 @@ fn test_sup() {
     }
 }
 
+#[test]
+fn test_subsume_quantity() {
+    let cases = vec![
+        (Arc::new(Type::STR), Arc::new(Type::quantity_ty()), true),
+        (
+            Arc::new(Type::str_lit("500m")),
+            Arc::new(Type::quantity_ty()),
+            true,
+        ),
+        (
+            Arc::new(Type::number_multiplier_non_lit_ty()),
+            Arc::new(Type::quantity_ty()),
+            true,
+        ),
+        (
+            Arc::new(Type::number_multiplier(1024.0, 1, "Ki")),
+            Arc::new(Type::quantity_ty()),
+            true,
+        ),
+        (
+            Arc::new(Type::STR),
+            Arc::new(Type::number_multiplier_non_lit_ty()),
+            false,
+        ),
+        (
+            Arc::new(Type::str_lit("1Ki")),
+            Arc::new(Type::number_multiplier_non_lit_ty()),
+            false,
+        ),
+        (
+            Arc::new(Type::STR),
+            Arc::new(Type::number_multiplier(1024.0, 1, "Ki")),
+            false,
+        ),
+    ];
+    for (ty_lhs, ty_rhs, expected) in cases {
+        assert_eq!(
+            subsume(ty_lhs.clone(), ty_rhs.clone(), true),
+            expected,
+            "{} -> {}",
+            ty_lhs.ty_str(),
+            ty_rhs.ty_str()
+        );
+    }
+}
+
 #[test]
 fn test_type_walker() {
     fn walk_fn(ty: &Type) -> TypeRef {
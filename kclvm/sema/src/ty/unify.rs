@@ -30,6 +30,9 @@ pub fn subsume(ty_lhs: TypeRef, ty_rhs: TypeRef, check_left_any: bool) -> bool {
         }
     } else if ty_lhs.is_int() && ty_rhs.is_float() {
         true
+    } else if ty_lhs.is_int() && ty_rhs.is_decimal() {
+        // Int values are converted to the exact decimal values at runtime.
+        true
     } else if ty_lhs.is_number_multiplier() && ty_rhs.is_number_multiplier() {
         let ty_lhs = ty_lhs.into_number_multiplier();
         let ty_rhs = ty_rhs.into_number_multiplier();
@@ -40,6 +43,9 @@ pub fn subsume(ty_lhs: TypeRef, ty_rhs: TypeRef, check_left_any: bool) -> bool {
         } else {
             ty_lhs.is_literal || !ty_rhs.is_literal
         }
+    } else if ty_lhs.is_str() && ty_rhs.is_number_multiplier() {
+        // Quantity strings e.g., "500m" are converted to the `units.Quantity` values at runtime.
+        ty_rhs.into_number_multiplier().is_quantity
     } else if ty_lhs.is_primitive() && ty_rhs.is_primitive() {
         ty_lhs.kind == ty_rhs.kind
     } else if ty_lhs.is_literal() {
@@ -103,7 +109,15 @@ pub fn equal(ty_lhs: TypeRef, ty_rhs: TypeRef) -> bool {
 /// Whether the schema is sub schema of another schema.
 pub fn is_sub_schema_of(schema_ty_lhs: &SchemaType, schema_ty_rhs: &SchemaType) -> bool {
     if schema_ty_lhs.ty_str_with_pkgpath() == schema_ty_rhs.ty_str_with_pkgpath() {
-        true
+        // The type arguments of generic schemas are invariant. Any instantiation can be
+        // assigned to the generic schema without type arguments, but not vice versa.
+        schema_ty_rhs.type_args.is_empty()
+            || (schema_ty_lhs.type_args.len() == schema_ty_rhs.type_args.len()
+                && schema_ty_lhs
+                    .type_args
+                    .iter()
+                    .zip(schema_ty_rhs.type_args.iter())
+                    .all(|(lhs, rhs)| lhs.is_any() || rhs.is_any() || lhs.ty_str() == rhs.ty_str()))
     } else {
         match &schema_ty_lhs.base {
             Some(base) => is_sub_schema_of(base, schema_ty_rhs),
@@ -14,8 +14,10 @@ pub mod symbol;
 mod tests;
 
 pub use compiler_base_span::{BytePos, FilePathMapping, Loc, SourceFile, SourceMap, Span};
-pub use session_globals::create_session_globals_then;
 use session_globals::with_session_globals;
+pub use session_globals::{
+    create_session_globals_then, current_session_globals, set_session_globals_then, SessionGlobals,
+};
 pub use symbol::{Ident, Symbol};
 
 #[macro_use]
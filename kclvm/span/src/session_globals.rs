@@ -9,7 +9,7 @@ use std::{collections::HashMap, sync::Arc};
 ///
 /// The `kclvm_span::Symbol` uses `SessionGlobals` to implement a fast global
 /// string cache.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SessionGlobals {
     pub symbol_interner: Interner,
 }
@@ -40,6 +40,20 @@ pub fn create_session_globals_then<R>(f: impl FnOnce() -> R) -> R {
     SESSION_GLOBALS.set(&session_globals, f)
 }
 
+/// Set the given session globals for the current thread then run `f`, it is used
+/// to share the symbol interner of the calling thread with worker threads.
+#[inline]
+pub fn set_session_globals_then<R>(session_globals: &SessionGlobals, f: impl FnOnce() -> R) -> R {
+    SESSION_GLOBALS.set(session_globals, f)
+}
+
+/// Returns a handle of the session globals set on the current thread. The returned
+/// value shares the same symbol interner.
+#[inline]
+pub fn current_session_globals() -> SessionGlobals {
+    with_session_globals(|session_globals| session_globals.clone())
+}
+
 #[inline]
 pub fn with_session_globals<R, F>(f: F) -> R
 where
@@ -51,7 +65,7 @@ where
 // Global sessions to store strings and symbols.
 scoped_tls::scoped_thread_local!(static SESSION_GLOBALS: SessionGlobals);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Interner(Arc<Mutex<InternerInner>>);
 
 // This type is private to prevent accidentally constructing more than one
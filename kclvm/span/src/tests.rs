@@ -25,3 +25,20 @@ fn interner_symbols() {
         assert_eq!(symbol2.as_u32(), symbol1.as_u32() + 1);
     });
 }
+
+#[test]
+fn interner_shared_between_threads() {
+    create_session_globals_then(|| {
+        let symbol = Symbol::intern("test_shared_str");
+        let session_globals = current_session_globals();
+        let other = std::thread::spawn(move || {
+            set_session_globals_then(&session_globals, || {
+                assert_eq!(Symbol::intern("test_shared_str"), symbol);
+                Symbol::intern("test_shared_str_from_thread")
+            })
+        })
+        .join()
+        .unwrap();
+        assert_eq!(other.as_str(), "test_shared_str_from_thread");
+    });
+}
@@ -1,9 +1,21 @@
 use crate::db::AnalysisDatabase;
+use kclvm_parser::KCLModuleCache;
 use parking_lot::RwLock;
 use ra_ap_vfs::FileId;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+/// Holds the analysis state of one workspace root, i.e., a directory containing
+/// `kcl.mod` or `kcl.yaml`. Roots never share compile results or parse caches.
+#[derive(Default, Clone)]
+pub struct RootDatabase {
+    /// The compile results of the files in the root.
+    pub files: HashMap<FileId, AnalysisDatabase>,
+    /// KCL parse cache of the root
+    pub module_cache: KCLModuleCache,
+}
 
 #[derive(Default)]
 pub struct Analysis {
-    pub db: Arc<RwLock<HashMap<FileId, AnalysisDatabase>>>,
+    /// The analysis state of every workspace root, keyed by the root path.
+    pub db: Arc<RwLock<HashMap<PathBuf, RootDatabase>>>,
 }
@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use kclvm_ast::ast::{Expr, Module, Stmt};
+use kclvm_tools::testing::{TEST_FILE_SUFFIX, TEST_SUITE_PREFIX};
+use lsp_types::{CodeLens, Command, Position, Range};
+
+/// Command used to run a single test case with the `tools::testing` runner.
+pub(crate) const RUN_TEST_COMMAND: &str = "kcl.test.run";
+/// Command used to run a single test case in the debug mode.
+pub(crate) const DEBUG_TEST_COMMAND: &str = "kcl.test.debug";
+/// Command used to render the YAML output of the compile unit.
+pub(crate) const PREVIEW_COMMAND: &str = "kcl.preview";
+
+/// All the commands that can be executed by the `workspace/executeCommand` request.
+pub(crate) const COMMANDS: &[&str] = &[RUN_TEST_COMMAND, DEBUG_TEST_COMMAND, PREVIEW_COMMAND];
+
+/// Returns the code lenses of the file. For `_test.k` files, each `test_*` lambda
+/// gets the run and debug lenses, and other KCL files get a preview lens on the first line.
+pub(crate) fn code_lens(file: &str, module: &Module) -> Option<Vec<CodeLens>> {
+    let path = Path::new(file);
+    let file_name = path.file_name()?.to_str()?;
+    if file_name.ends_with(TEST_FILE_SUFFIX) {
+        let pkg = path.parent()?.to_str()?.to_string();
+        let mut lenses = vec![];
+        for stmt in &module.body {
+            if let Stmt::Assign(assign_stmt) = &stmt.node {
+                if let Expr::Lambda(_) = &assign_stmt.value.node {
+                    for target in &assign_stmt.targets {
+                        let name = target.node.get_name();
+                        if !name.starts_with(TEST_SUITE_PREFIX) {
+                            continue;
+                        }
+                        let range = Range {
+                            start: Position::new(
+                                target.line.saturating_sub(1) as u32,
+                                target.column as u32,
+                            ),
+                            end: Position::new(
+                                target.end_line.saturating_sub(1) as u32,
+                                target.end_column as u32,
+                            ),
+                        };
+                        let arguments = Some(vec![
+                            serde_json::Value::String(pkg.clone()),
+                            serde_json::Value::String(name.clone()),
+                        ]);
+                        lenses.push(CodeLens {
+                            range,
+                            command: Some(Command {
+                                title: "▶ Run Test".to_string(),
+                                command: RUN_TEST_COMMAND.to_string(),
+                                arguments: arguments.clone(),
+                            }),
+                            data: None,
+                        });
+                        lenses.push(CodeLens {
+                            range,
+                            command: Some(Command {
+                                title: "Debug Test".to_string(),
+                                command: DEBUG_TEST_COMMAND.to_string(),
+                                arguments,
+                            }),
+                            data: None,
+                        });
+                    }
+                }
+            }
+        }
+        Some(lenses)
+    } else {
+        Some(vec![CodeLens {
+            range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+            command: Some(Command {
+                title: "▶ Preview YAML".to_string(),
+                command: PREVIEW_COMMAND.to_string(),
+                arguments: Some(vec![serde_json::Value::String(file.to_string())]),
+            }),
+            data: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use kclvm_parser::parse_file_force_errors;
+
+    use super::{code_lens, DEBUG_TEST_COMMAND, PREVIEW_COMMAND, RUN_TEST_COMMAND};
+
+    #[test]
+    fn code_lens_test_file_test() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_data/code_lens/main_test.k");
+        let file = path.to_str().unwrap();
+        let module = parse_file_force_errors(file, None).unwrap();
+
+        let lenses = code_lens(file, &module).unwrap();
+        assert_eq!(lenses.len(), 4);
+        let commands: Vec<(String, u32, serde_json::Value)> = lenses
+            .iter()
+            .map(|lens| {
+                let command = lens.command.as_ref().unwrap();
+                (
+                    command.command.clone(),
+                    lens.range.start.line,
+                    command.arguments.as_ref().unwrap()[1].clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                (RUN_TEST_COMMAND.to_string(), 0, "test_name".into()),
+                (DEBUG_TEST_COMMAND.to_string(), 0, "test_name".into()),
+                (RUN_TEST_COMMAND.to_string(), 4, "test_age".into()),
+                (DEBUG_TEST_COMMAND.to_string(), 4, "test_age".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_lens_main_file_test() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_data/code_lens/main.k");
+        let file = path.to_str().unwrap();
+        let module = parse_file_force_errors(file, None).unwrap();
+
+        let lenses = code_lens(file, &module).unwrap();
+        assert_eq!(lenses.len(), 1);
+        assert_eq!(lenses[0].range.start.line, 0);
+        assert_eq!(
+            lenses[0].command.as_ref().unwrap().command,
+            PREVIEW_COMMAND.to_string()
+        );
+    }
+}
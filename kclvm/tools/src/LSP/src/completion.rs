@@ -229,7 +229,19 @@ fn completion_dot(
                     if let Some(attr_def) = attr_def {
                         let sema_info = attr_def.get_sema_info();
                         let name = attr_def.get_name();
-                        match &sema_info.ty {
+                        // Use the attribute type substituted with the type arguments
+                        // for the generic schema instances.
+                        let attr_ty = match def.get_sema_info().ty.as_ref().map(|ty| &ty.kind) {
+                            Some(kclvm_sema::ty::TypeKind::Schema(schema_ty))
+                                if !schema_ty.type_args.is_empty() =>
+                            {
+                                schema_ty
+                                    .get_type_of_attr(&name)
+                                    .or_else(|| sema_info.ty.clone())
+                            }
+                            _ => sema_info.ty.clone(),
+                        };
+                        match &attr_ty {
                             Some(attr_ty) => {
                                 let label: String = match &attr_ty.kind {
                                     kclvm_sema::ty::TypeKind::Function(func_ty) => {
@@ -673,13 +685,15 @@ fn type_to_item_kind(ty: &Type) -> Option<KCLCompletionItemKind> {
         | kclvm_sema::ty::TypeKind::IntLit(_)
         | kclvm_sema::ty::TypeKind::Float
         | kclvm_sema::ty::TypeKind::FloatLit(_)
+        | kclvm_sema::ty::TypeKind::Decimal
         | kclvm_sema::ty::TypeKind::Str
         | kclvm_sema::ty::TypeKind::StrLit(_)
         | kclvm_sema::ty::TypeKind::List(_)
         | kclvm_sema::ty::TypeKind::Dict(_)
         | kclvm_sema::ty::TypeKind::Union(_)
         | kclvm_sema::ty::TypeKind::NumberMultiplier(_)
-        | kclvm_sema::ty::TypeKind::Named(_) => Some(KCLCompletionItemKind::Variable),
+        | kclvm_sema::ty::TypeKind::Named(_)
+        | kclvm_sema::ty::TypeKind::TypeParam(_) => Some(KCLCompletionItemKind::Variable),
         kclvm_sema::ty::TypeKind::Schema(_) => Some(KCLCompletionItemKind::Schema),
         kclvm_sema::ty::TypeKind::Function(_) => Some(KCLCompletionItemKind::Function),
         kclvm_sema::ty::TypeKind::Module(_) => Some(KCLCompletionItemKind::Module),
@@ -1092,6 +1106,9 @@ mod tests {
                 "crypto",
                 "base64",
                 "units",
+                "semver",
+                "random",
+                "uuid",
             ]
             .iter()
             .map(|name| KCLCompletionItem {
@@ -1445,6 +1462,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generic_schema_attr_complete() {
+        let (file, program, _, _, gs) = compile_test_file(
+            "src/test_data/completion_test/dot/generic_schema_attr/generic_schema_attr.k",
+        );
+
+        let pos = KCLPos {
+            filename: file.to_owned(),
+            line: 5,
+            column: Some(5),
+        };
+
+        let got = completion(Some('.'), &program, &pos, &gs).unwrap();
+        match got {
+            CompletionResponse::Array(arr) => {
+                assert_eq!(
+                    arr[0],
+                    CompletionItem {
+                        label: "items".to_string(),
+                        detail: Some("items: [int]".to_string()),
+                        kind: Some(CompletionItemKind::FIELD),
+                        ..Default::default()
+                    }
+                )
+            }
+            CompletionResponse::List(_) => panic!("test failed"),
+        }
+    }
+
     #[test]
     fn schema_end_pos() {
         let (file, program, _, _, gs) =
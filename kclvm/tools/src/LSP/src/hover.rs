@@ -28,7 +28,13 @@ pub(crate) fn hover(
                 },
                 kclvm_sema::core::symbol::SymbolKind::Attribute => {
                     let sema_info = obj.get_sema_info();
-                    match &sema_info.ty {
+                    // Prefer the attribute type at the reference, e.g., the attribute
+                    // type substituted with the type arguments of a generic schema.
+                    let ref_ty = gs
+                        .look_up_exact_symbol(kcl_pos)
+                        .and_then(|symbol_ref| gs.get_symbols().get_symbol(symbol_ref))
+                        .and_then(|symbol| symbol.get_sema_info().ty.clone());
+                    match ref_ty.as_ref().or(sema_info.ty.as_ref()) {
                         Some(ty) => {
                             docs.push(format!("{}: {}", &obj.get_name(), ty.ty_str()));
                             if let Some(doc) = &sema_info.doc {
@@ -450,6 +456,30 @@ mod tests {
         }
     }
 
+    #[test]
+    #[bench_test]
+    fn plugin_func_hover() {
+        let (file, program, _, _, gs) = compile_test_file("src/test_data/hover_test/plugin/main.k");
+        let pos = KCLPos {
+            filename: file.clone(),
+            line: 3,
+            column: Some(14),
+        };
+        let got = hover(&program, &pos, &gs).unwrap();
+        match got.contents {
+            lsp_types::HoverContents::Array(vec) => {
+                assert_eq!(
+                    vec,
+                    vec![
+                        MarkedString::String("fn repeat(s: str, n: int) -> str".to_string()),
+                        MarkedString::String("Repeat the string `s` for `n` times.".to_string()),
+                    ]
+                );
+            }
+            _ => unreachable!("test error"),
+        }
+    }
+
     #[test]
     #[bench_test]
     fn schema_scope_variable_hover() {
@@ -469,4 +499,49 @@ mod tests {
             _ => unreachable!("test error"),
         }
     }
+
+    #[test]
+    #[bench_test]
+    fn generic_schema_hover() {
+        let (file, program, _, _, gs) =
+            compile_test_file("src/test_data/hover_test/generic_schema.k");
+
+        // test hover of the generic schema in the type annotation: ints: Page[int]
+        let pos = KCLPos {
+            filename: file.clone(),
+            line: 4,
+            column: Some(7),
+        };
+        let got = hover(&program, &pos, &gs).unwrap();
+        match got.contents {
+            lsp_types::HoverContents::Array(vec) => {
+                assert_eq!(
+                    vec[0],
+                    MarkedString::String("__main__\n\nschema Page[type T]".to_string())
+                );
+                assert_eq!(
+                    vec[1],
+                    MarkedString::String("Attributes:\n\nitems: [T]".to_string())
+                );
+            }
+            _ => unreachable!("test error"),
+        }
+
+        // test hover of the attribute substituted with the type arguments: ints.items
+        let pos = KCLPos {
+            filename: file.clone(),
+            line: 5,
+            column: Some(14),
+        };
+        let got = hover(&program, &pos, &gs).unwrap();
+        match got.contents {
+            lsp_types::HoverContents::Scalar(marked_string) => {
+                assert_eq!(
+                    marked_string,
+                    MarkedString::String("items: [int]".to_string())
+                );
+            }
+            _ => unreachable!("test error"),
+        }
+    }
 }
@@ -1,5 +1,6 @@
 mod analysis;
 mod capabilities;
+mod code_lens;
 mod completion;
 mod config;
 mod db;
@@ -12,6 +13,7 @@ mod goto_def;
 mod hover;
 mod main_loop;
 mod notification;
+mod preview;
 mod quick_fix;
 pub mod rename;
 mod request;
@@ -21,3 +23,4 @@ mod state;
 mod tests;
 mod to_lsp;
 mod util;
+mod workspace;
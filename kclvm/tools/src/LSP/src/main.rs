@@ -4,6 +4,7 @@ use main_loop::app;
 
 mod analysis;
 mod capabilities;
+mod code_lens;
 mod completion;
 mod config;
 mod db;
@@ -15,12 +16,14 @@ mod goto_def;
 mod hover;
 mod main_loop;
 mod notification;
+mod preview;
 mod quick_fix;
 mod request;
 mod semantic_token;
 mod state;
 mod to_lsp;
 mod util;
+mod workspace;
 
 mod formatting;
 #[cfg(test)]
@@ -1,15 +1,18 @@
 use lsp_types::notification::{
-    Cancel, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
-    DidOpenTextDocument, DidSaveTextDocument,
+    Cancel, DidChangeTextDocument, DidChangeWatchedFiles, DidChangeWorkspaceFolders,
+    DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
 };
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::{
     dispatcher::NotificationDispatcher,
     from_lsp,
-    state::LanguageServerState,
+    state::{log_message, LanguageServerState},
     util::apply_document_changes,
-    util::{build_word_index_for_file_content, word_index_add, word_index_subtract},
+    util::{
+        build_word_index, build_word_index_for_file_content, word_index_add, word_index_subtract,
+    },
+    workspace::Workspaces,
 };
 
 impl LanguageServerState {
@@ -24,6 +27,7 @@ impl LanguageServerState {
             .on::<DidSaveTextDocument>(LanguageServerState::on_did_save_text_document)?
             .on::<DidCloseTextDocument>(LanguageServerState::on_did_close_text_document)?
             .on::<DidChangeWatchedFiles>(LanguageServerState::on_did_change_watched_files)?
+            .on::<DidChangeWorkspaceFolders>(LanguageServerState::on_did_change_workspace_folders)?
             .on::<Cancel>(LanguageServerState::cancel)?
             .finish();
         Ok(())
@@ -134,10 +138,58 @@ impl LanguageServerState {
         &mut self,
         params: lsp_types::DidChangeWatchedFilesParams,
     ) -> anyhow::Result<()> {
+        let mut roots_changed = false;
         for change in params.changes {
             let path = from_lsp::abs_path(&change.uri)?;
+            roots_changed |= Workspaces::is_root_marker(path.as_ref());
             self.loader.handle.invalidate(path);
         }
+        if roots_changed {
+            self.refresh_roots();
+        }
+        Ok(())
+    }
+
+    /// Called when a `DidChangeWorkspaceFolders` notification was received.
+    fn on_did_change_workspace_folders(
+        &mut self,
+        params: lsp_types::DidChangeWorkspaceFoldersParams,
+    ) -> anyhow::Result<()> {
+        for folder in params.event.removed {
+            let path = PathBuf::from(from_lsp::file_path_from_url(&folder.uri)?);
+            self.log_message(format!("on did remove workspace folder: {:?}", path));
+            let workspaces = &mut *self.workspaces.write();
+            let removed = workspaces.remove_folder(&path);
+            // Drop the analysis of the roots which are no longer in any workspace folder,
+            // including the directories of the single files out of any discovered root.
+            self.analysis.db.write().retain(|root, _| {
+                !removed.contains(root) && (!root.starts_with(&path) || workspaces.contains(root))
+            });
+            self.word_index_map.write().remove(&folder.uri);
+        }
+        let added = !params.event.added.is_empty();
+        for folder in params.event.added {
+            let path = PathBuf::from(from_lsp::file_path_from_url(&folder.uri)?);
+            self.log_message(format!("on did add workspace folder: {:?}", path));
+            self.workspaces.write().add_folder(path.clone());
+            let word_index_map = self.word_index_map.clone();
+            let sender = self.task_sender.clone();
+            self.thread_pool.execute(move || {
+                match build_word_index(path.to_string_lossy().to_string(), true) {
+                    Ok(word_index) => {
+                        word_index_map.write().insert(folder.uri, word_index);
+                    }
+                    Err(err) => {
+                        let _ = log_message(err.to_string(), &sender);
+                    }
+                }
+            });
+        }
+        // The opened files may belong to the roots discovered in the added folders,
+        // re-route and recompile them.
+        if added {
+            self.refresh_roots();
+        }
         Ok(())
     }
 }
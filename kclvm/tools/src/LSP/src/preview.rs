@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use kclvm_config::settings::build_settings_pathbuf;
+use kclvm_driver::{lookup_compile_unit, lookup_compile_unit_path, lookup_setting_files};
+use kclvm_parser::ParseSession;
+use kclvm_runner::{exec_program, ExecProgramArgs, MapErrorResult};
+use kclvm_tools::testing::{load_test_suites, TestOptions, TestRun, TEST_FILE_SUFFIX};
+use lsp_types::TextDocumentIdentifier;
+use parking_lot::RwLock;
+use ra_ap_vfs::Vfs;
+use serde::{Deserialize, Serialize};
+
+use crate::util::load_files_code_from_vfs;
+
+/// The custom `kcl/preview` request which returns the planned YAML
+/// of the compile unit that the document belongs to.
+pub(crate) enum Preview {}
+
+impl lsp_types::request::Request for Preview {
+    type Params = PreviewParams;
+    type Result = PreviewResult;
+    const METHOD: &'static str = "kcl/preview";
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PreviewParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PreviewResult {
+    /// The input files of the compile unit.
+    pub files: Vec<String>,
+    /// The planned YAML output.
+    pub yaml: String,
+}
+
+/// The result of one test case run by the code lens commands.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TestCaseReport {
+    pub name: String,
+    pub passed: bool,
+    pub error: Option<String>,
+    pub log_message: String,
+    pub duration_ms: u128,
+}
+
+/// Compiles and runs the compile unit of the file found by `lookup_compile_unit`,
+/// the unsaved file contents in the vfs are used if exists. The test files are
+/// not a part of the compile unit and are always skipped. Like `kcl run`, the
+/// other arguments e.g., the options, overrides and path selectors are read from
+/// the `kcl.yaml` settings of the compile unit.
+pub(crate) fn preview(file: &str, vfs: Option<Arc<RwLock<Vfs>>>) -> anyhow::Result<PreviewResult> {
+    let (files, opt) = lookup_compile_unit(file, true);
+    let files: Vec<String> = files
+        .into_iter()
+        .filter(|f| !f.ends_with(TEST_FILE_SUFFIX))
+        .collect();
+    let opt = opt.unwrap_or_default();
+    let k_code_list = match vfs {
+        Some(vfs) => {
+            let files: Vec<&str> = files.iter().map(|s| s.as_str()).collect();
+            load_files_code_from_vfs(&files, vfs)?
+        }
+        None => vec![],
+    };
+    let mut args = compile_unit_args(file)?;
+    if args.work_dir.is_none() && !opt.work_dir.is_empty() {
+        args.work_dir = Some(opt.work_dir.clone());
+    }
+    args.k_filename_list = files.clone();
+    args.k_code_list = k_code_list;
+    // The package maps in the settings take precedence over the kcl.mod dependencies.
+    let mut package_maps = opt.package_maps;
+    package_maps.extend(args.get_package_maps_from_external_pkg());
+    args.set_external_pkg_from_package_maps(package_maps);
+    let result = exec_program(Arc::new(ParseSession::default()), &args).map_err_to_result()?;
+    Ok(PreviewResult {
+        files,
+        yaml: result.yaml_result,
+    })
+}
+
+/// Builds the exec arguments from the `kcl.yaml` settings of the compile unit
+/// that the file belongs to, or the default arguments if there are no settings.
+fn compile_unit_args(file: &str) -> anyhow::Result<ExecProgramArgs> {
+    let settings_files = match lookup_compile_unit_path(file) {
+        Ok(dir) => lookup_setting_files(&dir),
+        Err(_) => vec![],
+    };
+    if settings_files.is_empty() {
+        return Ok(ExecProgramArgs::default());
+    }
+    let settings_files = settings_files
+        .iter()
+        .map(|f| f.to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+    let settings_files = settings_files.iter().map(|f| f.as_str()).collect();
+    ExecProgramArgs::try_from(build_settings_pathbuf(&[], Some(settings_files), None)?)
+}
+
+/// Runs the test case `name` in the package `pkg` with the `tools::testing` runner.
+/// In the debug mode, the generated test main file is kept for inspection.
+pub(crate) fn run_test(pkg: &str, name: &str, debug: bool) -> anyhow::Result<Vec<TestCaseReport>> {
+    let mut opts = TestOptions {
+        run_regexp: format!("^{}$", regex::escape(name)),
+        ..Default::default()
+    };
+    if debug {
+        opts.exec_args.debug = 1;
+        opts.exec_args.verbose = 1;
+    }
+    let mut reports = vec![];
+    for suite in load_test_suites(pkg, &opts)? {
+        let result = suite.run(&opts)?;
+        for (name, info) in result.info {
+            reports.push(TestCaseReport {
+                name,
+                passed: info.error.is_none(),
+                error: info.error.map(|err| err.to_string()),
+                log_message: info.log_message,
+                duration_ms: info.duration.as_millis(),
+            });
+        }
+    }
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::preview;
+
+    #[test]
+    fn preview_test() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_data/code_lens/main.k");
+        let file = path.to_str().unwrap();
+
+        let res = preview(file, None).unwrap();
+        assert_eq!(res.files.len(), 1);
+        assert!(res.files[0].ends_with("main.k"));
+        assert_eq!(res.yaml, "name: Alice\nage: 18\n");
+    }
+
+    #[test]
+    fn preview_with_settings_test() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_data/preview/main.k");
+        let file = path.to_str().unwrap();
+
+        let res = preview(file, None).unwrap();
+        assert_eq!(res.files.len(), 1);
+        assert!(res.files[0].ends_with("main.k"));
+        assert_eq!(res.yaml, "app:\n  name: nginx\n  replicas: 3\n");
+    }
+}
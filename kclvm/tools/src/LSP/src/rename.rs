@@ -199,7 +199,7 @@ where
     );
 
     let gs = GlobalState::default();
-    let gs = Namer::find_symbols(&program, gs);
+    let gs = Namer::find_symbols_with_plugin_stubs(&program, gs, &prog_scope.plugin_stubs);
     let node_ty_map = prog_scope.node_ty_map.clone();
     let global_state = AdvancedResolver::resolve_program(&program, gs, node_ty_map);
 
@@ -2,13 +2,16 @@ use anyhow::anyhow;
 use crossbeam_channel::Sender;
 
 use kclvm_config::modfile::KCL_FILE_SUFFIX;
+use kclvm_parser::{parse_file, KCLModuleCache};
 use kclvm_sema::info::is_valid_kcl_name;
 use lsp_types::{Location, SemanticTokensResult, TextEdit};
 use ra_ap_vfs::VfsPath;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use crate::{
+    code_lens::{code_lens, DEBUG_TEST_COMMAND, PREVIEW_COMMAND, RUN_TEST_COMMAND},
     completion::completion,
     db::AnalysisDatabase,
     dispatcher::RequestDispatcher,
@@ -17,10 +20,12 @@ use crate::{
     formatting::format,
     from_lsp::{self, file_path_from_url, kcl_pos},
     goto_def::goto_definition_with_gs,
-    hover, quick_fix,
+    hover,
+    preview::{preview, run_test, Preview, PreviewParams, PreviewResult},
+    quick_fix,
     semantic_token::semantic_tokens_full,
     state::{log_message, LanguageServerSnapshot, LanguageServerState, Task},
-    util::{parse_param_and_compile, Param},
+    util::{parse_param_and_compile, to_json, Param},
 };
 
 impl LanguageServerState {
@@ -59,6 +64,9 @@ impl LanguageServerState {
             .on::<lsp_types::request::RangeFormatting>(handle_range_formatting)?
             .on::<lsp_types::request::Rename>(handle_rename)?
             .on::<lsp_types::request::SemanticTokensFullRequest>(handle_semantic_tokens_full)?
+            .on::<lsp_types::request::CodeLensRequest>(handle_code_lens)?
+            .on::<lsp_types::request::ExecuteCommand>(handle_execute_command)?
+            .on::<Preview>(handle_preview)?
             .finish();
 
         Ok(())
@@ -68,21 +76,23 @@ impl LanguageServerState {
 impl LanguageServerSnapshot {
     // defend against non-kcl files
     pub(crate) fn verify_request_path(&self, path: &VfsPath, sender: &Sender<Task>) -> bool {
-        let res = self.vfs.read().file_id(path).is_some()
-            && self
-                .db
-                .read()
-                .get(&self.vfs.read().file_id(path).unwrap())
-                .is_some();
+        let res = self.get_db(path).is_ok();
         if !res {
             let _ = log_message("Not a valid kcl path, request failed".to_string(), sender);
         }
         res
     }
 
+    /// Returns the analysis database of the file, which is routed by the root of the file path.
     pub(crate) fn get_db(&self, path: &VfsPath) -> anyhow::Result<AnalysisDatabase> {
+        let root = self.root_of(path)?;
         match self.vfs.read().file_id(path) {
-            Some(id) => match self.db.read().get(&id) {
+            Some(id) => match self
+                .db
+                .read()
+                .get(&root)
+                .and_then(|root_db| root_db.files.get(&id))
+            {
                 Some(db) => Ok(db.clone()),
                 None => Err(anyhow::anyhow!(format!(
                     "Path {path} AnalysisDatabase not found"
@@ -91,6 +101,30 @@ impl LanguageServerSnapshot {
             None => Err(anyhow::anyhow!(format!("Path {path} fileId not found"))),
         }
     }
+
+    /// Returns the `kcl.mod` or `kcl.yaml` root that the path belongs to.
+    pub(crate) fn root_of(&self, path: &VfsPath) -> anyhow::Result<PathBuf> {
+        let path = path
+            .as_path()
+            .ok_or(anyhow::anyhow!("{path} isn't on the file system."))?;
+        Ok(self.workspaces.read().root_of(path.as_ref()))
+    }
+
+    /// Returns the parse cache of the root, the caches are never shared across roots.
+    /// The roots are only created by the main loop, so `None` is returned if the root
+    /// has not been compiled yet.
+    pub(crate) fn module_cache(&self, root: &Path) -> Option<KCLModuleCache> {
+        self.db
+            .read()
+            .get(root)
+            .map(|root_db| root_db.module_cache.clone())
+    }
+
+    /// Returns the parse cache of the root that the file belongs to.
+    pub(crate) fn file_module_cache(&self, file: &str) -> Option<KCLModuleCache> {
+        let root = self.workspaces.read().root_of(Path::new(file));
+        self.module_cache(&root)
+    }
 }
 
 pub(crate) fn handle_semantic_tokens_full(
@@ -154,6 +188,81 @@ pub(crate) fn handle_code_action(
     Ok(Some(code_actions))
 }
 
+/// Called when a `textDocument/codeLens` request was received.
+pub(crate) fn handle_code_lens(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CodeLensParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<Option<Vec<lsp_types::CodeLens>>> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+    if !file.ends_with(KCL_FILE_SUFFIX) {
+        return Ok(None);
+    }
+    let path = from_lsp::abs_path(&params.text_document.uri)?;
+    // Use the unsaved file contents in the vfs if exists.
+    let src = {
+        let vfs = snapshot.vfs.read();
+        match vfs.file_id(&path.into()) {
+            Some(id) => Some(String::from_utf8(vfs.file_contents(id).to_vec())?),
+            None => None,
+        }
+    };
+    let module = parse_file(&file, src)?.module;
+    Ok(code_lens(&file, &module))
+}
+
+/// Called when a `workspace/executeCommand` request was received.
+pub(crate) fn handle_execute_command(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::ExecuteCommandParams,
+    sender: Sender<Task>,
+) -> anyhow::Result<Option<serde_json::Value>> {
+    let arg = |i: usize| -> anyhow::Result<String> {
+        params
+            .arguments
+            .get(i)
+            .and_then(|arg| arg.as_str())
+            .map(|arg| arg.to_string())
+            .ok_or(anyhow!(
+                "Invalid argument {} of the command {}",
+                i,
+                params.command
+            ))
+    };
+    match params.command.as_str() {
+        PREVIEW_COMMAND => {
+            let res = preview(&arg(0)?, Some(snapshot.vfs.clone()))?;
+            Ok(Some(to_json(res)?))
+        }
+        RUN_TEST_COMMAND | DEBUG_TEST_COMMAND => {
+            let (pkg, name) = (arg(0)?, arg(1)?);
+            let reports = run_test(&pkg, &name, params.command == DEBUG_TEST_COMMAND)?;
+            for report in &reports {
+                let status = if report.passed { "PASS" } else { "FAIL" };
+                log_message(
+                    format!(
+                        "test {} ... {} ({}ms)",
+                        report.name, status, report.duration_ms
+                    ),
+                    &sender,
+                )?;
+            }
+            Ok(Some(to_json(reports)?))
+        }
+        command => Err(anyhow!("Unknown command: {command}")),
+    }
+}
+
+/// Called when a `kcl/preview` request was received.
+pub(crate) fn handle_preview(
+    snapshot: LanguageServerSnapshot,
+    params: PreviewParams,
+    _sender: Sender<Task>,
+) -> anyhow::Result<PreviewResult> {
+    let file = file_path_from_url(&params.text_document.uri)?;
+    preview(&file, Some(snapshot.vfs.clone()))
+}
+
 /// Called when a `textDocument/definition` request was received.
 pub(crate) fn handle_goto_definition(
     snapshot: LanguageServerSnapshot,
@@ -191,7 +300,7 @@ pub(crate) fn handle_reference(
     let db = snapshot.get_db(&path.clone().into())?;
     let pos = kcl_pos(&file, params.text_document_position.position);
     let log = |msg: String| log_message(msg, &sender);
-    let module_cache = snapshot.module_cache.clone();
+    let module_cache = snapshot.file_module_cache(&file);
     match find_refs(
         &db.prog,
         &pos,
@@ -234,7 +343,7 @@ pub(crate) fn handle_completion(
                 match parse_param_and_compile(
                     Param {
                         file: file.clone(),
-                        module_cache: snapshot.module_cache.clone(),
+                        module_cache: snapshot.file_module_cache(&file),
                     },
                     Some(snapshot.vfs.clone()),
                 ) {
@@ -299,7 +408,7 @@ pub(crate) fn handle_document_symbol(
     match parse_param_and_compile(
         Param {
             file: file.clone(),
-            module_cache: snapshot.module_cache.clone(),
+            module_cache: snapshot.file_module_cache(&file),
         },
         Some(snapshot.vfs.clone()),
     ) {
@@ -343,7 +452,7 @@ pub(crate) fn handle_rename(
         Some(snapshot.vfs.clone()),
         log,
         &db.gs,
-        snapshot.module_cache.clone(),
+        snapshot.file_module_cache(&file),
     );
     match references {
         Result::Ok(locations) => {
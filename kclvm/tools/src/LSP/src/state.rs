@@ -1,13 +1,13 @@
-use crate::analysis::Analysis;
+use crate::analysis::{Analysis, RootDatabase};
 use crate::config::Config;
 use crate::db::AnalysisDatabase;
 use crate::from_lsp::file_path_from_url;
 use crate::to_lsp::{kcl_diag_to_lsp_diags, url};
 use crate::util::{build_word_index, get_file_name, parse_param_and_compile, to_json, Param};
+use crate::workspace::Workspaces;
 use anyhow::Result;
 use crossbeam_channel::{select, unbounded, Receiver, Sender};
 use indexmap::IndexSet;
-use kclvm_parser::KCLModuleCache;
 use lsp_server::{ReqQueue, Response};
 use lsp_types::Url;
 use lsp_types::{
@@ -17,6 +17,7 @@ use lsp_types::{
 use parking_lot::RwLock;
 use ra_ap_vfs::{FileId, Vfs};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::{sync::Arc, time::Instant};
 
 pub(crate) type RequestHandler = fn(&mut LanguageServerState, lsp_server::Response);
@@ -79,8 +80,8 @@ pub(crate) struct LanguageServerState {
     /// The word index map
     pub word_index_map: Arc<RwLock<HashMap<Url, HashMap<String, Vec<Location>>>>>,
 
-    /// KCL parse cache
-    pub module_cache: Option<KCLModuleCache>,
+    /// The workspace folders and the compile unit roots in them
+    pub workspaces: Arc<RwLock<Workspaces>>,
 }
 
 /// A snapshot of the state of the language server
@@ -89,13 +90,13 @@ pub(crate) struct LanguageServerSnapshot {
     /// The virtual filesystem that holds all the file contents
     pub vfs: Arc<RwLock<Vfs>>,
     /// Holds the state of the analysis process
-    pub db: Arc<RwLock<HashMap<FileId, AnalysisDatabase>>>,
+    pub db: Arc<RwLock<HashMap<PathBuf, RootDatabase>>>,
     /// Documents that are currently kept in memory from the client
     pub opened_files: IndexSet<FileId>,
     /// The word index map
     pub word_index_map: Arc<RwLock<HashMap<Url, HashMap<String, Vec<Location>>>>>,
-    /// KCL parse cache
-    pub module_cache: Option<KCLModuleCache>,
+    /// The workspace folders and the compile unit roots in them
+    pub workspaces: Arc<RwLock<Workspaces>>,
 }
 
 #[allow(unused)]
@@ -128,7 +129,7 @@ impl LanguageServerState {
             opened_files: IndexSet::new(),
             word_index_map: Arc::new(RwLock::new(HashMap::new())),
             loader,
-            module_cache: Some(KCLModuleCache::default()),
+            workspaces: Arc::new(RwLock::new(Workspaces::new(&initialize_params))),
         };
 
         let word_index_map = state.word_index_map.clone();
@@ -199,75 +200,101 @@ impl LanguageServerState {
 
         // Construct an AnalysisChange to apply to the analysis
         for file in changed_files {
-            let vfs = self.vfs.read();
-            let start = Instant::now();
-            let filename = get_file_name(vfs, file.file_id);
-            match filename {
-                Ok(filename) => {
-                    self.thread_pool.execute({
-                        let mut snapshot = self.snapshot();
-                        let sender = self.task_sender.clone();
-                        let module_cache = self.module_cache.clone();
-                        move || match url(&snapshot, file.file_id) {
-                            Ok(uri) => {
-                                match parse_param_and_compile(
-                                    Param {
-                                        file: filename.clone(),
-                                        module_cache,
-                                    },
-                                    Some(snapshot.vfs),
-                                ) {
-                                    Ok((prog, _, diags, gs)) => {
-                                        let mut db = snapshot.db.write();
-                                        db.insert(
-                                            file.file_id,
+            self.compile_file(file.file_id);
+        }
+        true
+    }
+
+    /// Compiles the file in the thread pool and stores the result in the analysis
+    /// database of the root that the file belongs to.
+    pub(crate) fn compile_file(&mut self, file_id: FileId) {
+        let filename = get_file_name(self.vfs.read(), file_id);
+        match filename {
+            Ok(filename) => {
+                let root = self.workspaces.read().root_of(Path::new(&filename));
+                // The root databases are only created in the main loop, the snapshots
+                // and the compile threads never insert them.
+                let module_cache = self
+                    .analysis
+                    .db
+                    .write()
+                    .entry(root.clone())
+                    .or_default()
+                    .module_cache
+                    .clone();
+                self.thread_pool.execute({
+                    let mut snapshot = self.snapshot();
+                    let sender = self.task_sender.clone();
+                    move || match url(&snapshot, file_id) {
+                        Ok(uri) => {
+                            match parse_param_and_compile(
+                                Param {
+                                    file: filename.clone(),
+                                    module_cache: Some(module_cache),
+                                },
+                                Some(snapshot.vfs),
+                            ) {
+                                Ok((prog, _, diags, gs)) => {
+                                    // The root may have been dropped during the compilation.
+                                    if let Some(root_db) = snapshot.db.write().get_mut(&root) {
+                                        root_db.files.insert(
+                                            file_id,
                                             AnalysisDatabase {
                                                 prog,
                                                 diags: diags.clone(),
                                                 gs,
                                             },
                                         );
-
-                                        let diagnostics = diags
-                                            .iter()
-                                            .flat_map(|diag| {
-                                                kcl_diag_to_lsp_diags(diag, filename.as_str())
-                                            })
-                                            .collect::<Vec<Diagnostic>>();
-                                        sender.send(Task::Notify(lsp_server::Notification {
-                                            method: PublishDiagnostics::METHOD.to_owned(),
-                                            params: to_json(PublishDiagnosticsParams {
-                                                uri,
-                                                diagnostics,
-                                                version: None,
-                                            })
-                                            .unwrap(),
-                                        }));
-                                    }
-                                    Err(err) => {
-                                        log_message(
-                                            format!("compile failed: {:?}", err.to_string()),
-                                            &sender,
-                                        );
                                     }
+
+                                    let diagnostics = diags
+                                        .iter()
+                                        .flat_map(|diag| {
+                                            kcl_diag_to_lsp_diags(diag, filename.as_str())
+                                        })
+                                        .collect::<Vec<Diagnostic>>();
+                                    sender.send(Task::Notify(lsp_server::Notification {
+                                        method: PublishDiagnostics::METHOD.to_owned(),
+                                        params: to_json(PublishDiagnosticsParams {
+                                            uri,
+                                            diagnostics,
+                                            version: None,
+                                        })
+                                        .unwrap(),
+                                    }));
+                                }
+                                Err(err) => {
+                                    log_message(
+                                        format!("compile failed: {:?}", err.to_string()),
+                                        &sender,
+                                    );
                                 }
-                            }
-                            Err(_) => {
-                                log_message(
-                                    format!("Interal bug: not a valid file:{:?}", filename),
-                                    &sender,
-                                );
                             }
                         }
-                    });
-                }
-                Err(_) => {
-                    self.log_message(format!("{:?} not found", file.file_id));
-                    continue;
-                }
+                        Err(_) => {
+                            log_message(
+                                format!("Interal bug: not a valid file:{:?}", filename),
+                                &sender,
+                            );
+                        }
+                    }
+                });
+            }
+            Err(_) => {
+                self.log_message(format!("{:?} not found", file_id));
             }
         }
-        true
+    }
+
+    /// Rediscovers the compile unit roots, e.g., when a `kcl.mod` or `kcl.yaml` file is
+    /// created or deleted. The files may belong to other roots now, so the analysis of
+    /// all roots is dropped and the opened files are compiled again.
+    pub(crate) fn refresh_roots(&mut self) {
+        self.workspaces.write().refresh();
+        self.analysis.db.write().clear();
+        for file_id in self.opened_files.clone() {
+            self.compile_file(file_id);
+        }
     }
 
     /// Handles a task sent by another async task
@@ -318,7 +345,7 @@ impl LanguageServerState {
             db: self.analysis.db.clone(),
             opened_files: self.opened_files.clone(),
             word_index_map: self.word_index_map.clone(),
-            module_cache: self.module_cache.clone(),
+            workspaces: self.workspaces.clone(),
         }
     }
 
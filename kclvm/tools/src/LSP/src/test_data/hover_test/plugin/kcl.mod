@@ -0,0 +1,5 @@
+[package]
+name = "plugin"
+
+[plugins]
+strings = "./plugins/libstrings.so"
@@ -0,0 +1,4 @@
+[package]
+name = "mod_a"
+edition = "0.0.1"
+version = "0.0.1"
@@ -1895,3 +1895,136 @@ fn rename_test() {
     ]));
     assert_eq!(res.result.unwrap(), to_json(expect).unwrap());
 }
+
+#[test]
+fn workspace_folders_test() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut folder = root.clone();
+    folder.push("src/test_data/workspace");
+
+    let files = [
+        folder.join("mod_a").join("main.k"),
+        folder.join("mod_b").join("prod").join("main.k"),
+    ];
+    let folder_uri = Url::from_file_path(folder.clone()).unwrap();
+    let initialize_params = InitializeParams {
+        workspace_folders: Some(vec![WorkspaceFolder {
+            uri: folder_uri.clone(),
+            name: "workspace".to_string(),
+        }]),
+        ..Default::default()
+    };
+    let server = Project {}.server(initialize_params);
+
+    // Mock open files in different kcl.mod and kcl.yaml roots
+    for file in &files {
+        server.notification::<lsp_types::notification::DidOpenTextDocument>(
+            lsp_types::DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: Url::from_file_path(file).unwrap(),
+                    language_id: "KCL".to_string(),
+                    version: 0,
+                    text: std::fs::read_to_string(file).unwrap(),
+                },
+            },
+        );
+    }
+    wait_async_compile!();
+
+    let hover = |file: &PathBuf, position: Position| {
+        let id = server.next_request_id.get();
+        server.next_request_id.set(id.wrapping_add(1));
+        let r: Request = Request::new(
+            id.into(),
+            "textDocument/hover".to_string(),
+            HoverParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: Url::from_file_path(file).unwrap(),
+                    },
+                    position,
+                },
+                work_done_progress_params: Default::default(),
+            },
+        );
+        server.send_and_receive(r)
+    };
+
+    // Each root is compiled with its own compile unit.
+    let res = hover(&files[0], Position::new(3, 5));
+    assert!(!res.result.unwrap().is_null());
+    let res = hover(&files[1], Position::new(0, 8));
+    assert!(!res.result.unwrap().is_null());
+
+    // Remove the workspace folder and the analysis of its roots is dropped.
+    server.notification::<lsp_types::notification::DidChangeWorkspaceFolders>(
+        lsp_types::DidChangeWorkspaceFoldersParams {
+            event: lsp_types::WorkspaceFoldersChangeEvent {
+                added: vec![],
+                removed: vec![WorkspaceFolder {
+                    uri: folder_uri,
+                    name: "workspace".to_string(),
+                }],
+            },
+        },
+    );
+    let res = hover(&files[0], Position::new(3, 5));
+    assert!(res.result.unwrap().is_null());
+}
+
+#[test]
+fn workspace_folders_add_with_opened_file_test() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut folder = root.clone();
+    folder.push("src/test_data/workspace");
+
+    let file = folder.join("mod_b").join("base").join("base.k");
+    let folder_uri = Url::from_file_path(folder.clone()).unwrap();
+    let server = Project {}.server(InitializeParams::default());
+
+    // Mock open the file before its workspace folder is added, the directory
+    // of the file is regarded as its root.
+    server.notification::<lsp_types::notification::DidOpenTextDocument>(
+        lsp_types::DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: Url::from_file_path(&file).unwrap(),
+                language_id: "KCL".to_string(),
+                version: 0,
+                text: std::fs::read_to_string(&file).unwrap(),
+            },
+        },
+    );
+    wait_async_compile!();
+
+    // Add the workspace folder and the file is re-routed to the `mod_b` root.
+    server.notification::<lsp_types::notification::DidChangeWorkspaceFolders>(
+        lsp_types::DidChangeWorkspaceFoldersParams {
+            event: lsp_types::WorkspaceFoldersChangeEvent {
+                added: vec![WorkspaceFolder {
+                    uri: folder_uri,
+                    name: "workspace".to_string(),
+                }],
+                removed: vec![],
+            },
+        },
+    );
+    wait_async_compile!();
+
+    let id = server.next_request_id.get();
+    server.next_request_id.set(id.wrapping_add(1));
+    let r: Request = Request::new(
+        id.into(),
+        "textDocument/hover".to_string(),
+        HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: Url::from_file_path(&file).unwrap(),
+                },
+                position: Position::new(0, 8),
+            },
+            work_done_progress_params: Default::default(),
+        },
+    );
+    let res = server.send_and_receive(r);
+    assert!(!res.result.unwrap().is_null());
+}
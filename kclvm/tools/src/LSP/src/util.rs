@@ -96,7 +96,7 @@ pub(crate) fn parse_param_and_compile(
     );
 
     let gs = GlobalState::default();
-    let gs = Namer::find_symbols(&program, gs);
+    let gs = Namer::find_symbols_with_plugin_stubs(&program, gs, &prog_scope.plugin_stubs);
     let node_ty_map = prog_scope.node_ty_map.clone();
     let global_state = AdvancedResolver::resolve_program(&program, gs, node_ty_map);
 
@@ -123,7 +123,10 @@ pub(crate) fn apply_document_changes(
     }
 }
 
-fn load_files_code_from_vfs(files: &[&str], vfs: Arc<RwLock<Vfs>>) -> anyhow::Result<Vec<String>> {
+pub(crate) fn load_files_code_from_vfs(
+    files: &[&str],
+    vfs: Arc<RwLock<Vfs>>,
+) -> anyhow::Result<Vec<String>> {
     let mut res = vec![];
     let vfs = &mut vfs.read();
     for file in files {
@@ -719,6 +722,23 @@ fn build_identifier_from_ty_string(ty: &NodeRef<Type>, pos: &KCLPos) -> Option<N
         }
         Type::Literal(_) => None,
         Type::Function(_) => None,
+        Type::Generic(generic_ty) => {
+            for ty in &generic_ty.type_args {
+                if ty.contains_pos(pos) {
+                    return build_identifier_from_ty_string(ty, pos);
+                }
+            }
+            Some(Node::node_with_pos(
+                Expr::Identifier(generic_ty.name.clone()),
+                (
+                    ty.filename.clone(),
+                    ty.line,
+                    ty.column,
+                    ty.end_line,
+                    ty.end_column,
+                ),
+            ))
+        }
     }
 }
 
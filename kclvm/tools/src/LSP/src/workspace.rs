@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexSet;
+use kclvm_config::{modfile::KCL_MOD_FILE, settings::DEFAULT_SETTING_FILE};
+use lsp_types::InitializeParams;
+use walkdir::WalkDir;
+
+use crate::from_lsp::file_path_from_url;
+
+/// The files that mark the root directory of a KCL compile unit.
+const ROOT_MARKERS: &[&str] = &[KCL_MOD_FILE, DEFAULT_SETTING_FILE];
+
+/// The workspace folders opened by the client and the `kcl.mod` and `kcl.yaml`
+/// roots discovered in them. Each root holds its own analysis database, and
+/// requests are routed to the root that the file belongs to.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct Workspaces {
+    /// Workspace folders opened by the client.
+    pub folders: IndexSet<PathBuf>,
+    /// The compile unit roots discovered in the workspace folders.
+    pub roots: IndexSet<PathBuf>,
+}
+
+impl Workspaces {
+    /// Creates the workspaces from the workspace folders or the root uri of the initialize params.
+    pub fn new(initialize_params: &InitializeParams) -> Self {
+        let mut workspaces = Workspaces::default();
+        if let Some(folders) = &initialize_params.workspace_folders {
+            for folder in folders {
+                if let Ok(path) = file_path_from_url(&folder.uri) {
+                    workspaces.add_folder(PathBuf::from(path));
+                }
+            }
+        } else if let Some(root_uri) = &initialize_params.root_uri {
+            if let Ok(path) = file_path_from_url(root_uri) {
+                workspaces.add_folder(PathBuf::from(path));
+            }
+        }
+        workspaces
+    }
+
+    /// Adds a workspace folder and discovers the compile unit roots in it.
+    pub fn add_folder(&mut self, folder: PathBuf) {
+        self.roots.extend(discover_roots(&folder));
+        self.folders.insert(folder);
+    }
+
+    /// Removes a workspace folder and returns the roots that no longer belong to any folder.
+    pub fn remove_folder(&mut self, folder: &Path) -> Vec<PathBuf> {
+        self.folders.remove(folder);
+        let removed: Vec<PathBuf> = self
+            .roots
+            .iter()
+            .filter(|root| root.starts_with(folder) && !self.contains(root))
+            .cloned()
+            .collect();
+        for root in &removed {
+            self.roots.remove(root);
+        }
+        removed
+    }
+
+    /// Rediscovers the compile unit roots of all workspace folders, e.g., when a
+    /// `kcl.mod` or `kcl.yaml` file is created or deleted.
+    pub fn refresh(&mut self) {
+        self.roots = self
+            .folders
+            .iter()
+            .flat_map(|folder| discover_roots(folder))
+            .collect();
+    }
+
+    /// Returns whether the path is in any of the workspace folders.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.folders.iter().any(|folder| path.starts_with(folder))
+    }
+
+    /// Returns whether the file marks a compile unit root.
+    pub fn is_root_marker(file: &Path) -> bool {
+        file.file_name()
+            .map(|name| ROOT_MARKERS.iter().any(|marker| name == *marker))
+            .unwrap_or(false)
+    }
+
+    /// Returns the deepest compile unit root which contains the file. If the file is not in
+    /// any discovered root, the directory of the file is regarded as its root.
+    pub fn root_of(&self, file: &Path) -> PathBuf {
+        self.roots
+            .iter()
+            .filter(|root| file.starts_with(root))
+            .max_by_key(|root| root.components().count())
+            .cloned()
+            .unwrap_or_else(|| {
+                file.parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| file.to_path_buf())
+            })
+    }
+}
+
+/// Discovers all the directories which contain `kcl.mod` or `kcl.yaml` in the folder.
+/// Hidden directories e.g., `.git` are skipped.
+pub(crate) fn discover_roots(folder: &Path) -> Vec<PathBuf> {
+    let mut roots = vec![];
+    let walker = WalkDir::new(folder).into_iter().filter_entry(|e| {
+        e.depth() == 0
+            || !e
+                .file_name()
+                .to_str()
+                .map(|name| name.starts_with('.'))
+                .unwrap_or(false)
+    });
+    for entry in walker.filter_map(|e| e.ok()) {
+        if entry.file_type().is_file()
+            && ROOT_MARKERS
+                .iter()
+                .any(|marker| entry.file_name() == *marker)
+        {
+            if let Some(parent) = entry.path().parent() {
+                if !roots.contains(&parent.to_path_buf()) {
+                    roots.push(parent.to_path_buf());
+                }
+            }
+        }
+    }
+    roots.sort();
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{discover_roots, Workspaces};
+
+    fn test_workspace() -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_data/workspace");
+        path
+    }
+
+    #[test]
+    fn discover_roots_test() {
+        let folder = test_workspace();
+        let roots = discover_roots(&folder);
+        assert_eq!(
+            roots,
+            vec![
+                folder.join("mod_a"),
+                folder.join("mod_b"),
+                folder.join("mod_b").join("prod"),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_of_test() {
+        let folder = test_workspace();
+        let mut workspaces = Workspaces::default();
+        workspaces.add_folder(folder.clone());
+
+        assert_eq!(
+            workspaces.root_of(&folder.join("mod_a").join("main.k")),
+            folder.join("mod_a")
+        );
+        assert_eq!(
+            workspaces.root_of(&folder.join("mod_b").join("base").join("base.k")),
+            folder.join("mod_b")
+        );
+        assert_eq!(
+            workspaces.root_of(&folder.join("mod_b").join("prod").join("main.k")),
+            folder.join("mod_b").join("prod")
+        );
+        assert_eq!(workspaces.root_of(&folder.join("single.k")), folder.clone());
+
+        let removed = workspaces.remove_folder(&folder);
+        assert_eq!(removed.len(), 3);
+        assert!(workspaces.roots.is_empty());
+    }
+}
@@ -0,0 +1,591 @@
+//! The KCL documentation generator renders the schemas of a package and all
+//! its dependencies into cross-linked Markdown or static HTML pages, one page
+//! per schema, from the schema docstrings and the resolved schema types.
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use kclvm_ast::ast;
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use kclvm_parser::{load_program, LoadProgramOptions, ParseSession};
+use kclvm_sema::advanced_resolver::AdvancedResolver;
+use kclvm_sema::core::global_state::GlobalState;
+use kclvm_sema::namer::Namer;
+use kclvm_sema::resolver::resolve_program_with_opts;
+use kclvm_sema::ty::{DictType, SchemaType, Type, TypeKind};
+
+#[cfg(test)]
+mod tests;
+
+/// The output format of the documentation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DocFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl DocFormat {
+    /// The page file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+
+impl FromStr for DocFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "md" | "markdown" => Ok(DocFormat::Markdown),
+            "html" => Ok(DocFormat::Html),
+            _ => Err(anyhow!(
+                "unsupported documentation format '{}', expected markdown or html",
+                s
+            )),
+        }
+    }
+}
+
+/// The documentation of a package.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PackageDoc {
+    pub pkgpath: String,
+    pub schemas: Vec<SchemaDoc>,
+}
+
+/// The documentation of a schema.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDoc {
+    pub name: String,
+    pub pkgpath: String,
+    pub summary: String,
+    pub is_mixin: bool,
+    pub is_protocol: bool,
+    pub base: Option<SchemaRef>,
+    pub protocol: Option<SchemaRef>,
+    pub mixins: Vec<SchemaRef>,
+    pub attrs: Vec<AttrDoc>,
+    pub checks: Vec<String>,
+    /// Examples sorted by the example name.
+    pub examples: Vec<(String, String)>,
+}
+
+/// A reference to a schema, which is rendered as a link when the schema is
+/// documented.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SchemaRef {
+    pub name: String,
+    pub pkgpath: String,
+}
+
+impl SchemaRef {
+    fn from_schema_ty(schema_ty: &SchemaType) -> Self {
+        Self {
+            name: schema_ty.name.clone(),
+            pkgpath: schema_ty.pkgpath.clone(),
+        }
+    }
+}
+
+/// The documentation of a schema attribute.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AttrDoc {
+    pub name: String,
+    pub ty: Vec<TypePart>,
+    pub default: Option<String>,
+    pub required: bool,
+    pub doc: String,
+}
+
+/// A type string is split into text and schema references, so that each
+/// format can render the schema references as links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypePart {
+    Text(String),
+    Schema(SchemaRef),
+}
+
+/// Load the documentation of the schemas defined in the files and all the
+/// dependency packages. Types are resolved with the [`GlobalState`].
+pub fn load_package_docs(
+    files: &[&str],
+    opts: Option<LoadProgramOptions>,
+) -> Result<Vec<PackageDoc>> {
+    let sess = Arc::new(ParseSession::default());
+    let mut program = load_program(sess, files, opts, None)?.program;
+    let prog_scope = resolve_program_with_opts(
+        &mut program,
+        kclvm_sema::resolver::Options {
+            merge_program: false,
+            type_erasure: false,
+            resolve_val: true,
+            ..Default::default()
+        },
+        None,
+    );
+    let gs = Namer::find_symbols_with_plugin_stubs(
+        &program,
+        GlobalState::default(),
+        &prog_scope.plugin_stubs,
+    );
+    let gs = AdvancedResolver::resolve_program(&program, gs, prog_scope.node_ty_map);
+
+    let mut pkgpaths: Vec<&String> = program.pkgs.keys().collect();
+    pkgpaths.sort();
+    let mut docs = vec![];
+    for pkgpath in pkgpaths {
+        let mut schemas = vec![];
+        for module in &program.pkgs[pkgpath] {
+            for stmt in &module.body {
+                if let ast::Stmt::Schema(schema_stmt) = &stmt.node {
+                    if let Some(schema_ty) = lookup_schema_ty(&gs, pkgpath, &schema_stmt.name.node)
+                    {
+                        schemas.push(schema_doc(&schema_ty, schema_stmt));
+                    }
+                }
+            }
+        }
+        if !schemas.is_empty() {
+            docs.push(PackageDoc {
+                pkgpath: pkgpath.to_string(),
+                schemas,
+            });
+        }
+    }
+    Ok(docs)
+}
+
+/// Generate the documentation pages of the files and all the dependency packages
+/// into the output directory, and returns the written page paths.
+pub fn gen_doc(
+    files: &[&str],
+    opts: Option<LoadProgramOptions>,
+    format: DocFormat,
+    output: &Path,
+) -> Result<Vec<PathBuf>> {
+    let docs = load_package_docs(files, opts)?;
+    let mut paths = vec![];
+    for (path, content) in render_docs(&docs, format) {
+        let path = output.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Render the package documentation into pages, which are returned as the
+/// relative page paths and the page contents. Every schema page is placed at
+/// `<pkgpath>/<schema>.<ext>` and an index page is placed at `index.<ext>`.
+pub fn render_docs(docs: &[PackageDoc], format: DocFormat) -> Vec<(PathBuf, String)> {
+    let documented: HashSet<SchemaRef> = docs
+        .iter()
+        .flat_map(|pkg| {
+            pkg.schemas.iter().map(|schema| SchemaRef {
+                name: schema.name.clone(),
+                pkgpath: schema.pkgpath.clone(),
+            })
+        })
+        .collect();
+    let renderer: Box<dyn Renderer> = match format {
+        DocFormat::Markdown => Box::new(MarkdownRenderer),
+        DocFormat::Html => Box::new(HtmlRenderer),
+    };
+    let ctx = RenderContext {
+        renderer: renderer.as_ref(),
+        documented: &documented,
+        ext: format.extension(),
+    };
+    let mut pages = vec![(
+        PathBuf::from(format!("index.{}", ctx.ext)),
+        ctx.render_index(docs),
+    )];
+    for pkg in docs {
+        for schema in &pkg.schemas {
+            pages.push((
+                Path::new(&pkg.pkgpath).join(format!("{}.{}", schema.name, ctx.ext)),
+                ctx.render_schema(schema),
+            ));
+        }
+    }
+    pages
+}
+
+fn lookup_schema_ty(gs: &GlobalState, pkgpath: &str, name: &str) -> Option<SchemaType> {
+    let symbols = gs.get_symbols();
+    let symbol_ref =
+        symbols.get_symbol_by_fully_qualified_name(&format!("{}.{}", pkgpath, name))?;
+    let ty = symbols.get_symbol(symbol_ref)?.get_sema_info().ty.clone()?;
+    match &ty.kind {
+        TypeKind::Schema(schema_ty) => Some(schema_ty.clone()),
+        _ => None,
+    }
+}
+
+fn schema_doc(schema_ty: &SchemaType, schema_stmt: &ast::SchemaStmt) -> SchemaDoc {
+    let attrs = schema_ty
+        .attrs
+        .iter()
+        .filter(|(name, _)| !name.starts_with('_'))
+        .map(|(name, attr)| AttrDoc {
+            name: name.to_string(),
+            ty: type_parts(&attr.ty),
+            default: attr.default.clone(),
+            required: !attr.is_optional,
+            doc: attr.doc.clone().unwrap_or_default(),
+        })
+        .collect();
+    let checks = schema_stmt
+        .checks
+        .iter()
+        .map(|check| {
+            let mut check_str = print_ast_node(ASTNode::Expr(&check.node.test));
+            if let Some(if_cond) = &check.node.if_cond {
+                check_str.push_str(&format!(" if {}", print_ast_node(ASTNode::Expr(if_cond))));
+            }
+            if let Some(msg) = &check.node.msg {
+                check_str.push_str(&format!(", {}", print_ast_node(ASTNode::Expr(msg))));
+            }
+            check_str
+        })
+        .collect();
+    let mut examples: Vec<(String, String)> = schema_ty
+        .examples
+        .iter()
+        .map(|(name, example)| (name.clone(), example.value.clone()))
+        .collect();
+    examples.sort();
+    SchemaDoc {
+        name: schema_ty.name.clone(),
+        pkgpath: schema_ty.pkgpath.clone(),
+        summary: schema_ty.doc.clone(),
+        is_mixin: schema_ty.is_mixin,
+        is_protocol: schema_ty.is_protocol,
+        base: schema_ty
+            .base
+            .as_ref()
+            .map(|base| SchemaRef::from_schema_ty(base)),
+        protocol: schema_ty
+            .protocol
+            .as_ref()
+            .map(|protocol| SchemaRef::from_schema_ty(protocol)),
+        mixins: schema_ty
+            .mixins
+            .iter()
+            .map(SchemaRef::from_schema_ty)
+            .collect(),
+        attrs,
+        checks,
+        examples,
+    }
+}
+
+/// Split the type string into text and schema references.
+fn type_parts(ty: &Type) -> Vec<TypePart> {
+    let mut parts = vec![];
+    collect_type_parts(ty, &mut parts);
+    // Merge adjacent text parts.
+    let mut merged: Vec<TypePart> = vec![];
+    for part in parts {
+        match (merged.last_mut(), part) {
+            (Some(TypePart::Text(last)), TypePart::Text(text)) => last.push_str(&text),
+            (_, part) => merged.push(part),
+        }
+    }
+    merged
+}
+
+fn collect_type_parts(ty: &Type, parts: &mut Vec<TypePart>) {
+    match &ty.kind {
+        TypeKind::List(item_ty) => {
+            parts.push(TypePart::Text("[".to_string()));
+            collect_type_parts(item_ty, parts);
+            parts.push(TypePart::Text("]".to_string()));
+        }
+        TypeKind::Dict(DictType { key_ty, val_ty, .. }) => {
+            parts.push(TypePart::Text("{".to_string()));
+            collect_type_parts(key_ty, parts);
+            parts.push(TypePart::Text(":".to_string()));
+            collect_type_parts(val_ty, parts);
+            parts.push(TypePart::Text("}".to_string()));
+        }
+        TypeKind::Union(types) => {
+            for (i, ty) in types.iter().enumerate() {
+                if i > 0 {
+                    parts.push(TypePart::Text(" | ".to_string()));
+                }
+                collect_type_parts(ty, parts);
+            }
+        }
+        TypeKind::Schema(schema_ty) if schema_ty.type_args.is_empty() => {
+            parts.push(TypePart::Schema(SchemaRef::from_schema_ty(schema_ty)))
+        }
+        _ => parts.push(TypePart::Text(ty.ty_str())),
+    }
+}
+
+/// The format specific rendering functions.
+trait Renderer {
+    fn heading(&self, level: usize, text: &str) -> String;
+    fn paragraph(&self, text: &str) -> String;
+    fn text(&self, text: &str) -> String;
+    fn code(&self, text: &str) -> String;
+    fn code_block(&self, text: &str) -> String;
+    fn link(&self, text: &str, href: &str) -> String;
+    fn list(&self, items: &[String]) -> String;
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) -> String;
+    fn page(&self, title: &str, body: &str) -> String;
+}
+
+struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn heading(&self, level: usize, text: &str) -> String {
+        format!("{} {}\n\n", "#".repeat(level), text)
+    }
+
+    fn paragraph(&self, text: &str) -> String {
+        format!("{}\n\n", text)
+    }
+
+    fn text(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn code(&self, text: &str) -> String {
+        format!("`{}`", text)
+    }
+
+    fn code_block(&self, text: &str) -> String {
+        format!("```kcl\n{}\n```\n\n", text)
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        format!("[{}]({})", text, href)
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        let mut out: String = items.iter().map(|item| format!("- {}\n", item)).collect();
+        out.push('\n');
+        out
+    }
+
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) -> String {
+        let mut out = format!("| {} |\n", headers.join(" | "));
+        out.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+        for row in rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|cell| cell.replace('|', "\\|").replace('\n', "<br>"))
+                .collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out.push('\n');
+        out
+    }
+
+    fn page(&self, _title: &str, body: &str) -> String {
+        body.trim_end().to_string() + "\n"
+    }
+}
+
+struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn heading(&self, level: usize, text: &str) -> String {
+        format!("<h{level}>{}</h{level}>\n", escape_html(text))
+    }
+
+    fn paragraph(&self, text: &str) -> String {
+        format!("<p>{}</p>\n", escape_html(text))
+    }
+
+    fn text(&self, text: &str) -> String {
+        escape_html(text)
+    }
+
+    fn code(&self, text: &str) -> String {
+        format!("<code>{}</code>", escape_html(text))
+    }
+
+    fn code_block(&self, text: &str) -> String {
+        format!("<pre><code>{}</code></pre>\n", escape_html(text))
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        format!(
+            "<a href=\"{}\">{}</a>",
+            escape_html(href),
+            escape_html(text)
+        )
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        let items: String = items
+            .iter()
+            .map(|item| format!("<li>{}</li>\n", item))
+            .collect();
+        format!("<ul>\n{}</ul>\n", items)
+    }
+
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) -> String {
+        let headers: String = headers
+            .iter()
+            .map(|header| format!("<th>{}</th>", header))
+            .collect();
+        let rows: String = rows
+            .iter()
+            .map(|row| {
+                let cells: String = row
+                    .iter()
+                    .map(|cell| format!("<td>{}</td>", cell))
+                    .collect();
+                format!("<tr>{}</tr>\n", cells)
+            })
+            .collect();
+        format!("<table>\n<tr>{}</tr>\n{}</table>\n", headers, rows)
+    }
+
+    fn page(&self, title: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+            escape_html(title),
+            body
+        )
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+struct RenderContext<'a> {
+    renderer: &'a dyn Renderer,
+    documented: &'a HashSet<SchemaRef>,
+    ext: &'a str,
+}
+
+impl<'a> RenderContext<'a> {
+    fn render_index(&self, docs: &[PackageDoc]) -> String {
+        let r = self.renderer;
+        let mut body = r.heading(1, "Index");
+        for pkg in docs {
+            body.push_str(&r.heading(2, &pkg.pkgpath));
+            let items: Vec<String> = pkg
+                .schemas
+                .iter()
+                .map(|schema| {
+                    r.link(
+                        &schema.name,
+                        &format!("{}/{}.{}", pkg.pkgpath, schema.name, self.ext),
+                    )
+                })
+                .collect();
+            body.push_str(&r.list(&items));
+        }
+        r.page("Index", &body)
+    }
+
+    fn render_schema(&self, schema: &SchemaDoc) -> String {
+        let r = self.renderer;
+        let kind = if schema.is_mixin {
+            "mixin"
+        } else if schema.is_protocol {
+            "protocol"
+        } else {
+            "schema"
+        };
+        let mut body = r.heading(1, &format!("{} {}", kind, schema.name));
+        body.push_str(&r.paragraph(&format!("Package: {}", schema.pkgpath)));
+        if !schema.summary.is_empty() {
+            body.push_str(&r.paragraph(&schema.summary));
+        }
+        let mut relations = vec![];
+        if let Some(base) = &schema.base {
+            relations.push(format!("Inherits: {}", self.schema_link(base)));
+        }
+        if let Some(protocol) = &schema.protocol {
+            relations.push(format!("Protocol: {}", self.schema_link(protocol)));
+        }
+        if !schema.mixins.is_empty() {
+            let mixins: Vec<String> = schema
+                .mixins
+                .iter()
+                .map(|mixin| self.schema_link(mixin))
+                .collect();
+            relations.push(format!("Mixins: {}", mixins.join(", ")));
+        }
+        if !relations.is_empty() {
+            body.push_str(&r.list(&relations));
+        }
+        if !schema.attrs.is_empty() {
+            body.push_str(&r.heading(2, "Attributes"));
+            let rows: Vec<Vec<String>> = schema
+                .attrs
+                .iter()
+                .map(|attr| {
+                    vec![
+                        r.code(&attr.name),
+                        self.type_link(&attr.ty),
+                        attr.default
+                            .as_ref()
+                            .map(|default| r.code(default))
+                            .unwrap_or_default(),
+                        if attr.required { "Y" } else { "" }.to_string(),
+                        r.text(&attr.doc),
+                    ]
+                })
+                .collect();
+            body.push_str(&r.table(
+                &["Name", "Type", "Default", "Required", "Description"],
+                &rows,
+            ));
+        }
+        if !schema.checks.is_empty() {
+            body.push_str(&r.heading(2, "Checks"));
+            let checks: Vec<String> = schema.checks.iter().map(|check| r.code(check)).collect();
+            body.push_str(&r.list(&checks));
+        }
+        if !schema.examples.is_empty() {
+            body.push_str(&r.heading(2, "Examples"));
+            for (name, value) in &schema.examples {
+                if schema.examples.len() > 1 {
+                    body.push_str(&r.heading(3, name));
+                }
+                body.push_str(&r.code_block(value));
+            }
+        }
+        r.page(&schema.name, &body)
+    }
+
+    fn schema_link(&self, schema: &SchemaRef) -> String {
+        if self.documented.contains(schema) {
+            self.renderer.link(
+                &schema.name,
+                &format!("../{}/{}.{}", schema.pkgpath, schema.name, self.ext),
+            )
+        } else {
+            self.renderer.code(&schema.name)
+        }
+    }
+
+    fn type_link(&self, ty: &[TypePart]) -> String {
+        ty.iter()
+            .map(|part| match part {
+                TypePart::Text(text) => self.renderer.code(text),
+                TypePart::Schema(schema) => self.schema_link(schema),
+            })
+            .collect()
+    }
+}
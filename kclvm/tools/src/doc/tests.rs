@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+use super::{load_package_docs, render_docs, DocFormat, SchemaRef, TypePart};
+
+fn test_pkg_main_file() -> String {
+    Path::new(".")
+        .join("src")
+        .join("doc")
+        .join("test_data")
+        .join("pkg")
+        .join("main.k")
+        .display()
+        .to_string()
+}
+
+#[test]
+fn test_load_package_docs() {
+    let docs = load_package_docs(&[&test_pkg_main_file()], None).unwrap();
+    let pkgpaths: Vec<&str> = docs.iter().map(|pkg| pkg.pkgpath.as_str()).collect();
+    assert_eq!(pkgpaths, vec!["__main__", "base"]);
+
+    let app = docs[0]
+        .schemas
+        .iter()
+        .find(|schema| schema.name == "App")
+        .unwrap();
+    assert_eq!(app.summary, "App is an application with replicas.");
+    assert_eq!(
+        app.base,
+        Some(SchemaRef {
+            name: "Resource".to_string(),
+            pkgpath: "__main__".to_string(),
+        })
+    );
+    let replicas = app
+        .attrs
+        .iter()
+        .find(|attr| attr.name == "replicas")
+        .unwrap();
+    assert_eq!(replicas.default, Some("1".to_string()));
+    assert!(!replicas.required);
+    assert_eq!(replicas.doc, "The replica count.");
+    let sidecars = app
+        .attrs
+        .iter()
+        .find(|attr| attr.name == "sidecars")
+        .unwrap();
+    assert_eq!(
+        sidecars.ty,
+        vec![
+            TypePart::Text("[".to_string()),
+            TypePart::Schema(SchemaRef {
+                name: "Metadata".to_string(),
+                pkgpath: "base".to_string(),
+            }),
+            TypePart::Text("]".to_string()),
+        ]
+    );
+    assert_eq!(
+        app.checks,
+        vec![r#"replicas >= 0, "replicas must be non-negative""#.to_string()]
+    );
+    assert_eq!(app.examples.len(), 1);
+    assert!(app.examples[0].1.contains("replicas = 2"));
+
+    let metadata = &docs[1].schemas[0];
+    assert_eq!(metadata.name, "Metadata");
+    let name = metadata
+        .attrs
+        .iter()
+        .find(|attr| attr.name == "name")
+        .unwrap();
+    assert!(name.required);
+}
+
+#[test]
+fn test_render_docs() {
+    let docs = load_package_docs(&[&test_pkg_main_file()], None).unwrap();
+    for format in [DocFormat::Markdown, DocFormat::Html] {
+        let ext = format.extension();
+        let pages = render_docs(&docs, format);
+        let paths: Vec<&PathBuf> = pages.iter().map(|(path, _)| path).collect();
+        assert!(paths.contains(&&PathBuf::from(format!("index.{ext}"))));
+        assert!(paths.contains(&&Path::new("__main__").join(format!("App.{ext}"))));
+        assert!(paths.contains(&&Path::new("base").join(format!("Metadata.{ext}"))));
+
+        let (_, app_page) = pages
+            .iter()
+            .find(|(path, _)| path.ends_with(format!("App.{ext}")))
+            .unwrap();
+        // Cross links to the base schema and the attribute schema types.
+        assert!(app_page.contains(&format!("../__main__/Resource.{ext}")));
+        assert!(app_page.contains(&format!("../base/Metadata.{ext}")));
+        assert!(app_page.contains("replicas must be non-negative"));
+    }
+}
+
+#[test]
+fn test_doc_format_from_str() {
+    assert_eq!("md".parse::<DocFormat>().unwrap(), DocFormat::Markdown);
+    assert_eq!("HTML".parse::<DocFormat>().unwrap(), DocFormat::Html);
+    assert!("pdf".parse::<DocFormat>().is_err());
+}
@@ -0,0 +1,533 @@
+//! [kclvm_tools::import] module converts YAML, JSON and TOML data into formatted KCL
+//! modules, and generates KCL schemas from the protobuf definitions in `.proto` files.
+//!
+//! Every data document is converted into a KCL expression with the [ExprBuilder] of
+//! the vet tool. Mapping keys which are valid KCL identifiers are written without quotes,
+//! and the module is printed and formatted with [kclvm_ast_pretty]. Optionally, the
+//! documents are instantiated with a schema, and draft schema definitions can be
+//! inferred from the shape of the data.
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use indexmap::IndexMap;
+use kclvm_ast::ast::{ConfigEntryOperation, Expr, ExprContext, Identifier, Node, NodeRef};
+use kclvm_ast_pretty::{print_ast_node, ASTNode};
+use kclvm_span::symbol::reserved::is_reserved_word;
+
+use crate::format::{format_source, FormatOptions};
+use crate::util::loader::LoaderKind;
+use crate::vet::expr_builder::{ExprBuilder, ExprGenerator};
+
+mod proto;
+#[cfg(test)]
+mod tests;
+
+/// The default variable name of the imported documents when they can't be
+/// written as top-level attributes.
+pub const DEFAULT_VAR_NAME: &str = "items";
+
+/// The supported data formats of the import tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Yaml,
+    Json,
+    Toml,
+    /// Protobuf definitions, the messages and enums are generated as schemas.
+    Proto,
+}
+
+impl ImportFormat {
+    /// Get the data format from the file extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let ext = path
+            .as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        ext.parse()
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(ImportFormat::Yaml),
+            "json" => Ok(ImportFormat::Json),
+            "toml" => Ok(ImportFormat::Toml),
+            "proto" | "protobuf" => Ok(ImportFormat::Proto),
+            _ => Err(anyhow!(
+                "unsupported data format '{}', expected yaml, json, toml or proto",
+                s
+            )),
+        }
+    }
+}
+
+/// Represents options for importing data.
+#[derive(Debug, Default, Clone)]
+pub struct ImportOptions {
+    /// The schema name used to instantiate every document.
+    pub schema: Option<String>,
+    /// Whether to infer the draft schema definition from the data shape. The
+    /// schema name is required.
+    pub infer_schema: bool,
+    /// The variable name of the imported documents. When it is not set, a single
+    /// mapping document without a schema is written as top-level attributes.
+    pub var_name: Option<String>,
+}
+
+/// Import the data file into a formatted KCL module. The data format is
+/// detected from the file extension when it is not specified.
+pub fn import_file<P: AsRef<Path>>(
+    path: P,
+    format: Option<ImportFormat>,
+    opts: &ImportOptions,
+) -> Result<String> {
+    let path = path.as_ref();
+    let format = match format {
+        Some(format) => format,
+        None => ImportFormat::from_path(path)?,
+    };
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to load '{}'", path.display()))?;
+    import_data(&data, format, opts)
+}
+
+/// Import the data string into a formatted KCL module. Every document in a YAML
+/// stream is imported. The schema options are not used for protobuf definitions.
+pub fn import_data(data: &str, format: ImportFormat, opts: &ImportOptions) -> Result<String> {
+    if format == ImportFormat::Proto {
+        let code = proto::proto_to_kcl(data)?;
+        let (code, _) = format_source("", &code, &FormatOptions::default())?;
+        return Ok(code);
+    }
+    let docs = load_documents(data, format)?;
+    if opts.infer_schema && opts.schema.is_none() {
+        bail!("a schema name is required to infer the schema definition");
+    }
+    let mut code = String::new();
+    if let (true, Some(schema)) = (opts.infer_schema, &opts.schema) {
+        let mut schemas = IndexMap::new();
+        for doc in &docs {
+            infer_shape(doc, schema, &mut vec![], &mut schemas);
+        }
+        for schema in schemas.values() {
+            code.push_str(&schema.to_code());
+            code.push('\n');
+        }
+    }
+    // The data loader is only used to locate the values with spans, plain
+    // values are generated without it.
+    let builder = ExprBuilder::new_with_str(LoaderKind::YAML, data.to_string())?;
+    let mut exprs = vec![];
+    for doc in &docs {
+        let mut expr = builder.generate(doc, &opts.schema)?;
+        idiomatic_expr(&mut expr);
+        exprs.push(expr);
+    }
+    let top_level_keys = match (&opts.var_name, &opts.schema, docs.as_slice()) {
+        (None, None, [serde_yaml::Value::Mapping(mapping)]) => mapping
+            .keys()
+            .map(|key| key.as_str().filter(|key| is_identifier(key)))
+            .collect::<Option<Vec<&str>>>(),
+        _ => None,
+    };
+    match top_level_keys {
+        // Write the single mapping document as top-level attributes.
+        Some(keys) => {
+            if let Expr::Config(config_expr) = &mut exprs[0].node {
+                for (key, item) in keys.iter().zip(config_expr.items.iter_mut()) {
+                    layout_expr(&mut item.node.value, &mut 1);
+                    code.push_str(&format!(
+                        "{} = {}\n",
+                        key,
+                        print_ast_node(ASTNode::Expr(&item.node.value))
+                    ));
+                }
+            }
+        }
+        None => {
+            let var_name = opts.var_name.clone().unwrap_or_else(|| match &opts.schema {
+                Some(schema) if docs.len() == 1 => lower_camel_case(schema),
+                _ => DEFAULT_VAR_NAME.to_string(),
+            });
+            let mut expr = if exprs.len() == 1 {
+                exprs.remove(0)
+            } else {
+                NodeRef::new(Node::dummy_node(Expr::List(kclvm_ast::ast::ListExpr {
+                    elts: exprs,
+                    ctx: ExprContext::Load,
+                })))
+            };
+            layout_expr(&mut expr, &mut 1);
+            code.push_str(&format!(
+                "{} = {}\n",
+                var_name,
+                print_ast_node(ASTNode::Expr(&expr))
+            ));
+        }
+    }
+    let (code, _) = format_source("", &code, &FormatOptions::default())?;
+    Ok(code)
+}
+
+/// Load the data documents into YAML values.
+fn load_documents(data: &str, format: ImportFormat) -> Result<Vec<serde_yaml::Value>> {
+    use serde::Deserialize;
+    let docs = match format {
+        ImportFormat::Yaml => {
+            let mut docs = vec![];
+            for doc in serde_yaml::Deserializer::from_str(data) {
+                let value = serde_yaml::Value::deserialize(doc)
+                    .with_context(|| "Failed to load YAML".to_string())?;
+                // Skip empty documents in the YAML stream.
+                if !value.is_null() {
+                    docs.push(value);
+                }
+            }
+            docs
+        }
+        ImportFormat::Json => vec![serde_json::from_str::<serde_yaml::Value>(data)
+            .with_context(|| "Failed to load JSON".to_string())?],
+        ImportFormat::Toml => {
+            let value = toml::from_str::<toml::Value>(data)
+                .with_context(|| "Failed to load TOML".to_string())?;
+            vec![toml_to_yaml_value(value)]
+        }
+        ImportFormat::Proto => bail!("protobuf definitions are not data documents"),
+    };
+    if docs.is_empty() {
+        bail!("no data document is found");
+    }
+    Ok(docs)
+}
+
+fn toml_to_yaml_value(value: toml::Value) -> serde_yaml::Value {
+    match value {
+        toml::Value::String(v) => serde_yaml::Value::String(v),
+        toml::Value::Integer(v) => serde_yaml::Value::Number(v.into()),
+        toml::Value::Float(v) => serde_yaml::Value::Number(v.into()),
+        toml::Value::Boolean(v) => serde_yaml::Value::Bool(v),
+        toml::Value::Datetime(v) => serde_yaml::Value::String(v.to_string()),
+        toml::Value::Array(values) => {
+            serde_yaml::Value::Sequence(values.into_iter().map(toml_to_yaml_value).collect())
+        }
+        toml::Value::Table(table) => serde_yaml::Value::Mapping(
+            table
+                .into_iter()
+                .map(|(k, v)| (serde_yaml::Value::String(k), toml_to_yaml_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Whether the string can be written as a public KCL identifier. Names with the
+/// leading `_` are private and omitted in the output, so they are quoted instead.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && !is_reserved_word(s)
+}
+
+/// Rewrite the generated expression in the KCL style: identifier keys are
+/// written without quotes, entries are written with the `=` operator, and the
+/// strings are written without the string interpolation.
+fn idiomatic_expr(expr: &mut NodeRef<Expr>) {
+    match &mut expr.node {
+        Expr::Config(config_expr) => {
+            for item in config_expr.items.iter_mut() {
+                let item = &mut item.node;
+                item.operation = ConfigEntryOperation::Override;
+                if let Some(key) = &mut item.key {
+                    match &key.node {
+                        Expr::StringLit(string_lit) if is_identifier(&string_lit.value) => {
+                            key.node = Expr::Identifier(Identifier {
+                                names: vec![Node::dummy_node(string_lit.value.clone())],
+                                pkgpath: String::new(),
+                                ctx: ExprContext::Load,
+                            });
+                        }
+                        _ => idiomatic_expr(key),
+                    }
+                }
+                idiomatic_expr(&mut item.value);
+            }
+        }
+        Expr::Schema(schema_expr) => idiomatic_expr(&mut schema_expr.config),
+        Expr::List(list_expr) => list_expr.elts.iter_mut().for_each(idiomatic_expr),
+        Expr::StringLit(string_lit) => string_lit.raw_value = quote_string(&string_lit.value),
+        _ => {}
+    }
+}
+
+fn quote_string(s: &str) -> String {
+    // Use raw strings to prevent the string interpolation.
+    if s.contains("${") && !s.contains("\"\"\"") && !s.ends_with('"') {
+        return format!("r\"\"\"{}\"\"\"", s);
+    }
+    // The `$$` is unescaped in the strings with the interpolation, and the `${` is
+    // written as an interpolated `$` which can't start an interpolation.
+    let interpolated = s.contains("${");
+    let mut quoted = String::from("\"");
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '$' if interpolated && chars.peek() == Some(&'{') => quoted.push_str("${'$'}"),
+            '$' if interpolated => quoted.push_str("$$"),
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Assign distinct lines to the config entries and the complex list items, so
+/// that the printer writes them in multiple lines.
+fn layout_expr(expr: &mut NodeRef<Expr>, line: &mut u64) {
+    match &mut expr.node {
+        Expr::Config(config_expr) => {
+            for item in config_expr.items.iter_mut() {
+                *line += 1;
+                item.line = *line;
+                layout_expr(&mut item.node.value, line);
+            }
+        }
+        Expr::Schema(schema_expr) => layout_expr(&mut schema_expr.config, line),
+        Expr::List(list_expr) => {
+            let multiline = list_expr
+                .elts
+                .iter()
+                .any(|elt| matches!(&elt.node, Expr::Config(_) | Expr::Schema(_) | Expr::List(_)));
+            for elt in list_expr.elts.iter_mut() {
+                if multiline {
+                    *line += 1;
+                    elt.line = *line;
+                }
+                layout_expr(elt, line);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn lower_camel_case(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn upper_camel_case(s: &str) -> String {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// The inferred type of the data.
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    /// The type of the null value, which is merged into other types.
+    None,
+    Bool,
+    Int,
+    Float,
+    Str,
+    List(Box<Shape>),
+    Dict(Box<Shape>),
+    Schema(String),
+    Union(Vec<Shape>),
+}
+
+impl Shape {
+    fn unify(self, other: Shape) -> Shape {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Shape::None, b) => b,
+            (a, Shape::None) => a,
+            (Shape::Int, Shape::Float) | (Shape::Float, Shape::Int) => Shape::Float,
+            (Shape::List(a), Shape::List(b)) => Shape::List(Box::new(a.unify(*b))),
+            (Shape::Dict(a), Shape::Dict(b)) => Shape::Dict(Box::new(a.unify(*b))),
+            (a, Shape::Union(types)) if !matches!(a, Shape::Union(_)) => {
+                Shape::Union(types).unify(a)
+            }
+            (Shape::Union(mut types), b) => {
+                if !types.contains(&b) {
+                    types.push(b);
+                }
+                Shape::Union(types)
+            }
+            (a, b) => Shape::Union(vec![a, b]),
+        }
+    }
+
+    fn ty_str(&self) -> String {
+        match self {
+            Shape::None => "any".to_string(),
+            Shape::Bool => "bool".to_string(),
+            Shape::Int => "int".to_string(),
+            Shape::Float => "float".to_string(),
+            Shape::Str => "str".to_string(),
+            Shape::List(item) => format!("[{}]", item.ty_str()),
+            Shape::Dict(value) => format!("{{str:{}}}", value.ty_str()),
+            Shape::Schema(name) => name.clone(),
+            Shape::Union(types) => types
+                .iter()
+                .map(|ty| ty.ty_str())
+                .collect::<Vec<String>>()
+                .join(" | "),
+        }
+    }
+}
+
+/// A draft schema definition inferred from the data.
+#[derive(Debug, Default)]
+struct DraftSchema {
+    name: String,
+    /// The attribute types and whether the attributes are optional.
+    attrs: IndexMap<String, (Shape, bool)>,
+    /// The number of the mapping values merged into the schema.
+    count: usize,
+}
+
+impl DraftSchema {
+    fn merge(&mut self, attrs: Vec<(String, Shape, bool)>) {
+        let names: Vec<&String> = attrs.iter().map(|(name, _, _)| name).collect();
+        for (name, (_, optional)) in self.attrs.iter_mut() {
+            if !names.contains(&name) {
+                *optional = true;
+            }
+        }
+        for (name, shape, is_none) in attrs {
+            let is_new = !self.attrs.contains_key(&name);
+            let optional = is_none || (is_new && self.count > 0);
+            match self.attrs.get_mut(&name) {
+                Some((ty, attr_optional)) => {
+                    *ty = std::mem::replace(ty, Shape::None).unify(shape);
+                    *attr_optional |= optional;
+                }
+                None => {
+                    self.attrs.insert(name, (shape, optional));
+                }
+            }
+        }
+        self.count += 1;
+    }
+
+    fn to_code(&self) -> String {
+        let mut code = format!("schema {}:\n", self.name);
+        if self.attrs.is_empty() {
+            code.push_str("    pass\n");
+        }
+        for (name, (ty, optional)) in &self.attrs {
+            code.push_str(&format!(
+                "    {}{}: {}\n",
+                name,
+                if *optional { "?" } else { "" },
+                ty.ty_str()
+            ));
+        }
+        code
+    }
+}
+
+/// Infer the data shape and collect the draft schemas. Mappings whose keys are
+/// all identifiers are inferred as schemas named with the name hint, and other
+/// mappings are inferred as dicts. The name hint is prefixed with the parent
+/// schema name when it is the same as an enclosing schema name, e.g., the
+/// `spec` in `spec.template.spec` is named `TemplateSpec`.
+fn infer_shape(
+    value: &serde_yaml::Value,
+    name: &str,
+    ancestors: &mut Vec<String>,
+    schemas: &mut IndexMap<String, DraftSchema>,
+) -> Shape {
+    match value {
+        serde_yaml::Value::Null => Shape::None,
+        serde_yaml::Value::Bool(_) => Shape::Bool,
+        serde_yaml::Value::Number(number) => {
+            if number.is_f64() {
+                Shape::Float
+            } else {
+                Shape::Int
+            }
+        }
+        serde_yaml::Value::String(_) => Shape::Str,
+        serde_yaml::Value::Sequence(values) => {
+            let item_name = match name.strip_suffix('s') {
+                Some(singular) if singular.len() > 1 => singular.to_string(),
+                _ => format!("{}Item", name),
+            };
+            let item = values.iter().fold(Shape::None, |shape, value| {
+                shape.unify(infer_shape(value, &item_name, ancestors, schemas))
+            });
+            Shape::List(Box::new(item))
+        }
+        serde_yaml::Value::Mapping(mapping) => {
+            let keys: Option<Vec<&str>> = mapping
+                .keys()
+                .map(|key| key.as_str().filter(|key| is_identifier(key)))
+                .collect();
+            match keys {
+                Some(keys) if !keys.is_empty() => {
+                    let name = match ancestors.last() {
+                        Some(parent) if ancestors.contains(&name.to_string()) => {
+                            format!("{}{}", parent, name)
+                        }
+                        _ => name.to_string(),
+                    };
+                    // Insert the schema before its nested schemas.
+                    schemas.entry(name.clone()).or_insert(DraftSchema {
+                        name: name.clone(),
+                        ..Default::default()
+                    });
+                    ancestors.push(name.clone());
+                    let attrs = keys
+                        .into_iter()
+                        .zip(mapping.values())
+                        .map(|(key, value)| {
+                            let shape =
+                                infer_shape(value, &upper_camel_case(key), ancestors, schemas);
+                            (key.to_string(), shape, value.is_null())
+                        })
+                        .collect();
+                    ancestors.pop();
+                    if let Some(schema) = schemas.get_mut(&name) {
+                        schema.merge(attrs);
+                    }
+                    Shape::Schema(name)
+                }
+                _ => {
+                    let value = mapping.values().fold(Shape::None, |shape, value| {
+                        shape.unify(infer_shape(
+                            value,
+                            &format!("{}Value", name),
+                            ancestors,
+                            schemas,
+                        ))
+                    });
+                    Shape::Dict(Box::new(value))
+                }
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => infer_shape(&tagged.value, name, ancestors, schemas),
+    }
+}
@@ -0,0 +1,617 @@
+//! Generate KCL schemas from the messages and enums in a `.proto` file.
+//!
+//! Messages are generated as schemas and nested messages are named with the
+//! enclosing message names, e.g., `Outer_Inner`. Enums are generated as type
+//! aliases of the string literal unions of the enum value names, repeated fields
+//! as lists and map fields as dicts. The member fields of a `oneof` are kept as
+//! separate optional attributes like the protobuf JSON mapping, and a check
+//! expression makes sure at most one of them is set. Leading and trailing
+//! comments are kept as the schema and attribute documents.
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use kclvm_span::symbol::reserved::is_reserved_word;
+
+/// The KCL types of the protobuf scalar types. Bytes values are base64 strings
+/// in the protobuf JSON mapping.
+const SCALAR_TYPES: &[(&str, &str)] = &[
+    ("double", "float"),
+    ("float", "float"),
+    ("int32", "int"),
+    ("int64", "int"),
+    ("uint32", "int"),
+    ("uint64", "int"),
+    ("sint32", "int"),
+    ("sint64", "int"),
+    ("fixed32", "int"),
+    ("fixed64", "int"),
+    ("sfixed32", "int"),
+    ("sfixed64", "int"),
+    ("bool", "bool"),
+    ("string", "str"),
+    ("bytes", "str"),
+];
+
+/// The KCL types of the well-known types in the protobuf JSON mapping.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    ("google.protobuf.Any", "{str:any}"),
+    ("google.protobuf.Struct", "{str:any}"),
+    ("google.protobuf.Value", "any"),
+    ("google.protobuf.ListValue", "[any]"),
+    ("google.protobuf.Empty", "{str:any}"),
+    ("google.protobuf.Timestamp", "str"),
+    ("google.protobuf.Duration", "str"),
+    ("google.protobuf.FieldMask", "str"),
+    ("google.protobuf.DoubleValue", "float"),
+    ("google.protobuf.FloatValue", "float"),
+    ("google.protobuf.Int64Value", "int"),
+    ("google.protobuf.UInt64Value", "int"),
+    ("google.protobuf.Int32Value", "int"),
+    ("google.protobuf.UInt32Value", "int"),
+    ("google.protobuf.BoolValue", "bool"),
+    ("google.protobuf.StringValue", "str"),
+    ("google.protobuf.BytesValue", "str"),
+];
+
+/// Generate the KCL schemas of the `.proto` source. The code is not formatted.
+pub(crate) fn proto_to_kcl(source: &str) -> Result<String> {
+    let tokens = tokenize(source)?;
+    let file = Parser { tokens, pos: 0 }.parse_file()?;
+    let mut names = HashMap::new();
+    for message in &file.messages {
+        collect_names(message, "", &mut names);
+    }
+    for enum_def in &file.enums {
+        names.insert(enum_def.name.clone(), enum_def.name.clone());
+    }
+    let gen = Generator {
+        package: file.package.clone(),
+        names,
+    };
+    let mut code = String::new();
+    for enum_def in &file.enums {
+        code.push_str(&gen.enum_code(enum_def, &enum_def.name));
+    }
+    for message in &file.messages {
+        gen.message_code(message, &[], &mut code);
+    }
+    Ok(code)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Identifiers, keywords, full names and number literals.
+    Word(String),
+    /// String literals, e.g., the import paths and option values.
+    Str,
+    Symbol(char),
+}
+
+#[derive(Debug, Clone)]
+struct TokenInfo {
+    token: Token,
+    /// The comment lines before the token.
+    leading_comment: String,
+    /// The comment after the token on the same line.
+    trailing_comment: String,
+}
+
+fn tokenize(source: &str) -> Result<Vec<TokenInfo>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<TokenInfo> = vec![];
+    let mut comment = String::new();
+    // Whether the source after the last token is on the same line.
+    let mut same_line = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            same_line = false;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            let start = i + 2;
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            push_comment(&mut tokens, &mut comment, same_line, text.trim());
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            let start = i + 2;
+            i += 2;
+            while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            if i + 1 >= chars.len() {
+                bail!("unterminated block comment");
+            }
+            let text: String = chars[start..i].iter().collect();
+            i += 2;
+            let text = text
+                .lines()
+                .map(|line| line.trim().trim_start_matches('*').trim())
+                .collect::<Vec<&str>>()
+                .join("\n");
+            push_comment(&mut tokens, &mut comment, same_line, text.trim());
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            if i >= chars.len() {
+                bail!("unterminated string literal");
+            }
+            i += 1;
+            push_token(&mut tokens, &mut comment, Token::Str);
+            same_line = true;
+        } else if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '+') {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || matches!(chars[i], '_' | '.' | '-' | '+'))
+            {
+                i += 1;
+            }
+            push_token(
+                &mut tokens,
+                &mut comment,
+                Token::Word(chars[start..i].iter().collect()),
+            );
+            same_line = true;
+        } else {
+            push_token(&mut tokens, &mut comment, Token::Symbol(c));
+            same_line = true;
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+fn push_comment(tokens: &mut [TokenInfo], comment: &mut String, same_line: bool, text: &str) {
+    match tokens.last_mut() {
+        Some(last) if same_line => {
+            if !last.trailing_comment.is_empty() {
+                last.trailing_comment.push('\n');
+            }
+            last.trailing_comment.push_str(text);
+        }
+        _ => {
+            if !comment.is_empty() {
+                comment.push('\n');
+            }
+            comment.push_str(text);
+        }
+    }
+}
+
+fn push_token(tokens: &mut Vec<TokenInfo>, comment: &mut String, token: Token) {
+    tokens.push(TokenInfo {
+        token,
+        leading_comment: std::mem::take(comment),
+        trailing_comment: String::new(),
+    });
+}
+
+#[derive(Debug, Default)]
+struct ProtoFile {
+    package: String,
+    messages: Vec<MessageDef>,
+    enums: Vec<EnumDef>,
+}
+
+#[derive(Debug, Default)]
+struct MessageDef {
+    name: String,
+    doc: String,
+    fields: Vec<FieldDef>,
+    /// The oneof names and the member field names.
+    oneofs: Vec<(String, Vec<String>)>,
+    messages: Vec<MessageDef>,
+    enums: Vec<EnumDef>,
+}
+
+#[derive(Debug)]
+struct FieldDef {
+    name: String,
+    doc: String,
+    ty: FieldTy,
+    repeated: bool,
+    required: bool,
+}
+
+#[derive(Debug)]
+enum FieldTy {
+    Named(String),
+    /// Map fields with the key and value types.
+    Map(String, String),
+}
+
+#[derive(Debug)]
+struct EnumDef {
+    name: String,
+    doc: String,
+    values: Vec<String>,
+}
+
+struct Parser {
+    tokens: Vec<TokenInfo>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.token)
+    }
+
+    fn next(&mut self) -> Result<&TokenInfo> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .ok_or_else(|| anyhow!("unexpected end of the proto file"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect_word(&mut self) -> Result<String> {
+        match &self.next()?.token {
+            Token::Word(word) => Ok(word.clone()),
+            token => bail!("expected a name, got {:?}", token),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<()> {
+        match &self.next()?.token {
+            Token::Symbol(c) if *c == symbol => Ok(()),
+            token => bail!("expected '{}', got {:?}", symbol, token),
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skip the statement ending with `;` or a block.
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0;
+        loop {
+            match &self.next()?.token {
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_file(&mut self) -> Result<ProtoFile> {
+        let mut file = ProtoFile::default();
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                Token::Word(word) if word == "package" => {
+                    self.pos += 1;
+                    file.package = self.expect_word()?;
+                    self.expect_symbol(';')?;
+                }
+                Token::Word(word) if word == "message" => file.messages.push(self.parse_message()?),
+                Token::Word(word) if word == "enum" => file.enums.push(self.parse_enum()?),
+                // Syntax, imports, options, services and extensions.
+                Token::Word(_) => self.skip_statement()?,
+                Token::Symbol(';') => self.pos += 1,
+                token => bail!("unexpected token {:?}", token),
+            }
+        }
+        Ok(file)
+    }
+
+    fn parse_message(&mut self) -> Result<MessageDef> {
+        let doc = self.next()?.leading_comment.clone();
+        let name = self.expect_word()?;
+        self.expect_symbol('{')?;
+        let mut message = MessageDef {
+            name,
+            doc,
+            ..Default::default()
+        };
+        self.parse_message_body(&mut message, None)?;
+        Ok(message)
+    }
+
+    /// Parse the message body or the oneof body until the closing brace.
+    fn parse_message_body(&mut self, message: &mut MessageDef, oneof: Option<usize>) -> Result<()> {
+        loop {
+            let token = self
+                .peek()
+                .cloned()
+                .ok_or_else(|| anyhow!("unexpected end of message '{}'", message.name))?;
+            match token {
+                Token::Symbol('}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Token::Symbol(';') => self.pos += 1,
+                Token::Word(word) => match word.as_str() {
+                    "message" if oneof.is_none() => message.messages.push(self.parse_message()?),
+                    "enum" if oneof.is_none() => message.enums.push(self.parse_enum()?),
+                    "oneof" if oneof.is_none() => {
+                        self.pos += 1;
+                        let name = self.expect_word()?;
+                        self.expect_symbol('{')?;
+                        message.oneofs.push((name, vec![]));
+                        self.parse_message_body(message, Some(message.oneofs.len() - 1))?;
+                    }
+                    "option" | "reserved" | "extensions" | "extend" => self.skip_statement()?,
+                    _ => {
+                        let field = self.parse_field()?;
+                        if let Some(index) = oneof {
+                            message.oneofs[index].1.push(field.name.clone());
+                        }
+                        message.fields.push(field);
+                    }
+                },
+                token => bail!("unexpected token {:?} in message '{}'", token, message.name),
+            }
+        }
+    }
+
+    fn parse_field(&mut self) -> Result<FieldDef> {
+        let start = self.pos;
+        let mut word = self.expect_word()?;
+        let mut repeated = false;
+        let mut required = false;
+        if matches!(word.as_str(), "optional" | "repeated" | "required") {
+            repeated = word == "repeated";
+            required = word == "required";
+            word = self.expect_word()?;
+        }
+        let ty = if word == "map" && self.eat_symbol('<') {
+            let key = self.expect_word()?;
+            self.expect_symbol(',')?;
+            let value = self.expect_word()?;
+            self.expect_symbol('>')?;
+            FieldTy::Map(key, value)
+        } else if word == "group" {
+            bail!("proto2 groups are not supported");
+        } else {
+            FieldTy::Named(word)
+        };
+        let name = self.expect_word()?;
+        self.expect_symbol('=')?;
+        self.expect_word()?;
+        if self.eat_symbol('[') {
+            while !self.eat_symbol(']') {
+                self.next()?;
+            }
+        }
+        self.expect_symbol(';')?;
+        let doc = match self.tokens[start].leading_comment.as_str() {
+            "" => self.tokens[self.pos - 1].trailing_comment.clone(),
+            doc => doc.to_string(),
+        };
+        Ok(FieldDef {
+            name,
+            doc,
+            ty,
+            repeated,
+            required,
+        })
+    }
+
+    fn parse_enum(&mut self) -> Result<EnumDef> {
+        let doc = self.next()?.leading_comment.clone();
+        let name = self.expect_word()?;
+        self.expect_symbol('{')?;
+        let mut values = vec![];
+        loop {
+            match self.peek().cloned() {
+                Some(Token::Symbol('}')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Token::Symbol(';')) => self.pos += 1,
+                Some(Token::Word(word)) if matches!(word.as_str(), "option" | "reserved") => {
+                    self.skip_statement()?
+                }
+                Some(Token::Word(_)) => {
+                    values.push(self.expect_word()?);
+                    self.skip_statement()?;
+                }
+                token => bail!("unexpected token {:?} in enum '{}'", token, name),
+            }
+        }
+        Ok(EnumDef { name, doc, values })
+    }
+}
+
+/// Collect the KCL names of the messages and enums by the proto names relative
+/// to the package, e.g., `Outer.Inner` is named `Outer_Inner`.
+fn collect_names(message: &MessageDef, scope: &str, names: &mut HashMap<String, String>) {
+    let full_name = if scope.is_empty() {
+        message.name.clone()
+    } else {
+        format!("{}.{}", scope, message.name)
+    };
+    names.insert(full_name.clone(), full_name.replace('.', "_"));
+    for enum_def in &message.enums {
+        let enum_name = format!("{}.{}", full_name, enum_def.name);
+        names.insert(enum_name.clone(), enum_name.replace('.', "_"));
+    }
+    for nested in &message.messages {
+        collect_names(nested, &full_name, names);
+    }
+}
+
+struct Generator {
+    package: String,
+    names: HashMap<String, String>,
+}
+
+impl Generator {
+    /// Resolve the type name in the scope of the enclosing messages like protoc,
+    /// the innermost definition is used.
+    fn type_str(&self, name: &str, scope: &[&str]) -> String {
+        if let Some((_, ty)) = SCALAR_TYPES.iter().find(|(n, _)| *n == name) {
+            return ty.to_string();
+        }
+        let name = name.trim_start_matches('.');
+        let relative = match name.strip_prefix(&format!("{}.", self.package)) {
+            Some(relative) if !self.package.is_empty() => relative,
+            _ => name,
+        };
+        for i in (0..=scope.len()).rev() {
+            let mut candidate = scope[..i].join(".");
+            if !candidate.is_empty() {
+                candidate.push('.');
+            }
+            candidate.push_str(relative);
+            if let Some(kcl_name) = self.names.get(&candidate) {
+                return kcl_name.clone();
+            }
+        }
+        WELL_KNOWN_TYPES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, ty)| ty.to_string())
+            .unwrap_or_else(|| "any".to_string())
+    }
+
+    fn enum_code(&self, enum_def: &EnumDef, kcl_name: &str) -> String {
+        let mut code = String::new();
+        for line in enum_def.doc.lines() {
+            code.push_str(&format!("# {}\n", line).replace("# \n", "#\n"));
+        }
+        let values: Vec<String> = enum_def
+            .values
+            .iter()
+            .map(|value| format!("\"{}\"", value))
+            .collect();
+        code.push_str(&format!("type {} = {}\n\n", kcl_name, values.join(" | ")));
+        code
+    }
+
+    fn message_code(&self, message: &MessageDef, scope: &[&str], code: &mut String) {
+        let mut path = scope.to_vec();
+        path.push(&message.name);
+        let kcl_name = path.join("_");
+        for enum_def in &message.enums {
+            code.push_str(&self.enum_code(enum_def, &format!("{}_{}", kcl_name, enum_def.name)));
+        }
+        let mut attrs = vec![];
+        for field in &message.fields {
+            let ty = match &field.ty {
+                FieldTy::Named(name) => self.type_str(name, &path),
+                FieldTy::Map(_, value) => format!("{{str:{}}}", self.type_str(value, &path)),
+            };
+            let ty = if field.repeated {
+                format!("[{}]", ty)
+            } else {
+                ty
+            };
+            attrs.push((attr_name(&field.name), ty, field));
+        }
+        code.push_str(&format!("schema {}:\n", kcl_name));
+        let documented = attrs.iter().any(|(_, _, field)| !field.doc.is_empty());
+        if !message.doc.is_empty() || documented {
+            let mut doc = message.doc.clone();
+            if documented {
+                if !doc.is_empty() {
+                    doc.push_str("\n\n");
+                }
+                doc.push_str("Attributes\n----------");
+                for (name, ty, field) in &attrs {
+                    doc.push_str(&format!(
+                        "\n{} : {}, {}",
+                        name,
+                        ty,
+                        if field.required {
+                            "required"
+                        } else {
+                            "optional"
+                        }
+                    ));
+                    for line in field.doc.lines() {
+                        doc.push_str(&format!("\n    {}", line));
+                    }
+                }
+            }
+            code.push_str(&docstring(&doc));
+        }
+        for (name, ty, field) in &attrs {
+            code.push_str(&format!(
+                "    {}{}: {}\n",
+                name,
+                if field.required { "" } else { "?" },
+                ty
+            ));
+        }
+        if attrs.is_empty() {
+            code.push_str("    pass\n");
+        }
+        let oneofs: Vec<&(String, Vec<String>)> = message
+            .oneofs
+            .iter()
+            .filter(|(_, fields)| fields.len() > 1)
+            .collect();
+        if !oneofs.is_empty() {
+            code.push_str("\n    check:\n");
+            for (name, fields) in oneofs {
+                let fields: Vec<String> = fields.iter().map(|f| attr_name(f)).collect();
+                code.push_str(&format!(
+                    "        len([_v for _v in [{}] if _v != None and _v != Undefined]) <= 1, \"only one of the oneof '{}' fields {} can be set\"\n",
+                    fields.join(", "),
+                    name,
+                    fields.join(", ")
+                ));
+            }
+        }
+        code.push('\n');
+        for nested in &message.messages {
+            self.message_code(nested, &path, code);
+        }
+    }
+}
+
+/// Escape the keyword attribute names with `$`.
+fn attr_name(name: &str) -> String {
+    if is_reserved_word(name) {
+        format!("${}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn docstring(doc: &str) -> String {
+    let doc = doc.replace("\"\"\"", "'''");
+    let raw = if doc.contains('\\') || doc.contains("${") {
+        "r"
+    } else {
+        ""
+    };
+    let mut code = format!("    {}\"\"\"", raw);
+    for (i, line) in doc.lines().enumerate() {
+        if i > 0 {
+            code.push('\n');
+            if !line.is_empty() {
+                code.push_str("    ");
+            }
+        }
+        code.push_str(line);
+    }
+    if doc.contains('\n') {
+        code.push_str("\n    ");
+    }
+    code.push_str("\"\"\"\n");
+    code
+}
@@ -0,0 +1,219 @@
+use kclvm_ast::ast::{Expr, Stmt};
+
+use super::{import_data, ImportFormat, ImportOptions};
+
+const DEPLOYMENT: &str = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: nginx
+  labels:
+    app.kubernetes.io/name: nginx
+spec:
+  replicas: 3
+  template:
+    spec:
+      containers:
+        - name: nginx
+          image: "nginx:${VERSION}"
+          ports:
+            - containerPort: 80
+        - name: sidecar
+          image: busybox
+"#;
+
+#[test]
+fn test_import_yaml_as_top_level_attributes() {
+    let code = import_data(DEPLOYMENT, ImportFormat::Yaml, &ImportOptions::default()).unwrap();
+    assert!(code.starts_with("apiVersion = \"apps/v1\"\nkind = \"Deployment\"\nmetadata = {\n"));
+    assert!(code.contains("    name = \"nginx\"\n"));
+    assert!(code.contains("\"app.kubernetes.io/name\" = \"nginx\""));
+    assert!(code.contains("replicas = 3"));
+    assert!(code.contains(r#"image = r"""nginx:${VERSION}""""#));
+    assert!(code.contains("ports = [{containerPort = 80}]"));
+}
+
+#[test]
+fn test_import_yaml_stream() {
+    let data = "a: 1\n---\na: 2\n---\n";
+    let code = import_data(data, ImportFormat::Yaml, &ImportOptions::default()).unwrap();
+    assert_eq!(code, "items = [\n    {a = 1}\n    {a = 2}\n]\n");
+}
+
+#[test]
+fn test_import_json_and_toml() {
+    let opts = ImportOptions {
+        var_name: Some("config".to_string()),
+        ..Default::default()
+    };
+    let code = import_data(
+        r#"{"name": "app", "tags": ["a", "b"]}"#,
+        ImportFormat::Json,
+        &opts,
+    )
+    .unwrap();
+    assert_eq!(
+        code,
+        "config = {\n    name = \"app\"\n    tags = [\"a\", \"b\"]\n}\n"
+    );
+    let code = import_data("name = \"app\"\n", ImportFormat::Toml, &opts).unwrap();
+    assert_eq!(code, "config = {name = \"app\"}\n");
+}
+
+#[test]
+fn test_import_with_schema() {
+    let opts = ImportOptions {
+        schema: Some("Deployment".to_string()),
+        infer_schema: true,
+        ..Default::default()
+    };
+    let code = import_data(DEPLOYMENT, ImportFormat::Yaml, &opts).unwrap();
+    assert!(code.contains("schema Deployment:\n    apiVersion: str\n    kind: str\n    metadata: Metadata\n    spec: Spec\n"));
+    assert!(code.contains("schema Metadata:\n    name: str\n    labels: {str:str}\n"));
+    // `ports` is only present in the first container.
+    assert!(code.contains("schema Container:\n    name: str\n    image: str\n    ports?: [Port]\n"));
+    assert!(code.contains("deployment = Deployment {\n"));
+
+    let opts = ImportOptions {
+        infer_schema: true,
+        ..Default::default()
+    };
+    assert!(import_data(DEPLOYMENT, ImportFormat::Yaml, &opts).is_err());
+}
+
+#[test]
+fn test_import_format_from_path() {
+    assert_eq!(
+        ImportFormat::from_path("deploy.yml").unwrap(),
+        ImportFormat::Yaml
+    );
+    assert_eq!(
+        ImportFormat::from_path("config.toml").unwrap(),
+        ImportFormat::Toml
+    );
+    assert_eq!(
+        ImportFormat::from_path("service.proto").unwrap(),
+        ImportFormat::Proto
+    );
+    assert!(ImportFormat::from_path("config.xml").is_err());
+}
+
+const SERVICE_PROTO: &str = r#"
+syntax = "proto3";
+
+package app.v1;
+
+import "google/protobuf/duration.proto";
+
+// Protocol of the port.
+enum Protocol {
+  TCP = 0;
+  UDP = 1;
+}
+
+// Service is an application service.
+message Service {
+  string name = 1; // The service name.
+  repeated Port ports = 2;
+  map<string, string> labels = 3;
+  google.protobuf.Duration timeout = 4 [deprecated = true];
+  oneof backend {
+    string image = 5;
+    Build build = 6;
+  }
+
+  message Port {
+    int32 number = 1;
+    app.v1.Protocol protocol = 2;
+  }
+
+  message Build {
+    string context = 1;
+    Mode mode = 2;
+    enum Mode {
+      FAST = 0;
+      SAFE = 1;
+    }
+  }
+}
+"#;
+
+#[test]
+fn test_import_proto() {
+    let code = import_data(
+        SERVICE_PROTO,
+        ImportFormat::Proto,
+        &ImportOptions::default(),
+    )
+    .unwrap();
+    assert!(code.contains("# Protocol of the port.\ntype Protocol = \"TCP\" | \"UDP\"\n"));
+    assert!(code.contains("schema Service:\n"));
+    assert!(code.contains("    name : str, optional\n        The service name.\n"));
+    assert!(code.contains("    name?: str\n    ports?: [Service_Port]\n    labels?: {str:str}\n    timeout?: str\n    image?: str\n    build?: Service_Build\n"));
+    assert!(code.contains("only one of the oneof 'backend' fields image, build can be set"));
+    // `protocol` is a keyword, so the attribute name is escaped.
+    assert!(code.contains("schema Service_Port:\n    number?: int\n    $protocol?: Protocol\n"));
+    assert!(code.contains("type Service_Build_Mode = \"FAST\" | \"SAFE\"\n"));
+    assert!(code.contains("    mode?: Service_Build_Mode\n"));
+}
+
+#[test]
+fn test_import_private_keys() {
+    // Keys with the leading `_` are quoted since the identifiers are private.
+    let data = "_internal: 1\nname: app\n";
+    let code = import_data(data, ImportFormat::Yaml, &ImportOptions::default()).unwrap();
+    assert_eq!(
+        code,
+        "items = {\n    \"_internal\" = 1\n    name = \"app\"\n}\n"
+    );
+    let opts = ImportOptions {
+        schema: Some("App".to_string()),
+        infer_schema: true,
+        ..Default::default()
+    };
+    let code = import_data(data, ImportFormat::Yaml, &opts).unwrap();
+    assert!(!code.contains("schema App"));
+}
+
+#[test]
+fn test_import_interpolation_escape() {
+    let opts = ImportOptions {
+        var_name: Some("config".to_string()),
+        ..Default::default()
+    };
+    // The raw string can't end with the quote, so the `$` is escaped.
+    let code = import_data(
+        r#"{"cmd": "echo $$ ${HOME} \"a\""}"#,
+        ImportFormat::Json,
+        &opts,
+    )
+    .unwrap();
+    assert_eq!(
+        code,
+        "config = {cmd = \"echo $$$$ ${'$'}{HOME} \\\"a\\\"\"}\n"
+    );
+    let module = kclvm_parser::parse_file_force_errors("", Some(code)).unwrap();
+    let joined_string = match &module.body[0].node {
+        Stmt::Assign(assign_stmt) => match &assign_stmt.value.node {
+            Expr::Config(config_expr) => match &config_expr.items[0].node.value.node {
+                Expr::JoinedString(joined_string) => joined_string.clone(),
+                _ => panic!("expect a joined string"),
+            },
+            _ => panic!("expect a config expression"),
+        },
+        _ => panic!("expect an assign statement"),
+    };
+    let value: String = joined_string
+        .values
+        .iter()
+        .map(|value| match &value.node {
+            Expr::StringLit(string_lit) => string_lit.value.clone(),
+            Expr::FormattedValue(formatted_value) => match &formatted_value.value.node {
+                Expr::StringLit(string_lit) => string_lit.value.clone(),
+                _ => panic!("expect a string literal"),
+            },
+            _ => panic!("expect a string literal"),
+        })
+        .collect();
+    assert_eq!(value, "echo $$ ${HOME} \"a\"");
+}
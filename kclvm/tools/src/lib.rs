@@ -1,6 +1,9 @@
+pub mod doc;
 pub mod fix;
 pub mod format;
+pub mod import;
 pub mod lint;
 pub mod testing;
+pub mod typegen;
 pub mod util;
 pub mod vet;
@@ -8,7 +8,13 @@
 //! [kclvm_runner::Artifact], which is regard as a new compilation entry point. Then,
 //! it executes each test case separately and collects information about the test cases,
 //! such as the execution time and whether the test passes or fails.
-pub use crate::testing::suite::{load_test_suites, TestSuite};
+//!
+//! Besides, the examples in the docstrings of the schemas and lambdas in the normal files
+//! are regarded as doctests named with the prefix "doctest_". Every doctest is compiled
+//! and executed with the normal files of the package it documents.
+pub use crate::testing::suite::{
+    load_test_suites, DocTest, TestSuite, DOCTEST_PREFIX, TEST_FILE_SUFFIX, TEST_SUITE_PREFIX,
+};
 use anyhow::{Error, Result};
 use indexmap::IndexMap;
 use kclvm_runner::ExecProgramArgs;
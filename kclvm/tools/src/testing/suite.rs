@@ -7,7 +7,8 @@ use kclvm_ast::ast;
 use kclvm_driver::{get_kcl_files, get_pkg_list};
 use kclvm_parser::{parse_file_force_errors, ParseSession};
 use kclvm_runner::runner::ProgramRunner;
-use kclvm_runner::{build_program, ExecProgramArgs};
+use kclvm_runner::{build_program, exec_program, ExecProgramArgs};
+use kclvm_sema::resolver::doc::parse_doc_string;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -15,6 +16,11 @@ use std::time::Instant;
 pub const TEST_FILE_SUFFIX: &str = "_test.k";
 /// Prefix for test suite names.
 pub const TEST_SUITE_PREFIX: &str = "test_";
+/// Prefix for doctest names, e.g., `doctest_Person` for the examples in the
+/// docstring of the schema `Person`.
+pub const DOCTEST_PREFIX: &str = "doctest_";
+
+const DOCTEST_MAIN_FILE: &str = "_kcl_doctest.k";
 
 const TEST_MAIN_FILE: &str = "_kcl_test.k";
 const TEST_CASE_RUN_OPTION: &str = "_kcl_test_case_run";
@@ -34,6 +40,9 @@ pub struct TestSuite {
     pub test_files: Vec<String>,
     // Map of test cases in the test suite.
     pub cases: IndexMap<String, TestCase>,
+    // Map of the doctests extracted from the docstring examples of the schemas
+    // and lambdas in the normal files.
+    pub doctests: IndexMap<String, DocTest>,
     // Flag indicating whether the test suite should be skipped.
     pub skip: bool,
 }
@@ -46,9 +55,21 @@ impl TestRun for TestSuite {
     fn run(&self, opts: &Self::Options) -> Result<Self::Result> {
         let mut result = TestResult::default();
         // Skip test suite if marked as skipped or if there are no test cases.
-        if self.skip || self.cases.is_empty() {
+        if self.skip || (self.cases.is_empty() && self.doctests.is_empty()) {
+            return Ok(result);
+        }
+        if !self.cases.is_empty() && self.run_cases(opts, &mut result)? {
             return Ok(result);
         }
+        self.run_doctests(opts, &mut result)?;
+        Ok(result)
+    }
+}
+
+impl TestSuite {
+    /// Run the test cases in the test files and returns whether the run should
+    /// stop because of the fail_fast option.
+    fn run_cases(&self, opts: &TestOptions, result: &mut TestResult) -> Result<bool> {
         // Generate the test main entry file.
         let main_file = self.gen_test_main_file()?;
         // Set up execution arguments.
@@ -61,6 +82,7 @@ impl TestRun for TestSuite {
         // Build the program.
         let artifact = build_program::<String>(Arc::new(ParseSession::default()), &args, None)?;
         // Test every case in the suite.
+        let mut stopped = false;
         for (name, _) in &self.cases {
             args.args = vec![ast::CmdArgSpec {
                 name: TEST_CASE_RUN_OPTION.into(),
@@ -86,6 +108,7 @@ impl TestRun for TestSuite {
                 },
             );
             if fail_fast {
+                stopped = true;
                 break;
             }
         }
@@ -93,11 +116,58 @@ impl TestRun for TestSuite {
         if opts.exec_args.debug == 0 {
             remove_file(main_file)?;
         }
-        Ok(result)
+        Ok(stopped)
+    }
+
+    /// Compile and execute every doctest against the normal files of the package,
+    /// compilation errors are reported as doctest failures.
+    fn run_doctests(&self, opts: &TestOptions, result: &mut TestResult) -> Result<()> {
+        let main_file = Path::new(&self.pkg).join(DOCTEST_MAIN_FILE);
+        let main_file = main_file
+            .to_str()
+            .ok_or(anyhow!("{} is not found", DOCTEST_MAIN_FILE))?;
+        let mut k_filename_list = self.normal_files.clone();
+        k_filename_list.push(main_file.into());
+        let args = ExecProgramArgs {
+            k_filename_list,
+            overrides: vec![],
+            disable_yaml_result: true,
+            ..opts.exec_args.clone()
+        };
+        for (name, doctest) in &self.doctests {
+            std::fs::write(main_file, &doctest.code)?;
+            let start = Instant::now();
+            let (log_message, error) = match exec_program(Arc::new(ParseSession::default()), &args)
+            {
+                Ok(exec_result) if exec_result.err_message.is_empty() => {
+                    (exec_result.log_message, None)
+                }
+                Ok(exec_result) => (
+                    exec_result.log_message,
+                    Some(anyhow!("{}", exec_result.err_message)),
+                ),
+                Err(err) => (String::new(), Some(err)),
+            };
+            let fail_fast = error.is_some() && opts.fail_fast;
+            result.info.insert(
+                name.clone(),
+                TestCaseInfo {
+                    log_message,
+                    duration: Instant::now() - start,
+                    error,
+                },
+            );
+            if fail_fast {
+                break;
+            }
+        }
+        // Remove the temp doctest main file
+        if opts.exec_args.debug == 0 && Path::new(main_file).exists() {
+            remove_file(main_file)?;
+        }
+        Ok(())
     }
-}
 
-impl TestSuite {
     fn gen_test_main_file(&self) -> Result<String> {
         let test_codes = self
             .cases
@@ -127,6 +197,12 @@ impl TestSuite {
 
 pub struct TestCase;
 
+/// A doctest is the example code in a schema or lambda docstring.
+pub struct DocTest {
+    /// The KCL code of the example.
+    pub code: String,
+}
+
 /// Load test suite from path
 pub fn load_test_suites<P: AsRef<str>>(path: P, opts: &TestOptions) -> Result<Vec<TestSuite>> {
     let pkg_list = get_pkg_list(path.as_ref())?;
@@ -150,9 +226,38 @@ pub fn load_test_suites<P: AsRef<str>>(path: P, opts: &TestOptions) -> Result<Ve
                 }
             }
         }
+        let mut doctests = IndexMap::new();
+        for file in &normal_files {
+            let module = parse_file_force_errors(file, None)?;
+            for (owner, doc) in docstrings(&module) {
+                let name = format!("{}{}", DOCTEST_PREFIX, owner);
+                if !should_run(&opts.run_regexp, &name) {
+                    continue;
+                }
+                let mut examples: Vec<_> = parse_doc_string(&doc).examples.into_iter().collect();
+                examples.sort_by(|a, b| a.0.cmp(&b.0));
+                for (i, (_, example)) in examples.into_iter().enumerate() {
+                    if example.value.trim().is_empty() {
+                        continue;
+                    }
+                    let name = if i == 0 {
+                        name.clone()
+                    } else {
+                        format!("{}_{}", name, i)
+                    };
+                    doctests.insert(
+                        name,
+                        DocTest {
+                            code: example.value,
+                        },
+                    );
+                }
+            }
+        }
         suites.push(TestSuite {
             pkg: pkg.clone(),
             cases,
+            doctests,
             normal_files,
             test_files,
             skip: false,
@@ -161,6 +266,44 @@ pub fn load_test_suites<P: AsRef<str>>(path: P, opts: &TestOptions) -> Result<Ve
     Ok(suites)
 }
 
+/// Returns the docstrings of the schemas and lambdas in the module with the
+/// schema or lambda names.
+fn docstrings(module: &ast::Module) -> Vec<(String, String)> {
+    let mut docs = vec![];
+    for stmt in &module.body {
+        match &stmt.node {
+            ast::Stmt::Schema(schema_stmt) => {
+                if let Some(doc) = &schema_stmt.doc {
+                    docs.push((schema_stmt.name.node.clone(), doc.node.clone()));
+                }
+            }
+            ast::Stmt::Assign(assign_stmt) => {
+                if let ast::Expr::Lambda(lambda_expr) = &assign_stmt.value.node {
+                    // The lambda docstring is the leading string literal in the lambda body.
+                    let doc = lambda_expr.body.first().and_then(|stmt| match &stmt.node {
+                        ast::Stmt::Expr(expr_stmt) if expr_stmt.exprs.len() == 1 => {
+                            match &expr_stmt.exprs[0].node {
+                                ast::Expr::StringLit(string_lit) => {
+                                    Some(string_lit.raw_value.clone())
+                                }
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    });
+                    if let Some(doc) = doc {
+                        for target in &assign_stmt.targets {
+                            docs.push((target.node.get_name(), doc.clone()));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    docs
+}
+
 #[inline]
 fn get_test_files<P: AsRef<Path>>(pkg: P) -> Result<(Vec<String>, Vec<String>)> {
     let files = get_kcl_files(pkg, false)?;
@@ -30,3 +30,31 @@ fn test_load_test_suites_and_run() {
         .to_string()
         .contains("Error"),);
 }
+
+#[test]
+fn test_load_doctests_and_run() {
+    let opts = TestOptions::default();
+    let suites = load_test_suites(
+        Path::new(".")
+            .join("src")
+            .join("testing")
+            .join("test_data")
+            .join("doctest")
+            .join("pkg")
+            .to_str()
+            .unwrap(),
+        &opts,
+    )
+    .unwrap();
+    assert_eq!(suites.len(), 1);
+    assert!(suites[0].cases.is_empty());
+    assert_eq!(
+        suites[0].doctests.keys().collect::<Vec<&String>>(),
+        vec!["doctest_Person", "doctest_add", "doctest_sub"]
+    );
+    let test_result = suites[0].run(&opts).unwrap();
+    assert_eq!(test_result.info.len(), 3);
+    assert!(test_result.info["doctest_Person"].error.is_none());
+    assert!(test_result.info["doctest_add"].error.is_none());
+    assert!(test_result.info["doctest_sub"].error.is_some());
+}
@@ -0,0 +1,85 @@
+//! Generate Go structs with `encoding/json` tags.
+use super::{doc_comment, pascal_case, FieldType, Literal, TypeDef, GENERATED_HEADER};
+
+pub(crate) fn gen(defs: &[TypeDef], package: &str) -> String {
+    let mut code = format!("// {}\n\npackage {}\n", GENERATED_HEADER, package);
+    for def in defs {
+        code.push('\n');
+        code.push_str(&doc_comment(&def.doc, "// ", ""));
+        code.push_str(&format!("type {} struct {{\n", def.name));
+        let fields: Vec<(String, String, String)> = def
+            .fields
+            .iter()
+            .map(|field| {
+                let mut ty = go_type(&field.ty);
+                let mut tag = field.name.clone();
+                if field.optional {
+                    if needs_pointer(&field.ty) {
+                        ty = format!("*{}", ty);
+                    }
+                    tag.push_str(",omitempty");
+                }
+                (field_ident(&field.name), ty, format!("`json:{:?}`", tag))
+            })
+            .collect();
+        // Align the field types and tags like gofmt.
+        let name_width = fields.iter().map(|f| f.0.len()).max().unwrap_or(0);
+        let ty_width = fields.iter().map(|f| f.1.len()).max().unwrap_or(0);
+        for (field, (ident, ty, tag)) in def.fields.iter().zip(fields.iter()) {
+            code.push_str(&doc_comment(&field.doc, "// ", "\t"));
+            if let Some(values) = field.ty.str_literals() {
+                let values: Vec<String> = values.iter().map(|v| format!("{:?}", v)).collect();
+                code.push_str(&format!("\t// One of {}.\n", values.join(", ")));
+            }
+            code.push_str(&format!(
+                "\t{:name_width$} {:ty_width$} {}\n",
+                ident,
+                ty,
+                tag,
+                name_width = name_width,
+                ty_width = ty_width
+            ));
+        }
+        code.push_str("}\n");
+    }
+    code
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = pascal_case(name);
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("X{}", ident)
+    } else {
+        ident
+    }
+}
+
+/// Optional scalars and structs are pointers to distinguish the zero values from
+/// absent values, slices, maps and interfaces are nil when absent.
+fn needs_pointer(ty: &FieldType) -> bool {
+    let ty = go_type(ty);
+    !(ty == "interface{}" || ty.starts_with("[]") || ty.starts_with("map["))
+}
+
+fn go_type(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Any => "interface{}".to_string(),
+        FieldType::Bool | FieldType::Literal(Literal::Bool(_)) => "bool".to_string(),
+        FieldType::Int | FieldType::Literal(Literal::Int(_)) => "int64".to_string(),
+        FieldType::Float | FieldType::Literal(Literal::Float(_)) => "float64".to_string(),
+        FieldType::Str | FieldType::Literal(Literal::Str(_)) => "string".to_string(),
+        FieldType::List(item) => format!("[]{}", go_type(item)),
+        FieldType::Dict(value) => format!("map[string]{}", go_type(value)),
+        FieldType::Schema(name) => name.clone(),
+        FieldType::Union(types) => {
+            // String literal unions and unions of the same Go type, e.g. int
+            // literals, keep the type.
+            let types: Vec<String> = types.iter().map(go_type).collect();
+            if types.iter().all(|t| t == &types[0]) {
+                types[0].clone()
+            } else {
+                "interface{}".to_string()
+            }
+        }
+    }
+}
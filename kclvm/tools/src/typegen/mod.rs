@@ -0,0 +1,391 @@
+//! [kclvm_tools::typegen] module generates the type definitions of other languages
+//! from KCL schemas, so that the services consuming the KCL output can deserialize
+//! it into typed values. Supported languages are Rust (serde), Go (encoding/json),
+//! TypeScript and Python (dataclasses or pydantic).
+//!
+//! Schema types are converted into the language independent [TypeDef]s first. The
+//! attributes of the base schemas and mixins are flattened into every definition,
+//! and the schemas referenced by the attribute types are generated as well.
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use kclvm_parser::LoadProgramOptions;
+use kclvm_query::query::{get_full_schema_type, CompilationOptions};
+use kclvm_query::GetSchemaOption;
+use kclvm_sema::ty::{DictType, SchemaType, Type, TypeKind};
+
+mod go;
+mod python;
+mod rust;
+mod typescript;
+
+#[cfg(test)]
+mod tests;
+
+/// The header comment of the generated code.
+const GENERATED_HEADER: &str = "Code generated by the KCL type generator. DO NOT EDIT.";
+
+/// The target language of the type generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Go,
+    TypeScript,
+    /// Python dataclasses.
+    Python,
+    /// Python pydantic models.
+    Pydantic,
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "rust" | "rs" => Ok(Language::Rust),
+            "go" | "golang" => Ok(Language::Go),
+            "typescript" | "ts" => Ok(Language::TypeScript),
+            "python" | "py" => Ok(Language::Python),
+            "pydantic" => Ok(Language::Pydantic),
+            _ => Err(anyhow!(
+                "unsupported language '{}', expected rust, go, typescript, python or pydantic",
+                s
+            )),
+        }
+    }
+}
+
+/// Represents options for generating type definitions.
+#[derive(Debug, Clone)]
+pub struct GenTypesOptions {
+    pub lang: Language,
+    /// The package name of the generated Go code.
+    pub go_package: String,
+}
+
+impl Default for GenTypesOptions {
+    fn default() -> Self {
+        Self {
+            lang: Language::Rust,
+            go_package: "types".to_string(),
+        }
+    }
+}
+
+/// A language independent type definition of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDef {
+    pub name: String,
+    pub doc: String,
+    pub fields: Vec<FieldDef>,
+}
+
+/// A field of the type definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDef {
+    pub name: String,
+    pub ty: FieldType,
+    /// Whether the field may be absent or null in the KCL output.
+    pub optional: bool,
+    pub doc: String,
+}
+
+/// The language independent field type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Any,
+    Bool,
+    Int,
+    Float,
+    Str,
+    Literal(Literal),
+    List(Box<FieldType>),
+    /// The dict type with string keys.
+    Dict(Box<FieldType>),
+    Schema(String),
+    Union(Vec<FieldType>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl FieldType {
+    /// Returns the string literal values if the type is a union of string literals.
+    pub(crate) fn str_literals(&self) -> Option<Vec<&str>> {
+        match self {
+            FieldType::Union(types) => types
+                .iter()
+                .map(|ty| match ty {
+                    FieldType::Literal(Literal::Str(value)) => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Generate the type definitions of the schemas defined in the files, all the
+/// schemas are generated when the schema name is not specified.
+pub fn gen_types_from_files(
+    files: &[&str],
+    load_opts: Option<LoadProgramOptions>,
+    schema_name: Option<&str>,
+    opts: &GenTypesOptions,
+) -> Result<String> {
+    let schemas = get_full_schema_type(
+        schema_name,
+        CompilationOptions {
+            k_files: files.iter().map(|f| f.to_string()).collect(),
+            loader_opts: load_opts,
+            get_schema_opts: GetSchemaOption::Definitions,
+            ..Default::default()
+        },
+    )?;
+    let schemas: Vec<SchemaType> = schemas.into_values().collect();
+    Ok(gen_types(&schemas, opts))
+}
+
+/// Generate the type definitions of the schemas and the schemas they reference.
+pub fn gen_types(schemas: &[SchemaType], opts: &GenTypesOptions) -> String {
+    let defs = type_defs(schemas);
+    match opts.lang {
+        Language::Rust => rust::gen(&defs),
+        Language::Go => go::gen(&defs, &opts.go_package),
+        Language::TypeScript => typescript::gen(&defs),
+        Language::Python => python::gen(&defs, false),
+        Language::Pydantic => python::gen(&defs, true),
+    }
+}
+
+/// Convert the schemas into type definitions. Referenced schemas are placed
+/// before the schemas referencing them. Mixins and protocols are not generated.
+///
+/// Schemas with the same name in different packages are prefixed with their package
+/// paths in `PascalCase`, e.g., `Config` in the package `app.base` is named `AppBaseConfig`.
+pub fn type_defs(schemas: &[SchemaType]) -> Vec<TypeDef> {
+    let mut defs = IndexMap::new();
+    for schema in schemas {
+        if !schema.is_mixin && !schema.is_protocol {
+            collect_type_def(schema, &mut defs);
+        }
+    }
+    // Definitions and references are named with the schema keys until all the
+    // schemas are collected.
+    let mut name_count: IndexMap<String, usize> = IndexMap::new();
+    for key in defs.keys() {
+        *name_count
+            .entry(schema_name(key).1.to_string())
+            .or_default() += 1;
+    }
+    let names: IndexMap<String, String> = defs
+        .keys()
+        .map(|key| {
+            let (pkgpath, name) = schema_name(key);
+            if name_count[name] > 1 {
+                (key.clone(), format!("{}{}", pascal_case(pkgpath), name))
+            } else {
+                (key.clone(), name.to_string())
+            }
+        })
+        .collect();
+    defs.into_values()
+        .flatten()
+        .map(|mut def| {
+            def.name = names[&def.name].clone();
+            for field in def.fields.iter_mut() {
+                rename_schema_refs(&mut field.ty, &names);
+            }
+            def
+        })
+        .collect()
+}
+
+/// Returns the package path and the schema name of the schema key.
+fn schema_name(key: &str) -> (&str, &str) {
+    key.rsplit_once('.').unwrap_or(("", key))
+}
+
+fn rename_schema_refs(ty: &mut FieldType, names: &IndexMap<String, String>) {
+    match ty {
+        FieldType::Schema(name) => {
+            if let Some(new_name) = names.get(name) {
+                *name = new_name.clone();
+            }
+        }
+        FieldType::List(item_ty) | FieldType::Dict(item_ty) => rename_schema_refs(item_ty, names),
+        FieldType::Union(types) => types
+            .iter_mut()
+            .for_each(|ty| rename_schema_refs(ty, names)),
+        _ => {}
+    }
+}
+
+fn schema_key(schema: &SchemaType) -> String {
+    format!("{}.{}", schema.pkgpath, schema.name)
+}
+
+fn collect_type_def(schema: &SchemaType, defs: &mut IndexMap<String, Option<TypeDef>>) {
+    let key = schema_key(schema);
+    if defs.contains_key(&key) {
+        return;
+    }
+    // Reserve the key to stop the recursion of self-referencing schemas.
+    defs.insert(key.clone(), None);
+    let mut attrs = IndexMap::new();
+    collect_attrs(schema, &mut attrs);
+    let mut fields = vec![];
+    for (name, attr) in attrs {
+        let (ty, nullable) = field_type(&attr.ty, defs);
+        fields.push(FieldDef {
+            name,
+            ty,
+            optional: attr.is_optional || nullable,
+            doc: attr.doc.clone().unwrap_or_default(),
+        });
+    }
+    let def = TypeDef {
+        name: key.clone(),
+        doc: schema.doc.clone(),
+        fields,
+    };
+    // Move the definition after its dependencies.
+    defs.shift_remove(&key);
+    defs.insert(key, Some(def));
+}
+
+/// Collect the attributes of the schema, its base schemas and mixins.
+fn collect_attrs(schema: &SchemaType, attrs: &mut IndexMap<String, kclvm_sema::ty::SchemaAttr>) {
+    if let Some(base) = &schema.base {
+        collect_attrs(base, attrs);
+    }
+    for mixin in &schema.mixins {
+        collect_attrs(mixin, attrs);
+    }
+    for (name, attr) in &schema.attrs {
+        // Private and internal attributes are not in the output.
+        if !name.starts_with('_') {
+            attrs.insert(name.clone(), attr.clone());
+        }
+    }
+}
+
+/// Convert the KCL type into the field type, and returns whether the type
+/// is nullable.
+fn field_type(ty: &Type, defs: &mut IndexMap<String, Option<TypeDef>>) -> (FieldType, bool) {
+    match &ty.kind {
+        TypeKind::None => (FieldType::Any, true),
+        TypeKind::Bool => (FieldType::Bool, false),
+        TypeKind::BoolLit(v) => (FieldType::Literal(Literal::Bool(*v)), false),
+        TypeKind::Int => (FieldType::Int, false),
+        TypeKind::IntLit(v) => (FieldType::Literal(Literal::Int(*v)), false),
+        TypeKind::Float => (FieldType::Float, false),
+        TypeKind::FloatLit(v) => (FieldType::Literal(Literal::Float(*v)), false),
+        // Decimals are encoded as strings to keep the exact values.
+        TypeKind::Decimal => (FieldType::Str, false),
+        TypeKind::Str => (FieldType::Str, false),
+        TypeKind::StrLit(v) => (FieldType::Literal(Literal::Str(v.clone())), false),
+        TypeKind::NumberMultiplier(_) => (FieldType::Str, false),
+        TypeKind::List(item_ty) => (
+            FieldType::List(Box::new(field_type(item_ty, defs).0)),
+            false,
+        ),
+        TypeKind::Dict(DictType { val_ty, .. }) => {
+            (FieldType::Dict(Box::new(field_type(val_ty, defs).0)), false)
+        }
+        TypeKind::Union(types) => {
+            let mut nullable = false;
+            let mut union = vec![];
+            for ty in types {
+                if ty.is_none() {
+                    nullable = true;
+                    continue;
+                }
+                let (ty, ty_nullable) = field_type(ty, defs);
+                nullable |= ty_nullable;
+                // Flatten the nested union types.
+                let tys = match ty {
+                    FieldType::Union(tys) => tys,
+                    ty => vec![ty],
+                };
+                for ty in tys {
+                    if !union.contains(&ty) {
+                        union.push(ty);
+                    }
+                }
+            }
+            match union.len() {
+                0 => (FieldType::Any, true),
+                1 => (union.remove(0), nullable),
+                _ => (FieldType::Union(union), nullable),
+            }
+        }
+        TypeKind::Schema(schema_ty) if !schema_ty.is_mixin && !schema_ty.is_protocol => {
+            collect_type_def(schema_ty, defs);
+            (FieldType::Schema(schema_key(schema_ty)), false)
+        }
+        _ => (FieldType::Any, false),
+    }
+}
+
+/// Convert the name into `PascalCase`.
+pub(crate) fn pascal_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if upper {
+                out.extend(c.to_uppercase());
+                upper = false;
+            } else {
+                out.push(c);
+            }
+        } else {
+            upper = true;
+        }
+    }
+    out
+}
+
+/// Convert the name into `snake_case`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let chars: Vec<char> = name.chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev_lower =
+                i > 0 && (chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit());
+            let next_lower = i + 1 < chars.len() && chars[i + 1].is_ascii_lowercase();
+            let prev_upper = i > 0 && chars[i - 1].is_ascii_uppercase();
+            if !out.is_empty() && !out.ends_with('_') && (prev_lower || (prev_upper && next_lower))
+            {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else if c.is_ascii_alphanumeric() || *c == '_' {
+            out.push(*c);
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    out
+}
+
+/// Write the doc string as line comments with the prefix.
+pub(crate) fn doc_comment(doc: &str, prefix: &str, indent: &str) -> String {
+    doc.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                format!("{}{}\n", indent, prefix.trim_end())
+            } else {
+                format!("{}{}{}\n", indent, prefix, line)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+//! Generate Python dataclasses or pydantic models.
+use super::{snake_case, FieldDef, FieldType, Literal, TypeDef, GENERATED_HEADER};
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub(crate) fn gen(defs: &[TypeDef], pydantic: bool) -> String {
+    let mut code = format!(
+        "# {}\n\nfrom __future__ import annotations\n\n{}from typing import Any, Dict, List, Literal, Optional, Union\n",
+        GENERATED_HEADER,
+        if pydantic {
+            ""
+        } else {
+            "from dataclasses import dataclass, field\n"
+        }
+    );
+    if pydantic {
+        code.push_str("\nfrom pydantic import BaseModel, Field\n");
+    }
+    for def in defs {
+        code.push_str("\n\n");
+        if pydantic {
+            code.push_str(&format!("class {}(BaseModel):\n", def.name));
+        } else {
+            code.push_str(&format!("@dataclass\nclass {}:\n", def.name));
+        }
+        let mut body = docstring(&def.doc);
+        // Fields without default values must precede the fields with defaults
+        // in dataclasses.
+        let (required, optional): (Vec<&FieldDef>, Vec<&FieldDef>) =
+            def.fields.iter().partition(|field| !field.optional);
+        for field in required.iter().chain(optional.iter()) {
+            body.push_str(&field_line(field, pydantic));
+            body.push_str(&docstring(&field.doc));
+        }
+        if body.is_empty() {
+            body.push_str("    pass\n");
+        }
+        code.push_str(&body);
+    }
+    code
+}
+
+fn field_line(field: &FieldDef, pydantic: bool) -> String {
+    let ident = field_ident(&field.name);
+    let renamed = ident != field.name;
+    let mut ty = py_type(&field.ty);
+    if field.optional {
+        ty = format!("Optional[{}]", ty);
+    }
+    let default = match (pydantic, renamed, field.optional) {
+        (_, false, false) => String::new(),
+        (_, false, true) => " = None".to_string(),
+        (true, true, false) => format!(" = Field(alias={:?})", field.name),
+        (true, true, true) => format!(" = Field(default=None, alias={:?})", field.name),
+        (false, true, false) => format!(" = field(metadata={{\"name\": {:?}}})", field.name),
+        (false, true, true) => format!(
+            " = field(default=None, metadata={{\"name\": {:?}}})",
+            field.name
+        ),
+    };
+    format!("    {}: {}{}\n", ident, ty, default)
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = snake_case(name);
+    if PYTHON_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else {
+        ident
+    }
+}
+
+fn docstring(doc: &str) -> String {
+    let doc = doc.trim();
+    if doc.is_empty() {
+        return String::new();
+    }
+    let doc = doc.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+    let mut out = String::from("    \"\"\"");
+    for (i, line) in doc.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+            if !line.trim().is_empty() {
+                out.push_str("    ");
+            }
+        }
+        out.push_str(line);
+    }
+    if doc.contains('\n') {
+        out.push_str("\n    ");
+    }
+    out.push_str("\"\"\"\n");
+    out
+}
+
+fn py_type(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Any => "Any".to_string(),
+        FieldType::Bool => "bool".to_string(),
+        FieldType::Int => "int".to_string(),
+        // Float values are not allowed in `Literal`.
+        FieldType::Float | FieldType::Literal(Literal::Float(_)) => "float".to_string(),
+        FieldType::Str => "str".to_string(),
+        FieldType::Literal(Literal::Bool(v)) => {
+            format!("Literal[{}]", if *v { "True" } else { "False" })
+        }
+        FieldType::Literal(Literal::Int(v)) => format!("Literal[{}]", v),
+        FieldType::Literal(Literal::Str(v)) => format!("Literal[{:?}]", v),
+        FieldType::List(item) => format!("List[{}]", py_type(item)),
+        FieldType::Dict(value) => format!("Dict[str, {}]", py_type(value)),
+        FieldType::Schema(name) => name.clone(),
+        FieldType::Union(types) => match ty.str_literals() {
+            Some(values) => {
+                let values: Vec<String> = values.iter().map(|v| format!("{:?}", v)).collect();
+                format!("Literal[{}]", values.join(", "))
+            }
+            None => {
+                let types: Vec<String> = types.iter().map(py_type).collect();
+                format!("Union[{}]", types.join(", "))
+            }
+        },
+    }
+}
@@ -0,0 +1,181 @@
+//! Generate Rust structs with serde attributes.
+use std::collections::HashSet;
+
+use super::{doc_comment, pascal_case, snake_case, FieldType, Literal, TypeDef, GENERATED_HEADER};
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "try", "typeof", "unsized", "virtual", "yield",
+];
+
+const DERIVES: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n";
+
+pub(crate) fn gen(defs: &[TypeDef]) -> String {
+    let mut code = format!(
+        "// {}\n\nuse serde::{{Deserialize, Serialize}};\nuse std::collections::HashMap;\n",
+        GENERATED_HEADER
+    );
+    for def in defs {
+        // Enums generated for the union types of the fields.
+        let mut enums = vec![];
+        let recursive = recursive_schemas(defs, &def.name);
+        code.push('\n');
+        code.push_str(&doc_comment(&def.doc, "/// ", ""));
+        code.push_str(DERIVES);
+        code.push_str(&format!("pub struct {} {{\n", def.name));
+        for field in &def.fields {
+            code.push_str(&doc_comment(&field.doc, "/// ", "    "));
+            let ident = field_ident(&field.name);
+            if ident.trim_start_matches("r#") != field.name {
+                code.push_str(&format!("    #[serde(rename = {:?})]\n", field.name));
+            }
+            let enum_name = format!("{}{}", def.name, pascal_case(&field.name));
+            let mut ty = rust_type(&field.ty, &enum_name, &recursive, &mut enums);
+            if field.optional {
+                code.push_str("    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n");
+                ty = format!("Option<{}>", ty);
+            }
+            code.push_str(&format!("    pub {}: {},\n", ident, ty));
+        }
+        code.push_str("}\n");
+        for enum_code in enums {
+            code.push('\n');
+            code.push_str(&enum_code);
+        }
+    }
+    code
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = snake_case(name);
+    if RUST_KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{}", ident)
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else {
+        ident
+    }
+}
+
+/// Returns the schemas which reach the schema `name` through the fields not behind
+/// a `Vec` or a `HashMap`, including the schema itself. The fields of these schema
+/// types are boxed, or the recursive structs would have infinite sizes.
+fn recursive_schemas(defs: &[TypeDef], name: &str) -> HashSet<String> {
+    let mut schemas = HashSet::new();
+    let mut stack = vec![name.to_string()];
+    while let Some(name) = stack.pop() {
+        if schemas.insert(name.clone()) {
+            for def in defs {
+                if def
+                    .fields
+                    .iter()
+                    .any(|field| references_directly(&field.ty, &name))
+                {
+                    stack.push(def.name.clone());
+                }
+            }
+        }
+    }
+    schemas
+}
+
+fn references_directly(ty: &FieldType, name: &str) -> bool {
+    match ty {
+        FieldType::Schema(schema) => schema == name,
+        FieldType::Union(types) => types.iter().any(|ty| references_directly(ty, name)),
+        _ => false,
+    }
+}
+
+/// Returns the Rust type of the field type. Union types are generated as enums
+/// named with the enum name and the enum index, and the `recursive` schemas not
+/// behind a `Vec` or a `HashMap` are boxed.
+fn rust_type(
+    ty: &FieldType,
+    enum_name: &str,
+    recursive: &HashSet<String>,
+    enums: &mut Vec<String>,
+) -> String {
+    match ty {
+        FieldType::Any => "serde_json::Value".to_string(),
+        FieldType::Bool | FieldType::Literal(Literal::Bool(_)) => "bool".to_string(),
+        FieldType::Int | FieldType::Literal(Literal::Int(_)) => "i64".to_string(),
+        FieldType::Float | FieldType::Literal(Literal::Float(_)) => "f64".to_string(),
+        FieldType::Str | FieldType::Literal(Literal::Str(_)) => "String".to_string(),
+        FieldType::List(item) => {
+            format!(
+                "Vec<{}>",
+                rust_type(item, enum_name, &HashSet::new(), enums)
+            )
+        }
+        FieldType::Dict(value) => format!(
+            "HashMap<String, {}>",
+            rust_type(value, enum_name, &HashSet::new(), enums)
+        ),
+        FieldType::Schema(name) if recursive.contains(name) => format!("Box<{}>", name),
+        FieldType::Schema(name) => name.clone(),
+        FieldType::Union(types) => {
+            let name = if enums.is_empty() {
+                enum_name.to_string()
+            } else {
+                format!("{}{}", enum_name, enums.len())
+            };
+            // Reserve the enum index before generating the nested enums.
+            let index = enums.len();
+            enums.push(String::new());
+            let mut code = DERIVES.to_string();
+            match ty.str_literals() {
+                Some(values) => {
+                    code.push_str(&format!("pub enum {} {{\n", name));
+                    for (i, value) in values.iter().enumerate() {
+                        let variant = pascal_case(&snake_case(value));
+                        let variant = if variant.is_empty()
+                            || variant.starts_with(|c: char| c.is_ascii_digit())
+                        {
+                            format!("V{}", i)
+                        } else {
+                            variant
+                        };
+                        code.push_str(&format!(
+                            "    #[serde(rename = {:?})]\n    {},\n",
+                            value, variant
+                        ));
+                    }
+                }
+                None => {
+                    code.push_str(&format!("#[serde(untagged)]\npub enum {} {{\n", name));
+                    let mut variants: Vec<String> = vec![];
+                    for ty in types {
+                        let mut variant = variant_name(ty);
+                        if variants.contains(&variant) {
+                            variant = format!("{}{}", variant, variants.len());
+                        }
+                        let ty = rust_type(ty, enum_name, recursive, enums);
+                        code.push_str(&format!("    {}({}),\n", variant, ty));
+                        variants.push(variant);
+                    }
+                }
+            }
+            code.push_str("}\n");
+            enums[index] = code;
+            name
+        }
+    }
+}
+
+fn variant_name(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Any => "Any".to_string(),
+        FieldType::Bool | FieldType::Literal(Literal::Bool(_)) => "Bool".to_string(),
+        FieldType::Int | FieldType::Literal(Literal::Int(_)) => "Int".to_string(),
+        FieldType::Float | FieldType::Literal(Literal::Float(_)) => "Float".to_string(),
+        FieldType::Str | FieldType::Literal(Literal::Str(_)) => "Str".to_string(),
+        FieldType::List(_) => "List".to_string(),
+        FieldType::Dict(_) => "Dict".to_string(),
+        FieldType::Schema(name) => name.clone(),
+        FieldType::Union(_) => "Union".to_string(),
+    }
+}
@@ -0,0 +1,135 @@
+use std::path::Path;
+
+use super::{gen_types_from_files, FieldType, GenTypesOptions, Language};
+
+fn gen_test_types(lang: Language) -> String {
+    let file = Path::new(".")
+        .join("src")
+        .join("typegen")
+        .join("test_data")
+        .join("app.k")
+        .display()
+        .to_string();
+    let opts = GenTypesOptions {
+        lang,
+        ..Default::default()
+    };
+    gen_types_from_files(&[&file], None, Some("App"), &opts).unwrap()
+}
+
+#[test]
+fn test_gen_rust_types() {
+    let code = gen_test_types(Language::Rust);
+    // Referenced schemas are generated before the schemas referencing them.
+    let metadata = code.find("pub struct Metadata").unwrap();
+    let app = code.find("pub struct App").unwrap();
+    assert!(metadata < app);
+    assert!(
+        code.contains("    #[serde(rename = \"containerPort\")]\n    pub container_port: i64,\n")
+    );
+    assert!(code.contains("    pub protocol: Option<PortProtocol>,\n"));
+    assert!(code.contains("pub enum PortProtocol {\n    #[serde(rename = \"TCP\")]\n    Tcp,\n"));
+    assert!(code.contains(
+        "#[serde(untagged)]\npub enum ContainerEnv {\n    Str(String),\n    Int(i64),\n}"
+    ));
+    assert!(code.contains("    pub env: Option<HashMap<String, ContainerEnv>>,\n"));
+    // Base schema attributes are flattened and `None` unions are optional.
+    assert!(code.contains("    pub metadata: Metadata,\n"));
+    assert!(code.contains("    pub replicas: Option<i64>,\n"));
+    assert!(code.contains("    /// The container name.\n    pub name: String,\n"));
+    assert!(!code.contains("private"));
+}
+
+#[test]
+fn test_gen_go_types() {
+    let code = gen_test_types(Language::Go);
+    assert!(code.contains("package types\n"));
+    assert!(code.contains("\tContainerPort int64   `json:\"containerPort\"`\n"));
+    assert!(code.contains("\tProtocol      *string `json:\"protocol,omitempty\"`\n"));
+    assert!(code.contains("map[string]interface{}"));
+    assert!(code.contains("[]Container"));
+    assert!(code.contains("\tReplicas   *int64"));
+}
+
+#[test]
+fn test_gen_typescript_types() {
+    let code = gen_test_types(Language::TypeScript);
+    assert!(code.contains(
+        "export interface Port {\n  containerPort: number;\n  protocol?: \"TCP\" | \"UDP\";\n}\n"
+    ));
+    assert!(code.contains("  env?: { [key: string]: string | number };\n"));
+    assert!(code.contains("/**\n * Metadata of the resource.\n */\nexport interface Metadata {\n"));
+    assert!(code.contains("  containers: Container[];\n"));
+}
+
+#[test]
+fn test_gen_python_types() {
+    let code = gen_test_types(Language::Python);
+    assert!(code.contains("@dataclass\nclass Port:\n"));
+    assert!(
+        code.contains("    container_port: int = field(metadata={\"name\": \"containerPort\"})\n")
+    );
+    assert!(code.contains("    protocol: Optional[Literal[\"TCP\", \"UDP\"]] = None\n"));
+    // Required fields precede the optional fields.
+    let containers = code.find("    containers: List[Container]\n").unwrap();
+    let replicas = code.find("    replicas: Optional[int] = None\n").unwrap();
+    assert!(containers < replicas);
+
+    let code = gen_test_types(Language::Pydantic);
+    assert!(code.contains("class Port(BaseModel):\n"));
+    assert!(code.contains("    container_port: int = Field(alias=\"containerPort\")\n"));
+    assert!(code.contains("    env: Optional[Dict[str, Union[str, int]]] = None\n"));
+}
+
+#[test]
+fn test_language_from_str() {
+    assert_eq!("ts".parse::<Language>().unwrap(), Language::TypeScript);
+    assert_eq!("Golang".parse::<Language>().unwrap(), Language::Go);
+    assert!("java".parse::<Language>().is_err());
+    assert_eq!(
+        FieldType::Union(vec![FieldType::Str, FieldType::Int]).str_literals(),
+        None
+    );
+}
+
+#[test]
+fn test_gen_rust_recursive_types() {
+    let file = Path::new(".")
+        .join("src")
+        .join("typegen")
+        .join("test_data")
+        .join("recursive.k")
+        .display()
+        .to_string();
+    let opts = GenTypesOptions::default();
+    // Self-referencing schema fields are boxed.
+    let code = gen_types_from_files(&[&file], None, Some("Node"), &opts).unwrap();
+    assert!(code.contains("    pub next: Option<Box<Node>>,\n"));
+    // Mutually recursive schema fields are boxed, and the fields behind the
+    // `Vec` or the `HashMap` are not.
+    let code = gen_types_from_files(&[&file], None, Some("Tree"), &opts).unwrap();
+    assert!(code.contains("    pub tree: Box<Tree>,\n"));
+    assert!(code.contains("    pub left: Option<Box<Branch>>,\n"));
+    assert!(code.contains("    pub children: Vec<Tree>,\n"));
+    assert!(code.contains("    pub labels: HashMap<String, Tree>,\n"));
+}
+
+#[test]
+fn test_gen_types_with_same_schema_names() {
+    let file = Path::new(".")
+        .join("src")
+        .join("typegen")
+        .join("test_data")
+        .join("collision")
+        .join("main.k")
+        .display()
+        .to_string();
+    let code =
+        gen_types_from_files(&[&file], None, Some("App"), &GenTypesOptions::default()).unwrap();
+    // Schemas with the same name are prefixed with their package paths.
+    assert!(code.contains("pub struct MainConfig {\n    pub name: String,\n}"));
+    assert!(code.contains("pub struct PkgConfig {\n    pub port: i64,\n}"));
+    assert!(code.contains("    pub config: MainConfig,\n"));
+    assert!(code.contains("    pub pkg_configs: Vec<PkgConfig>,\n"));
+    assert!(!code.contains("struct Config "));
+}
@@ -0,0 +1,73 @@
+//! Generate TypeScript interfaces.
+use super::{FieldType, Literal, TypeDef, GENERATED_HEADER};
+
+pub(crate) fn gen(defs: &[TypeDef]) -> String {
+    let mut code = format!("// {}\n", GENERATED_HEADER);
+    for def in defs {
+        code.push('\n');
+        code.push_str(&js_doc(&def.doc, ""));
+        code.push_str(&format!("export interface {} {{\n", def.name));
+        for field in &def.fields {
+            code.push_str(&js_doc(&field.doc, "  "));
+            code.push_str(&format!(
+                "  {}{}: {};\n",
+                property_name(&field.name),
+                if field.optional { "?" } else { "" },
+                ts_type(&field.ty)
+            ));
+        }
+        code.push_str("}\n");
+    }
+    code
+}
+
+fn js_doc(doc: &str, indent: &str) -> String {
+    if doc.trim().is_empty() {
+        return String::new();
+    }
+    let mut out = format!("{}/**\n", indent);
+    for line in doc.lines() {
+        let line = line.replace("*/", "*\\/");
+        if line.trim().is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+/// Quote the property name when it is not a valid identifier.
+fn property_name(name: &str) -> String {
+    let is_ident = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_ident {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+fn ts_type(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Any => "any".to_string(),
+        FieldType::Bool => "boolean".to_string(),
+        FieldType::Int | FieldType::Float => "number".to_string(),
+        FieldType::Str => "string".to_string(),
+        FieldType::Literal(Literal::Bool(v)) => v.to_string(),
+        FieldType::Literal(Literal::Int(v)) => v.to_string(),
+        FieldType::Literal(Literal::Float(v)) => v.to_string(),
+        FieldType::Literal(Literal::Str(v)) => format!("{:?}", v),
+        FieldType::List(item) => match item.as_ref() {
+            FieldType::Union(_) => format!("({})[]", ts_type(item)),
+            _ => format!("{}[]", ts_type(item)),
+        },
+        FieldType::Dict(value) => format!("{{ [key: string]: {} }}", ts_type(value)),
+        FieldType::Schema(name) => name.clone(),
+        FieldType::Union(types) => types.iter().map(ts_type).collect::<Vec<_>>().join(" | "),
+    }
+}
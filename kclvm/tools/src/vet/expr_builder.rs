@@ -14,7 +14,7 @@ use anyhow::{bail, Context, Result};
 
 const FAIL_LOAD_VALIDATED_ERR_MSG: &str = "Failed to load the validated file";
 
-trait ExprGenerator<T> {
+pub(crate) trait ExprGenerator<T> {
     fn generate(&self, value: &T, schema_name: &Option<String>) -> Result<NodeRef<Expr>>;
 }
 
@@ -33,7 +33,6 @@ impl ExprBuilder {
         Ok(Self { loader })
     }
 
-    #[allow(dead_code)]
     pub(crate) fn new_with_str(kind: LoaderKind, content: String) -> Result<Self> {
         let loader = DataLoader::new_with_str(kind, &content)
             .with_context(|| format!("Failed to Parse String '{}'", content))?;
@@ -0,0 +1,23 @@
+
+import sys
+import kclvm.kcl.error as kcl_error
+import os
+
+cwd = os.path.dirname(os.path.realpath(__file__))
+
+kcl_error.print_kcl_error_message(
+    kcl_error.get_exception(
+        err_type=kcl_error.ErrType.TypeError_Compile_TYPE,
+        file_msgs=[
+            kcl_error.ErrFileMsg(
+                filename=cwd + "/main.k",
+                line_no=3,
+                col_no=1,
+                arg_msg="got str(1Ki)"
+            )
+        ],
+        arg_msg="expect number_multiplier, got str(1Ki)"
+    ),
+    file=sys.stdout
+)
+
@@ -0,0 +1,21 @@
+import sys
+import kclvm.kcl.error as kcl_error
+import os
+
+cwd = os.path.dirname(os.path.realpath(__file__))
+
+kcl_error.print_kcl_error_message(
+    kcl_error.get_exception(
+        err_type=kcl_error.ErrType.TypeError_Compile_TYPE,
+        file_msgs=[
+            kcl_error.ErrFileMsg(
+                filename=cwd + "/main.k",
+                line_no=5,
+                col_no=1,
+                arg_msg="got Page"
+            )
+        ],
+        arg_msg="expect Page[int], got Page"
+    ),
+    file=sys.stdout
+)